@@ -0,0 +1,286 @@
+use crate::numerics::vector2d::Vector2D;
+
+/// Two-dimensional tree of points with values, e.g. node IDs, for nearest neighbor and
+/// radius queries. Trees built with `from_points` are balanced; inserting afterwards keeps
+/// the queries exact but may unbalance the tree. Equally distant points are returned in the
+/// order they were added.
+#[derive(Clone, Debug)]
+pub struct KdTree<V> {
+    nodes: Vec<KdNode<V>>,
+    root: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct KdNode<V> {
+    point: Vector2D,
+    value: V,
+    /// Splitting axis, 0 for x and 1 for y.
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<V> KdTree<V> {
+    pub fn new() -> Self {
+        return Self {
+            nodes: Vec::new(),
+            root: None,
+        };
+    }
+
+    /// Balanced tree of the points, splitting at the median alternately in x and y.
+    pub fn from_points(entries: Vec<(Vector2D, V)>) -> Self {
+        let mut ret = Self {
+            nodes: entries
+                .into_iter()
+                .map(|(point, value)| KdNode {
+                    point: point,
+                    value: value,
+                    axis: 0,
+                    left: None,
+                    right: None,
+                })
+                .collect(),
+            root: None,
+        };
+
+        let mut indices: Vec<usize> = (0..ret.nodes.len()).collect();
+        ret.root = ret.build(&mut indices, 0);
+        return ret;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.nodes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.nodes.is_empty();
+    }
+
+    pub fn insert(&mut self, point: Vector2D, value: V) {
+        let index = self.nodes.len();
+        let mut parent = match self.root {
+            Some(root) => root,
+            None => {
+                self.nodes.push(KdNode {
+                    point: point,
+                    value: value,
+                    axis: 0,
+                    left: None,
+                    right: None,
+                });
+                self.root = Some(index);
+                return;
+            }
+        };
+
+        loop {
+            let node = &self.nodes[parent];
+            let goes_left = coordinate(&point, node.axis) < coordinate(&node.point, node.axis);
+            let child = if goes_left { node.left } else { node.right };
+            match child {
+                Some(child) => parent = child,
+                None => {
+                    let axis = 1 - node.axis;
+                    if goes_left {
+                        self.nodes[parent].left = Some(index);
+                    } else {
+                        self.nodes[parent].right = Some(index);
+                    }
+                    self.nodes.push(KdNode {
+                        point: point,
+                        value: value,
+                        axis: axis,
+                        left: None,
+                        right: None,
+                    });
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Closest point to point, with its value. Returns `None` if the tree is empty.
+    pub fn nearest(&self, point: &Vector2D) -> Option<(&Vector2D, &V)> {
+        return self.k_nearest(point, 1).into_iter().next();
+    }
+
+    /// Up to k closest points to point, with their values, from the closest.
+    pub fn k_nearest(&self, point: &Vector2D, k: usize) -> Vec<(&Vector2D, &V)> {
+        let mut best: Vec<(f64, usize)> = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.search_k(self.root, point, k, &mut best);
+        }
+        return best.iter().map(|(_, i)| self.entry(*i)).collect();
+    }
+
+    /// Points within radius of point, with their values, from the closest.
+    pub fn within_radius(&self, point: &Vector2D, radius: f64) -> Vec<(&Vector2D, &V)> {
+        let mut found: Vec<(f64, usize)> = Vec::new();
+        self.search_radius(self.root, point, radius * radius, &mut found);
+        found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        return found.iter().map(|(_, i)| self.entry(*i)).collect();
+    }
+
+    fn entry(&self, index: usize) -> (&Vector2D, &V) {
+        return (&self.nodes[index].point, &self.nodes[index].value);
+    }
+
+    fn build(&mut self, indices: &mut [usize], depth: usize) -> Option<usize> {
+        if indices.is_empty() {
+            return None;
+        }
+
+        let axis = depth % 2;
+        let median = indices.len() / 2;
+        let nodes = &self.nodes;
+        indices.select_nth_unstable_by(median, |a, b| {
+            return coordinate(&nodes[*a].point, axis)
+                .total_cmp(&coordinate(&nodes[*b].point, axis))
+                .then(a.cmp(b));
+        });
+
+        let index = indices[median];
+        let (left, rest) = indices.split_at_mut(median);
+        self.nodes[index].axis = axis;
+        self.nodes[index].left = self.build(left, depth + 1);
+        self.nodes[index].right = self.build(&mut rest[1..], depth + 1);
+        return Some(index);
+    }
+
+    /// Keep best as the k closest nodes seen so far as (squared distance, index), sorted.
+    /// Indices follow the order the points were added, so they break ties.
+    fn search_k(
+        &self,
+        node: Option<usize>,
+        point: &Vector2D,
+        k: usize,
+        best: &mut Vec<(f64, usize)>,
+    ) {
+        let index = match node {
+            Some(index) => index,
+            None => return,
+        };
+        let current = &self.nodes[index];
+
+        let entry = (squared_distance(&current.point, point), index);
+        let position = best.partition_point(|e| e.0 < entry.0 || (e.0 == entry.0 && e.1 < entry.1));
+        if position < k {
+            best.insert(position, entry);
+            best.truncate(k);
+        }
+
+        let difference = coordinate(point, current.axis) - coordinate(&current.point, current.axis);
+        let (near, far) = if difference < 0_f64 {
+            (current.left, current.right)
+        } else {
+            (current.right, current.left)
+        };
+        self.search_k(near, point, k, best);
+        if best.len() < k || difference * difference <= best[best.len() - 1].0 {
+            self.search_k(far, point, k, best);
+        }
+    }
+
+    fn search_radius(
+        &self,
+        node: Option<usize>,
+        point: &Vector2D,
+        squared_radius: f64,
+        found: &mut Vec<(f64, usize)>,
+    ) {
+        let index = match node {
+            Some(index) => index,
+            None => return,
+        };
+        let current = &self.nodes[index];
+
+        let distance = squared_distance(&current.point, point);
+        if distance <= squared_radius {
+            found.push((distance, index));
+        }
+
+        let difference = coordinate(point, current.axis) - coordinate(&current.point, current.axis);
+        let (near, far) = if difference < 0_f64 {
+            (current.left, current.right)
+        } else {
+            (current.right, current.left)
+        };
+        self.search_radius(near, point, squared_radius, found);
+        if difference * difference <= squared_radius {
+            self.search_radius(far, point, squared_radius, found);
+        }
+    }
+}
+
+impl<V> Default for KdTree<V> {
+    fn default() -> Self {
+        return KdTree::new();
+    }
+}
+
+fn coordinate(point: &Vector2D, axis: usize) -> f64 {
+    return if axis == 0 { point.x } else { point.y };
+}
+
+fn squared_distance(a: &Vector2D, b: &Vector2D) -> f64 {
+    let (dx, dy) = (a.x - b.x, a.y - b.y);
+    return dx * dx + dy * dy;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numerics::vector2d::Vector2D;
+
+    use super::KdTree;
+
+    #[test]
+    fn matches_brute_force() {
+        // Points of a linear congruential generator, on a coarse lattice so that ties occur.
+        let mut state: u64 = 12345;
+        let mut next = || -> f64 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            return ((state >> 33) % 40) as f64 * 0.25;
+        };
+        let points: Vec<Vector2D> = (0..300)
+            .map(|_| Vector2D::from_xy(next(), next()))
+            .collect();
+        let queries: Vec<Vector2D> = (0..50).map(|_| Vector2D::from_xy(next(), next())).collect();
+
+        let mut tree = KdTree::from_points(points[..200].iter().copied().zip(0..200).collect());
+        for (i, point) in points.iter().enumerate().skip(200) {
+            tree.insert(*point, i);
+        }
+        assert_eq!(tree.len(), 300);
+
+        for query in queries.iter() {
+            let mut expected: Vec<(f64, usize)> = points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| ((p - query).x.hypot((p - query).y), i))
+                .collect();
+            expected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+            let nearest: Vec<usize> = tree.k_nearest(query, 7).iter().map(|(_, v)| **v).collect();
+            let expected_nearest: Vec<usize> = expected.iter().take(7).map(|e| e.1).collect();
+            assert_eq!(nearest, expected_nearest);
+            assert_eq!(tree.nearest(query).map(|(_, v)| *v), Some(expected[0].1));
+
+            let within: Vec<usize> = tree
+                .within_radius(query, 1_f64)
+                .iter()
+                .map(|(_, v)| **v)
+                .collect();
+            let expected_within: Vec<usize> = expected
+                .iter()
+                .filter(|e| e.0 <= 1_f64)
+                .map(|e| e.1)
+                .collect();
+            assert_eq!(within, expected_within);
+        }
+
+        assert!(KdTree::<usize>::new().nearest(&Vector2D::zero()).is_none());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod kd_tree;
+pub mod spatial_hash_map;
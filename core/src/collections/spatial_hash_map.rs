@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::numerics::vector2i::Vector2I;
+
+static NEIGHBOR_OFFSETS: [[i64; 2]; 8] = [
+    [0, -1],
+    [1, -1],
+    [1, 0],
+    [1, 1],
+    [0, 1],
+    [-1, 1],
+    [-1, 0],
+    [-1, -1],
+];
+
+/// Sparse map from grid cells to values, e.g. visited cells of a search or points bucketed
+/// by cell. `sorted_cells` and `sorted_entries` follow the Morton order of the cells, so they
+/// are deterministic and visit nearby cells close together.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpatialHashMap<V> {
+    cells: HashMap<Vector2I, V>,
+}
+
+impl<V> SpatialHashMap<V> {
+    pub fn new() -> Self {
+        return Self {
+            cells: HashMap::new(),
+        };
+    }
+
+    pub fn len(&self) -> usize {
+        return self.cells.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cells.is_empty();
+    }
+
+    /// Returns the previous value of the cell, if any.
+    pub fn insert(&mut self, cell: Vector2I, value: V) -> Option<V> {
+        return self.cells.insert(cell, value);
+    }
+
+    pub fn get(&self, cell: &Vector2I) -> Option<&V> {
+        return self.cells.get(cell);
+    }
+
+    pub fn get_mut(&mut self, cell: &Vector2I) -> Option<&mut V> {
+        return self.cells.get_mut(cell);
+    }
+
+    /// Value of the cell, inserting one from default_fn if the cell is empty.
+    pub fn get_or_insert_with<F>(&mut self, cell: Vector2I, default_fn: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        return self.cells.entry(cell).or_insert_with(default_fn);
+    }
+
+    pub fn contains(&self, cell: &Vector2I) -> bool {
+        return self.cells.contains_key(cell);
+    }
+
+    pub fn remove(&mut self, cell: &Vector2I) -> Option<V> {
+        return self.cells.remove(cell);
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Occupied cells in Morton order. Sorts all cells on every call.
+    pub fn sorted_cells(&self) -> Vec<Vector2I> {
+        let mut ret: Vec<Vector2I> = self.cells.keys().copied().collect();
+        ret.sort_by_key(|cell| (cell.morton_code(), *cell));
+        return ret;
+    }
+
+    /// Occupied cells and their values in Morton order. Sorts all cells on every call.
+    pub fn sorted_entries(&self) -> Vec<(Vector2I, &V)> {
+        return self
+            .sorted_cells()
+            .into_iter()
+            .map(|cell| (cell, self.cells.get(&cell).unwrap()))
+            .collect();
+    }
+
+    /// Occupied cells among the 8-neighborhood of cell, clockwise from the cell below.
+    pub fn neighbors(&self, cell: &Vector2I) -> Vec<(Vector2I, &V)> {
+        return NEIGHBOR_OFFSETS
+            .iter()
+            .filter_map(|[dx, dy]| {
+                let neighbor = cell + Vector2I::from_xy(*dx, *dy);
+                return self.cells.get(&neighbor).map(|value| (neighbor, value));
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numerics::vector2i::Vector2I;
+
+    use super::SpatialHashMap;
+
+    #[test]
+    fn insert_and_query() {
+        let mut map: SpatialHashMap<&str> = SpatialHashMap::new();
+        assert_eq!(map.insert(Vector2I::from_xy(1, 1), "a"), None);
+        assert_eq!(map.insert(Vector2I::from_xy(0, 0), "b"), None);
+        assert_eq!(map.insert(Vector2I::from_xy(5, 5), "c"), None);
+        assert_eq!(map.insert(Vector2I::from_xy(1, 1), "d"), Some("a"));
+
+        assert_eq!(map.len(), 3);
+        assert!(map.contains(&Vector2I::from_xy(0, 0)));
+        assert_eq!(
+            map.sorted_cells(),
+            vec![
+                Vector2I::from_xy(0, 0),
+                Vector2I::from_xy(1, 1),
+                Vector2I::from_xy(5, 5)
+            ]
+        );
+        assert_eq!(
+            map.neighbors(&Vector2I::from_xy(1, 0)),
+            vec![
+                (Vector2I::from_xy(1, 1), &"d"),
+                (Vector2I::from_xy(0, 0), &"b")
+            ]
+        );
+
+        *map.get_or_insert_with(Vector2I::from_xy(2, 2), || "e") = "f";
+        assert_eq!(map.remove(&Vector2I::from_xy(2, 2)), Some("f"));
+    }
+}
@@ -0,0 +1,229 @@
+use std::{marker::PhantomData, ops::Index};
+
+use super::{edge::EdgeId, graph::Graph, node::NodeId};
+
+/// Identifier of a graph entity which can key an `IdMap`.
+pub trait GraphId: Copy {
+    fn from_value(value: u32) -> Self;
+
+    fn value(&self) -> u32;
+
+    /// Check whether the entity exists in the graph.
+    fn exists_in<TNodeInfo, TEdgeInfo>(&self, graph: &Graph<TNodeInfo, TEdgeInfo>) -> bool;
+
+    /// Upper bound of the IDs allocated so far by the graph.
+    fn id_bound<TNodeInfo, TEdgeInfo>(graph: &Graph<TNodeInfo, TEdgeInfo>) -> u32;
+}
+
+impl GraphId for NodeId {
+    fn from_value(value: u32) -> Self {
+        return NodeId::new(value);
+    }
+
+    fn value(&self) -> u32 {
+        return NodeId::value(self);
+    }
+
+    fn exists_in<TNodeInfo, TEdgeInfo>(&self, graph: &Graph<TNodeInfo, TEdgeInfo>) -> bool {
+        return graph.get_node_by_id(self).is_some();
+    }
+
+    fn id_bound<TNodeInfo, TEdgeInfo>(graph: &Graph<TNodeInfo, TEdgeInfo>) -> u32 {
+        return graph.id_allocators().0;
+    }
+}
+
+impl GraphId for EdgeId {
+    fn from_value(value: u32) -> Self {
+        return EdgeId::new(value);
+    }
+
+    fn value(&self) -> u32 {
+        return EdgeId::value(self);
+    }
+
+    fn exists_in<TNodeInfo, TEdgeInfo>(&self, graph: &Graph<TNodeInfo, TEdgeInfo>) -> bool {
+        return graph.get_edge_by_id(self).is_some();
+    }
+
+    fn id_bound<TNodeInfo, TEdgeInfo>(graph: &Graph<TNodeInfo, TEdgeInfo>) -> u32 {
+        return graph.id_allocators().1;
+    }
+}
+
+/// Dense secondary storage of per-entity values, indexed directly by ID.
+/// Lets algorithms attach temporary data to a graph without touching the payload types.
+/// The map remembers the graph version it was last synchronized with, so that values of
+/// entities removed in the meantime can be detected and dropped.
+#[derive(Clone, Debug)]
+pub struct IdMap<TId, T> {
+    values: Vec<Option<T>>,
+    len: usize,
+    graph_version: u64,
+    _id: PhantomData<TId>,
+}
+
+/// Values keyed by node ID.
+pub type NodeMap<T> = IdMap<NodeId, T>;
+
+/// Values keyed by edge ID.
+pub type EdgeMap<T> = IdMap<EdgeId, T>;
+
+impl<TId, T> IdMap<TId, T>
+where
+    TId: GraphId,
+{
+    /// Create empty map with room for every entity currently allocated by the graph.
+    pub fn new<TNodeInfo, TEdgeInfo>(graph: &Graph<TNodeInfo, TEdgeInfo>) -> Self {
+        let mut values: Vec<Option<T>> = Vec::new();
+        values.resize_with(TId::id_bound(graph) as usize, || None);
+
+        return Self {
+            values: values,
+            len: 0,
+            graph_version: graph.version(),
+            _id: PhantomData,
+        };
+    }
+
+    /// Create map holding a copy of the value for every entity of the graph.
+    pub fn with_value<TNodeInfo, TEdgeInfo>(graph: &Graph<TNodeInfo, TEdgeInfo>, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut ret: Self = IdMap::new(graph);
+
+        for i in 0..ret.values.len() {
+            if TId::from_value(i as u32).exists_in(graph) {
+                ret.values[i] = Some(value.clone());
+                ret.len += 1;
+            }
+        }
+
+        return ret;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    pub fn contains_key(&self, id: &TId) -> bool {
+        return self.get(id).is_some();
+    }
+
+    pub fn get(&self, id: &TId) -> Option<&T> {
+        return self.values.get(id.value() as usize)?.as_ref();
+    }
+
+    pub fn get_mut(&mut self, id: &TId) -> Option<&mut T> {
+        return self.values.get_mut(id.value() as usize)?.as_mut();
+    }
+
+    /// Set value of entity, returning its previous value. Grows the map if needed.
+    pub fn insert(&mut self, id: TId, value: T) -> Option<T> {
+        let index = id.value() as usize;
+        if index >= self.values.len() {
+            self.values.resize_with(index + 1, || None);
+        }
+
+        let ret = self.values[index].replace(value);
+        if ret.is_none() {
+            self.len += 1;
+        }
+
+        return ret;
+    }
+
+    pub fn remove(&mut self, id: &TId) -> Option<T> {
+        let ret = self.values.get_mut(id.value() as usize)?.take();
+        if ret.is_some() {
+            self.len -= 1;
+        }
+
+        return ret;
+    }
+
+    /// Iterate over entries in order of their IDs.
+    pub fn iter(&self) -> impl Iterator<Item = (TId, &T)> + '_ {
+        return self
+            .values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.as_ref().map(|v| (TId::from_value(i as u32), v)));
+    }
+
+    /// Check whether entities have been added to or removed from the graph since the map was
+    /// created or last synchronized.
+    pub fn is_stale<TNodeInfo, TEdgeInfo>(&self, graph: &Graph<TNodeInfo, TEdgeInfo>) -> bool {
+        return self.graph_version != graph.version();
+    }
+
+    /// Drop values of entities which no longer exist in the graph and make room for new ones.
+    pub fn sync<TNodeInfo, TEdgeInfo>(&mut self, graph: &Graph<TNodeInfo, TEdgeInfo>) {
+        let id_bound = TId::id_bound(graph) as usize;
+        if id_bound > self.values.len() {
+            self.values.resize_with(id_bound, || None);
+        }
+
+        for i in 0..self.values.len() {
+            if self.values[i].is_some() && !TId::from_value(i as u32).exists_in(graph) {
+                self.values[i] = None;
+                self.len -= 1;
+            }
+        }
+
+        self.graph_version = graph.version();
+    }
+}
+
+impl<TId, T> Index<TId> for IdMap<TId, T>
+where
+    TId: GraphId,
+{
+    type Output = T;
+
+    fn index(&self, index: TId) -> &Self::Output {
+        return self.get(&index).expect("No value for ID in map.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::Graph;
+
+    use super::{EdgeMap, NodeMap};
+
+    #[test]
+    fn attach_values_to_entities() {
+        let mut graph: Graph<(), ()> = Graph::new(false, false);
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let e1 = graph.add_edge(n1, n2, ()).unwrap();
+
+        let mut depths: NodeMap<usize> = NodeMap::new(&graph);
+        for step in graph.bfs(n1) {
+            depths.insert(step.node_id, step.depth);
+        }
+        let mut colors: EdgeMap<char> = EdgeMap::with_value(&graph, 'r');
+        *colors.get_mut(&e1).unwrap() = 'g';
+
+        assert_eq!(depths[n2], 1);
+        assert_eq!(colors.iter().collect::<Vec<_>>(), vec![(e1, &'g')]);
+        assert!(!depths.is_stale(&graph));
+
+        graph.remove_node(&n2).unwrap();
+        let n3 = graph.add_node(());
+        assert!(depths.is_stale(&graph) && colors.is_stale(&graph));
+
+        depths.sync(&graph);
+        colors.sync(&graph);
+        depths.insert(n3, 0);
+        assert_eq!(depths.len(), 2);
+        assert!(!depths.contains_key(&n2));
+        assert!(colors.is_empty());
+    }
+}
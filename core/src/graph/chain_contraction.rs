@@ -0,0 +1,127 @@
+use super::{
+    edge::{Edge, EdgeId},
+    graph::Graph,
+    node::NodeId,
+};
+
+/// Edge of a chain handed to the merge function of `Graph::contract_chains`.
+pub struct ChainLink<TEdgeInfo> {
+    pub edge_info: TEdgeInfo,
+
+    /// Whether the edge runs from `node2` to `node1` when following the chain.
+    pub is_reversed: bool,
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Collapse chains of degree-2 nodes into single edges, returning the number of removed nodes.
+    /// For a node `v` between edges to `a` and `b`, both edges are replaced by an edge from `a` to
+    /// `b` whose payload is `merge_fn(link from a to v, link from v to b)`, and `v` is removed.
+    /// The new edge can be traversed in a direction only if both replaced edges could.
+    /// Nodes are not removed if that would leave no traversable direction, create a self-loop, or
+    /// add an edge the graph options forbid.
+    pub fn contract_chains<F>(&mut self, merge_fn: F) -> usize
+    where
+        F: Fn(ChainLink<TEdgeInfo>, ChainLink<TEdgeInfo>) -> TEdgeInfo,
+    {
+        let mut node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        node_ids.sort();
+        let mut removed_count: usize = 0;
+
+        for node_id in node_ids {
+            let node = self.get_node_by_id(&node_id).unwrap();
+            if node.degree() != 2 {
+                continue;
+            }
+
+            let mut links: Vec<(EdgeId, NodeId)> = node
+                .connected_edges()
+                .iter()
+                .map(|(edge_id, neighbor_id)| (*edge_id, *neighbor_id))
+                .collect();
+            links.sort();
+            let (edge1_id, a) = links[0];
+            let (edge2_id, b) = links[1];
+
+            if a == node_id || b == node_id || a == b {
+                continue;
+            }
+
+            let edge1 = self.get_edge_by_id(&edge1_id).unwrap();
+            let edge2 = self.get_edge_by_id(&edge2_id).unwrap();
+            let can_move_forward = can_move(edge1, a) && can_move(edge2, node_id);
+            let can_move_backward = can_move(edge2, b) && can_move(edge1, node_id);
+
+            if (!can_move_forward && !can_move_backward) || self.check_new_edge(a, b).is_err() {
+                continue;
+            }
+
+            let edge1 = self.take_edge(&edge1_id).unwrap();
+            let edge2 = self.take_edge(&edge2_id).unwrap();
+            let link1 = ChainLink {
+                is_reversed: edge1.node1() != a,
+                edge_info: edge1.into_edge_info(),
+            };
+            let link2 = ChainLink {
+                is_reversed: edge2.node1() != node_id,
+                edge_info: edge2.into_edge_info(),
+            };
+
+            self.remove_node(&node_id).unwrap();
+            self.add_directed_edge(
+                a,
+                b,
+                can_move_forward,
+                can_move_backward,
+                (merge_fn)(link1, link2),
+            )
+            .unwrap();
+            removed_count += 1;
+        }
+
+        return removed_count;
+    }
+}
+
+/// Check whether edge can be traversed away from the given end.
+fn can_move<TEdgeInfo>(edge: &Edge<TEdgeInfo>, from: NodeId) -> bool {
+    return (edge.node1() == from && edge.can_move_forward())
+        || (edge.node2() == from && edge.can_move_backward());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn collapses_chains() {
+        let mut graph: Graph<(), Vec<u32>> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..6).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], vec![1]).unwrap();
+        graph.add_edge(n[2], n[1], vec![2]).unwrap();
+        graph.add_edge(n[2], n[3], vec![3]).unwrap();
+        graph.add_edge(n[3], n[4], vec![4]).unwrap();
+        graph.add_edge(n[3], n[5], vec![5]).unwrap();
+
+        let removed = graph.contract_chains(|l1, l2| {
+            let mut segments = l1.edge_info;
+            if l1.is_reversed {
+                segments.reverse();
+            }
+
+            let mut rest = l2.edge_info;
+            if l2.is_reversed {
+                rest.reverse();
+            }
+            segments.extend(rest);
+            return segments;
+        });
+
+        assert_eq!(removed, 2);
+        assert_eq!(graph.get_node_count(), 4);
+        assert_eq!(graph.get_edge_count(), 3);
+
+        let edge = graph.edges_of(&n[0]).next().unwrap();
+        assert_eq!((edge.node1(), edge.node2()), (n[3], n[0]));
+        assert_eq!(*edge.edge_info(), vec![3, 2, 1]);
+    }
+}
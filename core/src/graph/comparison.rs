@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use super::{
+    edge::{Edge, EdgeId},
+    graph::Graph,
+    node::{Node, NodeId},
+};
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Check whether two graphs have the same structure and equivalent payloads.
+    /// Entities are paired in order of their IDs, so graphs built by the same sequence of
+    /// operations compare equal even if their IDs are offset; this is not an isomorphism test.
+    /// Paired edges must connect paired nodes with the same orientation and directions.
+    /// Payloads are compared by `node_eq` and `edge_eq`, which may apply a tolerance.
+    pub fn structurally_equal<NEq, EEq>(
+        &self,
+        other: &Graph<TNodeInfo, TEdgeInfo>,
+        node_eq: NEq,
+        edge_eq: EEq,
+    ) -> bool
+    where
+        NEq: Fn(&TNodeInfo, &TNodeInfo) -> bool,
+        EEq: Fn(&TEdgeInfo, &TEdgeInfo) -> bool,
+    {
+        if self.get_node_count() != other.get_node_count()
+            || self.get_edge_count() != other.get_edge_count()
+        {
+            return false;
+        }
+
+        let mut node_id_map: HashMap<NodeId, NodeId> = HashMap::new();
+        for (n1, n2) in sorted_nodes(self).iter().zip(sorted_nodes(other).iter()) {
+            if !(node_eq)(n1.node_info(), n2.node_info()) {
+                return false;
+            }
+
+            node_id_map.insert(n1.get_id(), n2.get_id());
+        }
+
+        for (e1, e2) in sorted_edges(self).iter().zip(sorted_edges(other).iter()) {
+            if node_id_map[&e1.node1()] != e2.node1()
+                || node_id_map[&e1.node2()] != e2.node2()
+                || e1.can_move_forward() != e2.can_move_forward()
+                || e1.can_move_backward() != e2.can_move_backward()
+                || !(edge_eq)(e1.edge_info(), e2.edge_info())
+            {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
+
+fn sorted_nodes<TNodeInfo, TEdgeInfo>(
+    graph: &Graph<TNodeInfo, TEdgeInfo>,
+) -> Vec<&Node<TNodeInfo>> {
+    let mut ret: Vec<&Node<TNodeInfo>> = graph.get_nodes().values().collect();
+    ret.sort_by(|n1, n2| NodeId::cmp(&n1.get_id(), &n2.get_id()));
+    return ret;
+}
+
+fn sorted_edges<TNodeInfo, TEdgeInfo>(
+    graph: &Graph<TNodeInfo, TEdgeInfo>,
+) -> Vec<&Edge<TEdgeInfo>> {
+    let mut ret: Vec<&Edge<TEdgeInfo>> = graph.get_edges().values().collect();
+    ret.sort_by(|e1, e2| EdgeId::cmp(&e1.get_id(), &e2.get_id()));
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::Graph;
+
+    fn build_graph(offset: usize, weight: f64) -> Graph<f64, f64> {
+        let mut graph: Graph<f64, f64> = Graph::new(false, false);
+        for _ in 0..offset {
+            let id = graph.add_node(0_f64);
+            graph.remove_node(&id).unwrap();
+        }
+
+        let n1 = graph.add_node(1_f64);
+        let n2 = graph.add_node(2_f64);
+        graph
+            .add_directed_edge(n1, n2, true, false, weight)
+            .unwrap();
+        return graph;
+    }
+
+    #[test]
+    fn compares_with_tolerance() {
+        let approx_eq = |a: &f64, b: &f64| (a - b).abs() < 1e-6;
+        let graph = build_graph(0, 1_f64);
+
+        assert!(graph.structurally_equal(&build_graph(3, 1_f64 + 1e-9), approx_eq, approx_eq));
+        assert!(!graph.structurally_equal(&build_graph(0, 1.1), approx_eq, approx_eq));
+
+        let mut reversed: Graph<f64, f64> = Graph::new(false, false);
+        let n1 = reversed.add_node(1_f64);
+        let n2 = reversed.add_node(2_f64);
+        reversed
+            .add_directed_edge(n2, n1, false, true, 1_f64)
+            .unwrap();
+        assert!(!graph.structurally_equal(&reversed, approx_eq, approx_eq));
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+
+use super::{graph::Graph, node::NodeId};
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Assign a component index to each node, ignoring edge directions.
+    /// For graphs with one-way edges these are the weakly connected components.
+    /// Components are numbered from 0 in order of their lowest node ID.
+    pub fn connected_components(&self) -> HashMap<NodeId, usize> {
+        let mut ret: HashMap<NodeId, usize> = HashMap::new();
+        let mut node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        node_ids.sort();
+
+        let mut component_count: usize = 0;
+        for node_id in node_ids {
+            if ret.contains_key(&node_id) {
+                continue;
+            }
+
+            let mut queue: VecDeque<NodeId> = VecDeque::new();
+            ret.insert(node_id, component_count);
+            queue.push_back(node_id);
+
+            while let Some(_node_id) = queue.pop_front() {
+                for neighbor_id in self.neighbors(&_node_id) {
+                    if let Entry::Vacant(e) = ret.entry(neighbor_id) {
+                        e.insert(component_count);
+                        queue.push_back(neighbor_id);
+                    }
+                }
+            }
+
+            component_count += 1;
+        }
+
+        return ret;
+    }
+
+    /// Assign a component index to each node, such that nodes share an index exactly when each is
+    /// reachable from the other along the directions the edges allow (Tarjan's algorithm).
+    /// Components are numbered from 0 in order of their lowest node ID.
+    pub fn strongly_connected_components(&self) -> HashMap<NodeId, usize> {
+        let mut state = TarjanState {
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            call_stack: Vec::new(),
+        };
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+
+        let mut node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        node_ids.sort();
+
+        for start in node_ids {
+            if state.indices.contains_key(&start) {
+                continue;
+            }
+
+            state.visit(self, start);
+
+            while let Some((node_id, successors, next)) = state.call_stack.last_mut() {
+                let node_id = *node_id;
+
+                if *next < successors.len() {
+                    let successor = successors[*next];
+                    *next += 1;
+
+                    if !state.indices.contains_key(&successor) {
+                        state.visit(self, successor);
+                    } else if state.on_stack.contains(&successor) {
+                        state.lower_low_link(node_id, state.indices[&successor]);
+                    }
+
+                    continue;
+                }
+
+                state.call_stack.pop();
+
+                if state.low_links[&node_id] == state.indices[&node_id] {
+                    let mut component: Vec<NodeId> = Vec::new();
+
+                    loop {
+                        let member = state.stack.pop().unwrap();
+                        state.on_stack.remove(&member);
+                        component.push(member);
+
+                        if member == node_id {
+                            break;
+                        }
+                    }
+
+                    components.push(component);
+                }
+
+                if let Some((parent_id, _, _)) = state.call_stack.last() {
+                    state.lower_low_link(*parent_id, state.low_links[&node_id]);
+                }
+            }
+        }
+
+        components.sort_by_key(|c| *c.iter().min().unwrap());
+
+        let mut ret: HashMap<NodeId, usize> = HashMap::new();
+        for (i, component) in components.into_iter().enumerate() {
+            for node_id in component {
+                ret.insert(node_id, i);
+            }
+        }
+
+        return ret;
+    }
+
+    /// Check whether every node is reachable from every other node.
+    pub fn is_strongly_connected(&self) -> bool {
+        return self
+            .strongly_connected_components()
+            .values()
+            .all(|c| *c == 0);
+    }
+
+    /// Number of connected components, ignoring edge directions.
+    pub fn connected_component_count(&self) -> usize {
+        return self
+            .connected_components()
+            .values()
+            .max()
+            .map_or(0, |c| c + 1);
+    }
+}
+
+/// Bookkeeping of Tarjan's algorithm, run without recursion.
+struct TarjanState {
+    indices: HashMap<NodeId, usize>,
+    low_links: HashMap<NodeId, usize>,
+    stack: Vec<NodeId>,
+    on_stack: HashSet<NodeId>,
+
+    /// Explicit call stack of (node ID, successors, index of next successor to visit).
+    call_stack: Vec<(NodeId, Vec<NodeId>, usize)>,
+}
+
+impl TarjanState {
+    fn visit<TNodeInfo, TEdgeInfo>(
+        &mut self,
+        graph: &Graph<TNodeInfo, TEdgeInfo>,
+        node_id: NodeId,
+    ) {
+        let index = self.indices.len();
+        self.indices.insert(node_id, index);
+        self.low_links.insert(node_id, index);
+        self.stack.push(node_id);
+        self.on_stack.insert(node_id);
+
+        let successors: Vec<NodeId> = graph
+            .traversable_edges(node_id)
+            .into_iter()
+            .map(|(_, neighbor_id)| neighbor_id)
+            .collect();
+        self.call_stack.push((node_id, successors, 0));
+    }
+
+    fn lower_low_link(&mut self, node_id: NodeId, value: usize) {
+        let low_link = self.low_links.get_mut(&node_id).unwrap();
+        *low_link = usize::min(*low_link, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn components_ignore_direction() {
+        let mut graph: Graph<(), ()> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..5).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph
+            .add_directed_edge(n[2], n[1], true, false, ())
+            .unwrap();
+        graph.add_edge(n[3], n[4], ()).unwrap();
+
+        let components = graph.connected_components();
+        assert_eq!(graph.connected_component_count(), 2);
+        assert_eq!(components[&n[0]], 0);
+        assert_eq!(components[&n[2]], 0);
+        assert_eq!(components[&n[3]], 1);
+        assert_eq!(components[&n[4]], 1);
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let mut graph: Graph<(), ()> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..5).map(|_| graph.add_node(())).collect();
+        for (i, j) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)] {
+            graph
+                .add_directed_edge(n[i], n[j], true, false, ())
+                .unwrap();
+        }
+
+        let components = graph.strongly_connected_components();
+        assert_eq!(components[&n[0]], 0);
+        assert_eq!(components[&n[1]], 0);
+        assert_eq!(components[&n[2]], 0);
+        assert_eq!(components[&n[3]], 1);
+        assert_eq!(components[&n[4]], 2);
+        assert!(!graph.is_strongly_connected());
+
+        graph
+            .add_directed_edge(n[4], n[0], true, false, ())
+            .unwrap();
+        assert!(graph.is_strongly_connected());
+    }
+}
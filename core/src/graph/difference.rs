@@ -0,0 +1,183 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    edge::{Edge, EdgeId},
+    graph::Graph,
+    node::NodeId,
+};
+
+/// Result of `Graph::difference`. All lists are in order of the IDs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphDifference {
+    /// Nodes of this graph without a match in the other graph.
+    pub removed_nodes: Vec<NodeId>,
+
+    /// Nodes of the other graph without a match in this graph.
+    pub added_nodes: Vec<NodeId>,
+
+    /// Edges of this graph without a match in the other graph.
+    pub removed_edges: Vec<EdgeId>,
+
+    /// Edges of the other graph without a match in this graph.
+    pub added_edges: Vec<EdgeId>,
+
+    /// Node IDs of this graph mapped to the IDs of their matches in the other graph.
+    pub matched_nodes: HashMap<NodeId, NodeId>,
+}
+
+impl GraphDifference {
+    pub fn is_empty(&self) -> bool {
+        return self.removed_nodes.is_empty()
+            && self.added_nodes.is_empty()
+            && self.removed_edges.is_empty()
+            && self.added_edges.is_empty();
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Compare this graph to another one, e.g. the topology of a later mapping session.
+    /// Each node of this graph is matched to the first unmatched node of the other graph, in order
+    /// of the IDs, for which `node_matcher(this, other)` holds. Edges match if they connect
+    /// matched nodes, allow the same directions between them and `edge_matcher` holds.
+    pub fn difference<NM, EM>(
+        &self,
+        other: &Graph<TNodeInfo, TEdgeInfo>,
+        node_matcher: NM,
+        edge_matcher: EM,
+    ) -> GraphDifference
+    where
+        NM: Fn(&TNodeInfo, &TNodeInfo) -> bool,
+        EM: Fn(&TEdgeInfo, &TEdgeInfo) -> bool,
+    {
+        let mut ret = GraphDifference {
+            removed_nodes: Vec::new(),
+            added_nodes: Vec::new(),
+            removed_edges: Vec::new(),
+            added_edges: Vec::new(),
+            matched_nodes: HashMap::new(),
+        };
+
+        let mut own_node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        let mut other_node_ids: Vec<NodeId> = other.get_nodes().keys().copied().collect();
+        own_node_ids.sort();
+        other_node_ids.sort();
+        let mut matched_other_node_ids: HashSet<NodeId> = HashSet::new();
+
+        for node_id in own_node_ids {
+            let node_info = self.get_node_by_id(&node_id).unwrap().node_info();
+            let matched_node_id = other_node_ids.iter().copied().find(|id| {
+                !matched_other_node_ids.contains(id)
+                    && (node_matcher)(node_info, other.get_node_by_id(id).unwrap().node_info())
+            });
+
+            match matched_node_id {
+                Some(id) => {
+                    matched_other_node_ids.insert(id);
+                    ret.matched_nodes.insert(node_id, id);
+                }
+                None => ret.removed_nodes.push(node_id),
+            }
+        }
+
+        for node_id in other_node_ids {
+            if !matched_other_node_ids.contains(&node_id) {
+                ret.added_nodes.push(node_id);
+            }
+        }
+
+        let mut own_edge_ids: Vec<EdgeId> = self.get_edges().keys().copied().collect();
+        own_edge_ids.sort();
+        let mut matched_other_edge_ids: HashSet<EdgeId> = HashSet::new();
+
+        for edge_id in own_edge_ids {
+            let edge = self.get_edge_by_id(&edge_id).unwrap();
+            let (n1, n2) = match (
+                ret.matched_nodes.get(&edge.node1()),
+                ret.matched_nodes.get(&edge.node2()),
+            ) {
+                (Some(n1), Some(n2)) => (*n1, *n2),
+                _ => {
+                    ret.removed_edges.push(edge_id);
+                    continue;
+                }
+            };
+
+            let matched_edge = other.edges_between(&n1, &n2).into_iter().find(|e| {
+                !matched_other_edge_ids.contains(&e.get_id())
+                    && same_directions(edge, e, n1)
+                    && (edge_matcher)(edge.edge_info(), e.edge_info())
+            });
+
+            match matched_edge {
+                Some(e) => {
+                    matched_other_edge_ids.insert(e.get_id());
+                }
+                None => ret.removed_edges.push(edge_id),
+            }
+        }
+
+        let mut other_edge_ids: Vec<EdgeId> = other.get_edges().keys().copied().collect();
+        other_edge_ids.sort();
+        for edge_id in other_edge_ids {
+            if !matched_other_edge_ids.contains(&edge_id) {
+                ret.added_edges.push(edge_id);
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Check whether edges allow the same directions, given the node the first edge's `node1`
+/// was matched to.
+fn same_directions<TEdgeInfo>(
+    edge: &Edge<TEdgeInfo>,
+    other_edge: &Edge<TEdgeInfo>,
+    matched_node1: NodeId,
+) -> bool {
+    let (forward, backward) = if other_edge.node1() == matched_node1 {
+        (
+            other_edge.can_move_forward(),
+            other_edge.can_move_backward(),
+        )
+    } else {
+        (
+            other_edge.can_move_backward(),
+            other_edge.can_move_forward(),
+        )
+    };
+
+    return edge.can_move_forward() == forward && edge.can_move_backward() == backward;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::Graph;
+
+    #[test]
+    fn reports_changes() {
+        let mut before: Graph<char, ()> = Graph::new(false, false);
+        let a = before.add_node('a');
+        let b = before.add_node('b');
+        let c = before.add_node('c');
+        before.add_edge(a, b, ()).unwrap();
+        let removed_edge = before.add_edge(b, c, ()).unwrap();
+
+        let mut after: Graph<char, ()> = Graph::new(false, false);
+        let d = after.add_node('d');
+        let b2 = after.add_node('b');
+        let a2 = after.add_node('a');
+        after.add_edge(b2, a2, ()).unwrap();
+        let added_edge = after.add_edge(a2, d, ()).unwrap();
+
+        let diff = before.difference(&after, |n1, n2| n1 == n2, |_, _| true);
+        assert_eq!(diff.removed_nodes, vec![c]);
+        assert_eq!(diff.added_nodes, vec![d]);
+        assert_eq!(diff.removed_edges, vec![removed_edge]);
+        assert_eq!(diff.added_edges, vec![added_edge]);
+        assert_eq!(diff.matched_nodes[&a], a2);
+        assert!(before
+            .difference(&before, |n1, n2| n1 == n2, |_, _| true)
+            .is_empty());
+    }
+}
@@ -1,8 +1,50 @@
+use std::fmt::Display;
+
+use super::node::NodeId;
+
+/// Handle of an edge within a graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct EdgeId(u32);
+
+impl EdgeId {
+    pub fn new(value: u32) -> Self {
+        return Self(value);
+    }
+
+    pub fn value(&self) -> u32 {
+        return self.0;
+    }
+}
+
+impl From<u32> for EdgeId {
+    fn from(value: u32) -> Self {
+        return Self(value);
+    }
+}
+
+impl From<EdgeId> for u32 {
+    fn from(value: EdgeId) -> Self {
+        return value.0;
+    }
+}
+
+impl Display for EdgeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "e{}", self.0);
+    }
+}
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edge<TEdgeInfo> {
-    id: u32,
-    node1: u32,
-    node2: u32,
+    id: EdgeId,
+    node1: NodeId,
+    node2: NodeId,
     can_move_forward: bool,
     can_move_backward: bool,
     edge_info: TEdgeInfo,
@@ -10,9 +52,9 @@ pub struct Edge<TEdgeInfo> {
 
 impl<TEdgeInfo> Edge<TEdgeInfo> {
     pub fn new(
-        id: u32,
-        node1: u32,
-        node2: u32,
+        id: EdgeId,
+        node1: NodeId,
+        node2: NodeId,
         can_move_forward: bool,
         can_move_backward: bool,
         edge_info: TEdgeInfo,
@@ -27,15 +69,15 @@ impl<TEdgeInfo> Edge<TEdgeInfo> {
         };
     }
 
-    pub fn get_id(&self) -> u32 {
+    pub fn get_id(&self) -> EdgeId {
         return self.id;
     }
 
-    pub fn node1(&self) -> u32 {
+    pub fn node1(&self) -> NodeId {
         return self.node1;
     }
 
-    pub fn node2(&self) -> u32 {
+    pub fn node2(&self) -> NodeId {
         return self.node2;
     }
 
@@ -54,4 +96,8 @@ impl<TEdgeInfo> Edge<TEdgeInfo> {
     pub fn edge_info_mut(&mut self) -> &mut TEdgeInfo {
         return &mut self.edge_info;
     }
+
+    pub fn into_edge_info(self) -> TEdgeInfo {
+        return self.edge_info;
+    }
 }
@@ -0,0 +1,11 @@
+/// Cost of traversing an edge, used by the graph algorithms which take no cost function.
+/// Weights must not be negative.
+pub trait EdgeWeight {
+    fn weight(&self) -> f64;
+}
+
+impl EdgeWeight for f64 {
+    fn weight(&self) -> f64 {
+        return *self;
+    }
+}
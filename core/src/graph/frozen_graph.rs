@@ -0,0 +1,230 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+use super::{
+    edge::{Edge, EdgeId},
+    edge_weight::EdgeWeight,
+    graph::Graph,
+    graph_error::GraphError,
+    node::NodeId,
+    path_result::PathResult,
+};
+
+/// Immutable snapshot of a graph in compressed sparse row layout, created by `Graph::freeze`.
+/// Nodes are stored by dense index in order of their IDs, and the traversable directions of all
+/// edges as arcs with precomputed costs in one contiguous array, grouped by their source node.
+/// Repeated queries on a static topology avoid hashing and pointer chasing.
+#[derive(Clone, Debug)]
+pub struct FrozenGraph {
+    node_ids: Vec<NodeId>,
+    node_indices: HashMap<NodeId, u32>,
+
+    /// Arcs leaving the node with index i are stored in `arc_offsets[i]..arc_offsets[i + 1]`.
+    arc_offsets: Vec<usize>,
+    arc_targets: Vec<u32>,
+    arc_edges: Vec<EdgeId>,
+    arc_costs: Vec<f64>,
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Create a frozen snapshot, evaluating the cost of every edge once.
+    /// Edges become one arc per direction they can be traversed in, at the same cost.
+    pub fn freeze<F>(&self, edge_cost_fn: F) -> FrozenGraph
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        let mut node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        node_ids.sort();
+        let node_indices: HashMap<NodeId, u32> = node_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, i as u32))
+            .collect();
+
+        let mut ret = FrozenGraph {
+            node_ids: Vec::new(),
+            node_indices: HashMap::new(),
+            arc_offsets: Vec::with_capacity(node_ids.len() + 1),
+            arc_targets: Vec::new(),
+            arc_edges: Vec::new(),
+            arc_costs: Vec::new(),
+        };
+        ret.arc_offsets.push(0);
+
+        for node_id in node_ids.iter() {
+            for (edge_id, neighbor_id) in self.traversable_edges(*node_id) {
+                ret.arc_targets.push(node_indices[&neighbor_id]);
+                ret.arc_edges.push(edge_id);
+                ret.arc_costs
+                    .push((edge_cost_fn)(self.get_edge_by_id(&edge_id).unwrap()));
+            }
+
+            ret.arc_offsets.push(ret.arc_targets.len());
+        }
+
+        ret.node_ids = node_ids;
+        ret.node_indices = node_indices;
+        return ret;
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo>
+where
+    TEdgeInfo: EdgeWeight,
+{
+    /// Create a frozen snapshot with the edge weights as costs.
+    pub fn freeze_by_weight(&self) -> FrozenGraph {
+        return self.freeze(|e| e.edge_info().weight());
+    }
+}
+
+impl FrozenGraph {
+    pub fn get_node_count(&self) -> usize {
+        return self.node_ids.len();
+    }
+
+    /// Number of arcs, i.e. traversable edge directions.
+    pub fn get_arc_count(&self) -> usize {
+        return self.arc_targets.len();
+    }
+
+    /// Iterate over the arcs leaving node as (edge ID, neighbor ID, cost), in order of edge IDs.
+    /// Yields nothing if the node does not exist.
+    pub fn successors(&self, node_id: &NodeId) -> impl Iterator<Item = (EdgeId, NodeId, f64)> + '_ {
+        let arcs = match self.node_indices.get(node_id) {
+            Some(i) => self.arcs_of(*i),
+            None => 0..0,
+        };
+
+        return arcs.map(|a| {
+            (
+                self.arc_edges[a],
+                self.node_ids[self.arc_targets[a] as usize],
+                self.arc_costs[a],
+            )
+        });
+    }
+
+    /// Find the cheapest path between two nodes using Dijkstra's algorithm over the arc arrays.
+    /// Returns the path, including both ends, with its edges and total cost.
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Result<PathResult, GraphError> {
+        let (from_index, to_index) =
+            match (self.node_indices.get(&from), self.node_indices.get(&to)) {
+                (Some(f), Some(t)) => (*f, *t),
+                _ => return Err(GraphError::NoSuchNode),
+            };
+
+        let mut costs: Vec<f64> = vec![f64::INFINITY; self.node_ids.len()];
+        let mut prev_indices: Vec<u32> = vec![u32::MAX; self.node_ids.len()];
+        let mut prev_arcs: Vec<usize> = vec![usize::MAX; self.node_ids.len()];
+        let mut queue: BinaryHeap<ArcQueueEntry> = BinaryHeap::new();
+        costs[from_index as usize] = 0_f64;
+        queue.push(ArcQueueEntry {
+            cost: 0_f64,
+            index: from_index,
+        });
+
+        while let Some(ArcQueueEntry { cost, index }) = queue.pop() {
+            if index == to_index {
+                let mut nodes: Vec<NodeId> = vec![to];
+                let mut edges: Vec<EdgeId> = Vec::new();
+                let mut _index = index;
+
+                while _index != from_index {
+                    edges.push(self.arc_edges[prev_arcs[_index as usize]]);
+                    _index = prev_indices[_index as usize];
+                    nodes.push(self.node_ids[_index as usize]);
+                }
+
+                nodes.reverse();
+                edges.reverse();
+                return Ok(PathResult {
+                    nodes: nodes,
+                    edges: edges,
+                    cost: cost,
+                });
+            }
+
+            if cost > costs[index as usize] {
+                continue;
+            }
+
+            for a in self.arcs_of(index) {
+                let target = self.arc_targets[a];
+                let next_cost = cost + self.arc_costs[a];
+
+                if next_cost < costs[target as usize] {
+                    costs[target as usize] = next_cost;
+                    prev_indices[target as usize] = index;
+                    prev_arcs[target as usize] = a;
+                    queue.push(ArcQueueEntry {
+                        cost: next_cost,
+                        index: target,
+                    });
+                }
+            }
+        }
+
+        return Err(GraphError::Unreachable);
+    }
+
+    fn arcs_of(&self, index: u32) -> std::ops::Range<usize> {
+        return self.arc_offsets[index as usize]..self.arc_offsets[index as usize + 1];
+    }
+}
+
+/// Min-heap entry ordered by cost, then by node index.
+struct ArcQueueEntry {
+    cost: f64,
+    index: u32,
+}
+
+impl PartialEq for ArcQueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for ArcQueueEntry {}
+
+impl PartialOrd for ArcQueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for ArcQueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return f64::total_cmp(&other.cost, &self.cost).then_with(|| other.index.cmp(&self.index));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, graph_error::GraphError, node::NodeId};
+
+    #[test]
+    fn frozen_paths_match_graph_paths() {
+        let mut graph: Graph<(usize, usize), f64> =
+            Graph::grid(6, 6, |r, c| (r, c), |n1, n2| 1_f64 + (n1.0 * n2.1) as f64);
+        let a = graph.add_node((9, 9));
+        let b = graph.add_node((9, 9));
+        graph.add_directed_edge(a, b, true, false, 1_f64).unwrap();
+
+        let frozen = graph.freeze_by_weight();
+        assert_eq!(frozen.get_node_count(), 38);
+        assert_eq!(frozen.get_arc_count(), 2 * 60 + 1);
+        assert_eq!(frozen.successors(&b).count(), 0);
+
+        let ids: Vec<NodeId> = graph.get_nodes().keys().copied().collect();
+        for from in ids.iter() {
+            for to in ids.iter() {
+                let expected = graph.shortest_path_by_weight(*from, *to).map(|p| p.cost);
+                assert_eq!(frozen.shortest_path(*from, *to).map(|p| p.cost), expected);
+            }
+        }
+        assert_eq!(frozen.shortest_path(b, a), Err(GraphError::Unreachable));
+    }
+}
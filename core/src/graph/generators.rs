@@ -0,0 +1,170 @@
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+#[cfg(feature = "rand")]
+use crate::numerics::{vector::Vector, vector2d::Vector2D};
+
+use super::{graph::Graph, node::NodeId};
+
+/// Standard graphs for tests and benchmarks. Payloads are created by user factories; edge
+/// factories receive the payloads of both ends. Generated graphs are simple and undirected, and
+/// random generators draw from the given generator only, so a seeded generator reproduces them.
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Build a 4-connected grid of `rows` by `cols` nodes, added row by row.
+    /// The node factory receives the (row, column) of each node.
+    pub fn grid<NF, EF>(
+        rows: usize,
+        cols: usize,
+        mut node_factory: NF,
+        mut edge_factory: EF,
+    ) -> Self
+    where
+        NF: FnMut(usize, usize) -> TNodeInfo,
+        EF: FnMut(&TNodeInfo, &TNodeInfo) -> TEdgeInfo,
+    {
+        let mut ret: Self = Graph::with_capacity(rows * cols, 2 * rows * cols);
+        let mut node_ids: Vec<NodeId> = Vec::with_capacity(rows * cols);
+
+        for r in 0..rows {
+            for c in 0..cols {
+                node_ids.push(ret.add_node((node_factory)(r, c)));
+            }
+        }
+
+        for r in 0..rows {
+            for c in 0..cols {
+                let node_id = node_ids[r * cols + c];
+                if c + 1 < cols {
+                    ret.add_generated_edge(node_id, node_ids[r * cols + c + 1], &mut edge_factory);
+                }
+
+                if r + 1 < rows {
+                    ret.add_generated_edge(
+                        node_id,
+                        node_ids[(r + 1) * cols + c],
+                        &mut edge_factory,
+                    );
+                }
+            }
+        }
+
+        return ret;
+    }
+
+    /// Build an Erdős–Rényi graph, connecting each pair of nodes with the given probability.
+    /// The node factory receives the index of each node.
+    #[cfg(feature = "rand")]
+    pub fn erdos_renyi<R, NF, EF>(
+        node_count: usize,
+        edge_probability: f64,
+        rng: &mut R,
+        node_factory: NF,
+        mut edge_factory: EF,
+    ) -> Self
+    where
+        R: Rng,
+        NF: FnMut(usize) -> TNodeInfo,
+        EF: FnMut(&TNodeInfo, &TNodeInfo) -> TEdgeInfo,
+    {
+        let mut ret: Self = Graph::with_capacity(node_count, 0);
+        let node_ids: Vec<NodeId> = (0..node_count)
+            .map(node_factory)
+            .map(|n| ret.add_node(n))
+            .collect();
+
+        for i in 0..node_count {
+            for j in (i + 1)..node_count {
+                if rng.gen_bool(edge_probability.clamp(0_f64, 1_f64)) {
+                    ret.add_generated_edge(node_ids[i], node_ids[j], &mut edge_factory);
+                }
+            }
+        }
+
+        return ret;
+    }
+
+    /// Build a random geometric graph: nodes are placed uniformly in the unit square and
+    /// connected if they are at most `radius` apart.
+    /// The node factory receives the position of each node.
+    #[cfg(feature = "rand")]
+    pub fn random_geometric<R, NF, EF>(
+        node_count: usize,
+        radius: f64,
+        rng: &mut R,
+        mut node_factory: NF,
+        mut edge_factory: EF,
+    ) -> Self
+    where
+        R: Rng,
+        NF: FnMut(Vector2D) -> TNodeInfo,
+        EF: FnMut(&TNodeInfo, &TNodeInfo) -> TEdgeInfo,
+    {
+        let mut ret: Self = Graph::with_capacity(node_count, 0);
+        let positions: Vec<Vector2D> = (0..node_count)
+            .map(|_| Vector2D::from_xy(rng.gen::<f64>(), rng.gen::<f64>()))
+            .collect();
+        let node_ids: Vec<NodeId> = positions
+            .iter()
+            .map(|p| ret.add_node((node_factory)(*p)))
+            .collect();
+
+        for i in 0..node_count {
+            for j in (i + 1)..node_count {
+                if (positions[i] - positions[j]).magnitude() <= radius {
+                    ret.add_generated_edge(node_ids[i], node_ids[j], &mut edge_factory);
+                }
+            }
+        }
+
+        return ret;
+    }
+
+    fn add_generated_edge<EF>(&mut self, node1_id: NodeId, node2_id: NodeId, edge_factory: &mut EF)
+    where
+        EF: FnMut(&TNodeInfo, &TNodeInfo) -> TEdgeInfo,
+    {
+        let edge_info = (edge_factory)(
+            self.get_node_by_id(&node1_id).unwrap().node_info(),
+            self.get_node_by_id(&node2_id).unwrap().node_info(),
+        );
+        self.add_edge(node1_id, node2_id, edge_info)
+            .expect("Error while adding generated edge.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::Graph;
+
+    #[test]
+    fn grid_is_four_connected() {
+        let graph: Graph<(usize, usize), usize> =
+            Graph::grid(3, 4, |r, c| (r, c), |n1, n2| n1.0 + n1.1 + n2.0 + n2.1);
+
+        assert_eq!(graph.get_node_count(), 12);
+        assert_eq!(graph.get_edge_count(), 17);
+        assert_eq!(graph.diameter(|_| 1_f64), Some(5_f64));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn seeded_generators_are_reproducible() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        use crate::numerics::vector::Vector;
+
+        let build = |seed: u64| -> Graph<(), ()> {
+            let mut rng = StdRng::seed_from_u64(seed);
+            return Graph::erdos_renyi(30, 0.2, &mut rng, |_| (), |_, _| ());
+        };
+        assert!(build(7).structurally_equal(&build(7), |_, _| true, |_, _| true));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let geometric: Graph<_, f64> =
+            Graph::random_geometric(50, 0.2, &mut rng, |p| p, |p1, p2| (*p1 - *p2).magnitude());
+        assert!(geometric
+            .get_edges()
+            .values()
+            .all(|e| *e.edge_info() <= 0.2));
+    }
+}
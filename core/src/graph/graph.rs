@@ -1,66 +1,106 @@
 use std::collections::{HashMap, HashSet, LinkedList};
 
-use super::{edge::Edge, graph_error::GraphError, node::Node};
+use super::{
+    edge::{Edge, EdgeId},
+    graph_error::GraphError,
+    graph_observer::{GraphEvent, ObserverId, Observers},
+    graph_options::GraphOptions,
+    node::{Node, NodeId},
+};
 
 #[derive(Debug)]
 pub struct Graph<TNodeInfo, TEdgeInfo> {
-    nodes: HashMap<u32, Node<TNodeInfo>>,
-    edges: HashMap<u32, Edge<TEdgeInfo>>,
+    nodes: HashMap<NodeId, Node<TNodeInfo>>,
+    edges: HashMap<EdgeId, Edge<TEdgeInfo>>,
 
-    allow_cyclic_edges: bool,
-    allow_duplicate_edges: bool,
+    options: GraphOptions,
 
     node_id_alloc: u32,
     edge_id_alloc: u32,
+
+    /// Incremented whenever entities are added or removed.
+    version: u64,
+
+    observers: Observers,
 }
 
 impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Create an undirected graph. Cyclic edges are self-loops, duplicate edges parallel edges.
     pub fn new(allow_cyclic_edges: bool, allow_duplicate_edges: bool) -> Self {
+        return Graph::with_options(GraphOptions {
+            directed: false,
+            allow_parallel_edges: allow_duplicate_edges,
+            allow_self_loops: allow_cyclic_edges,
+        });
+    }
+
+    pub fn with_options(options: GraphOptions) -> Self {
         return Self {
             nodes: HashMap::new(),
             edges: HashMap::new(),
-            allow_cyclic_edges: allow_cyclic_edges,
-            allow_duplicate_edges: allow_duplicate_edges,
+            options: options,
             node_id_alloc: 1_u32,
             edge_id_alloc: 1_u32,
+            version: 0,
+            observers: Observers::default(),
         };
     }
 
+    /// Create a simple undirected graph with room for the given number of entities.
+    /// Use `with_options` followed by `reserve` for other options.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        let mut ret: Self = Graph::with_options(GraphOptions::default());
+        ret.reserve(node_capacity, edge_capacity);
+        return ret;
+    }
+
+    /// Reserve room for at least the given number of additional entities.
+    pub fn reserve(&mut self, additional_nodes: usize, additional_edges: usize) {
+        self.nodes.reserve(additional_nodes);
+        self.edges.reserve(additional_edges);
+    }
+
+    /// Release unused capacity, e.g. after a large number of entities has been removed.
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+
     pub fn from_entities(
-        nodes: Vec<(u32, TNodeInfo)>,
-        edges: Vec<(u32, (u32, u32), TEdgeInfo)>,
+        nodes: Vec<(NodeId, TNodeInfo)>,
+        edges: Vec<(EdgeId, (NodeId, NodeId), TEdgeInfo)>,
         assume_bidirectional: bool,
     ) -> Result<Self, GraphError> {
         // Check if there are any nodes with duplicate IDs.
-        let mut unique_node_ids: HashSet<u32> = HashSet::new();
+        let mut unique_node_ids: HashSet<NodeId> = HashSet::new();
         let mut max_node_id: u32 = 0;
         for (node_id, _) in nodes.iter() {
             if !unique_node_ids.insert(*node_id) {
                 return Err(GraphError::DuplicateNodeId);
             }
 
-            max_node_id = u32::max(max_node_id, *node_id);
+            max_node_id = u32::max(max_node_id, node_id.value());
         }
 
         // Check if there are any edges with duplicate IDs.
-        let mut unique_edge_ids: HashSet<u32> = HashSet::new();
+        let mut unique_edge_ids: HashSet<EdgeId> = HashSet::new();
         let mut max_edge_id: u32 = 0;
-        for (edge_id, (n1, n2), _) in edges.iter() {
+        for (edge_id, _, _) in edges.iter() {
             if !unique_edge_ids.insert(*edge_id) {
                 return Err(GraphError::DuplicateEdgeId);
             }
 
-            max_edge_id = u32::max(max_edge_id, *edge_id);
+            max_edge_id = u32::max(max_edge_id, edge_id.value());
         }
 
         // Build list of nodes.
-        let mut _nodes: HashMap<u32, Node<TNodeInfo>> = HashMap::new();
+        let mut _nodes: HashMap<NodeId, Node<TNodeInfo>> = HashMap::new();
         for (node_id, node_info) in nodes {
             _nodes.insert(node_id, Node::new(node_id, node_info));
         }
 
         // Build list of edges.
-        let mut _edges: HashMap<u32, Edge<TEdgeInfo>> = HashMap::new();
+        let mut _edges: HashMap<EdgeId, Edge<TEdgeInfo>> = HashMap::new();
         for (edge_id, (n1, n2), edge_info) in edges {
             _edges.insert(
                 edge_id,
@@ -73,20 +113,22 @@ impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
         return Ok(Self {
             nodes: _nodes,
             edges: _edges,
-            allow_cyclic_edges: false,
-            allow_duplicate_edges: false,
+            options: GraphOptions::simple(),
             node_id_alloc: max_node_id + 1,
             edge_id_alloc: max_edge_id + 1,
+            version: 0,
+            observers: Observers::default(),
         });
     }
 
     pub fn from_entities_list(
-        nodes: LinkedList<(u32, TNodeInfo)>,
-        edges: LinkedList<(u32, (u32, u32), TEdgeInfo)>,
+        nodes: LinkedList<(NodeId, TNodeInfo)>,
+        edges: LinkedList<(EdgeId, (NodeId, NodeId), TEdgeInfo)>,
         assume_bidirectional: bool,
     ) -> Result<Self, GraphError> {
-        let mut _nodes: Vec<(u32, TNodeInfo)> = Vec::with_capacity(nodes.len());
-        let mut _edges: Vec<(u32, (u32, u32), TEdgeInfo)> = Vec::with_capacity(edges.len());
+        let mut _nodes: Vec<(NodeId, TNodeInfo)> = Vec::with_capacity(nodes.len());
+        let mut _edges: Vec<(EdgeId, (NodeId, NodeId), TEdgeInfo)> =
+            Vec::with_capacity(edges.len());
 
         for (node_id, node_info) in nodes {
             _nodes.push((node_id, node_info));
@@ -99,39 +141,103 @@ impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
         return Graph::from_entities(_nodes, _edges, assume_bidirectional);
     }
 
+    /// Build graph from fully specified entities, keeping their IDs and edge directions.
+    /// Edges are checked against the options in order of the given list.
+    /// ID allocators are advanced past the given values if needed, so that IDs are never reused.
+    /// The graph starts without observers.
+    #[cfg(any(feature = "serde", feature = "petgraph"))]
+    pub(crate) fn from_parts(
+        nodes: Vec<(NodeId, TNodeInfo)>,
+        edges: Vec<Edge<TEdgeInfo>>,
+        options: GraphOptions,
+        id_allocators: (u32, u32),
+    ) -> Result<Self, GraphError> {
+        let mut ret: Self = Graph::with_options(options);
+        let (mut node_id_alloc, mut edge_id_alloc) = id_allocators;
+
+        for (node_id, node_info) in nodes {
+            if ret.nodes.contains_key(&node_id) {
+                return Err(GraphError::DuplicateNodeId);
+            }
+
+            node_id_alloc = u32::max(node_id_alloc, node_id.value() + 1);
+            ret.nodes.insert(node_id, Node::new(node_id, node_info));
+        }
+
+        for edge in edges {
+            let (edge_id, n1, n2) = (edge.get_id(), edge.node1(), edge.node2());
+
+            if ret.edges.contains_key(&edge_id) {
+                return Err(GraphError::DuplicateEdgeId);
+            }
+
+            ret.check_new_edge(n1, n2)?;
+            edge_id_alloc = u32::max(edge_id_alloc, edge_id.value() + 1);
+            ret.nodes.get_mut(&n1).unwrap().add_connection(n2, edge_id);
+            ret.nodes.get_mut(&n2).unwrap().add_connection(n1, edge_id);
+            ret.edges.insert(edge_id, edge);
+        }
+
+        ret.node_id_alloc = node_id_alloc;
+        ret.edge_id_alloc = edge_id_alloc;
+        return Ok(ret);
+    }
+
+    /// Take apart graph into node payloads and edges, both in order of their IDs.
+    pub(crate) fn into_parts(self) -> (Vec<(NodeId, TNodeInfo)>, Vec<Edge<TEdgeInfo>>) {
+        let mut nodes: Vec<(NodeId, TNodeInfo)> = self
+            .nodes
+            .into_values()
+            .map(|n| (n.get_id(), n.into_node_info()))
+            .collect();
+        let mut edges: Vec<Edge<TEdgeInfo>> = self.edges.into_values().collect();
+        nodes.sort_by_key(|(id, _)| *id);
+        edges.sort_by_key(|e| e.get_id());
+        return (nodes, edges);
+    }
+
+    /// Next (node ID, edge ID) to be allocated.
+    pub(crate) fn id_allocators(&self) -> (u32, u32) {
+        return (self.node_id_alloc, self.edge_id_alloc);
+    }
+
     /// Add node and return its ID.
-    pub fn add_node(&mut self, node_info: TNodeInfo) -> u32 {
-        let node_id = self.node_id_alloc;
+    pub fn add_node(&mut self, node_info: TNodeInfo) -> NodeId {
+        let node_id = NodeId::new(self.node_id_alloc);
         self.node_id_alloc += 1;
+        self.version += 1;
         let node = Node::new(node_id, node_info);
         self.nodes.insert(node_id, node);
+        self.observers
+            .notify(GraphEvent::NodeAdded { node_id: node_id });
         return node_id;
     }
 
     /// Add edge and return its ID.
+    /// In directed graphs the edge can only be traversed from `node1_id` to `node2_id`.
     pub fn add_edge(
         &mut self,
-        node1_id: u32,
-        node2_id: u32,
+        node1_id: NodeId,
+        node2_id: NodeId,
         edge_info: TEdgeInfo,
-    ) -> Result<u32, GraphError> {
-        return self.add_directed_edge(node1_id, node2_id, true, true, edge_info);
+    ) -> Result<EdgeId, GraphError> {
+        let can_move_backward = !self.options.directed;
+        return self.add_directed_edge(node1_id, node2_id, true, can_move_backward, edge_info);
     }
 
     pub fn add_directed_edge(
         &mut self,
-        node1_id: u32,
-        node2_id: u32,
+        node1_id: NodeId,
+        node2_id: NodeId,
         can_move_forward: bool,
         can_move_backward: bool,
         edge_info: TEdgeInfo,
-    ) -> Result<u32, GraphError> {
-        if !self.nodes.contains_key(&node1_id) || !self.nodes.contains_key(&node2_id) {
-            return Err(GraphError::NoSuchNode);
-        }
+    ) -> Result<EdgeId, GraphError> {
+        self.check_new_edge(node1_id, node2_id)?;
 
-        let edge_id = self.edge_id_alloc;
+        let edge_id = EdgeId::new(self.edge_id_alloc);
         self.edge_id_alloc += 1;
+        self.version += 1;
         let edge = Edge::new(
             edge_id,
             node1_id,
@@ -150,10 +256,75 @@ impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
             .get_mut(&node2_id)
             .unwrap()
             .add_connection(node1_id, edge_id);
+        self.observers.notify(GraphEvent::EdgeAdded {
+            edge_id: edge_id,
+            node1: node1_id,
+            node2: node2_id,
+        });
 
         return Ok(edge_id);
     }
 
+    /// Check whether an edge between two nodes could be added without violating the options.
+    pub fn check_new_edge(&self, node1_id: NodeId, node2_id: NodeId) -> Result<(), GraphError> {
+        if !self.nodes.contains_key(&node1_id) || !self.nodes.contains_key(&node2_id) {
+            return Err(GraphError::NoSuchNode);
+        }
+
+        if node1_id == node2_id && !self.options.allow_self_loops {
+            return Err(GraphError::SelfLoopNotAllowed { node_id: node1_id });
+        }
+
+        if !self.options.allow_parallel_edges {
+            let existing_edge = self
+                .edges_between(&node1_id, &node2_id)
+                .into_iter()
+                .find(|e| !self.options.directed || e.node1() == node1_id);
+
+            if let Some(e) = existing_edge {
+                return Err(GraphError::ParallelEdgeNotAllowed {
+                    existing_edge_id: e.get_id(),
+                });
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Register callback notified of every node or edge added to or removed from the graph.
+    /// Lets higher layers such as spatial indices keep up with changes without re-scanning.
+    /// Observers stay with this graph: graphs converted from it, e.g. by serialization or
+    /// into petgraph, start without observers, and those of a graph merged into it are dropped.
+    pub fn add_observer<F>(&mut self, observer: F) -> ObserverId
+    where
+        F: FnMut(&GraphEvent) + Send + Sync + 'static,
+    {
+        return self.observers.add(Box::new(observer));
+    }
+
+    /// Unregister callback, returning whether it was registered.
+    pub fn remove_observer(&mut self, observer_id: ObserverId) -> bool {
+        return self.observers.remove(observer_id);
+    }
+
+    /// Counter incremented whenever nodes or edges are added or removed.
+    /// Payload changes do not count.
+    pub fn version(&self) -> u64 {
+        return self.version;
+    }
+
+    pub fn options(&self) -> GraphOptions {
+        return self.options;
+    }
+
+    pub fn allow_cyclic_edges(&self) -> bool {
+        return self.options.allow_self_loops;
+    }
+
+    pub fn allow_duplicate_edges(&self) -> bool {
+        return self.options.allow_parallel_edges;
+    }
+
     pub fn get_node_count(&self) -> usize {
         return self.nodes.len();
     }
@@ -162,54 +333,122 @@ impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
         return self.edges.len();
     }
 
-    pub fn get_nodes(&self) -> &HashMap<u32, Node<TNodeInfo>> {
+    pub fn get_nodes(&self) -> &HashMap<NodeId, Node<TNodeInfo>> {
         return &self.nodes;
     }
 
-    pub fn get_edges(&self) -> &HashMap<u32, Edge<TEdgeInfo>> {
+    pub fn get_edges(&self) -> &HashMap<EdgeId, Edge<TEdgeInfo>> {
         return &self.edges;
     }
 
-    pub fn get_node_by_id(&self, node_id: &u32) -> Option<&Node<TNodeInfo>> {
+    pub fn get_node_by_id(&self, node_id: &NodeId) -> Option<&Node<TNodeInfo>> {
         return self.nodes.get(node_id);
     }
 
-    pub fn get_edge_by_id(&self, edge_id: &u32) -> Option<&Edge<TEdgeInfo>> {
+    pub fn get_edge_by_id(&self, edge_id: &EdgeId) -> Option<&Edge<TEdgeInfo>> {
         return self.edges.get(edge_id);
     }
 
-    pub fn remove_node(&mut self, node_id: &u32) -> Result<u32, GraphError> {
+    pub fn get_node_by_id_mut(&mut self, node_id: &NodeId) -> Option<&mut Node<TNodeInfo>> {
+        return self.nodes.get_mut(node_id);
+    }
+
+    pub fn get_edge_by_id_mut(&mut self, edge_id: &EdgeId) -> Option<&mut Edge<TEdgeInfo>> {
+        return self.edges.get_mut(edge_id);
+    }
+
+    /// Iterate over IDs of the nodes adjacent to node, regardless of edge direction.
+    /// Yields nothing if the node does not exist.
+    pub fn neighbors(&self, node_id: &NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        return self
+            .nodes
+            .get(node_id)
+            .into_iter()
+            .flat_map(|n| n.adjacent_nodes().keys().copied());
+    }
+
+    /// Iterate over the edges connected to node, regardless of edge direction.
+    /// Yields nothing if the node does not exist.
+    pub fn edges_of(&self, node_id: &NodeId) -> impl Iterator<Item = &Edge<TEdgeInfo>> + '_ {
+        return self
+            .nodes
+            .get(node_id)
+            .into_iter()
+            .flat_map(|n| n.connected_edges().keys())
+            .map(|edge_id| self.edges.get(edge_id).unwrap());
+    }
+
+    /// Number of edges connected to node, or `None` if the node does not exist.
+    pub fn degree(&self, node_id: &NodeId) -> Option<usize> {
+        return self.nodes.get(node_id).map(|n| n.degree());
+    }
+
+    /// Edges connecting two nodes in either orientation, in order of their IDs.
+    /// Found through the adjacency of the first node, without scanning all edges.
+    pub fn edges_between(&self, node1_id: &NodeId, node2_id: &NodeId) -> Vec<&Edge<TEdgeInfo>> {
+        let mut ret: Vec<&Edge<TEdgeInfo>> = self
+            .nodes
+            .get(node1_id)
+            .and_then(|n| n.adjacent_nodes().get(node2_id))
+            .into_iter()
+            .flatten()
+            .map(|edge_id| self.edges.get(edge_id).unwrap())
+            .collect();
+        ret.sort_by_key(|e| e.get_id());
+        return ret;
+    }
+
+    /// Remove node along with all edges connected to it, returning its ID.
+    /// IDs are never reused, so IDs of the remaining entities stay valid and the removed ID
+    /// does not resolve to any entity added later.
+    pub fn remove_node(&mut self, node_id: &NodeId) -> Result<NodeId, GraphError> {
         // Remove corresponding node.
         let removed_node = match self.nodes.remove(node_id) {
             Some(n) => n,
             None => return Err(GraphError::NoSuchNode),
         };
+        self.version += 1;
 
         // Build list of entities adjacent to removed node.
-        let mut rm_list: LinkedList<(u32, u32)> = LinkedList::new();
+        let mut rm_list: Vec<(EdgeId, NodeId)> = Vec::new();
         for (rm_edge, rm_node) in removed_node.connected_edges().iter() {
-            rm_list.push_back((*rm_edge, *rm_node));
+            rm_list.push((*rm_edge, *rm_node));
         }
+        rm_list.sort();
 
         for (rm_edge, rm_node) in rm_list {
-            let node = match self.nodes.get_mut(&rm_node) {
-                Some(n) => n,
-                None => continue,
-            };
+            // Self-loops have no other end left to detach from.
+            let edge = self.edges.remove(&rm_edge).unwrap();
 
-            node.remove_connection(rm_edge);
-            self.edges.remove(&rm_edge);
+            if let Some(node) = self.nodes.get_mut(&rm_node) {
+                node.remove_connection(rm_edge);
+            }
+
+            self.observers.notify(GraphEvent::EdgeRemoved {
+                edge_id: rm_edge,
+                node1: edge.node1(),
+                node2: edge.node2(),
+            });
         }
 
+        self.observers
+            .notify(GraphEvent::NodeRemoved { node_id: *node_id });
         return Ok(removed_node.get_id());
     }
 
-    pub fn remove_edge(&mut self, edge_id: &u32) -> Result<u32, GraphError> {
+    /// Remove edge, returning its ID. IDs are never reused.
+    pub fn remove_edge(&mut self, edge_id: &EdgeId) -> Result<EdgeId, GraphError> {
+        return self.take_edge(edge_id).map(|e| e.get_id());
+    }
+
+    /// Remove edge, handing it back along with its payload.
+    pub fn take_edge(&mut self, edge_id: &EdgeId) -> Result<Edge<TEdgeInfo>, GraphError> {
         // Remove corresponding edge.
         let removed_edge = match self.edges.remove(edge_id) {
             Some(e) => e,
             None => return Err(GraphError::NoSuchEdge),
         };
+        self.version += 1;
 
         let n1 = match self.nodes.get_mut(&removed_edge.node1()) {
             Some(n) => n,
@@ -223,6 +462,97 @@ impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
         };
         n2.remove_connection(*edge_id);
 
-        return Ok(removed_edge.get_id());
+        self.observers.notify(GraphEvent::EdgeRemoved {
+            edge_id: *edge_id,
+            node1: removed_edge.node1(),
+            node2: removed_edge.node2(),
+        });
+        return Ok(removed_edge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EdgeId, Graph, GraphError, GraphOptions, NodeId};
+
+    #[test]
+    fn removal_keeps_ids_stable() {
+        let mut graph: Graph<(), ()> = Graph::new(true, false);
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let e1 = graph.add_edge(n1, n2, ()).unwrap();
+        let e2 = graph.add_edge(n2, n2, ()).unwrap();
+        let e3 = graph.add_edge(n2, n3, ()).unwrap();
+
+        assert_eq!(graph.remove_edge(&e3), Ok(e3));
+        assert_eq!(graph.remove_node(&n2), Ok(n2));
+        assert!(graph.get_edge_by_id(&e1).is_none());
+        assert!(graph.get_edge_by_id(&e2).is_none());
+        assert_eq!(graph.get_edge_count(), 0);
+        assert_eq!(graph.get_node_by_id(&n1).unwrap().degree(), 0);
+
+        let n4 = graph.add_node(());
+        let e4 = graph.add_edge(n1, n3, ()).unwrap();
+        assert!(n4 > n3 && e4 > e3);
+        assert!(graph.remove_node(&n2).is_err());
+    }
+
+    #[test]
+    fn options_are_enforced() {
+        let mut graph: Graph<(), ()> = Graph::with_options(GraphOptions::simple().directed(true));
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let e1 = graph.add_edge(n1, n2, ()).unwrap();
+
+        let edge = graph.get_edge_by_id(&e1).unwrap();
+        assert!(edge.can_move_forward() && !edge.can_move_backward());
+        assert_eq!(
+            graph.add_edge(n1, n2, ()),
+            Err(GraphError::ParallelEdgeNotAllowed {
+                existing_edge_id: e1
+            })
+        );
+        assert_eq!(
+            graph.add_edge(n1, n1, ()),
+            Err(GraphError::SelfLoopNotAllowed { node_id: n1 })
+        );
+        assert!(graph.add_edge(n2, n1, ()).is_ok());
+
+        let mut graph: Graph<(), ()> = Graph::with_options(GraphOptions::simple());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        graph.add_edge(n1, n2, ()).unwrap();
+        assert!(graph.add_edge(n2, n1, ()).is_err());
+    }
+
+    #[test]
+    fn adjacency_queries() {
+        let mut graph: Graph<(), ()> = Graph::new(false, true);
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let e1 = graph.add_edge(n1, n2, ()).unwrap();
+        let e2 = graph.add_edge(n1, n2, ()).unwrap();
+        let e3 = graph.add_edge(n3, n1, ()).unwrap();
+
+        let mut neighbors: Vec<NodeId> = graph.neighbors(&n1).collect();
+        let mut edges: Vec<EdgeId> = graph.edges_of(&n1).map(|e| e.get_id()).collect();
+        neighbors.sort();
+        edges.sort();
+
+        assert_eq!(neighbors, vec![n2, n3]);
+        assert_eq!(edges, vec![e1, e2, e3]);
+        assert_eq!(graph.degree(&n2), Some(2));
+        assert_eq!(graph.degree(&NodeId::new(100)), None);
+        assert_eq!(graph.neighbors(&NodeId::new(100)).count(), 0);
+
+        let between: Vec<EdgeId> = graph
+            .edges_between(&n2, &n1)
+            .iter()
+            .map(|e| e.get_id())
+            .collect();
+        assert_eq!(between, vec![e1, e2]);
+        assert!(graph.edges_between(&n2, &n3).is_empty());
     }
 }
@@ -1,7 +1,50 @@
+use std::fmt::Display;
+
+use super::{edge::EdgeId, node::NodeId};
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GraphError {
     NoSuchNode,
     NoSuchEdge,
     DuplicateNodeId,
     DuplicateEdgeId,
-}
\ No newline at end of file
+
+    /// No path exists between the requested nodes.
+    Unreachable,
+
+    /// Operation requires an acyclic graph, but a cycle was found.
+    CycleDetected,
+
+    /// Edge would connect node to itself, but the graph does not allow self-loops.
+    SelfLoopNotAllowed {
+        node_id: NodeId,
+    },
+
+    /// Edge would run parallel to an existing edge, but the graph does not allow parallel edges.
+    ParallelEdgeNotAllowed {
+        existing_edge_id: EdgeId,
+    },
+}
+
+impl Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            GraphError::NoSuchNode => write!(f, "no such node"),
+            GraphError::NoSuchEdge => write!(f, "no such edge"),
+            GraphError::DuplicateNodeId => write!(f, "duplicate node ID"),
+            GraphError::DuplicateEdgeId => write!(f, "duplicate edge ID"),
+            GraphError::Unreachable => write!(f, "no path between the requested nodes"),
+            GraphError::CycleDetected => write!(f, "graph contains a cycle"),
+            GraphError::SelfLoopNotAllowed { node_id } => {
+                write!(f, "self-loop at node {} is not allowed", node_id)
+            }
+            GraphError::ParallelEdgeNotAllowed { existing_edge_id } => write!(
+                f,
+                "edge would run parallel to edge {}, which is not allowed",
+                existing_edge_id
+            ),
+        };
+    }
+}
+
+impl std::error::Error for GraphError {}
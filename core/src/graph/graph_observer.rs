@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+
+use super::{edge::EdgeId, node::NodeId};
+
+/// Structural change of a graph, reported to observers after it has been applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GraphEvent {
+    NodeAdded {
+        node_id: NodeId,
+    },
+
+    /// Edges connected to the node are reported as removed before the node itself.
+    NodeRemoved {
+        node_id: NodeId,
+    },
+
+    EdgeAdded {
+        edge_id: EdgeId,
+        node1: NodeId,
+        node2: NodeId,
+    },
+
+    EdgeRemoved {
+        edge_id: EdgeId,
+        node1: NodeId,
+        node2: NodeId,
+    },
+}
+
+/// Handle of an observer registered with `Graph::add_observer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObserverId(u32);
+
+type Callback = Box<dyn FnMut(&GraphEvent) + Send + Sync>;
+
+/// Observers registered with a graph, notified in order of registration.
+#[derive(Default)]
+pub(crate) struct Observers {
+    callbacks: Vec<(ObserverId, Callback)>,
+    id_alloc: u32,
+}
+
+impl Observers {
+    pub(crate) fn add(&mut self, callback: Callback) -> ObserverId {
+        let id = ObserverId(self.id_alloc);
+        self.id_alloc += 1;
+        self.callbacks.push((id, callback));
+        return id;
+    }
+
+    pub(crate) fn remove(&mut self, id: ObserverId) -> bool {
+        let len = self.callbacks.len();
+        self.callbacks.retain(|(observer_id, _)| *observer_id != id);
+        return self.callbacks.len() != len;
+    }
+
+    pub(crate) fn notify(&mut self, event: GraphEvent) {
+        for (_, callback) in self.callbacks.iter_mut() {
+            (callback)(&event);
+        }
+    }
+}
+
+impl Debug for Observers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "Observers({})", self.callbacks.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::graph::graph::Graph;
+
+    use super::GraphEvent;
+
+    #[test]
+    fn observers_see_changes() {
+        let mut graph: Graph<(), ()> = Graph::new(false, false);
+        let events: Arc<Mutex<Vec<GraphEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let _events = events.clone();
+        let observer_id = graph.add_observer(move |e| _events.lock().unwrap().push(*e));
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let e1 = graph.add_edge(n1, n2, ()).unwrap();
+        graph.remove_node(&n1).unwrap();
+        assert!(graph.remove_observer(observer_id));
+        graph.add_node(());
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                GraphEvent::NodeAdded { node_id: n1 },
+                GraphEvent::NodeAdded { node_id: n2 },
+                GraphEvent::EdgeAdded {
+                    edge_id: e1,
+                    node1: n1,
+                    node2: n2
+                },
+                GraphEvent::EdgeRemoved {
+                    edge_id: e1,
+                    node1: n1,
+                    node2: n2
+                },
+                GraphEvent::NodeRemoved { node_id: n1 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,46 @@
+/// Structural rules of a graph, enforced whenever an edge is added.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphOptions {
+    /// Whether `Graph::add_edge` creates edges traversable only from `node1` to `node2`.
+    /// Edges added through `Graph::add_directed_edge` keep their explicit directions.
+    pub directed: bool,
+
+    /// Whether more than one edge may connect the same pair of nodes.
+    /// In directed graphs only edges with the same `node1` and `node2` are parallel.
+    pub allow_parallel_edges: bool,
+
+    /// Whether an edge may connect a node to itself.
+    pub allow_self_loops: bool,
+}
+
+impl GraphOptions {
+    /// Undirected graph without parallel edges or self-loops.
+    pub fn simple() -> Self {
+        return Self {
+            directed: false,
+            allow_parallel_edges: false,
+            allow_self_loops: false,
+        };
+    }
+
+    /// Undirected graph allowing parallel edges and self-loops.
+    pub fn multigraph() -> Self {
+        return Self {
+            directed: false,
+            allow_parallel_edges: true,
+            allow_self_loops: true,
+        };
+    }
+
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        return self;
+    }
+}
+
+impl Default for GraphOptions {
+    fn default() -> Self {
+        return GraphOptions::simple();
+    }
+}
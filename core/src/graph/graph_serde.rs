@@ -0,0 +1,105 @@
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{edge::Edge, graph::Graph, graph_options::GraphOptions, node::NodeId};
+
+/// Flat representation of a graph. Adjacency is rebuilt from the edges on deserialization.
+#[derive(Serialize)]
+#[serde(rename = "Graph")]
+struct GraphRepr<'a, TNodeInfo, TEdgeInfo> {
+    options: GraphOptions,
+    next_node_id: u32,
+    next_edge_id: u32,
+    nodes: Vec<(NodeId, &'a TNodeInfo)>,
+    edges: Vec<&'a Edge<TEdgeInfo>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Graph")]
+struct OwnedGraphRepr<TNodeInfo, TEdgeInfo> {
+    options: GraphOptions,
+    next_node_id: u32,
+    next_edge_id: u32,
+    nodes: Vec<(NodeId, TNodeInfo)>,
+    edges: Vec<Edge<TEdgeInfo>>,
+}
+
+impl<TNodeInfo, TEdgeInfo> Serialize for Graph<TNodeInfo, TEdgeInfo>
+where
+    TNodeInfo: Serialize,
+    TEdgeInfo: Serialize,
+{
+    /// Entities are written in order of their IDs, so that equal graphs serialize identically.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (next_node_id, next_edge_id) = self.id_allocators();
+        let mut nodes: Vec<(NodeId, &TNodeInfo)> = self
+            .get_nodes()
+            .values()
+            .map(|n| (n.get_id(), n.node_info()))
+            .collect();
+        let mut edges: Vec<&Edge<TEdgeInfo>> = self.get_edges().values().collect();
+        nodes.sort_by_key(|(id, _)| *id);
+        edges.sort_by_key(|e| e.get_id());
+
+        return GraphRepr {
+            options: self.options(),
+            next_node_id: next_node_id,
+            next_edge_id: next_edge_id,
+            nodes: nodes,
+            edges: edges,
+        }
+        .serialize(serializer);
+    }
+}
+
+impl<'de, TNodeInfo, TEdgeInfo> Deserialize<'de> for Graph<TNodeInfo, TEdgeInfo>
+where
+    TNodeInfo: Deserialize<'de>,
+    TEdgeInfo: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = OwnedGraphRepr::<TNodeInfo, TEdgeInfo>::deserialize(deserializer)?;
+
+        return Graph::from_parts(
+            repr.nodes,
+            repr.edges,
+            repr.options,
+            (repr.next_node_id, repr.next_edge_id),
+        )
+        .map_err(|e| D::Error::custom(format!("inconsistent graph: {:?}", e)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, graph_error::GraphError};
+
+    #[test]
+    fn round_trip() {
+        let mut graph: Graph<String, f64> = Graph::new(false, true);
+        let n1 = graph.add_node(String::from("a"));
+        let n2 = graph.add_node(String::from("b"));
+        let n3 = graph.add_node(String::from("c"));
+        graph.add_edge(n1, n2, 1.5).unwrap();
+        let e2 = graph.add_directed_edge(n2, n3, true, false, 2.5).unwrap();
+        graph.remove_node(&n1).unwrap();
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let mut restored: Graph<String, f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+        let edge = restored.get_edge_by_id(&e2).unwrap();
+        assert!(edge.can_move_forward() && !edge.can_move_backward());
+        assert_eq!(restored.get_node_by_id(&n3).unwrap().degree(), 1);
+        assert_eq!(
+            restored.shortest_path(n3, n2, |e| *e.edge_info()),
+            Err(GraphError::Unreachable)
+        );
+        assert!(restored.add_node(String::from("d")) > n3);
+    }
+}
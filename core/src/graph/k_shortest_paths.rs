@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+
+use super::{
+    edge::{Edge, EdgeId},
+    edge_weight::EdgeWeight,
+    graph::Graph,
+    graph_error::GraphError,
+    node::NodeId,
+    path_result::PathResult,
+};
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Find up to `k` cheapest loopless paths between two nodes using Yen's algorithm.
+    /// Paths differ in at least one edge, so parallel edges yield separate paths.
+    /// Returns the paths in order of increasing cost.
+    pub fn k_shortest_paths<F>(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        k: usize,
+        edge_cost_fn: F,
+    ) -> Result<Vec<PathResult>, GraphError>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        if self.get_node_by_id(&from).is_none() || self.get_node_by_id(&to).is_none() {
+            return Err(GraphError::NoSuchNode);
+        } else if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let heuristic_fn = |_: &_| 0_f64;
+        let mut paths: Vec<(Vec<NodeId>, Vec<EdgeId>, f64)> = Vec::new();
+        let mut candidates: Vec<(Vec<NodeId>, Vec<EdgeId>, f64)> = Vec::new();
+
+        match self.search(
+            from,
+            to,
+            &edge_cost_fn,
+            &heuristic_fn,
+            &HashSet::new(),
+            &HashSet::new(),
+        ) {
+            Some(path) => paths.push(path),
+            None => return Ok(Vec::new()),
+        };
+
+        while paths.len() < k {
+            let (prev_nodes, prev_edges, _) = paths.last().unwrap().clone();
+
+            for i in 0..prev_edges.len() {
+                let spur_node = prev_nodes[i];
+                let root_nodes = &prev_nodes[..=i];
+                let root_edges = &prev_edges[..i];
+
+                // Prevent the spur path from repeating any known path sharing the same root.
+                let mut excluded_edges: HashSet<EdgeId> = HashSet::new();
+                for (nodes, edges, _) in paths.iter() {
+                    if nodes.len() > i && &nodes[..=i] == root_nodes && &edges[..i] == root_edges {
+                        excluded_edges.insert(edges[i]);
+                    }
+                }
+                let excluded_nodes: HashSet<NodeId> = root_nodes[..i].iter().copied().collect();
+
+                let (spur_nodes, spur_edges, spur_cost) = match self.search(
+                    spur_node,
+                    to,
+                    &edge_cost_fn,
+                    &heuristic_fn,
+                    &excluded_nodes,
+                    &excluded_edges,
+                ) {
+                    Some(path) => path,
+                    None => continue,
+                };
+
+                let root_cost: f64 = root_edges
+                    .iter()
+                    .map(|id| (edge_cost_fn)(self.get_edge_by_id(id).unwrap()))
+                    .sum();
+                let mut nodes: Vec<NodeId> = root_nodes[..i].to_vec();
+                nodes.extend(spur_nodes);
+                let mut edges: Vec<EdgeId> = root_edges.to_vec();
+                edges.extend(spur_edges);
+
+                if paths
+                    .iter()
+                    .chain(candidates.iter())
+                    .any(|(_, e, _)| *e == edges)
+                {
+                    continue;
+                }
+
+                candidates.push((nodes, edges, root_cost + spur_cost));
+            }
+
+            if candidates.is_empty() {
+                break;
+            }
+
+            let (best_index, _) = candidates
+                .iter()
+                .enumerate()
+                .min_by(|(_, (_, e1, c1)), (_, (_, e2, c2))| {
+                    f64::total_cmp(c1, c2).then_with(|| e1.cmp(e2))
+                })
+                .unwrap();
+            paths.push(candidates.swap_remove(best_index));
+        }
+
+        return Ok(paths
+            .into_iter()
+            .map(|(nodes, edges, cost)| PathResult {
+                nodes: nodes,
+                edges: edges,
+                cost: cost,
+            })
+            .collect());
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo>
+where
+    TEdgeInfo: EdgeWeight,
+{
+    /// Find up to `k` cheapest loopless paths with the edge weights as costs.
+    pub fn k_shortest_paths_by_weight(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        k: usize,
+    ) -> Result<Vec<PathResult>, GraphError> {
+        return self.k_shortest_paths(from, to, k, |e| e.edge_info().weight());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn alternative_routes() {
+        // Classic example from Yen's paper, with nodes C, D, E, F, G, H.
+        let mut graph: Graph<(), f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..6).map(|_| graph.add_node(())).collect();
+        for (i, j, w) in [
+            (0, 1, 3_f64),
+            (0, 2, 2_f64),
+            (1, 3, 4_f64),
+            (2, 1, 1_f64),
+            (2, 3, 2_f64),
+            (2, 4, 3_f64),
+            (3, 4, 2_f64),
+            (3, 5, 1_f64),
+            (4, 5, 2_f64),
+        ] {
+            graph.add_directed_edge(n[i], n[j], true, false, w).unwrap();
+        }
+
+        let paths = graph.k_shortest_paths_by_weight(n[0], n[5], 3).unwrap();
+        assert_eq!(
+            paths
+                .into_iter()
+                .map(|p| (p.nodes, p.cost))
+                .collect::<Vec<_>>(),
+            vec![
+                (vec![n[0], n[2], n[3], n[5]], 5_f64),
+                (vec![n[0], n[2], n[4], n[5]], 7_f64),
+                (vec![n[0], n[1], n[3], n[5]], 8_f64),
+            ]
+        );
+        assert_eq!(
+            graph
+                .k_shortest_paths_by_weight(n[0], n[5], 10)
+                .unwrap()
+                .len(),
+            7
+        );
+    }
+
+    #[test]
+    fn no_paths_requested() {
+        let mut graph: Graph<(), f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..2).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], 1_f64).unwrap();
+
+        assert!(graph
+            .k_shortest_paths_by_weight(n[0], n[1], 0)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{
+    edge::{Edge, EdgeId},
+    graph::Graph,
+    graph_error::GraphError,
+    node::NodeId,
+};
+
+/// Result of `Graph::max_flow`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaxFlow {
+    /// Total flow from source to sink.
+    pub value: f64,
+
+    /// Net flow along each edge carrying flow, positive from `node1` to `node2`.
+    pub edge_flows: HashMap<EdgeId, f64>,
+
+    /// Nodes reachable from the source in the residual network.
+    pub source_side: HashSet<NodeId>,
+
+    /// Saturated edges leading from the source side to the sink side, in order of their IDs.
+    /// Their capacities sum up to the flow value.
+    pub min_cut: Vec<EdgeId>,
+}
+
+/// Directed arc of the residual network. Arcs are stored in pairs, so `i ^ 1` is the reverse arc.
+struct Arc {
+    target: NodeId,
+    edge_id: EdgeId,
+    residual: f64,
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Find a maximum flow from source to sink using the Edmonds-Karp algorithm.
+    /// Every direction an edge can be traversed in offers the capacity given by `capacity_fn`,
+    /// which must not be negative, e.g. the number of robots per minute fitting through a corridor.
+    pub fn max_flow<F>(
+        &self,
+        source: NodeId,
+        sink: NodeId,
+        capacity_fn: F,
+    ) -> Result<MaxFlow, GraphError>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        if self.get_node_by_id(&source).is_none() || self.get_node_by_id(&sink).is_none() {
+            return Err(GraphError::NoSuchNode);
+        }
+
+        // Build residual network, visiting edges in order of their IDs.
+        let mut edge_ids: Vec<EdgeId> = self.get_edges().keys().copied().collect();
+        edge_ids.sort();
+        let mut arcs: Vec<Arc> = Vec::new();
+        let mut outgoing_arcs: HashMap<NodeId, Vec<usize>> = HashMap::new();
+
+        for edge_id in edge_ids {
+            let edge = self.get_edge_by_id(&edge_id).unwrap();
+            let capacity = (capacity_fn)(edge);
+            let (n1, n2) = (edge.node1(), edge.node2());
+
+            for (from, to, can_move) in [
+                (n1, n2, edge.can_move_forward()),
+                (n2, n1, edge.can_move_backward()),
+            ] {
+                if !can_move || from == to {
+                    continue;
+                }
+
+                outgoing_arcs.entry(from).or_default().push(arcs.len());
+                arcs.push(Arc {
+                    target: to,
+                    edge_id: edge_id,
+                    residual: capacity,
+                });
+                outgoing_arcs.entry(to).or_default().push(arcs.len());
+                arcs.push(Arc {
+                    target: from,
+                    edge_id: edge_id,
+                    residual: 0_f64,
+                });
+            }
+        }
+
+        let mut value = 0_f64;
+        loop {
+            let prev_arcs = find_augmenting_path(&arcs, &outgoing_arcs, source);
+            if source == sink || !prev_arcs.contains_key(&sink) {
+                break;
+            }
+
+            let mut bottleneck = f64::INFINITY;
+            let mut node_id = sink;
+            while let Some(a) = prev_arcs.get(&node_id) {
+                bottleneck = f64::min(bottleneck, arcs[*a].residual);
+                node_id = arcs[*a ^ 1].target;
+            }
+
+            let mut node_id = sink;
+            while let Some(a) = prev_arcs.get(&node_id) {
+                arcs[*a].residual -= bottleneck;
+                arcs[*a ^ 1].residual += bottleneck;
+                node_id = arcs[*a ^ 1].target;
+            }
+
+            value += bottleneck;
+        }
+
+        // Flow along a forward arc is the residual of its reverse arc.
+        let mut edge_flows: HashMap<EdgeId, f64> = HashMap::new();
+        for a in (0..arcs.len()).step_by(2) {
+            let flow = arcs[a + 1].residual;
+            if flow <= 0_f64 {
+                continue;
+            }
+
+            let edge = self.get_edge_by_id(&arcs[a].edge_id).unwrap();
+            let sign = if arcs[a].target == edge.node2() {
+                1_f64
+            } else {
+                -1_f64
+            };
+            *edge_flows.entry(arcs[a].edge_id).or_insert(0_f64) += sign * flow;
+        }
+        edge_flows.retain(|_, flow| *flow != 0_f64);
+
+        let source_side: HashSet<NodeId> = find_augmenting_path(&arcs, &outgoing_arcs, source)
+            .into_keys()
+            .chain([source])
+            .collect();
+        let mut min_cut: Vec<EdgeId> = (0..arcs.len())
+            .step_by(2)
+            .filter(|a| {
+                source_side.contains(&arcs[a + 1].target) && !source_side.contains(&arcs[*a].target)
+            })
+            .map(|a| arcs[a].edge_id)
+            .collect();
+        min_cut.sort();
+        min_cut.dedup();
+
+        return Ok(MaxFlow {
+            value: value,
+            edge_flows: edge_flows,
+            source_side: source_side,
+            min_cut: min_cut,
+        });
+    }
+}
+
+/// Breadth-first search over arcs with residual capacity left.
+/// Returns the arc each reached node was first entered through.
+fn find_augmenting_path(
+    arcs: &[Arc],
+    outgoing_arcs: &HashMap<NodeId, Vec<usize>>,
+    source: NodeId,
+) -> HashMap<NodeId, usize> {
+    let mut ret: HashMap<NodeId, usize> = HashMap::new();
+    let mut queue: VecDeque<NodeId> = VecDeque::from([source]);
+
+    while let Some(node_id) = queue.pop_front() {
+        for a in outgoing_arcs.get(&node_id).into_iter().flatten() {
+            let target = arcs[*a].target;
+            if arcs[*a].residual > 0_f64 && target != source && !ret.contains_key(&target) {
+                ret.insert(target, *a);
+                queue.push_back(target);
+            }
+        }
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn flow_is_limited_by_min_cut() {
+        let mut graph: Graph<(), f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..6).map(|_| graph.add_node(())).collect();
+        let mut edge = |i: usize, j: usize, capacity: f64| {
+            return graph
+                .add_directed_edge(n[i], n[j], true, false, capacity)
+                .unwrap();
+        };
+        edge(0, 1, 16_f64);
+        edge(0, 2, 13_f64);
+        edge(2, 1, 4_f64);
+        let e13 = edge(1, 3, 12_f64);
+        edge(3, 2, 9_f64);
+        edge(2, 4, 14_f64);
+        let e43 = edge(4, 3, 7_f64);
+        edge(3, 5, 20_f64);
+        let e45 = edge(4, 5, 4_f64);
+
+        let flow = graph.max_flow(n[0], n[5], |e| *e.edge_info()).unwrap();
+        assert_eq!(flow.value, 23_f64);
+        assert_eq!(flow.min_cut, vec![e13, e43, e45]);
+        assert_eq!(flow.edge_flows[&e13], 12_f64);
+        assert!(flow.source_side.contains(&n[4]) && !flow.source_side.contains(&n[3]));
+
+        let reverse = graph.max_flow(n[5], n[0], |e| *e.edge_info()).unwrap();
+        assert_eq!(reverse.value, 0_f64);
+        assert!(reverse.min_cut.is_empty());
+    }
+}
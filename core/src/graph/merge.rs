@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use super::{edge::EdgeId, graph::Graph, node::NodeId};
+
+/// Outcome of `Graph::merge`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergeResult {
+    /// Node IDs of the other graph mapped to node IDs in this graph.
+    pub node_ids: HashMap<NodeId, NodeId>,
+
+    /// Edges of the other graph which were not added as they would violate the options of
+    /// this graph, e.g. parallel edges between fused nodes, in order of their IDs.
+    pub skipped_edges: Vec<EdgeId>,
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Add all entities of another graph to this graph, assigning new IDs.
+    /// Each node of the other graph is fused with the first node of this graph, in order of
+    /// the IDs, for which `node_matcher(this, other)` holds; its payload is then dropped.
+    /// Edges which this graph does not allow are skipped and reported instead of failing
+    /// halfway through the merge. Observers of this graph are notified of every added entity,
+    /// while those of the other graph are dropped along with it.
+    pub fn merge<M>(&mut self, other: Graph<TNodeInfo, TEdgeInfo>, node_matcher: M) -> MergeResult
+    where
+        M: Fn(&TNodeInfo, &TNodeInfo) -> bool,
+    {
+        let mut own_node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        own_node_ids.sort();
+
+        let (nodes, edges) = other.into_parts();
+        let mut ret: HashMap<NodeId, NodeId> = HashMap::new();
+        let mut skipped_edges: Vec<EdgeId> = Vec::new();
+
+        for (node_id, node_info) in nodes {
+            let matched_node_id = own_node_ids.iter().copied().find(|id| {
+                (node_matcher)(self.get_node_by_id(id).unwrap().node_info(), &node_info)
+            });
+
+            let new_node_id = match matched_node_id {
+                Some(id) => id,
+                None => self.add_node(node_info),
+            };
+            ret.insert(node_id, new_node_id);
+        }
+
+        for edge in edges {
+            let (n1, n2) = (ret[&edge.node1()], ret[&edge.node2()]);
+            if self.check_new_edge(n1, n2).is_err() {
+                skipped_edges.push(edge.get_id());
+                continue;
+            }
+
+            let (can_move_forward, can_move_backward) =
+                (edge.can_move_forward(), edge.can_move_backward());
+            self.add_directed_edge(
+                n1,
+                n2,
+                can_move_forward,
+                can_move_backward,
+                edge.into_edge_info(),
+            )
+            .expect("Error while adding checked edge.");
+        }
+
+        return MergeResult {
+            node_ids: ret,
+            skipped_edges: skipped_edges,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::graph::{graph::Graph, graph_observer::GraphEvent};
+
+    #[test]
+    fn fuses_matching_nodes() {
+        let mut graph1: Graph<char, ()> = Graph::new(false, false);
+        let a = graph1.add_node('a');
+        let b = graph1.add_node('b');
+        graph1.add_edge(a, b, ()).unwrap();
+
+        let mut graph2: Graph<char, ()> = Graph::new(false, false);
+        let b2 = graph2.add_node('b');
+        let c2 = graph2.add_node('c');
+        graph2.add_directed_edge(b2, c2, true, false, ()).unwrap();
+
+        let id_map = graph1.merge(graph2, |n1, n2| n1 == n2).node_ids;
+        assert_eq!(id_map[&b2], b);
+        assert_eq!(graph1.get_node_count(), 3);
+        assert_eq!(graph1.get_edge_count(), 2);
+        let path = graph1.shortest_path(a, id_map[&c2], |_| 1_f64).unwrap();
+        assert_eq!((path.nodes, path.cost), (vec![a, b, id_map[&c2]], 2_f64));
+    }
+
+    #[test]
+    fn skips_parallel_edges_between_fused_nodes() {
+        let mut graph1: Graph<char, u32> = Graph::new(false, false);
+        let a = graph1.add_node('a');
+        let b = graph1.add_node('b');
+        graph1.add_edge(a, b, 1).unwrap();
+
+        let mut graph2: Graph<char, u32> = Graph::new(false, false);
+        let a2 = graph2.add_node('a');
+        let b2 = graph2.add_node('b');
+        let c2 = graph2.add_node('c');
+        let parallel = graph2.add_edge(b2, a2, 2).unwrap();
+        graph2.add_edge(b2, c2, 3).unwrap();
+
+        let result = graph1.merge(graph2, |n1, n2| n1 == n2);
+        assert_eq!(result.skipped_edges, vec![parallel]);
+        assert_eq!(graph1.get_node_count(), 3);
+        assert_eq!(graph1.get_edge_count(), 2);
+        assert_eq!(*graph1.edges_between(&a, &b)[0].edge_info(), 1);
+        assert_eq!(graph1.edges_between(&b, &result.node_ids[&c2]).len(), 1);
+    }
+
+    #[test]
+    fn observers_see_merged_entities() {
+        let mut graph1: Graph<char, ()> = Graph::new(false, false);
+        let a = graph1.add_node('a');
+        let events: Arc<Mutex<Vec<GraphEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        graph1.add_observer(move |e| sink.lock().unwrap().push(*e));
+
+        let mut graph2: Graph<char, ()> = Graph::new(false, false);
+        let a2 = graph2.add_node('a');
+        let b2 = graph2.add_node('b');
+        graph2.add_edge(a2, b2, ()).unwrap();
+        graph2.add_observer(|_| panic!("Observer of merged graph was notified."));
+
+        let b = graph1.merge(graph2, |n1, n2| n1 == n2).node_ids[&b2];
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], GraphEvent::NodeAdded { node_id: b });
+        assert_eq!(
+            events[1],
+            GraphEvent::EdgeAdded {
+                edge_id: graph1.edges_between(&a, &b)[0].get_id(),
+                node1: a,
+                node2: b,
+            }
+        );
+    }
+}
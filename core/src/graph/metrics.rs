@@ -0,0 +1,201 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use super::{
+    edge::{Edge, EdgeId},
+    edge_weight::EdgeWeight,
+    graph::Graph,
+    node::NodeId,
+    shortest_path::QueueEntry,
+};
+
+/// Shortest paths from a single source, with every predecessor on an equally cheap path.
+struct ShortestPathDag {
+    /// Reachable nodes in order of increasing cost.
+    order: Vec<NodeId>,
+    costs: HashMap<NodeId, f64>,
+
+    /// Number of distinct cheapest paths from the source to each node.
+    path_counts: HashMap<NodeId, f64>,
+    predecessors: HashMap<NodeId, Vec<(NodeId, EdgeId)>>,
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Largest cost of a cheapest path between any two nodes, following edge directions.
+    /// Pairs without a path are ignored. Returns `None` for a graph without nodes.
+    pub fn diameter<F>(&self, edge_cost_fn: F) -> Option<f64>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        let mut ret: Option<f64> = None;
+
+        for node_id in self.get_nodes().keys() {
+            let dag = self.shortest_path_dag(*node_id, &edge_cost_fn);
+            for cost in dag.costs.values() {
+                ret = Some(f64::max(ret.unwrap_or(0_f64), *cost));
+            }
+        }
+
+        return ret;
+    }
+
+    /// Node betweenness centrality using Brandes' algorithm: for each node, the number of
+    /// cheapest paths between other nodes passing through it, split evenly among equally cheap
+    /// alternatives. Ordered pairs are counted, so every path of an undirected graph counts twice.
+    /// Costs must be positive.
+    pub fn betweenness_centrality<F>(&self, edge_cost_fn: F) -> HashMap<NodeId, f64>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        return self.brandes(&edge_cost_fn).0;
+    }
+
+    /// Edge betweenness centrality: for each edge, the number of cheapest paths between any two
+    /// nodes running along it, counted as for `betweenness_centrality`.
+    /// Costs must be positive.
+    pub fn edge_betweenness_centrality<F>(&self, edge_cost_fn: F) -> HashMap<EdgeId, f64>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        return self.brandes(&edge_cost_fn).1;
+    }
+
+    fn brandes<F>(&self, edge_cost_fn: &F) -> (HashMap<NodeId, f64>, HashMap<EdgeId, f64>)
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        let mut node_centrality: HashMap<NodeId, f64> =
+            self.get_nodes().keys().map(|id| (*id, 0_f64)).collect();
+        let mut edge_centrality: HashMap<EdgeId, f64> =
+            self.get_edges().keys().map(|id| (*id, 0_f64)).collect();
+
+        let mut node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        node_ids.sort();
+
+        for source_id in node_ids {
+            let dag = self.shortest_path_dag(source_id, edge_cost_fn);
+            let mut dependencies: HashMap<NodeId, f64> = HashMap::new();
+
+            for node_id in dag.order.iter().rev() {
+                let dependency = *dependencies.get(node_id).unwrap_or(&0_f64);
+                let path_count = dag.path_counts[node_id];
+
+                for (prev_node_id, edge_id) in dag.predecessors.get(node_id).into_iter().flatten() {
+                    let share = dag.path_counts[prev_node_id] / path_count * (1_f64 + dependency);
+                    *edge_centrality.get_mut(edge_id).unwrap() += share;
+                    *dependencies.entry(*prev_node_id).or_insert(0_f64) += share;
+                }
+
+                if *node_id != source_id {
+                    *node_centrality.get_mut(node_id).unwrap() += dependency;
+                }
+            }
+        }
+
+        return (node_centrality, edge_centrality);
+    }
+
+    fn shortest_path_dag<F>(&self, from: NodeId, edge_cost_fn: &F) -> ShortestPathDag
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        let mut dag = ShortestPathDag {
+            order: Vec::new(),
+            costs: HashMap::new(),
+            path_counts: HashMap::new(),
+            predecessors: HashMap::new(),
+        };
+        let mut settled: HashSet<NodeId> = HashSet::new();
+        let mut queue: BinaryHeap<QueueEntry> = BinaryHeap::new();
+
+        dag.costs.insert(from, 0_f64);
+        dag.path_counts.insert(from, 1_f64);
+        queue.push(QueueEntry {
+            priority: 0_f64,
+            cost: 0_f64,
+            node_id: from,
+        });
+
+        while let Some(QueueEntry { cost, node_id, .. }) = queue.pop() {
+            if !settled.insert(node_id) {
+                continue;
+            }
+
+            dag.order.push(node_id);
+            let path_count = dag.path_counts[&node_id];
+
+            for (edge_id, neighbor_id) in self.traversable_edges(node_id) {
+                if settled.contains(&neighbor_id) {
+                    continue;
+                }
+
+                let next_cost = cost + (edge_cost_fn)(self.get_edge_by_id(&edge_id).unwrap());
+                let prev_cost = *dag.costs.get(&neighbor_id).unwrap_or(&f64::INFINITY);
+
+                if next_cost < prev_cost {
+                    dag.costs.insert(neighbor_id, next_cost);
+                    dag.path_counts.insert(neighbor_id, path_count);
+                    dag.predecessors
+                        .insert(neighbor_id, vec![(node_id, edge_id)]);
+                    queue.push(QueueEntry {
+                        priority: next_cost,
+                        cost: next_cost,
+                        node_id: neighbor_id,
+                    });
+                } else if next_cost == prev_cost {
+                    *dag.path_counts.get_mut(&neighbor_id).unwrap() += path_count;
+                    dag.predecessors
+                        .get_mut(&neighbor_id)
+                        .unwrap()
+                        .push((node_id, edge_id));
+                }
+            }
+        }
+
+        return dag;
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo>
+where
+    TEdgeInfo: EdgeWeight,
+{
+    /// Diameter with the edge weights as costs.
+    pub fn diameter_by_weight(&self) -> Option<f64> {
+        return self.diameter(|e| e.edge_info().weight());
+    }
+
+    /// Node betweenness centrality with the edge weights as costs.
+    pub fn betweenness_centrality_by_weight(&self) -> HashMap<NodeId, f64> {
+        return self.betweenness_centrality(|e| e.edge_info().weight());
+    }
+
+    /// Edge betweenness centrality with the edge weights as costs.
+    pub fn edge_betweenness_centrality_by_weight(&self) -> HashMap<EdgeId, f64> {
+        return self.edge_betweenness_centrality(|e| e.edge_info().weight());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn bottlenecks_have_high_centrality() {
+        // Two triangles joined by a single corridor n[2] - n[3].
+        let mut graph: Graph<(), f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..6).map(|_| graph.add_node(())).collect();
+        for (i, j) in [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)] {
+            graph.add_edge(n[i], n[j], 1_f64).unwrap();
+        }
+        let corridor = graph.add_edge(n[2], n[3], 2_f64).unwrap();
+
+        let node_centrality = graph.betweenness_centrality_by_weight();
+        let edge_centrality = graph.edge_betweenness_centrality_by_weight();
+
+        assert_eq!(graph.diameter_by_weight(), Some(4_f64));
+        assert_eq!(node_centrality[&n[2]], 12_f64);
+        assert_eq!(node_centrality[&n[0]], 0_f64);
+        assert_eq!(edge_centrality[&corridor], 18_f64);
+        assert!(edge_centrality.values().all(|c| *c <= 18_f64));
+    }
+}
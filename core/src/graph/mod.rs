@@ -1,4 +1,29 @@
+pub mod attribute_map;
+pub mod bidirectional_search;
+pub mod chain_contraction;
+pub mod comparison;
+pub mod components;
+pub mod difference;
 pub mod edge;
+pub mod edge_weight;
+pub mod frozen_graph;
+pub mod generators;
 pub mod graph;
 pub mod graph_error;
+pub mod graph_observer;
+pub mod graph_options;
+#[cfg(feature = "serde")]
+mod graph_serde;
+pub mod k_shortest_paths;
+pub mod max_flow;
+pub mod merge;
+pub mod metrics;
 pub mod node;
+pub mod path_result;
+#[cfg(feature = "petgraph")]
+mod petgraph_interop;
+pub mod shortest_path;
+pub mod spanning_tree;
+pub mod subgraph;
+pub mod topological_sort;
+pub mod traversal;
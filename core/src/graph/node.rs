@@ -1,15 +1,57 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use super::edge::EdgeId;
+
+/// Handle of a node within a graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    pub fn new(value: u32) -> Self {
+        return Self(value);
+    }
+
+    pub fn value(&self) -> u32 {
+        return self.0;
+    }
+}
+
+impl From<u32> for NodeId {
+    fn from(value: u32) -> Self {
+        return Self(value);
+    }
+}
+
+impl From<NodeId> for u32 {
+    fn from(value: NodeId) -> Self {
+        return value.0;
+    }
+}
+
+impl Display for NodeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "n{}", self.0);
+    }
+}
 
 #[derive(Debug)]
 pub struct Node<TNodeInfo> {
-    id: u32,
-    adjacent_nodes: HashMap<u32, HashSet<u32>>,
-    connected_edges: HashMap<u32, u32>,
+    id: NodeId,
+    adjacent_nodes: HashMap<NodeId, HashSet<EdgeId>>,
+    connected_edges: HashMap<EdgeId, NodeId>,
     node_info: TNodeInfo,
 }
 
 impl<TNodeInfo> Node<TNodeInfo> {
-    pub fn new(id: u32, node_info: TNodeInfo) -> Self {
+    pub fn new(id: NodeId, node_info: TNodeInfo) -> Self {
         return Self {
             id: id,
             adjacent_nodes: HashMap::new(),
@@ -18,7 +60,7 @@ impl<TNodeInfo> Node<TNodeInfo> {
         };
     }
 
-    pub fn get_id(&self) -> u32 {
+    pub fn get_id(&self) -> NodeId {
         return self.id;
     }
 
@@ -26,11 +68,11 @@ impl<TNodeInfo> Node<TNodeInfo> {
         return self.connected_edges.len();
     }
 
-    pub fn adjacent_nodes(&self) -> &HashMap<u32, HashSet<u32>> {
+    pub fn adjacent_nodes(&self) -> &HashMap<NodeId, HashSet<EdgeId>> {
         return &self.adjacent_nodes;
     }
 
-    pub fn connected_edges(&self) -> &HashMap<u32, u32> {
+    pub fn connected_edges(&self) -> &HashMap<EdgeId, NodeId> {
         return &self.connected_edges;
     }
 
@@ -42,12 +84,16 @@ impl<TNodeInfo> Node<TNodeInfo> {
         return &mut self.node_info;
     }
 
-    pub fn add_connection(&mut self, node_id: u32, edge_id: u32) -> bool {
+    pub fn into_node_info(self) -> TNodeInfo {
+        return self.node_info;
+    }
+
+    pub fn add_connection(&mut self, node_id: NodeId, edge_id: EdgeId) -> bool {
         if self.connected_edges.contains_key(&edge_id) {
             return false;
         }
 
-        let mut edge_set: Option<&mut HashSet<u32>> = self.adjacent_nodes.get_mut(&node_id);
+        let mut edge_set: Option<&mut HashSet<EdgeId>> = self.adjacent_nodes.get_mut(&node_id);
         if edge_set.is_none() {
             self.adjacent_nodes.insert(node_id, HashSet::new());
             edge_set = self.adjacent_nodes.get_mut(&node_id);
@@ -59,7 +105,7 @@ impl<TNodeInfo> Node<TNodeInfo> {
         return true;
     }
 
-    pub fn remove_connection(&mut self, edge_id: u32) -> bool {
+    pub fn remove_connection(&mut self, edge_id: EdgeId) -> bool {
         let node_id = match self.connected_edges.get(&edge_id) {
             Some(id) => *id,
             None => return false,
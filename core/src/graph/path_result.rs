@@ -0,0 +1,93 @@
+use super::{edge::EdgeId, node::NodeId};
+
+/// Path through a graph, as returned by path planners.
+/// `edges[i]` leads from `nodes[i]` to `nodes[i + 1]`, so there is one node more than edges.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathResult {
+    pub nodes: Vec<NodeId>,
+    pub edges: Vec<EdgeId>,
+    pub cost: f64,
+}
+
+impl PathResult {
+    /// Path consisting only of the start node.
+    pub fn from_start(node_id: NodeId) -> Self {
+        return Self {
+            nodes: vec![node_id],
+            edges: Vec::new(),
+            cost: 0_f64,
+        };
+    }
+
+    pub fn start(&self) -> Option<NodeId> {
+        return self.nodes.first().copied();
+    }
+
+    pub fn end(&self) -> Option<NodeId> {
+        return self.nodes.last().copied();
+    }
+
+    /// Number of edges along the path.
+    pub fn len(&self) -> usize {
+        return self.edges.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.edges.is_empty();
+    }
+
+    /// Extend path by one edge.
+    pub fn push(&mut self, edge_id: EdgeId, node_id: NodeId, cost: f64) {
+        self.edges.push(edge_id);
+        self.nodes.push(node_id);
+        self.cost += cost;
+    }
+
+    /// Append a path starting where this one ends, or return `None` if they do not meet.
+    pub fn concat(mut self, other: PathResult) -> Option<PathResult> {
+        if self.end() != other.start() {
+            return None;
+        }
+
+        self.nodes.extend(other.nodes.into_iter().skip(1));
+        self.edges.extend(other.edges);
+        self.cost += other.cost;
+        return Some(self);
+    }
+
+    /// Path along the same edges in opposite order. Edge directions are not checked.
+    pub fn reversed(mut self) -> PathResult {
+        self.nodes.reverse();
+        self.edges.reverse();
+        return self;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{edge::EdgeId, node::NodeId};
+
+    use super::PathResult;
+
+    #[test]
+    fn combine_paths() {
+        let n: Vec<NodeId> = (1..5).map(NodeId::new).collect();
+        let e: Vec<EdgeId> = (1..4).map(EdgeId::new).collect();
+        let mut path1 = PathResult::from_start(n[0]);
+        path1.push(e[0], n[1], 1_f64);
+        let mut path2 = PathResult::from_start(n[1]);
+        path2.push(e[1], n[2], 2_f64);
+        path2.push(e[2], n[3], 3_f64);
+
+        assert!(path2.clone().concat(path1.clone()).is_none());
+
+        let path = path1.concat(path2).unwrap();
+        assert_eq!(path.nodes, n);
+        assert_eq!(path.edges, e);
+        assert_eq!(path.cost, 6_f64);
+
+        let reversed = path.reversed();
+        assert_eq!((reversed.start(), reversed.end()), (Some(n[3]), Some(n[0])));
+        assert_eq!(reversed.edges[0], e[2]);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+
+use super::{
+    edge::{Edge, EdgeId},
+    graph::Graph,
+    graph_options::GraphOptions,
+    node::NodeId,
+};
+
+/// Nodes are added in order of their IDs, so the n-th node index refers to the n-th lowest ID.
+/// Every direction an edge can be traversed in becomes a separate directed edge.
+impl<TNodeInfo, TEdgeInfo> From<Graph<TNodeInfo, TEdgeInfo>> for DiGraph<TNodeInfo, TEdgeInfo>
+where
+    TEdgeInfo: Clone,
+{
+    fn from(value: Graph<TNodeInfo, TEdgeInfo>) -> Self {
+        let (nodes, edges) = value.into_parts();
+        let mut ret: DiGraph<TNodeInfo, TEdgeInfo> =
+            DiGraph::with_capacity(nodes.len(), edges.len());
+        let mut id_to_index_map: HashMap<NodeId, NodeIndex> = HashMap::new();
+
+        for (node_id, node_info) in nodes {
+            id_to_index_map.insert(node_id, ret.add_node(node_info));
+        }
+
+        for edge in edges {
+            let n1 = *id_to_index_map.get(&edge.node1()).unwrap();
+            let n2 = *id_to_index_map.get(&edge.node2()).unwrap();
+
+            if edge.can_move_forward() {
+                ret.add_edge(n1, n2, edge.edge_info().clone());
+            }
+
+            if edge.can_move_backward() {
+                ret.add_edge(n2, n1, edge.into_edge_info());
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Nodes are added in order of their IDs, so the n-th node index refers to the n-th lowest ID.
+/// Edge directions are discarded.
+impl<TNodeInfo, TEdgeInfo> From<Graph<TNodeInfo, TEdgeInfo>> for UnGraph<TNodeInfo, TEdgeInfo> {
+    fn from(value: Graph<TNodeInfo, TEdgeInfo>) -> Self {
+        let (nodes, edges) = value.into_parts();
+        let mut ret: UnGraph<TNodeInfo, TEdgeInfo> =
+            UnGraph::with_capacity(nodes.len(), edges.len());
+        let mut id_to_index_map: HashMap<NodeId, NodeIndex> = HashMap::new();
+
+        for (node_id, node_info) in nodes {
+            id_to_index_map.insert(node_id, ret.add_node(node_info));
+        }
+
+        for edge in edges {
+            let n1 = *id_to_index_map.get(&edge.node1()).unwrap();
+            let n2 = *id_to_index_map.get(&edge.node2()).unwrap();
+            ret.add_edge(n1, n2, edge.into_edge_info());
+        }
+
+        return ret;
+    }
+}
+
+/// Node with index n gets ID n + 1, and edges become one-way edges.
+impl<TNodeInfo, TEdgeInfo> From<DiGraph<TNodeInfo, TEdgeInfo>> for Graph<TNodeInfo, TEdgeInfo> {
+    fn from(value: DiGraph<TNodeInfo, TEdgeInfo>) -> Self {
+        return from_petgraph(value, false);
+    }
+}
+
+/// Node with index n gets ID n + 1, and edges become bidirectional edges.
+impl<TNodeInfo, TEdgeInfo> From<UnGraph<TNodeInfo, TEdgeInfo>> for Graph<TNodeInfo, TEdgeInfo> {
+    fn from(value: UnGraph<TNodeInfo, TEdgeInfo>) -> Self {
+        return from_petgraph(value, true);
+    }
+}
+
+fn from_petgraph<TNodeInfo, TEdgeInfo, Ty>(
+    value: petgraph::Graph<TNodeInfo, TEdgeInfo, Ty>,
+    is_bidirectional: bool,
+) -> Graph<TNodeInfo, TEdgeInfo>
+where
+    Ty: petgraph::EdgeType,
+{
+    let (nodes, edges) = value.into_nodes_edges();
+    let mut _nodes: Vec<(NodeId, TNodeInfo)> = Vec::with_capacity(nodes.len());
+    let mut _edges: Vec<Edge<TEdgeInfo>> = Vec::with_capacity(edges.len());
+
+    for (i, node) in nodes.into_iter().enumerate() {
+        _nodes.push((NodeId::new(i as u32 + 1), node.weight));
+    }
+
+    for (i, edge) in edges.into_iter().enumerate() {
+        _edges.push(Edge::new(
+            EdgeId::new(i as u32 + 1),
+            NodeId::new(edge.source().index() as u32 + 1),
+            NodeId::new(edge.target().index() as u32 + 1),
+            true,
+            is_bidirectional,
+            edge.weight,
+        ));
+    }
+
+    return Graph::from_parts(
+        _nodes,
+        _edges,
+        GraphOptions::multigraph().directed(!is_bidirectional),
+        (1, 1),
+    )
+    .expect("petgraph graph refers to missing node.");
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::{
+        algo::dijkstra,
+        graph::{DiGraph, NodeIndex},
+    };
+
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn round_trip_keeps_directions() {
+        let mut graph: Graph<char, f64> = Graph::new(false, false);
+        let n1 = graph.add_node('a');
+        let n2 = graph.add_node('b');
+        let n3 = graph.add_node('c');
+        graph.add_edge(n1, n2, 1_f64).unwrap();
+        graph.add_directed_edge(n2, n3, true, false, 2_f64).unwrap();
+
+        let petgraph: DiGraph<char, f64> = graph.into();
+        assert_eq!(petgraph.edge_count(), 3);
+        let costs = dijkstra(&petgraph, NodeIndex::new(0), None, |e| *e.weight());
+        assert_eq!(costs.get(&NodeIndex::new(2)), Some(&3_f64));
+
+        let restored: Graph<char, f64> = petgraph.into();
+        assert_eq!(restored.get_node_count(), 3);
+        assert_eq!(restored.get_edge_count(), 3);
+        assert_eq!(
+            *restored
+                .get_node_by_id(&NodeId::new(3))
+                .unwrap()
+                .node_info(),
+            'c'
+        );
+        assert!(restored
+            .shortest_path(NodeId::new(3), NodeId::new(1), |e| *e.edge_info())
+            .is_err());
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use super::{
+    edge::{Edge, EdgeId},
+    edge_weight::EdgeWeight,
+    graph::Graph,
+    graph_error::GraphError,
+    node::{Node, NodeId},
+    path_result::PathResult,
+};
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Find the cheapest path between two nodes using Dijkstra's algorithm.
+    /// Edges are traversed only in the directions they allow, at the cost given by `edge_cost_fn`,
+    /// which must not be negative.
+    pub fn shortest_path<F>(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        edge_cost_fn: F,
+    ) -> Result<PathResult, GraphError>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        return self.a_star(from, to, edge_cost_fn, |_| 0_f64);
+    }
+
+    /// Find the cheapest path between two nodes using A* search.
+    /// `heuristic_fn` estimates the remaining cost from a node to the goal. The path is optimal
+    /// as long as the estimate never exceeds the actual cost.
+    pub fn a_star<F, H>(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        edge_cost_fn: F,
+        heuristic_fn: H,
+    ) -> Result<PathResult, GraphError>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+        H: Fn(&Node<TNodeInfo>) -> f64,
+    {
+        if self.get_node_by_id(&from).is_none() || self.get_node_by_id(&to).is_none() {
+            return Err(GraphError::NoSuchNode);
+        }
+
+        return match self.search(
+            from,
+            to,
+            &edge_cost_fn,
+            &heuristic_fn,
+            &HashSet::new(),
+            &HashSet::new(),
+        ) {
+            Some((nodes, edges, cost)) => Ok(PathResult {
+                nodes: nodes,
+                edges: edges,
+                cost: cost,
+            }),
+            None => Err(GraphError::Unreachable),
+        };
+    }
+
+    /// Run A* search, skipping the excluded nodes and edges.
+    /// Returns the node sequence, the edge sequence and the total cost.
+    pub(crate) fn search<F, H>(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        edge_cost_fn: &F,
+        heuristic_fn: &H,
+        excluded_nodes: &HashSet<NodeId>,
+        excluded_edges: &HashSet<EdgeId>,
+    ) -> Option<(Vec<NodeId>, Vec<EdgeId>, f64)>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+        H: Fn(&Node<TNodeInfo>) -> f64,
+    {
+        let mut costs: HashMap<NodeId, f64> = HashMap::new();
+        let mut prev_steps: HashMap<NodeId, (NodeId, EdgeId)> = HashMap::new();
+        let mut queue: BinaryHeap<QueueEntry> = BinaryHeap::new();
+        costs.insert(from, 0_f64);
+        queue.push(QueueEntry {
+            priority: (heuristic_fn)(self.get_node_by_id(&from).unwrap()),
+            cost: 0_f64,
+            node_id: from,
+        });
+
+        while let Some(QueueEntry { cost, node_id, .. }) = queue.pop() {
+            if node_id == to {
+                let mut nodes: Vec<NodeId> = vec![to];
+                let mut edges: Vec<EdgeId> = Vec::new();
+                let mut _node_id = to;
+
+                while let Some((prev_node_id, edge_id)) = prev_steps.get(&_node_id) {
+                    nodes.push(*prev_node_id);
+                    edges.push(*edge_id);
+                    _node_id = *prev_node_id;
+                }
+
+                nodes.reverse();
+                edges.reverse();
+                return Some((nodes, edges, cost));
+            }
+
+            if cost > *costs.get(&node_id).unwrap() {
+                continue;
+            }
+
+            for (edge_id, neighbor_id) in self.traversable_edges(node_id) {
+                if excluded_edges.contains(&edge_id) || excluded_nodes.contains(&neighbor_id) {
+                    continue;
+                }
+
+                let edge = self.get_edge_by_id(&edge_id).unwrap();
+                let next_cost = cost + (edge_cost_fn)(edge);
+
+                if let Some(c) = costs.get(&neighbor_id) {
+                    if *c <= next_cost {
+                        continue;
+                    }
+                }
+
+                costs.insert(neighbor_id, next_cost);
+                prev_steps.insert(neighbor_id, (node_id, edge_id));
+                queue.push(QueueEntry {
+                    priority: next_cost
+                        + (heuristic_fn)(self.get_node_by_id(&neighbor_id).unwrap()),
+                    cost: next_cost,
+                    node_id: neighbor_id,
+                });
+            }
+        }
+
+        return None;
+    }
+
+    /// List (edge ID, neighbor ID) of the edges which can be traversed away from node,
+    /// in order of the edge IDs.
+    pub(crate) fn traversable_edges(&self, node_id: NodeId) -> Vec<(EdgeId, NodeId)> {
+        let node = match self.get_node_by_id(&node_id) {
+            Some(n) => n,
+            None => return Vec::new(),
+        };
+
+        let mut ret: Vec<(EdgeId, NodeId)> = node
+            .connected_edges()
+            .iter()
+            .filter(|(edge_id, _)| {
+                let edge = self.get_edge_by_id(edge_id).unwrap();
+                return (edge.node1() == node_id && edge.can_move_forward())
+                    || (edge.node2() == node_id && edge.can_move_backward());
+            })
+            .map(|(edge_id, neighbor_id)| (*edge_id, *neighbor_id))
+            .collect();
+        ret.sort();
+        return ret;
+    }
+
+    /// List (edge ID, neighbor ID) of the edges which can be traversed towards node,
+    /// in order of the edge IDs.
+    pub(crate) fn reverse_traversable_edges(&self, node_id: NodeId) -> Vec<(EdgeId, NodeId)> {
+        let node = match self.get_node_by_id(&node_id) {
+            Some(n) => n,
+            None => return Vec::new(),
+        };
+
+        let mut ret: Vec<(EdgeId, NodeId)> = node
+            .connected_edges()
+            .iter()
+            .filter(|(edge_id, _)| {
+                let edge = self.get_edge_by_id(edge_id).unwrap();
+                return (edge.node2() == node_id && edge.can_move_forward())
+                    || (edge.node1() == node_id && edge.can_move_backward());
+            })
+            .map(|(edge_id, neighbor_id)| (*edge_id, *neighbor_id))
+            .collect();
+        ret.sort();
+        return ret;
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo>
+where
+    TEdgeInfo: EdgeWeight,
+{
+    /// Find the cheapest path between two nodes using Dijkstra's algorithm, with the edge
+    /// weights as costs.
+    pub fn shortest_path_by_weight(
+        &self,
+        from: NodeId,
+        to: NodeId,
+    ) -> Result<PathResult, GraphError> {
+        return self.shortest_path(from, to, |e| e.edge_info().weight());
+    }
+}
+
+/// Entry of the priority queue, ordered so that the lowest priority is popped first.
+/// Ties are broken by the node ID to keep the search deterministic.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct QueueEntry {
+    pub priority: f64,
+    pub cost: f64,
+    pub node_id: NodeId,
+}
+
+impl PartialEq for QueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for QueueEntry {}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return f64::total_cmp(&other.priority, &self.priority)
+            .then_with(|| NodeId::cmp(&other.node_id, &self.node_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{edge::Edge, graph::Graph, graph_error::GraphError, node::NodeId};
+
+    #[test]
+    fn finds_cheapest_directed_path() {
+        let mut graph: Graph<(), f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..5).map(|_| graph.add_node(())).collect();
+        let e = [
+            graph.add_edge(n[0], n[1], 1_f64).unwrap(),
+            graph.add_edge(n[1], n[2], 1_f64).unwrap(),
+        ];
+        graph.add_edge(n[0], n[2], 5_f64).unwrap();
+        graph
+            .add_directed_edge(n[2], n[3], false, true, 1_f64)
+            .unwrap();
+        graph.add_edge(n[0], n[3], 4_f64).unwrap();
+
+        let cost_fn = |e: &Edge<f64>| *e.edge_info();
+        let path = graph.shortest_path(n[0], n[2], cost_fn).unwrap();
+        assert_eq!((path.nodes, path.cost), (vec![n[0], n[1], n[2]], 2_f64));
+        assert_eq!(path.edges, vec![e[0], e[1]]);
+        let path = graph.shortest_path(n[0], n[3], cost_fn).unwrap();
+        assert_eq!((path.nodes, path.cost), (vec![n[0], n[3]], 4_f64));
+        let path = graph.shortest_path(n[3], n[2], cost_fn).unwrap();
+        assert_eq!((path.nodes, path.cost), (vec![n[3], n[2]], 1_f64));
+        assert_eq!(
+            graph.shortest_path(n[0], n[4], cost_fn),
+            Err(GraphError::Unreachable)
+        );
+        assert_eq!(
+            graph.shortest_path_by_weight(n[0], n[2]),
+            graph.shortest_path(n[0], n[2], cost_fn)
+        );
+    }
+}
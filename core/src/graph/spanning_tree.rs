@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use super::{
+    edge::{Edge, EdgeId},
+    edge_weight::EdgeWeight,
+    graph::Graph,
+    node::NodeId,
+};
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Find a minimum spanning forest using Kruskal's algorithm, ignoring edge directions.
+    /// Returns IDs of the edges in the forest, in order of increasing cost.
+    /// Edges of equal cost are taken in order of their IDs.
+    pub fn minimum_spanning_tree<F>(&self, edge_cost_fn: F) -> Vec<EdgeId>
+    where
+        F: Fn(&Edge<TEdgeInfo>) -> f64,
+    {
+        let mut edges: Vec<(f64, EdgeId)> = self
+            .get_edges()
+            .values()
+            .map(|e| ((edge_cost_fn)(e), e.get_id()))
+            .collect();
+        edges.sort_by(|(c1, id1), (c2, id2)| f64::total_cmp(c1, c2).then(EdgeId::cmp(id1, id2)));
+
+        let mut parents: HashMap<NodeId, NodeId> =
+            self.get_nodes().keys().map(|id| (*id, *id)).collect();
+        let mut ret: Vec<EdgeId> = Vec::new();
+
+        for (_, edge_id) in edges {
+            let edge = self.get_edge_by_id(&edge_id).unwrap();
+            let root1 = find_root(&mut parents, edge.node1());
+            let root2 = find_root(&mut parents, edge.node2());
+
+            if root1 == root2 {
+                continue;
+            }
+
+            parents.insert(NodeId::max(root1, root2), NodeId::min(root1, root2));
+            ret.push(edge_id);
+        }
+
+        return ret;
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo>
+where
+    TEdgeInfo: EdgeWeight,
+{
+    /// Find a minimum spanning forest with the edge weights as costs.
+    pub fn minimum_spanning_tree_by_weight(&self) -> Vec<EdgeId> {
+        return self.minimum_spanning_tree(|e| e.edge_info().weight());
+    }
+}
+
+/// Find the root of a node in the union-find forest, compressing the path along the way.
+fn find_root(parents: &mut HashMap<NodeId, NodeId>, node_id: NodeId) -> NodeId {
+    let mut root = node_id;
+    while parents[&root] != root {
+        root = parents[&root];
+    }
+
+    let mut _node_id = node_id;
+    while _node_id != root {
+        let parent = parents[&_node_id];
+        parents.insert(_node_id, root);
+        _node_id = parent;
+    }
+
+    return root;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn spanning_forest() {
+        let mut graph: Graph<(), f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..6).map(|_| graph.add_node(())).collect();
+        let e1 = graph.add_edge(n[0], n[1], 1_f64).unwrap();
+        let e2 = graph.add_edge(n[1], n[2], 2_f64).unwrap();
+        graph.add_edge(n[0], n[2], 3_f64).unwrap();
+        let e4 = graph.add_edge(n[2], n[3], 1.5).unwrap();
+        let e5 = graph.add_edge(n[4], n[5], 4_f64).unwrap();
+
+        assert_eq!(
+            graph.minimum_spanning_tree_by_weight(),
+            vec![e1, e4, e2, e5]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use super::{
+    edge::Edge,
+    graph::Graph,
+    graph_error::GraphError,
+    node::{Node, NodeId},
+};
+
+/// Node IDs of a graph mapped to the IDs of their copies in another graph.
+pub type NodeIdMap = HashMap<NodeId, NodeId>;
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo>
+where
+    TNodeInfo: Clone,
+    TEdgeInfo: Clone,
+{
+    /// Build the subgraph of the nodes and edges satisfying the predicates.
+    /// Edges are kept only if both of their nodes are kept as well. Entities are copied in order
+    /// of their IDs and receive new IDs. The subgraph has the options of this graph.
+    /// Returns the subgraph and a map from old to new node IDs.
+    pub fn filter<NP, EP>(
+        &self,
+        node_predicate: NP,
+        edge_predicate: EP,
+    ) -> Result<(Graph<TNodeInfo, TEdgeInfo>, NodeIdMap), GraphError>
+    where
+        NP: Fn(&Node<TNodeInfo>) -> bool,
+        EP: Fn(&Edge<TEdgeInfo>) -> bool,
+    {
+        let mut ret: Graph<TNodeInfo, TEdgeInfo> = Graph::with_options(self.options());
+        let mut old_to_new_id_map: NodeIdMap = HashMap::new();
+
+        let mut nodes: Vec<&Node<TNodeInfo>> = self
+            .get_nodes()
+            .values()
+            .filter(|n| (node_predicate)(n))
+            .collect();
+        let mut edges: Vec<&Edge<TEdgeInfo>> = self
+            .get_edges()
+            .values()
+            .filter(|e| (edge_predicate)(e))
+            .collect();
+        nodes.sort_by_key(|n| n.get_id());
+        edges.sort_by_key(|e| e.get_id());
+
+        for node in nodes {
+            let new_node_id = ret.add_node(node.node_info().clone());
+            old_to_new_id_map.insert(node.get_id(), new_node_id);
+        }
+
+        for edge in edges {
+            let (n1, n2) = match (
+                old_to_new_id_map.get(&edge.node1()),
+                old_to_new_id_map.get(&edge.node2()),
+            ) {
+                (Some(n1), Some(n2)) => (*n1, *n2),
+                _ => continue,
+            };
+
+            ret.add_directed_edge(
+                n1,
+                n2,
+                edge.can_move_forward(),
+                edge.can_move_backward(),
+                edge.edge_info().clone(),
+            )?;
+        }
+
+        return Ok((ret, old_to_new_id_map));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, graph_options::GraphOptions, node::NodeId};
+
+    #[test]
+    fn induced_subgraph() {
+        let mut graph: Graph<u32, f64> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 2_f64).unwrap();
+        graph.add_edge(n[1], n[2], 0.5).unwrap();
+        graph.add_edge(n[2], n[3], 2_f64).unwrap();
+        graph.add_edge(n[0], n[3], 3_f64).unwrap();
+
+        let (subgraph, id_map) = graph
+            .filter(|n| *n.node_info() != 3, |e| *e.edge_info() >= 1_f64)
+            .unwrap();
+        assert_eq!(subgraph.get_node_count(), 3);
+        assert_eq!(subgraph.get_edge_count(), 1);
+        assert!(!id_map.contains_key(&n[3]));
+        assert_eq!(subgraph.degree(&id_map[&n[1]]), Some(1));
+        assert_eq!(subgraph.degree(&id_map[&n[2]]), Some(0));
+    }
+
+    #[test]
+    fn keeps_direction_of_opposite_edges() {
+        let mut graph: Graph<u32, ()> = Graph::with_options(GraphOptions::simple().directed(true));
+        let n: Vec<NodeId> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+
+        let (subgraph, id_map) = graph.filter(|n| *n.node_info() < 2, |_| true).unwrap();
+        assert_eq!(subgraph.options(), graph.options());
+        assert_eq!(subgraph.get_edge_count(), 2);
+        let (a, b) = (id_map[&n[0]], id_map[&n[1]]);
+        assert!(subgraph
+            .edges_between(&a, &b)
+            .iter()
+            .all(|e| e.can_move_forward() && !e.can_move_backward()));
+    }
+}
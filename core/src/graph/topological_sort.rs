@@ -0,0 +1,73 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use super::{graph::Graph, graph_error::GraphError, node::NodeId};
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Order the nodes so that every edge leads from an earlier node to a later one, using Kahn's
+    /// algorithm. Edges traversable in both directions form a cycle of their own.
+    /// Among nodes which may come next, the one with the lowest ID is taken first.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, GraphError> {
+        let mut in_degrees: HashMap<NodeId, usize> =
+            self.get_nodes().keys().map(|id| (*id, 0)).collect();
+
+        for node_id in self.get_nodes().keys() {
+            for (_, neighbor_id) in self.traversable_edges(*node_id) {
+                *in_degrees.get_mut(&neighbor_id).unwrap() += 1;
+            }
+        }
+
+        let mut queue: BinaryHeap<Reverse<NodeId>> = in_degrees
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(id, _)| Reverse(*id))
+            .collect();
+        let mut ret: Vec<NodeId> = Vec::with_capacity(self.get_node_count());
+
+        while let Some(Reverse(node_id)) = queue.pop() {
+            ret.push(node_id);
+
+            for (_, neighbor_id) in self.traversable_edges(node_id) {
+                let in_degree = in_degrees.get_mut(&neighbor_id).unwrap();
+                *in_degree -= 1;
+
+                if *in_degree == 0 {
+                    queue.push(Reverse(neighbor_id));
+                }
+            }
+        }
+
+        if ret.len() < self.get_node_count() {
+            return Err(GraphError::CycleDetected);
+        }
+
+        return Ok(ret);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, graph_error::GraphError, node::NodeId};
+
+    #[test]
+    fn sorts_dag_and_detects_cycles() {
+        let mut graph: Graph<(), ()> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..4).map(|_| graph.add_node(())).collect();
+        graph
+            .add_directed_edge(n[3], n[1], true, false, ())
+            .unwrap();
+        graph
+            .add_directed_edge(n[1], n[0], true, false, ())
+            .unwrap();
+        graph
+            .add_directed_edge(n[0], n[2], false, true, ())
+            .unwrap();
+
+        assert_eq!(graph.topological_sort(), Ok(vec![n[2], n[3], n[1], n[0]]));
+
+        graph.add_edge(n[0], n[3], ()).unwrap();
+        assert_eq!(graph.topological_sort(), Err(GraphError::CycleDetected));
+    }
+}
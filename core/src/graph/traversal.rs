@@ -0,0 +1,151 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{graph::Graph, node::NodeId};
+
+/// Node visited during a traversal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TraversalStep {
+    pub node_id: NodeId,
+
+    /// Number of edges between the start node and this node along the traversal tree.
+    pub depth: usize,
+
+    /// Node this node was reached from, or `None` for the start node.
+    pub parent: Option<NodeId>,
+}
+
+/// Breadth-first traversal, created by `Graph::bfs`.
+pub struct Bfs<'a, TNodeInfo, TEdgeInfo> {
+    graph: &'a Graph<TNodeInfo, TEdgeInfo>,
+    queue: VecDeque<TraversalStep>,
+    visited: HashSet<NodeId>,
+}
+
+/// Depth-first traversal in pre-order, created by `Graph::dfs`.
+pub struct Dfs<'a, TNodeInfo, TEdgeInfo> {
+    graph: &'a Graph<TNodeInfo, TEdgeInfo>,
+    stack: Vec<TraversalStep>,
+    visited: HashSet<NodeId>,
+}
+
+impl<TNodeInfo, TEdgeInfo> Graph<TNodeInfo, TEdgeInfo> {
+    /// Traverse the nodes reachable from start in breadth-first order.
+    /// Edges are followed only in the directions they allow, neighbors in order of the edge IDs.
+    /// Yields nothing if the start node does not exist.
+    pub fn bfs(&self, start: NodeId) -> Bfs<'_, TNodeInfo, TEdgeInfo> {
+        let mut queue: VecDeque<TraversalStep> = VecDeque::new();
+        let mut visited: HashSet<NodeId> = HashSet::new();
+
+        if self.get_node_by_id(&start).is_some() {
+            visited.insert(start);
+            queue.push_back(TraversalStep {
+                node_id: start,
+                depth: 0,
+                parent: None,
+            });
+        }
+
+        return Bfs {
+            graph: self,
+            queue: queue,
+            visited: visited,
+        };
+    }
+
+    /// Traverse the nodes reachable from start in depth-first pre-order.
+    /// Edges are followed only in the directions they allow, neighbors in order of the edge IDs.
+    /// Yields nothing if the start node does not exist.
+    pub fn dfs(&self, start: NodeId) -> Dfs<'_, TNodeInfo, TEdgeInfo> {
+        let mut stack: Vec<TraversalStep> = Vec::new();
+
+        if self.get_node_by_id(&start).is_some() {
+            stack.push(TraversalStep {
+                node_id: start,
+                depth: 0,
+                parent: None,
+            });
+        }
+
+        return Dfs {
+            graph: self,
+            stack: stack,
+            visited: HashSet::new(),
+        };
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Iterator for Bfs<'_, TNodeInfo, TEdgeInfo> {
+    type Item = TraversalStep;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.queue.pop_front()?;
+
+        for (_, neighbor_id) in self.graph.traversable_edges(step.node_id) {
+            if self.visited.insert(neighbor_id) {
+                self.queue.push_back(TraversalStep {
+                    node_id: neighbor_id,
+                    depth: step.depth + 1,
+                    parent: Some(step.node_id),
+                });
+            }
+        }
+
+        return Some(step);
+    }
+}
+
+impl<TNodeInfo, TEdgeInfo> Iterator for Dfs<'_, TNodeInfo, TEdgeInfo> {
+    type Item = TraversalStep;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(step) = self.stack.pop() {
+            if !self.visited.insert(step.node_id) {
+                continue;
+            }
+
+            // Push in reverse, so that the neighbor of the lowest edge ID is visited first.
+            for (_, neighbor_id) in self.graph.traversable_edges(step.node_id).into_iter().rev() {
+                if !self.visited.contains(&neighbor_id) {
+                    self.stack.push(TraversalStep {
+                        node_id: neighbor_id,
+                        depth: step.depth + 1,
+                        parent: Some(step.node_id),
+                    });
+                }
+            }
+
+            return Some(step);
+        }
+
+        return None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{graph::Graph, node::NodeId};
+
+    #[test]
+    fn traversal_order() {
+        let mut graph: Graph<(), ()> = Graph::new(false, false);
+        let n: Vec<NodeId> = (0..6).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        graph.add_edge(n[2], n[4], ()).unwrap();
+        graph
+            .add_directed_edge(n[5], n[4], true, false, ())
+            .unwrap();
+
+        let bfs: Vec<NodeId> = graph.bfs(n[0]).map(|s| s.node_id).collect();
+        let dfs: Vec<NodeId> = graph.dfs(n[0]).map(|s| s.node_id).collect();
+        assert_eq!(bfs, vec![n[0], n[1], n[2], n[3], n[4]]);
+        assert_eq!(dfs, vec![n[0], n[1], n[3], n[2], n[4]]);
+
+        let last = graph.bfs(n[0]).last().unwrap();
+        assert_eq!(last.depth, 2);
+        assert_eq!(last.parent, Some(n[2]));
+        assert_eq!(graph.bfs(n[5]).count(), 6);
+        assert_eq!(graph.bfs(n[4]).count(), 5);
+    }
+}
@@ -1,2 +1,4 @@
+pub mod collections;
 pub mod enums;
+pub mod graph;
 pub mod numerics;
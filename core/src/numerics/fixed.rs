@@ -0,0 +1,474 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+use num_traits::{Num, One, ToPrimitive, Zero};
+
+use super::{pose2::Pose2, real::Real, vector2::Vector2};
+
+/// Signed fixed-point number with 32 integer and 32 fractional bits.
+/// All arithmetic, including square roots and trigonometry, uses integer instructions only,
+/// so geometry over `Vector2<Fixed>` and `Pose2<Fixed>` runs on targets without a floating
+/// point unit.
+/// Like the integer types, results that do not fit wrap around and division by zero panics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Fixed(i64);
+
+pub type Vector2Fixed = Vector2<Fixed>;
+
+pub type Pose2Fixed = Pose2<Fixed>;
+
+const FRACTIONAL_BITS: u32 = 32;
+
+/// CORDIC gain compensation, the product of 1 / sqrt(1 + 2^-2i) over all iterations.
+const CORDIC_GAIN: i128 = 2608131496;
+
+/// atan(2^-i) for each CORDIC iteration i.
+const CORDIC_ANGLES: [i128; 32] = [
+    3373259426, 1991351318, 1052175346, 534100635, 268086748, 134174063, 67103403, 33553749,
+    16777131, 8388597, 4194303, 2097152, 1048576, 524288, 262144, 131072, 65536, 32768, 16384,
+    8192, 4096, 2048, 1024, 512, 256, 128, 64, 32, 16, 8, 4, 2,
+];
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << FRACTIONAL_BITS);
+    pub const EPSILON: Fixed = Fixed(1);
+    pub const MIN: Fixed = Fixed(i64::MIN);
+    pub const MAX: Fixed = Fixed(i64::MAX);
+    pub const PI: Fixed = Fixed(13493037705);
+    pub const FRAC_PI_2: Fixed = Fixed(6746518852);
+    pub const TAU: Fixed = Fixed(26986075409);
+
+    pub fn from_bits(bits: i64) -> Self {
+        return Fixed(bits);
+    }
+
+    pub fn to_bits(&self) -> i64 {
+        return self.0;
+    }
+
+    /// Closest fixed-point value, saturating at the limits of the range.
+    pub fn from_f64(value: f64) -> Self {
+        return Fixed((value * (1_u64 << FRACTIONAL_BITS) as f64).round() as i64);
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        return self.0 as f64 / (1_u64 << FRACTIONAL_BITS) as f64;
+    }
+
+    pub fn abs(&self) -> Self {
+        return Fixed(self.0.wrapping_abs());
+    }
+
+    /// Largest integer not greater than self.
+    pub fn floor(&self) -> Self {
+        return Fixed(self.0 & !((1 << FRACTIONAL_BITS) - 1));
+    }
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+
+    let mut x: u128 = 1 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+impl Real for Fixed {
+    /// Returns zero for negative values.
+    fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+
+        return Fixed(isqrt((self.0 as u128) << FRACTIONAL_BITS) as i64);
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let x = self.0.unsigned_abs() as u128;
+        let y = other.0.unsigned_abs() as u128;
+        return Fixed(isqrt(x * x + y * y) as i64);
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        let pi = Fixed::PI.0 as i128;
+        let half_pi = Fixed::FRAC_PI_2.0 as i128;
+
+        // Reduce to (-pi, pi], then to [-pi/2, pi/2] where the rotation converges.
+        let mut angle = self.0 as i128 % Fixed::TAU.0 as i128;
+        if angle > pi {
+            angle -= Fixed::TAU.0 as i128;
+        } else if angle <= -pi {
+            angle += Fixed::TAU.0 as i128;
+        }
+
+        let mut sign = 1;
+        if angle > half_pi {
+            angle -= pi;
+            sign = -1;
+        } else if angle < -half_pi {
+            angle += pi;
+            sign = -1;
+        }
+
+        let mut x = CORDIC_GAIN;
+        let mut y = 0_i128;
+        for (i, step) in CORDIC_ANGLES.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if angle >= 0 {
+                x -= dx;
+                y += dy;
+                angle -= step;
+            } else {
+                x += dx;
+                y -= dy;
+                angle += step;
+            }
+        }
+
+        return (Fixed((sign * y) as i64), Fixed((sign * x) as i64));
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        if self.0 == 0 {
+            return if other.0 < 0 { Fixed::PI } else { Fixed::ZERO };
+        }
+
+        let mut x = other.0 as i128;
+        let mut y = self.0 as i128;
+
+        // Rotate into the right half plane, where the vectoring converges.
+        let mut angle = 0_i128;
+        if x < 0 {
+            angle = if y >= 0 {
+                Fixed::PI.0 as i128
+            } else {
+                -Fixed::PI.0 as i128
+            };
+            x = -x;
+            y = -y;
+        }
+
+        // Scale up so that the shifts below keep full precision.
+        let magnitude = x.abs().max(y.abs());
+        let shift = 90 - (128 - magnitude.leading_zeros()) as i32;
+        if shift > 0 {
+            x <<= shift;
+            y <<= shift;
+        }
+
+        for (i, step) in CORDIC_ANGLES.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if y > 0 {
+                x += dx;
+                y -= dy;
+                angle += step;
+            } else {
+                x -= dx;
+                y += dy;
+                angle -= step;
+            }
+        }
+
+        return Fixed(angle as i64);
+    }
+
+    fn pi() -> Self {
+        return Fixed::PI;
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        return Fixed((value as i64) << FRACTIONAL_BITS);
+    }
+}
+
+impl fmt::Display for Fixed {
+    /// Exact decimal representation, with at most ten fractional digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bits = self.0.unsigned_abs();
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let integer = bits >> FRACTIONAL_BITS;
+        let mut fraction = bits & ((1 << FRACTIONAL_BITS) - 1);
+
+        let mut digits = String::new();
+        while fraction != 0 && digits.len() < 10 {
+            fraction *= 10;
+            digits.push(char::from(b'0' + (fraction >> FRACTIONAL_BITS) as u8));
+            fraction &= (1 << FRACTIONAL_BITS) - 1;
+        }
+
+        if digits.is_empty() {
+            return write!(f, "{}{}", sign, integer);
+        }
+        return write!(f, "{}{}.{}", sign, integer, digits);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParseFixedError;
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "invalid fixed-point literal");
+    }
+}
+
+impl std::error::Error for ParseFixedError {}
+
+impl Zero for Fixed {
+    fn zero() -> Self {
+        return Fixed::ZERO;
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.0 == 0;
+    }
+}
+
+impl One for Fixed {
+    fn one() -> Self {
+        return Fixed::ONE;
+    }
+}
+
+impl Num for Fixed {
+    type FromStrRadixErr = ParseFixedError;
+
+    /// Parse an optionally signed number with an optional fractional part, e.g. "-1.25".
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (negative, digits) = match str.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, str),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (digits, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseFixedError);
+        }
+
+        let mut bits: i128 = 0;
+        for c in integer.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseFixedError)?;
+            bits = bits * radix as i128 + ((digit as i128) << FRACTIONAL_BITS);
+            if bits > i64::MAX as i128 + 1 {
+                return Err(ParseFixedError);
+            }
+        }
+
+        // Digits beyond the resolution of the fractional bits are ignored.
+        let mut numerator: i128 = 0;
+        let mut denominator: i128 = 1;
+        for c in fraction.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseFixedError)?;
+            if denominator < 1 << 64 {
+                numerator = numerator * radix as i128 + digit as i128;
+                denominator *= radix as i128;
+            }
+        }
+        bits += (numerator << FRACTIONAL_BITS) / denominator;
+
+        if negative {
+            bits = -bits;
+        }
+        if bits > i64::MAX as i128 || bits < i64::MIN as i128 {
+            return Err(ParseFixedError);
+        }
+
+        return Ok(Fixed(bits as i64));
+    }
+}
+
+impl ToPrimitive for Fixed {
+    /// Integer part, rounded towards zero.
+    fn to_i64(&self) -> Option<i64> {
+        let integer = self.0 >> FRACTIONAL_BITS;
+        if self.0 < 0 && self.0 & ((1 << FRACTIONAL_BITS) - 1) != 0 {
+            return Some(integer + 1);
+        }
+
+        return Some(integer);
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        return self.to_i64().and_then(|i| i.to_u64());
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        return Some(Fixed::to_f64(self));
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, rhs: Fixed) -> Fixed {
+        return Fixed(self.0.wrapping_add(rhs.0));
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, rhs: Fixed) -> Fixed {
+        return Fixed(self.0.wrapping_sub(rhs.0));
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, rhs: Fixed) -> Fixed {
+        return Fixed(((self.0 as i128 * rhs.0 as i128) >> FRACTIONAL_BITS) as i64);
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    fn div(self, rhs: Fixed) -> Fixed {
+        return Fixed((((self.0 as i128) << FRACTIONAL_BITS) / rhs.0 as i128) as i64);
+    }
+}
+
+impl Rem for Fixed {
+    type Output = Fixed;
+
+    fn rem(self, rhs: Fixed) -> Fixed {
+        return Fixed(self.0 % rhs.0);
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        return Fixed(self.0.wrapping_neg());
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Fixed) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Fixed) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Fixed {
+    fn div_assign(&mut self, rhs: Fixed) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Num;
+
+    use crate::numerics::{real::Real, vector::Vector};
+
+    use super::{Fixed, Pose2Fixed, Vector2Fixed};
+
+    fn assert_close(value: Fixed, expected: f64) {
+        assert!(
+            (value.to_f64() - expected).abs() < 1e-8,
+            "{} != {}",
+            value,
+            expected
+        );
+    }
+
+    #[test]
+    fn arithmetic_and_functions() {
+        let a = Fixed::from_str_radix("2.5", 10).unwrap();
+        let b = Fixed::from(-4);
+        assert_eq!(a * b, Fixed::from(-10));
+        assert_eq!(b / a, Fixed::from_str_radix("-1.6", 10).unwrap());
+        assert_eq!((a + b).to_string(), "-1.5");
+        assert_eq!(Fixed::from(9).sqrt(), Fixed::from(3));
+        assert_eq!(Fixed::from(3).hypot(Fixed::from(-4)), Fixed::from(5));
+
+        let step = Fixed::from_bits(1).to_f64();
+        assert_eq!(Fixed::from_f64(0.75 * step), Fixed::from_bits(1));
+        assert_eq!(Fixed::from_f64(-0.75 * step), Fixed::from_bits(-1));
+
+        for angle in [-7_f64, -2_f64, -0.5, 0_f64, 1_f64, 2.5, 3_f64, 10_f64] {
+            let (sin, cos) = Fixed::from_f64(angle).sin_cos();
+            assert_close(sin, angle.sin());
+            assert_close(cos, angle.cos());
+        }
+        for (y, x) in [
+            (1_f64, 1_f64),
+            (-1_f64, 0_f64),
+            (0.001, -3_f64),
+            (-2_f64, -5_f64),
+        ] {
+            assert_close(Fixed::from_f64(y).atan2(Fixed::from_f64(x)), y.atan2(x));
+        }
+        assert_eq!(Fixed::ZERO.atan2(Fixed::from(-1)), Fixed::PI);
+    }
+
+    #[test]
+    fn vector_operations() {
+        let v = Vector2Fixed::from_xy(Fixed::from(3), Fixed::from(4));
+        assert_eq!(v.dot(&v), Fixed::from(25));
+        assert_eq!(v.magnitude(), 5_f64);
+        assert_eq!(v.clamp_length(Fixed::ONE), v.unit_vector());
+        assert_close(v.unit_vector().x, 0.6);
+
+        let rotated = v.rotate(Fixed::FRAC_PI_2);
+        assert_close(rotated.x, -4_f64);
+        assert_close(rotated.y, 3_f64);
+        assert_close(v.angle_to(&rotated), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn pose_operations() {
+        let robot = Pose2Fixed::from_xytheta(Fixed::from(1), Fixed::from(2), Fixed::FRAC_PI_2);
+        let point = robot.transform_point(&Vector2Fixed::from_xy(Fixed::ONE, Fixed::ZERO));
+        assert_close(point.x, 1_f64);
+        assert_close(point.y, 3_f64);
+
+        let identity = robot.compose(&robot.inverse());
+        assert_close(identity.position.x, 0_f64);
+        assert_close(identity.position.y, 0_f64);
+        assert_close(identity.theta, 0_f64);
+
+        let turned = robot.compose(&Pose2Fixed::from_xytheta(
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::from(3),
+        ));
+        assert_close(
+            turned.theta,
+            3_f64 + std::f64::consts::FRAC_PI_2 - std::f64::consts::TAU,
+        );
+        let relative = robot.relative_pose(&turned);
+        assert_close(relative.theta, 3_f64);
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::Mul;
+
+use super::{matrix3::Matrix3, pose2d::normalize_angle, pose2d::Pose2D, vector2d::Vector2D};
+
+/// Rigid motion in the plane: rotation by `rotation` (radians) followed by `translation`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isometry2D {
+    pub rotation: f64,
+    pub translation: Vector2D,
+}
+
+impl Isometry2D {
+    pub fn new(rotation: f64, translation: Vector2D) -> Self {
+        return Self {
+            rotation: normalize_angle(rotation),
+            translation: translation,
+        };
+    }
+
+    pub fn identity() -> Self {
+        return Self::new(0_f64, Vector2D::zero());
+    }
+
+    pub fn transform_point(&self, point: &Vector2D) -> Vector2D {
+        return point.rotate(self.rotation) + self.translation;
+    }
+
+    /// Rotate a direction, without translating it.
+    pub fn transform_vector(&self, v: &Vector2D) -> Vector2D {
+        return v.rotate(self.rotation);
+    }
+
+    /// Move a pose given in the source frame into the target frame.
+    pub fn transform_pose(&self, pose: &Pose2D) -> Pose2D {
+        return Pose2D::new(
+            self.transform_point(&pose.position),
+            pose.theta + self.rotation,
+        );
+    }
+
+    /// Apply other first, then this isometry.
+    pub fn compose(&self, other: &Isometry2D) -> Isometry2D {
+        return Isometry2D::new(
+            self.rotation + other.rotation,
+            self.transform_point(&other.translation),
+        );
+    }
+
+    pub fn inverse(&self) -> Isometry2D {
+        return Isometry2D::new(-self.rotation, -self.translation.rotate(-self.rotation));
+    }
+
+    /// Homogeneous matrix form.
+    pub fn to_matrix(&self) -> Matrix3 {
+        let (sin, cos) = self.rotation.sin_cos();
+        return Matrix3::from_rows([
+            [cos, -sin, self.translation.x],
+            [sin, cos, self.translation.y],
+            [0_f64, 0_f64, 1_f64],
+        ]);
+    }
+}
+
+/// Isometry2D * Isometry2D
+impl Mul<Isometry2D> for Isometry2D {
+    type Output = Isometry2D;
+
+    fn mul(self, rhs: Isometry2D) -> Self::Output {
+        return self.compose(&rhs);
+    }
+}
+
+/// Isometry2D * Pose2D
+impl Mul<Pose2D> for Isometry2D {
+    type Output = Pose2D;
+
+    fn mul(self, rhs: Pose2D) -> Self::Output {
+        return self.transform_pose(&rhs);
+    }
+}
+
+/// The isometry mapping the frame of the pose into its parent frame.
+impl From<Pose2D> for Isometry2D {
+    fn from(value: Pose2D) -> Self {
+        return Isometry2D::new(value.theta, value.position);
+    }
+}
+
+impl From<Isometry2D> for Pose2D {
+    fn from(value: Isometry2D) -> Self {
+        return Pose2D::new(value.translation, value.rotation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+
+    use super::Isometry2D;
+
+    #[test]
+    fn compose_and_invert() {
+        let a = Isometry2D::new(FRAC_PI_2, Vector2D::from_xy(1_f64, 2_f64));
+        let b = Isometry2D::new(-0.3, Vector2D::from_xy(-0.5, 4_f64));
+        let point = Vector2D::from_xy(1_f64, 0_f64);
+
+        let moved = a.transform_point(&point);
+        assert!((moved - Vector2D::from_xy(1_f64, 3_f64)).magnitude() < 1e-9);
+        assert!((a.transform_vector(&point) - Vector2D::from_xy(0_f64, 1_f64)).magnitude() < 1e-9);
+        assert!((a.to_matrix().transform_point(&point) - moved).magnitude() < 1e-9);
+
+        let ab = a * b;
+        let expected = a.transform_point(&b.transform_point(&point));
+        assert!((ab.transform_point(&point) - expected).magnitude() < 1e-9);
+
+        for identity in [a.compose(&a.inverse()), a.inverse().compose(&a)] {
+            assert!(identity.rotation.abs() < 1e-9);
+            assert!(identity.translation.magnitude() < 1e-9);
+        }
+        assert!((a.inverse().transform_point(&moved) - point).magnitude() < 1e-9);
+
+        let pose = Pose2D::from_xytheta(1_f64, 0_f64, 0.2);
+        let moved_pose = a * pose;
+        assert!((moved_pose.position - moved).magnitude() < 1e-9);
+        assert!((moved_pose.theta - (0.2 + FRAC_PI_2)).abs() < 1e-9);
+        assert_eq!(Pose2D::from(Isometry2D::from(pose)), pose);
+        assert_eq!(Isometry2D::identity().transform_point(&point), point);
+    }
+}
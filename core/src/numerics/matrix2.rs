@@ -0,0 +1,116 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::vector2d::Vector2D;
+
+/// 2x2 matrix, stored row by row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix2 {
+    pub rows: [[f64; 2]; 2],
+}
+
+impl Matrix2 {
+    pub fn from_rows(rows: [[f64; 2]; 2]) -> Self {
+        return Self { rows: rows };
+    }
+
+    pub fn zero() -> Self {
+        return Self::from_rows([[0_f64; 2]; 2]);
+    }
+
+    pub fn identity() -> Self {
+        return Self::from_rows([[1_f64, 0_f64], [0_f64, 1_f64]]);
+    }
+
+    /// Counterclockwise rotation by angle (radians).
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        return Self::from_rows([[cos, -sin], [sin, cos]]);
+    }
+
+    pub fn transpose(&self) -> Self {
+        let r = &self.rows;
+        return Self::from_rows([[r[0][0], r[1][0]], [r[0][1], r[1][1]]]);
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let r = &self.rows;
+        return r[0][0] * r[1][1] - r[0][1] * r[1][0];
+    }
+
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0_f64 {
+            return None;
+        }
+
+        let r = &self.rows;
+        return Some(Self::from_rows([[r[1][1], -r[0][1]], [-r[1][0], r[0][0]]]) * (1_f64 / det));
+    }
+}
+
+/// Matrix2 * Matrix2
+impl Mul<Matrix2> for Matrix2 {
+    type Output = Matrix2;
+
+    fn mul(self, rhs: Matrix2) -> Self::Output {
+        let mut ret = Matrix2::zero();
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    ret.rows[i][j] += self.rows[i][k] * rhs.rows[k][j];
+                }
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Matrix2 * Vector2D
+impl Mul<Vector2D> for Matrix2 {
+    type Output = Vector2D;
+
+    fn mul(self, rhs: Vector2D) -> Self::Output {
+        let r = &self.rows;
+        return Vector2D::from_xy(
+            r[0][0] * rhs.x + r[0][1] * rhs.y,
+            r[1][0] * rhs.x + r[1][1] * rhs.y,
+        );
+    }
+}
+
+/// Matrix2 * f64
+impl Mul<f64> for Matrix2 {
+    type Output = Matrix2;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        return Matrix2::from_rows(self.rows.map(|row| row.map(|v| v * rhs)));
+    }
+}
+
+/// Matrix2 + Matrix2
+impl Add<Matrix2> for Matrix2 {
+    type Output = Matrix2;
+
+    fn add(self, rhs: Matrix2) -> Self::Output {
+        let mut ret = self;
+        for i in 0..2 {
+            for j in 0..2 {
+                ret.rows[i][j] += rhs.rows[i][j];
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Matrix2 - Matrix2
+impl Sub<Matrix2> for Matrix2 {
+    type Output = Matrix2;
+
+    fn sub(self, rhs: Matrix2) -> Self::Output {
+        return self + rhs * -1_f64;
+    }
+}
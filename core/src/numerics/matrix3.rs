@@ -0,0 +1,212 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::{pose2d::Pose2D, quaternion::Quaternion, vector2d::Vector2D, vector3d::Vector3D};
+
+/// 3x3 matrix, stored row by row. Also used as homogeneous transform in the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix3 {
+    pub rows: [[f64; 3]; 3],
+}
+
+impl Matrix3 {
+    pub fn from_rows(rows: [[f64; 3]; 3]) -> Self {
+        return Self { rows: rows };
+    }
+
+    pub fn zero() -> Self {
+        return Self::from_rows([[0_f64; 3]; 3]);
+    }
+
+    pub fn identity() -> Self {
+        let mut ret = Self::zero();
+        for i in 0..3 {
+            ret.rows[i][i] = 1_f64;
+        }
+
+        return ret;
+    }
+
+    /// Rotation matrix of a unit quaternion.
+    pub fn from_quaternion(q: &Quaternion) -> Self {
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        return Self::from_rows([
+            [
+                1_f64 - 2_f64 * (y * y + z * z),
+                2_f64 * (x * y - w * z),
+                2_f64 * (x * z + w * y),
+            ],
+            [
+                2_f64 * (x * y + w * z),
+                1_f64 - 2_f64 * (x * x + z * z),
+                2_f64 * (y * z - w * x),
+            ],
+            [
+                2_f64 * (x * z - w * y),
+                2_f64 * (y * z + w * x),
+                1_f64 - 2_f64 * (x * x + y * y),
+            ],
+        ]);
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut ret = Self::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                ret.rows[i][j] = self.rows[j][i];
+            }
+        }
+
+        return ret;
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let r = &self.rows;
+        return r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1])
+            - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+            + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+    }
+
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0_f64 {
+            return None;
+        }
+
+        // Transposed matrix of cofactors, with cyclic indices taking care of the signs.
+        let r = &self.rows;
+        let mut ret = Self::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                ret.rows[j][i] = (r[i1][j1] * r[i2][j2] - r[i1][j2] * r[i2][j1]) / det;
+            }
+        }
+
+        return Some(ret);
+    }
+
+    /// Apply as homogeneous transform to a point in the plane.
+    pub fn transform_point(&self, point: &Vector2D) -> Vector2D {
+        let v = *self * Vector3D::from_xyz(point.x, point.y, 1_f64);
+        return Vector2D::from_xy(v.x / v.z, v.y / v.z);
+    }
+}
+
+impl From<Pose2D> for Matrix3 {
+    fn from(value: Pose2D) -> Self {
+        let (sin, cos) = value.theta.sin_cos();
+        return Matrix3::from_rows([
+            [cos, -sin, value.position.x],
+            [sin, cos, value.position.y],
+            [0_f64, 0_f64, 1_f64],
+        ]);
+    }
+}
+
+/// Matrix3 * Matrix3
+impl Mul<Matrix3> for Matrix3 {
+    type Output = Matrix3;
+
+    fn mul(self, rhs: Matrix3) -> Self::Output {
+        let mut ret = Matrix3::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    ret.rows[i][j] += self.rows[i][k] * rhs.rows[k][j];
+                }
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Matrix3 * Vector3D
+impl Mul<Vector3D> for Matrix3 {
+    type Output = Vector3D;
+
+    fn mul(self, rhs: Vector3D) -> Self::Output {
+        let r = &self.rows;
+        return Vector3D::from_xyz(
+            r[0][0] * rhs.x + r[0][1] * rhs.y + r[0][2] * rhs.z,
+            r[1][0] * rhs.x + r[1][1] * rhs.y + r[1][2] * rhs.z,
+            r[2][0] * rhs.x + r[2][1] * rhs.y + r[2][2] * rhs.z,
+        );
+    }
+}
+
+/// Matrix3 * f64
+impl Mul<f64> for Matrix3 {
+    type Output = Matrix3;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        return Matrix3::from_rows(self.rows.map(|row| row.map(|v| v * rhs)));
+    }
+}
+
+/// Matrix3 + Matrix3
+impl Add<Matrix3> for Matrix3 {
+    type Output = Matrix3;
+
+    fn add(self, rhs: Matrix3) -> Self::Output {
+        let mut ret = self;
+        for i in 0..3 {
+            for j in 0..3 {
+                ret.rows[i][j] += rhs.rows[i][j];
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Matrix3 - Matrix3
+impl Sub<Matrix3> for Matrix3 {
+    type Output = Matrix3;
+
+    fn sub(self, rhs: Matrix3) -> Self::Output {
+        return self + rhs * -1_f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::numerics::{
+        pose2d::Pose2D, quaternion::Quaternion, vector::Vector, vector2d::Vector2D,
+        vector3d::Vector3D,
+    };
+
+    use super::Matrix3;
+
+    #[test]
+    fn inverse_and_transforms() {
+        let m = Matrix3::from_rows([
+            [2_f64, 0_f64, 1_f64],
+            [1_f64, 3_f64, 0_f64],
+            [0_f64, 1_f64, 4_f64],
+        ]);
+        assert_eq!(m.determinant(), 25_f64);
+        let product = m * m.inverse().unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1_f64 } else { 0_f64 };
+                assert!((product.rows[i][j] - expected).abs() < 1e-9);
+            }
+        }
+        assert_eq!(Matrix3::zero().inverse(), None);
+
+        let pose = Pose2D::from_xytheta(1_f64, 2_f64, FRAC_PI_2);
+        let point = Vector2D::from_xy(3_f64, -1_f64);
+        let transformed = Matrix3::from(pose).transform_point(&point);
+        assert!((transformed - pose.transform_point(&point)).magnitude() < 1e-9);
+
+        let q = Quaternion::from_euler(0.3, -0.4, 1.2);
+        let v = Vector3D::from_xyz(1_f64, 2_f64, 3_f64);
+        assert!((Matrix3::from_quaternion(&q) * v - q.rotate(&v)).magnitude() < 1e-9);
+    }
+}
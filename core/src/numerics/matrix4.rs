@@ -0,0 +1,239 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::{matrix3::Matrix3, pose3d::Pose3D, vector3d::Vector3D};
+
+/// 4x4 matrix, stored row by row. Mostly used as homogeneous transform in space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix4 {
+    pub rows: [[f64; 4]; 4],
+}
+
+impl Matrix4 {
+    pub fn from_rows(rows: [[f64; 4]; 4]) -> Self {
+        return Self { rows: rows };
+    }
+
+    pub fn zero() -> Self {
+        return Self::from_rows([[0_f64; 4]; 4]);
+    }
+
+    pub fn identity() -> Self {
+        let mut ret = Self::zero();
+        for i in 0..4 {
+            ret.rows[i][i] = 1_f64;
+        }
+
+        return ret;
+    }
+
+    /// Homogeneous transform rotating by `rotation`, then translating by `translation`.
+    pub fn from_rotation_translation(rotation: &Matrix3, translation: &Vector3D) -> Self {
+        let mut ret = Self::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                ret.rows[i][j] = rotation.rows[i][j];
+            }
+        }
+
+        ret.rows[0][3] = translation.x;
+        ret.rows[1][3] = translation.y;
+        ret.rows[2][3] = translation.z;
+        return ret;
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut ret = Self::zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                ret.rows[i][j] = self.rows[j][i];
+            }
+        }
+
+        return ret;
+    }
+
+    /// Determinant by Gaussian elimination with partial pivoting.
+    pub fn determinant(&self) -> f64 {
+        let mut r = self.rows;
+        let mut ret = 1_f64;
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|a, b| f64::total_cmp(&r[*a][col].abs(), &r[*b][col].abs()))
+                .unwrap();
+            if r[pivot][col] == 0_f64 {
+                return 0_f64;
+            }
+
+            if pivot != col {
+                r.swap(pivot, col);
+                ret = -ret;
+            }
+
+            ret *= r[col][col];
+            for row in col + 1..4 {
+                let factor = r[row][col] / r[col][col];
+                for k in col..4 {
+                    r[row][k] -= factor * r[col][k];
+                }
+            }
+        }
+
+        return ret;
+    }
+
+    /// Inverse by Gauss-Jordan elimination with partial pivoting.
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut r = self.rows;
+        let mut ret = Self::identity();
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|a, b| f64::total_cmp(&r[*a][col].abs(), &r[*b][col].abs()))
+                .unwrap();
+            if r[pivot][col] == 0_f64 {
+                return None;
+            }
+
+            r.swap(pivot, col);
+            ret.rows.swap(pivot, col);
+
+            let scale = r[col][col];
+            for k in 0..4 {
+                r[col][k] /= scale;
+                ret.rows[col][k] /= scale;
+            }
+
+            for row in 0..4 {
+                if row == col {
+                    continue;
+                }
+
+                let factor = r[row][col];
+                for k in 0..4 {
+                    r[row][k] -= factor * r[col][k];
+                    ret.rows[row][k] -= factor * ret.rows[col][k];
+                }
+            }
+        }
+
+        return Some(ret);
+    }
+
+    /// Apply as homogeneous transform to a point, including the translation.
+    pub fn transform_point(&self, point: &Vector3D) -> Vector3D {
+        let r = &self.rows;
+        let mut v = [0_f64; 4];
+        for i in 0..4 {
+            v[i] = r[i][0] * point.x + r[i][1] * point.y + r[i][2] * point.z + r[i][3];
+        }
+
+        return Vector3D::from_xyz(v[0] / v[3], v[1] / v[3], v[2] / v[3]);
+    }
+
+    /// Apply as homogeneous transform to a direction, ignoring the translation.
+    pub fn transform_vector(&self, v: &Vector3D) -> Vector3D {
+        let r = &self.rows;
+        return Vector3D::from_xyz(
+            r[0][0] * v.x + r[0][1] * v.y + r[0][2] * v.z,
+            r[1][0] * v.x + r[1][1] * v.y + r[1][2] * v.z,
+            r[2][0] * v.x + r[2][1] * v.y + r[2][2] * v.z,
+        );
+    }
+}
+
+impl From<Pose3D> for Matrix4 {
+    fn from(value: Pose3D) -> Self {
+        return Matrix4::from_rotation_translation(
+            &Matrix3::from_quaternion(&value.orientation),
+            &value.position,
+        );
+    }
+}
+
+/// Matrix4 * Matrix4
+impl Mul<Matrix4> for Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: Matrix4) -> Self::Output {
+        let mut ret = Matrix4::zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                for k in 0..4 {
+                    ret.rows[i][j] += self.rows[i][k] * rhs.rows[k][j];
+                }
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Matrix4 * f64
+impl Mul<f64> for Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        return Matrix4::from_rows(self.rows.map(|row| row.map(|v| v * rhs)));
+    }
+}
+
+/// Matrix4 + Matrix4
+impl Add<Matrix4> for Matrix4 {
+    type Output = Matrix4;
+
+    fn add(self, rhs: Matrix4) -> Self::Output {
+        let mut ret = self;
+        for i in 0..4 {
+            for j in 0..4 {
+                ret.rows[i][j] += rhs.rows[i][j];
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Matrix4 - Matrix4
+impl Sub<Matrix4> for Matrix4 {
+    type Output = Matrix4;
+
+    fn sub(self, rhs: Matrix4) -> Self::Output {
+        return self + rhs * -1_f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numerics::{
+        pose3d::Pose3D, quaternion::Quaternion, vector::Vector, vector3d::Vector3D,
+    };
+
+    use super::Matrix4;
+
+    #[test]
+    fn homogeneous_transforms() {
+        let pose = Pose3D::new(
+            Vector3D::from_xyz(1_f64, -2_f64, 0.5),
+            Quaternion::from_euler(0.2, 0.1, -0.7),
+        );
+        let m = Matrix4::from(pose);
+        let point = Vector3D::from_xyz(0.3, 4_f64, -1_f64);
+        assert!((m.transform_point(&point) - pose.transform_point(&point)).magnitude() < 1e-9);
+        assert!((m.determinant() - 1_f64).abs() < 1e-9);
+
+        let inverse = m.inverse().unwrap();
+        let back = inverse.transform_point(&m.transform_point(&point));
+        assert!((back - point).magnitude() < 1e-9);
+        assert!(
+            (inverse * Matrix4::from(pose.inverse()).inverse().unwrap() - Matrix4::identity())
+                .rows
+                .iter()
+                .flatten()
+                .all(|v| v.abs() < 1e-9)
+        );
+        assert_eq!(Matrix4::zero().inverse(), None);
+    }
+}
@@ -1,5 +1,23 @@
+#[cfg(feature = "fixed_point")]
+pub mod fixed;
+pub mod isometry2d;
+pub mod matrix2;
+pub mod matrix3;
+pub mod matrix4;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+pub mod pose2;
+pub mod pose2d;
+pub mod pose2f;
+pub mod pose3d;
+pub mod quaternion;
+pub mod real;
+pub mod similarity2d;
+pub mod units;
 pub mod vector;
+pub mod vector2;
 pub mod vector2d;
+pub mod vector2f;
 pub mod vector2i;
 pub mod vector3d;
 pub mod vector3i;
@@ -0,0 +1,227 @@
+use nalgebra::{Isometry2, Isometry3, Point2, Point3, Translation3, UnitQuaternion};
+
+use super::{
+    matrix2::Matrix2, matrix3::Matrix3, matrix4::Matrix4, pose2d::Pose2D, pose3d::Pose3D,
+    quaternion::Quaternion, vector2d::Vector2D, vector2i::Vector2I, vector3d::Vector3D,
+    vector3i::Vector3I,
+};
+
+impl From<Vector2D> for nalgebra::Vector2<f64> {
+    fn from(value: Vector2D) -> Self {
+        return nalgebra::Vector2::new(value.x, value.y);
+    }
+}
+
+impl From<nalgebra::Vector2<f64>> for Vector2D {
+    fn from(value: nalgebra::Vector2<f64>) -> Self {
+        return Vector2D::from_xy(value.x, value.y);
+    }
+}
+
+impl From<Vector2D> for Point2<f64> {
+    fn from(value: Vector2D) -> Self {
+        return Point2::new(value.x, value.y);
+    }
+}
+
+impl From<Point2<f64>> for Vector2D {
+    fn from(value: Point2<f64>) -> Self {
+        return Vector2D::from_xy(value.x, value.y);
+    }
+}
+
+impl From<Vector3D> for nalgebra::Vector3<f64> {
+    fn from(value: Vector3D) -> Self {
+        return nalgebra::Vector3::new(value.x, value.y, value.z);
+    }
+}
+
+impl From<nalgebra::Vector3<f64>> for Vector3D {
+    fn from(value: nalgebra::Vector3<f64>) -> Self {
+        return Vector3D::from_xyz(value.x, value.y, value.z);
+    }
+}
+
+impl From<Vector3D> for Point3<f64> {
+    fn from(value: Vector3D) -> Self {
+        return Point3::new(value.x, value.y, value.z);
+    }
+}
+
+impl From<Point3<f64>> for Vector3D {
+    fn from(value: Point3<f64>) -> Self {
+        return Vector3D::from_xyz(value.x, value.y, value.z);
+    }
+}
+
+impl From<Vector2I> for nalgebra::Vector2<i64> {
+    fn from(value: Vector2I) -> Self {
+        return nalgebra::Vector2::new(value.x, value.y);
+    }
+}
+
+impl From<nalgebra::Vector2<i64>> for Vector2I {
+    fn from(value: nalgebra::Vector2<i64>) -> Self {
+        return Vector2I::from_xy(value.x, value.y);
+    }
+}
+
+impl From<Vector3I> for nalgebra::Vector3<i64> {
+    fn from(value: Vector3I) -> Self {
+        return nalgebra::Vector3::new(value.x, value.y, value.z);
+    }
+}
+
+impl From<nalgebra::Vector3<i64>> for Vector3I {
+    fn from(value: nalgebra::Vector3<i64>) -> Self {
+        return Vector3I::from_xyz(value.x, value.y, value.z);
+    }
+}
+
+impl From<Quaternion> for nalgebra::Quaternion<f64> {
+    fn from(value: Quaternion) -> Self {
+        return nalgebra::Quaternion::new(value.w, value.x, value.y, value.z);
+    }
+}
+
+impl From<nalgebra::Quaternion<f64>> for Quaternion {
+    fn from(value: nalgebra::Quaternion<f64>) -> Self {
+        return Quaternion::from_wxyz(value.w, value.i, value.j, value.k);
+    }
+}
+
+/// The quaternion is normalized.
+impl From<Quaternion> for UnitQuaternion<f64> {
+    fn from(value: Quaternion) -> Self {
+        return UnitQuaternion::from_quaternion(value.into());
+    }
+}
+
+impl From<UnitQuaternion<f64>> for Quaternion {
+    fn from(value: UnitQuaternion<f64>) -> Self {
+        return value.into_inner().into();
+    }
+}
+
+impl From<Pose2D> for Isometry2<f64> {
+    fn from(value: Pose2D) -> Self {
+        return Isometry2::new(value.position.into(), value.theta);
+    }
+}
+
+impl From<Isometry2<f64>> for Pose2D {
+    fn from(value: Isometry2<f64>) -> Self {
+        return Pose2D::new(value.translation.vector.into(), value.rotation.angle());
+    }
+}
+
+impl From<Pose3D> for Isometry3<f64> {
+    fn from(value: Pose3D) -> Self {
+        return Isometry3::from_parts(
+            Translation3::new(value.position.x, value.position.y, value.position.z),
+            value.orientation.into(),
+        );
+    }
+}
+
+impl From<Isometry3<f64>> for Pose3D {
+    fn from(value: Isometry3<f64>) -> Self {
+        return Pose3D::new(value.translation.vector.into(), value.rotation.into());
+    }
+}
+
+impl From<Matrix2> for nalgebra::Matrix2<f64> {
+    fn from(value: Matrix2) -> Self {
+        return nalgebra::Matrix2::from_fn(|i, j| value.rows[i][j]);
+    }
+}
+
+impl From<nalgebra::Matrix2<f64>> for Matrix2 {
+    fn from(value: nalgebra::Matrix2<f64>) -> Self {
+        let mut ret = Matrix2::zero();
+        for i in 0..2 {
+            for j in 0..2 {
+                ret.rows[i][j] = value[(i, j)];
+            }
+        }
+
+        return ret;
+    }
+}
+
+impl From<Matrix3> for nalgebra::Matrix3<f64> {
+    fn from(value: Matrix3) -> Self {
+        return nalgebra::Matrix3::from_fn(|i, j| value.rows[i][j]);
+    }
+}
+
+impl From<nalgebra::Matrix3<f64>> for Matrix3 {
+    fn from(value: nalgebra::Matrix3<f64>) -> Self {
+        let mut ret = Matrix3::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                ret.rows[i][j] = value[(i, j)];
+            }
+        }
+
+        return ret;
+    }
+}
+
+impl From<Matrix4> for nalgebra::Matrix4<f64> {
+    fn from(value: Matrix4) -> Self {
+        return nalgebra::Matrix4::from_fn(|i, j| value.rows[i][j]);
+    }
+}
+
+impl From<nalgebra::Matrix4<f64>> for Matrix4 {
+    fn from(value: nalgebra::Matrix4<f64>) -> Self {
+        let mut ret = Matrix4::zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                ret.rows[i][j] = value[(i, j)];
+            }
+        }
+
+        return ret;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Isometry2, Isometry3, Point2};
+
+    use crate::numerics::{
+        matrix3::Matrix3, pose2d::Pose2D, pose3d::Pose3D, quaternion::Quaternion, vector::Vector,
+        vector2d::Vector2D, vector3d::Vector3D,
+    };
+
+    #[test]
+    fn conversions_agree_with_nalgebra() {
+        let pose = Pose2D::from_xytheta(1_f64, 2_f64, 0.5);
+        let isometry: Isometry2<f64> = pose.into();
+        let point = Vector2D::from_xy(-3_f64, 4_f64);
+        let transformed: Vector2D = (isometry * Point2::from(point)).into();
+        assert!((transformed - pose.transform_point(&point)).magnitude() < 1e-9);
+        assert_eq!(Pose2D::from(isometry), pose);
+
+        let pose = Pose3D::new(
+            Vector3D::from_xyz(1_f64, -2_f64, 3_f64),
+            Quaternion::from_euler(0.1, 0.2, 0.3),
+        );
+        let isometry: Isometry3<f64> = pose.into();
+        let v = Vector3D::from_xyz(0.5, 0.25, -1_f64);
+        let transformed: Vector3D = isometry.transform_point(&v.into()).into();
+        assert!((transformed - pose.transform_point(&v)).magnitude() < 1e-9);
+
+        let m = Matrix3::from_rows([
+            [1_f64, 2_f64, 3_f64],
+            [0_f64, 1_f64, 4_f64],
+            [5_f64, 6_f64, 0_f64],
+        ]);
+        let n: nalgebra::Matrix3<f64> = m.into();
+        assert_eq!(n[(0, 2)], 3_f64);
+        let difference = Matrix3::from(n.try_inverse().unwrap()) - m.inverse().unwrap();
+        assert!(difference.rows.iter().flatten().all(|v| v.abs() < 1e-9));
+    }
+}
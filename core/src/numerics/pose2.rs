@@ -0,0 +1,87 @@
+use super::{real::Real, vector2::Vector2};
+
+/// Position and heading in the plane, i.e. an element of SE(2), over any `Real` scalar.
+/// `Pose2D` and `Pose2F` are the common instances. The heading is kept in (-pi, pi].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pose2<T> {
+    pub position: Vector2<T>,
+    pub theta: T,
+}
+
+impl<T> Pose2<T>
+where
+    T: Real,
+{
+    pub fn new(position: Vector2<T>, theta: T) -> Self {
+        return Self {
+            position: position,
+            theta: normalize_angle(theta),
+        };
+    }
+
+    pub fn from_xytheta(x: T, y: T, theta: T) -> Self {
+        return Self::new(Vector2::from_xy(x, y), theta);
+    }
+
+    /// Pose at from, heading towards to. The heading is zero if both points coincide.
+    pub fn looking_at(from: Vector2<T>, to: Vector2<T>) -> Self {
+        return Self::new(from, (to - from).angle());
+    }
+
+    pub fn identity() -> Self {
+        return Self {
+            position: Vector2::zero(),
+            theta: T::zero(),
+        };
+    }
+
+    /// Unit vector in the direction of the heading.
+    pub fn heading(&self) -> Vector2<T> {
+        return Vector2::from_angle(self.theta);
+    }
+
+    /// Rotate a vector by the heading, without translating it.
+    pub fn rotate(&self, v: &Vector2<T>) -> Vector2<T> {
+        return v.rotate(self.theta);
+    }
+
+    /// Map a point from the frame of this pose into the parent frame.
+    pub fn transform_point(&self, point: &Vector2<T>) -> Vector2<T> {
+        return self.rotate(point) + self.position;
+    }
+
+    /// Apply another pose, given relative to this one, e.g. a sensor mounted on a robot.
+    pub fn compose(&self, other: &Self) -> Self {
+        return Self::new(
+            self.transform_point(&other.position),
+            self.theta + other.theta,
+        );
+    }
+
+    pub fn inverse(&self) -> Self {
+        return Self::new(-self.position.rotate(-self.theta), -self.theta);
+    }
+
+    /// Express another pose in the frame of this one, so that `self.compose(&relative)`
+    /// gives back the other pose.
+    pub fn relative_pose(&self, other: &Self) -> Self {
+        return self.inverse().compose(other);
+    }
+}
+
+/// Wrap an angle into (-pi, pi].
+pub fn normalize_angle<T: Real>(angle: T) -> T {
+    let tau = T::pi() + T::pi();
+    let mut ret = (angle + T::pi()) % tau;
+    if ret < T::zero() {
+        ret = ret + tau;
+    }
+
+    ret = ret - T::pi();
+    if ret == -T::pi() {
+        return T::pi();
+    }
+
+    return ret;
+}
@@ -0,0 +1,37 @@
+pub use super::pose2::normalize_angle;
+use super::pose2::Pose2;
+
+pub type Pose2D = Pose2<f64>;
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use crate::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use super::{normalize_angle, Pose2D};
+
+    #[test]
+    fn compose_and_invert() {
+        let robot = Pose2D::from_xytheta(1_f64, 2_f64, FRAC_PI_2);
+        let sensor = Pose2D::from_xytheta(0.5, 0_f64, FRAC_PI_2);
+
+        let sensor_in_world = robot.compose(&sensor);
+        assert!((sensor_in_world.position - Vector2D::from_xy(1_f64, 2.5)).magnitude() < 1e-9);
+        assert_eq!(sensor_in_world.theta, PI);
+
+        let relative = robot.relative_pose(&sensor_in_world);
+        assert!((relative.position - sensor.position).magnitude() < 1e-9);
+        assert!((relative.theta - sensor.theta).abs() < 1e-9);
+
+        let looking = Pose2D::looking_at(robot.position, Vector2D::from_xy(0_f64, 1_f64));
+        assert!((looking.theta + 3_f64 * FRAC_PI_4).abs() < 1e-9);
+        assert!((looking.heading() - Vector2D::from_angle(looking.theta)).magnitude() < 1e-9);
+        assert!((robot.heading() - Vector2D::from_xy(0_f64, 1_f64)).magnitude() < 1e-9);
+
+        let identity = robot.compose(&robot.inverse());
+        assert!(identity.position.magnitude() < 1e-9 && identity.theta.abs() < 1e-9);
+        assert_eq!(normalize_angle(-PI), PI);
+        assert!((normalize_angle(3_f64 * PI + 0.5) - (0.5 - PI)).abs() < 1e-9);
+    }
+}
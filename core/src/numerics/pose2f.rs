@@ -0,0 +1,41 @@
+use super::{pose2::Pose2, pose2d::Pose2D};
+
+/// Pose with `f32` position and heading, e.g. for exchanging poses with single precision consumers.
+pub type Pose2F = Pose2<f32>;
+
+impl From<Pose2F> for Pose2D {
+    fn from(value: Pose2F) -> Self {
+        return Pose2D::new(value.position.into(), value.theta as f64);
+    }
+}
+
+/// Narrows the position like `Vector2F::from` and the heading to `f32`.
+impl From<Pose2D> for Pose2F {
+    fn from(value: Pose2D) -> Self {
+        return Pose2F::new(value.position.into(), value.theta as f32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::numerics::{pose2d::Pose2D, vector2f::Vector2F};
+
+    use super::Pose2F;
+
+    #[test]
+    fn single_precision_pose() {
+        let robot = Pose2F::from_xytheta(1_f32, 2_f32, FRAC_PI_2);
+        let point = robot.transform_point(&Vector2F::from_xy(1_f32, 0_f32));
+        let error = point - Vector2F::from_xy(1_f32, 3_f32);
+        assert!(error.dot(&error) < 1e-10);
+
+        let identity = robot.compose(&robot.inverse());
+        assert!(identity.position.x.abs() < 1e-6 && identity.theta.abs() < 1e-6);
+
+        let converted = Pose2D::from(robot);
+        assert_eq!(converted.position.y, 2_f64);
+        assert_eq!(Pose2F::from(converted), robot);
+    }
+}
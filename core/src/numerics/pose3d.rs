@@ -0,0 +1,96 @@
+use super::{quaternion::Quaternion, vector3d::Vector3D};
+
+/// Position and orientation in space, e.g. the extrinsics of a sensor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pose3D {
+    pub position: Vector3D,
+    pub orientation: Quaternion,
+}
+
+impl Pose3D {
+    /// The orientation is normalized.
+    pub fn new(position: Vector3D, orientation: Quaternion) -> Self {
+        return Self {
+            position: position,
+            orientation: orientation.normalize(),
+        };
+    }
+
+    pub fn identity() -> Self {
+        return Self {
+            position: Vector3D::zero(),
+            orientation: Quaternion::identity(),
+        };
+    }
+
+    /// Map a point from the frame of this pose into the parent frame.
+    pub fn transform_point(&self, point: &Vector3D) -> Vector3D {
+        return self.orientation.rotate(point) + self.position;
+    }
+
+    /// Apply another pose, given relative to this one.
+    pub fn compose(&self, other: &Pose3D) -> Pose3D {
+        return Pose3D::new(
+            self.transform_point(&other.position),
+            self.orientation * other.orientation,
+        );
+    }
+
+    pub fn inverse(&self) -> Pose3D {
+        let orientation = self.orientation.conjugate();
+        return Pose3D::new(-1_f64 * orientation.rotate(&self.position), orientation);
+    }
+
+    /// Express another pose in the frame of this one, so that `self.compose(&relative)`
+    /// gives back the other pose.
+    pub fn relative_pose(&self, other: &Pose3D) -> Pose3D {
+        return self.inverse().compose(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::numerics::{quaternion::Quaternion, vector::Vector, vector3d::Vector3D};
+
+    use super::Pose3D;
+
+    #[test]
+    fn compose_and_invert() {
+        let robot = Pose3D::new(
+            Vector3D::from_xyz(1_f64, 2_f64, 0_f64),
+            Quaternion::from_euler(0_f64, 0_f64, FRAC_PI_2),
+        );
+        let sensor = Pose3D::new(
+            Vector3D::from_xyz(0.5, 0_f64, 1_f64),
+            Quaternion::from_euler(0_f64, -FRAC_PI_2, 0_f64),
+        );
+
+        let point = robot.transform_point(&Vector3D::from_xyz(1_f64, 0_f64, 0_f64));
+        assert!((point - Vector3D::from_xyz(1_f64, 3_f64, 0_f64)).magnitude() < 1e-9);
+
+        let sensor_in_world = robot.compose(&sensor);
+        let expected = Vector3D::from_xyz(1_f64, 2.5, 1_f64);
+        assert!((sensor_in_world.position - expected).magnitude() < 1e-9);
+        let local = Vector3D::from_xyz(0_f64, 1_f64, 2_f64);
+        assert!(
+            (sensor_in_world.transform_point(&local)
+                - robot.transform_point(&sensor.transform_point(&local)))
+            .magnitude()
+                < 1e-9
+        );
+
+        let relative = robot.relative_pose(&sensor_in_world);
+        assert!((relative.position - sensor.position).magnitude() < 1e-9);
+        assert!((relative.orientation.dot(&sensor.orientation).abs() - 1_f64).abs() < 1e-9);
+
+        let identity = robot.compose(&robot.inverse());
+        assert!(identity.position.magnitude() < 1e-9);
+        assert!((identity.orientation.dot(&Quaternion::identity()).abs() - 1_f64).abs() < 1e-9);
+        let round_trip = robot.inverse().transform_point(&point);
+        assert!((round_trip - Vector3D::from_xyz(1_f64, 0_f64, 0_f64)).magnitude() < 1e-9);
+        assert_eq!(Pose3D::identity().transform_point(&local), local);
+    }
+}
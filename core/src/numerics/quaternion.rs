@@ -0,0 +1,199 @@
+use std::ops::Mul;
+
+use super::vector3d::Vector3D;
+
+/// Quaternion w + xi + yj + zk. Rotations are represented by unit quaternions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    pub fn from_wxyz(w: f64, x: f64, y: f64, z: f64) -> Self {
+        return Self {
+            w: w,
+            x: x,
+            y: y,
+            z: z,
+        };
+    }
+
+    pub fn identity() -> Self {
+        return Self::from_wxyz(1_f64, 0_f64, 0_f64, 0_f64);
+    }
+
+    /// Rotation by angle (radians, counterclockwise) around axis, which need not be normalized.
+    pub fn from_axis_angle(axis: &Vector3D, angle: f64) -> Self {
+        let (sin, cos) = (angle / 2_f64).sin_cos();
+        let axis = axis.unit_vector();
+        return Self::from_wxyz(cos, axis.x * sin, axis.y * sin, axis.z * sin);
+    }
+
+    /// Returns the unit rotation axis and the angle in [0, 2 pi).
+    /// The axis is arbitrary for the identity rotation.
+    pub fn to_axis_angle(&self) -> (Vector3D, f64) {
+        let q = self.normalize();
+        let sin = (1_f64 - q.w.powi(2)).max(0_f64).sqrt();
+        let angle = 2_f64 * q.w.clamp(-1_f64, 1_f64).acos();
+
+        if sin < 1e-12 {
+            return (Vector3D::from_xyz(1_f64, 0_f64, 0_f64), angle);
+        }
+
+        return (Vector3D::from_xyz(q.x / sin, q.y / sin, q.z / sin), angle);
+    }
+
+    /// Rotation by yaw around z, then pitch around the new y and roll around the new x axis.
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Self {
+        let (sr, cr) = (roll / 2_f64).sin_cos();
+        let (sp, cp) = (pitch / 2_f64).sin_cos();
+        let (sy, cy) = (yaw / 2_f64).sin_cos();
+
+        return Self::from_wxyz(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        );
+    }
+
+    /// Returns (roll, pitch, yaw), the inverse of `from_euler`.
+    pub fn to_euler(&self) -> (f64, f64, f64) {
+        let q = self.normalize();
+        let roll = f64::atan2(
+            2_f64 * (q.w * q.x + q.y * q.z),
+            1_f64 - 2_f64 * (q.x.powi(2) + q.y.powi(2)),
+        );
+        let pitch = (2_f64 * (q.w * q.y - q.z * q.x))
+            .clamp(-1_f64, 1_f64)
+            .asin();
+        let yaw = f64::atan2(
+            2_f64 * (q.w * q.z + q.x * q.y),
+            1_f64 - 2_f64 * (q.y.powi(2) + q.z.powi(2)),
+        );
+
+        return (roll, pitch, yaw);
+    }
+
+    pub fn dot(&self, q: &Quaternion) -> f64 {
+        return self.w * q.w + self.x * q.x + self.y * q.y + self.z * q.z;
+    }
+
+    pub fn norm(&self) -> f64 {
+        return self.dot(self).sqrt();
+    }
+
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        return Self::from_wxyz(self.w / norm, self.x / norm, self.y / norm, self.z / norm);
+    }
+
+    pub fn conjugate(&self) -> Self {
+        return Self::from_wxyz(self.w, -self.x, -self.y, -self.z);
+    }
+
+    pub fn inverse(&self) -> Self {
+        let norm_squared = self.dot(self);
+        let c = self.conjugate();
+        return Self::from_wxyz(
+            c.w / norm_squared,
+            c.x / norm_squared,
+            c.y / norm_squared,
+            c.z / norm_squared,
+        );
+    }
+
+    /// Rotate vector, assuming this is a unit quaternion.
+    pub fn rotate(&self, v: &Vector3D) -> Vector3D {
+        let u = Vector3D::from_xyz(self.x, self.y, self.z);
+        let t = 2_f64 * u.cross(v);
+        return v + self.w * t + u.cross(&t);
+    }
+
+    /// Spherical linear interpolation between unit quaternions along the shorter arc,
+    /// with t = 0 giving this rotation and t = 1 the other.
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Self {
+        let mut other = *other;
+        let mut cos = self.dot(&other);
+        if cos < 0_f64 {
+            other = Self::from_wxyz(-other.w, -other.x, -other.y, -other.z);
+            cos = -cos;
+        }
+
+        // Nearly identical rotations: fall back to linear interpolation.
+        let (s1, s2) = if cos > 1_f64 - 1e-9 {
+            (1_f64 - t, t)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (((1_f64 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+
+        return Self::from_wxyz(
+            s1 * self.w + s2 * other.w,
+            s1 * self.x + s2 * other.x,
+            s1 * self.y + s2 * other.y,
+            s1 * self.z + s2 * other.z,
+        )
+        .normalize();
+    }
+}
+
+/// Quaternion * Quaternion (Hamilton product), applying rhs first when used as rotations.
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        return Quaternion {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        };
+    }
+}
+
+/// &Quaternion * &Quaternion
+impl Mul<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: &Quaternion) -> Self::Output {
+        return *self * *rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::numerics::{vector::Vector, vector3d::Vector3D};
+
+    use super::Quaternion;
+
+    #[test]
+    fn rotations() {
+        let z_axis = Vector3D::from_xyz(0_f64, 0_f64, 2_f64);
+        let q = Quaternion::from_axis_angle(&z_axis, FRAC_PI_2);
+        let rotated = q.rotate(&Vector3D::from_xyz(1_f64, 0_f64, 0_f64));
+        assert!((rotated - Vector3D::from_xyz(0_f64, 1_f64, 0_f64)).magnitude() < 1e-9);
+
+        let (axis, angle) = (q * q).to_axis_angle();
+        assert!((axis - z_axis.unit_vector()).magnitude() < 1e-9);
+        assert!((angle - 2_f64 * FRAC_PI_2).abs() < 1e-9);
+
+        let (roll, pitch, yaw) = Quaternion::from_euler(0.1, -0.2, 0.3).to_euler();
+        assert!(
+            (roll - 0.1).abs() < 1e-9 && (pitch + 0.2).abs() < 1e-9 && (yaw - 0.3).abs() < 1e-9
+        );
+        assert!((Quaternion::from_euler(0_f64, 0_f64, FRAC_PI_2).dot(&q) - 1_f64).abs() < 1e-9);
+
+        let half = Quaternion::identity().slerp(&q, 0.5);
+        let (_, half_angle) = half.to_axis_angle();
+        assert!((half_angle - FRAC_PI_2 / 2_f64).abs() < 1e-9);
+        assert!(((q * q.inverse()).dot(&Quaternion::identity()) - 1_f64).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,42 @@
+use std::ops::Neg;
+
+use num_traits::{Float, FloatConst, Num};
+
+/// Scalar supporting the non-linear operations used by the geometry types.
+/// Implemented for all floating point types and, with the `fixed_point` feature, for `Fixed`.
+pub trait Real: Num + Copy + PartialOrd + Neg<Output = Self> {
+    fn sqrt(self) -> Self;
+
+    /// Length of the hypotenuse of a right triangle with legs self and other.
+    fn hypot(self, other: Self) -> Self;
+
+    /// Sine and cosine of self (radians).
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Four quadrant arctangent of self (y) and other (x), in (-pi, pi].
+    fn atan2(self, other: Self) -> Self;
+
+    fn pi() -> Self;
+}
+
+impl<T: Float + FloatConst> Real for T {
+    fn sqrt(self) -> Self {
+        return Float::sqrt(self);
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        return Float::hypot(self, other);
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        return Float::sin_cos(self);
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        return Float::atan2(self, other);
+    }
+
+    fn pi() -> Self {
+        return T::PI();
+    }
+}
@@ -0,0 +1,145 @@
+use std::ops::Mul;
+
+use super::{
+    isometry2d::Isometry2D, matrix3::Matrix3, pose2d::normalize_angle, pose2d::Pose2D,
+    vector2d::Vector2D,
+};
+
+/// Isometry with uniform scaling: scale, then rotate by `rotation` (radians), then translate.
+/// E.g. aligns a floorplan drawn in pixels with the map frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Similarity2D {
+    pub scale: f64,
+    pub rotation: f64,
+    pub translation: Vector2D,
+}
+
+impl Similarity2D {
+    pub fn new(scale: f64, rotation: f64, translation: Vector2D) -> Self {
+        return Self {
+            scale: scale,
+            rotation: normalize_angle(rotation),
+            translation: translation,
+        };
+    }
+
+    pub fn identity() -> Self {
+        return Self::new(1_f64, 0_f64, Vector2D::zero());
+    }
+
+    pub fn transform_point(&self, point: &Vector2D) -> Vector2D {
+        return point.rotate(self.rotation) * self.scale + self.translation;
+    }
+
+    /// Scale and rotate a direction, without translating it.
+    pub fn transform_vector(&self, v: &Vector2D) -> Vector2D {
+        return v.rotate(self.rotation) * self.scale;
+    }
+
+    /// Move a pose given in the source frame into the target frame. The heading only rotates.
+    pub fn transform_pose(&self, pose: &Pose2D) -> Pose2D {
+        return Pose2D::new(
+            self.transform_point(&pose.position),
+            pose.theta + self.rotation,
+        );
+    }
+
+    /// Apply other first, then this similarity.
+    pub fn compose(&self, other: &Similarity2D) -> Similarity2D {
+        return Similarity2D::new(
+            self.scale * other.scale,
+            self.rotation + other.rotation,
+            self.transform_point(&other.translation),
+        );
+    }
+
+    /// Returns `None` if the scale is zero.
+    pub fn inverse(&self) -> Option<Similarity2D> {
+        if self.scale == 0_f64 {
+            return None;
+        }
+
+        let scale = 1_f64 / self.scale;
+        return Some(Similarity2D::new(
+            scale,
+            -self.rotation,
+            -self.translation.rotate(-self.rotation) * scale,
+        ));
+    }
+
+    /// Homogeneous matrix form.
+    pub fn to_matrix(&self) -> Matrix3 {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (sin, cos) = (sin * self.scale, cos * self.scale);
+        return Matrix3::from_rows([
+            [cos, -sin, self.translation.x],
+            [sin, cos, self.translation.y],
+            [0_f64, 0_f64, 1_f64],
+        ]);
+    }
+}
+
+/// Similarity2D * Similarity2D
+impl Mul<Similarity2D> for Similarity2D {
+    type Output = Similarity2D;
+
+    fn mul(self, rhs: Similarity2D) -> Self::Output {
+        return self.compose(&rhs);
+    }
+}
+
+/// Similarity2D * Pose2D
+impl Mul<Pose2D> for Similarity2D {
+    type Output = Pose2D;
+
+    fn mul(self, rhs: Pose2D) -> Self::Output {
+        return self.transform_pose(&rhs);
+    }
+}
+
+impl From<Isometry2D> for Similarity2D {
+    fn from(value: Isometry2D) -> Self {
+        return Similarity2D::new(1_f64, value.rotation, value.translation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::numerics::{
+        isometry2d::Isometry2D, pose2d::Pose2D, vector::Vector, vector2d::Vector2D,
+    };
+
+    use super::Similarity2D;
+
+    #[test]
+    fn transforms_and_matrices_agree() {
+        let floorplan_to_map = Similarity2D::new(0.05, FRAC_PI_2, Vector2D::from_xy(10_f64, 0_f64));
+        let pixel = Vector2D::from_xy(100_f64, 20_f64);
+        let point = floorplan_to_map.transform_point(&pixel);
+        assert!((point - Vector2D::from_xy(9_f64, 5_f64)).magnitude() < 1e-9);
+        assert!((floorplan_to_map.to_matrix().transform_point(&pixel) - point).magnitude() < 1e-9);
+
+        let back = floorplan_to_map.inverse().unwrap().transform_point(&point);
+        assert!((back - pixel).magnitude() < 1e-9);
+
+        let robot = Pose2D::from_xytheta(1_f64, 2_f64, 0.3);
+        let isometry = Isometry2D::from(robot);
+        assert_eq!(Pose2D::from(isometry), robot);
+        assert!(
+            (isometry.to_matrix().transform_point(&pixel) - robot.transform_point(&pixel))
+                .magnitude()
+                < 1e-9
+        );
+
+        let composed = Similarity2D::from(isometry) * floorplan_to_map;
+        let pose = Pose2D::from_xytheta(40_f64, -60_f64, 0.1);
+        let expected = isometry * (floorplan_to_map * pose);
+        let actual = composed * pose;
+        assert!((actual.position - expected.position).magnitude() < 1e-9);
+        assert!((actual.theta - expected.theta).abs() < 1e-9);
+        assert!(((isometry * isometry.inverse()).translation).magnitude() < 1e-9);
+    }
+}
@@ -0,0 +1,194 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::pose2d::normalize_angle;
+
+/// Length in meters, e.g. between topology node positions.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Meters(pub f64);
+
+/// Length in grid cells, possibly fractional. Converts to meters only through a cell size.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Cells(pub f64);
+
+/// Angle in radians.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Radians(pub f64);
+
+impl Meters {
+    pub fn value(&self) -> f64 {
+        return self.0;
+    }
+
+    /// Convert using the edge length of a cell in meters.
+    pub fn to_cells(&self, cell_size: f64) -> Cells {
+        return Cells(self.0 / cell_size);
+    }
+}
+
+impl Cells {
+    pub fn value(&self) -> f64 {
+        return self.0;
+    }
+
+    /// Convert using the edge length of a cell in meters.
+    pub fn to_meters(&self, cell_size: f64) -> Meters {
+        return Meters(self.0 * cell_size);
+    }
+
+    /// Index of the cell this length reaches into, counting from 0.
+    /// Returns `None` for negative lengths.
+    pub fn to_index(&self) -> Option<usize> {
+        if self.0 < 0_f64 {
+            return None;
+        }
+
+        return Some(self.0.floor() as usize);
+    }
+}
+
+impl Radians {
+    pub fn from_degrees(degrees: f64) -> Self {
+        return Self(degrees.to_radians());
+    }
+
+    pub fn value(&self) -> f64 {
+        return self.0;
+    }
+
+    pub fn to_degrees(&self) -> f64 {
+        return self.0.to_degrees();
+    }
+
+    /// Wrap into (-pi, pi].
+    pub fn normalized(&self) -> Self {
+        return Self(normalize_angle(self.0));
+    }
+}
+
+/// Meters + Meters
+impl Add<Meters> for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: Meters) -> Self::Output {
+        return Meters(self.0 + rhs.0);
+    }
+}
+
+/// Meters - Meters
+impl Sub<Meters> for Meters {
+    type Output = Meters;
+
+    fn sub(self, rhs: Meters) -> Self::Output {
+        return Meters(self.0 - rhs.0);
+    }
+}
+
+/// Meters * f64
+impl Mul<f64> for Meters {
+    type Output = Meters;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        return Meters(self.0 * rhs);
+    }
+}
+
+/// Meters / f64
+impl Div<f64> for Meters {
+    type Output = Meters;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        return Meters(self.0 / rhs);
+    }
+}
+
+/// -Meters
+impl Neg for Meters {
+    type Output = Meters;
+
+    fn neg(self) -> Self::Output {
+        return Meters(-self.0);
+    }
+}
+
+/// Cells + Cells
+impl Add<Cells> for Cells {
+    type Output = Cells;
+
+    fn add(self, rhs: Cells) -> Self::Output {
+        return Cells(self.0 + rhs.0);
+    }
+}
+
+/// Cells - Cells
+impl Sub<Cells> for Cells {
+    type Output = Cells;
+
+    fn sub(self, rhs: Cells) -> Self::Output {
+        return Cells(self.0 - rhs.0);
+    }
+}
+
+/// Radians + Radians
+impl Add<Radians> for Radians {
+    type Output = Radians;
+
+    fn add(self, rhs: Radians) -> Self::Output {
+        return Radians(self.0 + rhs.0);
+    }
+}
+
+/// Radians - Radians
+impl Sub<Radians> for Radians {
+    type Output = Radians;
+
+    fn sub(self, rhs: Radians) -> Self::Output {
+        return Radians(self.0 - rhs.0);
+    }
+}
+
+/// Radians * f64
+impl Mul<f64> for Radians {
+    type Output = Radians;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        return Radians(self.0 * rhs);
+    }
+}
+
+/// -Radians
+impl Neg for Radians {
+    type Output = Radians;
+
+    fn neg(self) -> Self::Output {
+        return Radians(-self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{Cells, Meters, Radians};
+
+    #[test]
+    fn converts_through_cell_size() {
+        let length = Meters(1.25);
+        assert_eq!(length.to_cells(0.05), Cells(25_f64));
+        assert_eq!(Cells(25_f64).to_meters(0.05), Meters(1.25));
+        assert_eq!(Cells(2.7).to_index(), Some(2));
+        assert_eq!(Cells(-0.5).to_index(), None);
+        assert_eq!(length * 2_f64 - Meters(0.5), Meters(2_f64));
+
+        assert_eq!(Radians::from_degrees(180_f64), Radians(PI));
+        assert_eq!(
+            (Radians(PI) + Radians(PI / 2_f64)).normalized(),
+            Radians(-PI / 2_f64)
+        );
+    }
+}
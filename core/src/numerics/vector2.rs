@@ -0,0 +1,339 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{Num, ToPrimitive};
+
+use super::{real::Real, vector::Vector};
+
+/// Two-dimensional vector over any numeric component type.
+/// `Vector2D` and `Vector2I` are the common instances. Ordering compares x first, then y.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector2<T>
+where
+    T: Num + Copy,
+{
+    pub fn from_xy(x: T, y: T) -> Self {
+        return Self {
+            x: x,
+            y: y,
+        };
+    }
+
+    pub fn zero() -> Self {
+        return Self {
+            x: T::zero(),
+            y: T::zero(),
+        };
+    }
+
+    pub fn dot(&self, v: &Self) -> T {
+        return self.x * v.x + self.y * v.y;
+    }
+
+    /// z component of the cross product, positive if v is counterclockwise from this vector.
+    pub fn cross(&self, v: &Self) -> T {
+        return self.x * v.y - self.y * v.x;
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    /// Rotate counterclockwise by a right angle.
+    pub fn perpendicular(&self) -> Self {
+        return Self {
+            x: -self.y,
+            y: self.x,
+        };
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Real,
+{
+    /// Unit vector pointing at angle (radians) counterclockwise from the x axis.
+    pub fn from_angle(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        return Self { x: cos, y: sin };
+    }
+
+    pub fn unit_vector(&self) -> Self {
+        return self / self.length();
+    }
+
+    /// Rotate counterclockwise by angle (radians).
+    pub fn rotate(&self, angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        return Self {
+            x: cos * self.x - sin * self.y,
+            y: sin * self.x + cos * self.y,
+        };
+    }
+
+    /// Component of this vector along v. Returns zero if v is zero.
+    pub fn project_onto(&self, v: &Self) -> Self {
+        let length_squared = v.dot(v);
+        if length_squared == T::zero() {
+            return Self::zero();
+        }
+
+        return v * (self.dot(v) / length_squared);
+    }
+
+    /// Component of this vector perpendicular to v.
+    pub fn reject_from(&self, v: &Self) -> Self {
+        return self - self.project_onto(v);
+    }
+
+    /// Shorten the vector to max_length if it is longer, keeping its direction.
+    pub fn clamp_length(&self, max_length: T) -> Self {
+        let length = self.length();
+        if length <= max_length {
+            return *self;
+        }
+
+        return self * (max_length / length);
+    }
+
+    /// Direction (radians) measured counterclockwise from the x axis, in (-pi, pi].
+    pub fn angle(&self) -> T {
+        return self.y.atan2(self.x);
+    }
+
+    /// Signed angle (radians) to rotate this vector counterclockwise onto v, in (-pi, pi].
+    pub fn angle_to(&self, v: &Self) -> T {
+        return self.cross(v).atan2(self.dot(v));
+    }
+
+    /// Magnitude in the component type.
+    fn length(&self) -> T {
+        return self.x.hypot(self.y);
+    }
+}
+
+impl<T> Vector for Vector2<T>
+where
+    T: Num + Copy + ToPrimitive,
+{
+    fn dimensions(&self) -> usize {
+        return 2;
+    }
+
+    fn magnitude(&self) -> f64 {
+        return (self.x * self.x + self.y * self.y).to_f64().unwrap().sqrt();
+    }
+}
+
+/// Vector2 + Vector2
+impl<T: Num + Copy> Add<Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn add(self, rhs: Vector2<T>) -> Self::Output {
+        return Vector2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        };
+    }
+}
+
+/// Vector2 + &Vector2
+impl<T: Num + Copy> Add<&Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn add(self, rhs: &Vector2<T>) -> Self::Output {
+        return self + *rhs;
+    }
+}
+
+/// &Vector2 + Vector2
+impl<T: Num + Copy> Add<Vector2<T>> for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn add(self, rhs: Vector2<T>) -> Self::Output {
+        return *self + rhs;
+    }
+}
+
+/// &Vector2 + &Vector2
+impl<T: Num + Copy> Add<&Vector2<T>> for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn add(self, rhs: &Vector2<T>) -> Self::Output {
+        return *self + *rhs;
+    }
+}
+
+/// Vector2 - Vector2
+impl<T: Num + Copy> Sub<Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Vector2<T>) -> Self::Output {
+        return Vector2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        };
+    }
+}
+
+/// Vector2 - &Vector2
+impl<T: Num + Copy> Sub<&Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: &Vector2<T>) -> Self::Output {
+        return self - *rhs;
+    }
+}
+
+/// &Vector2 - Vector2
+impl<T: Num + Copy> Sub<Vector2<T>> for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Vector2<T>) -> Self::Output {
+        return *self - rhs;
+    }
+}
+
+/// &Vector2 - &Vector2
+impl<T: Num + Copy> Sub<&Vector2<T>> for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: &Vector2<T>) -> Self::Output {
+        return *self - *rhs;
+    }
+}
+
+/// Vector2 * T
+impl<T: Num + Copy> Mul<T> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        return Vector2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        };
+    }
+}
+
+/// &Vector2 * T
+impl<T: Num + Copy> Mul<T> for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        return *self * rhs;
+    }
+}
+
+/// Vector2 / T
+impl<T: Num + Copy> Div<T> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        return Vector2 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        };
+    }
+}
+
+/// &Vector2 / T
+impl<T: Num + Copy> Div<T> for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        return *self / rhs;
+    }
+}
+
+/// Vector2 += Vector2
+impl<T: Num + Copy> AddAssign<Vector2<T>> for Vector2<T> {
+    fn add_assign(&mut self, rhs: Vector2<T>) {
+        *self = *self + rhs;
+    }
+}
+
+/// Vector2 -= Vector2
+impl<T: Num + Copy> SubAssign<Vector2<T>> for Vector2<T> {
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
+        *self = *self - rhs;
+    }
+}
+
+/// Vector2 *= T
+impl<T: Num + Copy> MulAssign<T> for Vector2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector2 /= T
+impl<T: Num + Copy> DivAssign<T> for Vector2<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// -Vector2
+impl<T: Num + Copy + Neg<Output = T>> Neg for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Self::Output {
+        return Vector2 {
+            x: -self.x,
+            y: -self.y,
+        };
+    }
+}
+
+/// -&Vector2
+impl<T: Num + Copy + Neg<Output = T>> Neg for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Self::Output {
+        return -*self;
+    }
+}
+
+/// f32 * Vector2
+impl Mul<Vector2<f32>> for f32 {
+    type Output = Vector2<f32>;
+
+    fn mul(self, rhs: Vector2<f32>) -> Self::Output {
+        return rhs * self;
+    }
+}
+
+/// f32 * &Vector2
+impl Mul<&Vector2<f32>> for f32 {
+    type Output = Vector2<f32>;
+
+    fn mul(self, rhs: &Vector2<f32>) -> Self::Output {
+        return rhs * self;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numerics::vector::Vector;
+
+    use super::Vector2;
+
+    #[test]
+    fn shared_across_component_types() {
+        let u: Vector2<f32> = Vector2::from_xy(3_f32, 4_f32);
+        assert_eq!(u.magnitude(), 5_f64);
+        assert_eq!(2_f32 * u - u, u);
+        assert_eq!(u.unit_vector(), Vector2::from_xy(0.6, 0.8));
+
+        let v: Vector2<i32> = Vector2::from_xy(3, -4);
+        assert_eq!(v.dot(&v.perpendicular()), 0);
+        assert_eq!((v + v) / 2, v);
+        assert_eq!(v.magnitude(), 5_f64);
+    }
+}
@@ -1,136 +1,8 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::Mul;
 
-use super::{vector::Vector, vector2i::Vector2I};
+use super::{vector2::Vector2, vector2i::Vector2I};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Vector2D {
-    pub x: f64,
-    pub y: f64,
-}
-
-impl Vector2D {
-    pub fn from_xy(x: f64, y: f64) -> Self {
-        return Self { x: x, y: y };
-    }
-
-    pub fn zero() -> Self {
-        return Self { x: 0_f64, y: 0_f64 };
-    }
-
-    pub fn dot(&self, v: &Vector2D) -> f64 {
-        return self.x * v.x + self.y + v.y;
-    }
-
-    pub fn unit_vector(&self) -> Self {
-        return self / self.magnitude();
-    }
-}
-
-impl Vector for Vector2D {
-    fn dimensions(&self) -> usize {
-        return 2;
-    }
-
-    fn magnitude(&self) -> f64 {
-        return (self.x.powi(2) + self.y.powi(2)).sqrt();
-    }
-}
-
-/// Vector2D + Vector2D
-impl Add<Vector2D> for Vector2D {
-    type Output = Vector2D;
-
-    fn add(self, rhs: Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// Vector2D + &Vector2D
-impl Add<&Vector2D> for Vector2D {
-    type Output = Vector2D;
-
-    fn add(self, rhs: &Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// &Vector2D + Vector2D
-impl Add<Vector2D> for &Vector2D {
-    type Output = Vector2D;
-
-    fn add(self, rhs: Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// &Vector2D + &Vector2D
-impl Add<&Vector2D> for &Vector2D {
-    type Output = Vector2D;
-
-    fn add(self, rhs: &Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// Vector2D - Vector2D
-impl Sub<Vector2D> for Vector2D {
-    type Output = Vector2D;
-
-    fn sub(self, rhs: Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
-    }
-}
-
-/// Vector2D - &Vector2D
-impl Sub<&Vector2D> for Vector2D {
-    type Output = Vector2D;
-
-    fn sub(self, rhs: &Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
-    }
-}
-
-/// &Vector2D - Vector2D
-impl Sub<Vector2D> for &Vector2D {
-    type Output = Vector2D;
-
-    fn sub(self, rhs: Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
-    }
-}
-
-/// &Vector2D - &Vector2D
-impl Sub<&Vector2D> for &Vector2D {
-    type Output = Vector2D;
-
-    fn sub(self, rhs: &Vector2D) -> Self::Output {
-        return Vector2D {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
-    }
-}
+pub type Vector2D = Vector2<f64>;
 
 /// f64 * Vector2D
 impl Mul<Vector2D> for f64 {
@@ -156,62 +28,38 @@ impl Mul<&Vector2D> for f64 {
     }
 }
 
-/// Vector2D * f64
-impl Mul<f64> for Vector2D {
-    type Output = Vector2D;
-
-    fn mul(self, rhs: f64) -> Self::Output {
-        return Vector2D {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        };
+impl From<Vector2D> for Vector2I {
+    fn from(value: Vector2D) -> Self {
+        return Vector2I::from_xy(value.x as i64, value.y as i64);
     }
 }
 
-/// &Vector2D * f64
-impl Mul<f64> for &Vector2D {
-    type Output = Vector2D;
-
-    fn mul(self, rhs: f64) -> Self::Output {
-        return Vector2D {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        };
+impl From<&Vector2D> for Vector2I {
+    fn from(value: &Vector2D) -> Self {
+        return Vector2I::from_xy(value.x as i64, value.y as i64);
     }
 }
 
-/// Vector2D / f64
-impl Div<f64> for Vector2D {
-    type Output = Vector2D;
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        return Vector2D {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        };
-    }
-}
-
-/// &Vector2D / f64
-impl Div<f64> for &Vector2D {
-    type Output = Vector2D;
+    use crate::numerics::vector::Vector;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        return Vector2D {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        };
-    }
-}
-
-impl Into<Vector2I> for Vector2D {
-    fn into(self) -> Vector2I {
-        return Vector2I::from_xy(self.x as i64, self.y as i64);
-    }
-}
+    use super::Vector2D;
 
-impl Into<Vector2I> for &Vector2D {
-    fn into(self) -> Vector2I {
-        return Vector2I::from_xy(self.x as i64, self.y as i64);
+    #[test]
+    fn geometric_operations() {
+        let u = Vector2D::from_xy(3_f64, 4_f64);
+        let v = Vector2D::from_xy(2_f64, 0_f64);
+        assert_eq!(u.dot(&v), 6_f64);
+        assert_eq!(v.cross(&u), 8_f64);
+        assert_eq!(u.perpendicular(), Vector2D::from_xy(-4_f64, 3_f64));
+        assert!((v.rotate(FRAC_PI_2) - Vector2D::from_xy(0_f64, 2_f64)).magnitude() < 1e-9);
+        assert_eq!(u.project_onto(&v), Vector2D::from_xy(3_f64, 0_f64));
+        assert_eq!(u.reject_from(&v), Vector2D::from_xy(0_f64, 4_f64));
+        assert_eq!(u.clamp_length(2.5), Vector2D::from_xy(1.5, 2_f64));
+        assert_eq!(v.angle_to(&Vector2D::from_xy(0_f64, -1_f64)), -FRAC_PI_2);
+        assert_eq!(-v, Vector2D::from_xy(-2_f64, 0_f64));
     }
 }
@@ -0,0 +1,17 @@
+use super::{vector2::Vector2, vector2d::Vector2D};
+
+/// Vector with `f32` components, taking half the memory of a `Vector2D`.
+pub type Vector2F = Vector2<f32>;
+
+impl From<Vector2F> for Vector2D {
+    fn from(value: Vector2F) -> Self {
+        return Vector2D::from_xy(value.x as f64, value.y as f64);
+    }
+}
+
+/// Each component is rounded to the nearest `f32`.
+impl From<Vector2D> for Vector2F {
+    fn from(value: Vector2D) -> Self {
+        return Vector2F::from_xy(value.x as f32, value.y as f32);
+    }
+}
@@ -1,132 +1,57 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::{
+    num::TryFromIntError,
+    ops::{Div, Mul},
+};
 
-use super::vector::Vector;
+use super::vector2::Vector2;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct Vector2I {
-    pub x: i64,
-    pub y: i64,
-}
+pub type Vector2I = Vector2<i64>;
 
 impl Vector2I {
-    pub fn from_xy(x: i64, y: i64) -> Self {
-        return Self {
-            x: x,
-            y: y,
-        };
-    }
-
-    pub fn zero() -> Self {
-        return Self {
-            x: 0_i64,
-            y: 0_i64,
-        };
+    /// Number of axis-aligned steps between the cells, i.e. the 4-neighborhood distance.
+    pub fn manhattan_distance(&self, other: &Vector2I) -> i64 {
+        return (self.x - other.x).abs() + (self.y - other.y).abs();
     }
-}
 
-impl Vector for Vector2I {
-    fn dimensions(&self) -> usize {
-        return 2;
+    /// Number of steps between the cells when diagonal steps are allowed,
+    /// i.e. the 8-neighborhood distance.
+    pub fn chebyshev_distance(&self, other: &Vector2I) -> i64 {
+        return i64::max((self.x - other.x).abs(), (self.y - other.y).abs());
     }
 
-    fn magnitude(&self) -> f64 {
-        return ((self.x.pow(2) + self.y.pow(2)) as f64).sqrt();
+    /// Interleave the bits of x (even bits) and y (odd bits), so that cells close in the plane
+    /// tend to be close in the code. Components are truncated to 32 bits and offset so that
+    /// the code increases with each component over the i32 range.
+    pub fn morton_code(&self) -> u64 {
+        return spread_bits(self.x as i32 as u32 ^ 0x8000_0000)
+            | spread_bits(self.y as i32 as u32 ^ 0x8000_0000) << 1;
     }
 }
 
-/// Vector2I + Vector2I
-impl Add<Vector2I> for Vector2I {
-    type Output = Vector2I;
-
-    fn add(self, rhs: Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// Vector2I + &Vector2I
-impl Add<&Vector2I> for Vector2I {
-    type Output = Vector2I;
-
-    fn add(self, rhs: &Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// &Vector2I + Vector2I
-impl Add<Vector2I> for &Vector2I {
-    type Output = Vector2I;
-
-    fn add(self, rhs: Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// &Vector2I + &Vector2I
-impl Add<&Vector2I> for &Vector2I {
-    type Output = Vector2I;
-
-    fn add(self, rhs: &Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        };
-    }
-}
-
-/// Vector2I - Vector2I
-impl Sub<Vector2I> for Vector2I {
-    type Output = Vector2I;
-
-    fn sub(self, rhs: Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
-    }
-}
-
-/// Vector2I - &Vector2I
-impl Sub<&Vector2I> for Vector2I {
-    type Output = Vector2I;
-
-    fn sub(self, rhs: &Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
-    }
+/// Move bit i of value to bit 2i.
+fn spread_bits(value: u32) -> u64 {
+    let mut ret = value as u64;
+    ret = (ret | ret << 16) & 0x0000_FFFF_0000_FFFF;
+    ret = (ret | ret << 8) & 0x00FF_00FF_00FF_00FF;
+    ret = (ret | ret << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+    ret = (ret | ret << 2) & 0x3333_3333_3333_3333;
+    ret = (ret | ret << 1) & 0x5555_5555_5555_5555;
+    return ret;
 }
 
-/// &Vector2I - Vector2I
-impl Sub<Vector2I> for &Vector2I {
-    type Output = Vector2I;
-
-    fn sub(self, rhs: Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
+/// Grid index (x, y).
+impl From<(usize, usize)> for Vector2I {
+    fn from(value: (usize, usize)) -> Self {
+        return Vector2I::from_xy(value.0 as i64, value.1 as i64);
     }
 }
 
-/// &Vector2I - &Vector2I
-impl Sub<&Vector2I> for &Vector2I {
-    type Output = Vector2I;
+/// Grid index (x, y). Fails for negative components.
+impl TryFrom<Vector2I> for (usize, usize) {
+    type Error = TryFromIntError;
 
-    fn sub(self, rhs: &Vector2I) -> Self::Output {
-        return Vector2I {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        };
+    fn try_from(value: Vector2I) -> Result<Self, Self::Error> {
+        return Ok((usize::try_from(value.x)?, usize::try_from(value.y)?));
     }
 }
 
@@ -154,30 +79,6 @@ impl Mul<&Vector2I> for i64 {
     }
 }
 
-/// Vector2I * i64
-impl Mul<i64> for Vector2I {
-    type Output = Vector2I;
-
-    fn mul(self, rhs: i64) -> Self::Output {
-        return Vector2I {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        };
-    }
-}
-
-/// &Vector2I * i64
-impl Mul<i64> for &Vector2I {
-    type Output = Vector2I;
-
-    fn mul(self, rhs: i64) -> Self::Output {
-        return Vector2I {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        };
-    }
-}
-
 /// i32 * Vector2I
 impl Mul<Vector2I> for i32 {
     type Output = Vector2I;
@@ -226,30 +127,6 @@ impl Mul<i32> for &Vector2I {
     }
 }
 
-/// Vector2I / i64
-impl Div<i64> for Vector2I {
-    type Output = Vector2I;
-
-    fn div(self, rhs: i64) -> Self::Output {
-        return Vector2I {
-            x: self.x / rhs as i64,
-            y: self.y / rhs as i64,
-        };
-    }
-}
-
-/// &Vector2I / i64
-impl Div<i64> for &Vector2I {
-    type Output = Vector2I;
-
-    fn div(self, rhs: i64) -> Self::Output {
-        return Vector2I {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        };
-    }
-}
-
 /// Vector2I / i32
 impl Div<i32> for Vector2I {
     type Output = Vector2I;
@@ -273,3 +150,30 @@ impl Div<i32> for &Vector2I {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::Vector2I;
+
+    #[test]
+    fn grid_conversions_and_distances() {
+        let a = Vector2I::from((2, 5));
+        let mut b = a;
+        b += Vector2I::from_xy(-3, 1);
+        assert_eq!(b, Vector2I::from_xy(-1, 6));
+        assert_eq!(a.manhattan_distance(&b), 4);
+        assert_eq!(a.chebyshev_distance(&b), 3);
+        assert_eq!(<(usize, usize)>::try_from(a), Ok((2, 5)));
+        assert!(<(usize, usize)>::try_from(b).is_err());
+
+        let sorted: Vec<Vector2I> = BTreeSet::from([a, b, Vector2I::from_xy(2, 0)])
+            .into_iter()
+            .collect();
+        assert_eq!(sorted, vec![b, Vector2I::from_xy(2, 0), a]);
+
+        assert_eq!(Vector2I::from_xy(3, 5).morton_code() & 0xFF, 0b0010_0111);
+        assert!(Vector2I::from_xy(-1, 0).morton_code() < Vector2I::from_xy(0, 0).morton_code());
+    }
+}
@@ -1,8 +1,9 @@
 use std::ops::{Add, Div, Mul, Sub};
 
-use super::{vector::Vector, vector2d::Vector2D};
+use super::{vector::Vector, vector2d::Vector2D, vector3i::Vector3I};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector3D {
     pub x: f64,
     pub y: f64,
@@ -18,6 +19,10 @@ impl Vector3D {
         };
     }
 
+    pub fn from_xyz(x: f64, y: f64, z: f64) -> Self {
+        return Self { x: x, y: y, z: z };
+    }
+
     pub fn zero() -> Self {
         return Self {
             x: 0_f64,
@@ -239,4 +244,16 @@ impl From<Vector2D> for Vector3D {
     fn from(value: Vector2D) -> Self {
         return Vector3D::from_xy(value.x, value.y);
     }
-}
\ No newline at end of file
+}
+
+impl From<Vector3D> for Vector3I {
+    fn from(value: Vector3D) -> Self {
+        return Vector3I::from_xyz(value.x as i64, value.y as i64, value.z as i64);
+    }
+}
+
+impl From<&Vector3D> for Vector3I {
+    fn from(value: &Vector3D) -> Self {
+        return Vector3I::from_xyz(value.x as i64, value.y as i64, value.z as i64);
+    }
+}
@@ -2,7 +2,7 @@ use std::ops::{Add, Div, Mul, Sub};
 
 use super::{vector::Vector, vector2i::Vector2I};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Vector3I {
     pub x: i64,
     pub y: i64,
@@ -18,6 +18,10 @@ impl Vector3I {
         };
     }
 
+    pub fn from_xyz(x: i64, y: i64, z: i64) -> Self {
+        return Self { x: x, y: y, z: z };
+    }
+
     pub fn zero() -> Self {
         return Self {
             x: 0_i64,
@@ -27,7 +31,7 @@ impl Vector3I {
     }
 
     pub fn dot(&self, v: &Self) -> i64 {
-        return self.x * v.x + self.y + v.y + self.z * v.z;
+        return self.x * v.x + self.y * v.y + self.z * v.z;
     }
 
     pub fn cross(&self, v: &Self) -> Self {
@@ -263,9 +267,9 @@ impl Div<i64> for Vector3I {
 
     fn div(self, rhs: i64) -> Self::Output {
         return Vector3I {
-            x: self.x / rhs as i64,
-            y: self.y / rhs as i64,
-            z: self.z / rhs as i64,
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
         };
     }
 }
@@ -313,4 +317,25 @@ impl From<Vector2I> for Vector3I {
     fn from(value: Vector2I) -> Self {
         return Vector3I::from_xy(value.x, value.y);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numerics::{vector::Vector, vector3d::Vector3D};
+
+    use super::Vector3I;
+
+    #[test]
+    fn dot_and_cross() {
+        let u = Vector3I::from_xyz(1, 2, 3);
+        let v = Vector3I::from_xyz(4, 5, 6);
+        assert_eq!(u.dot(&v), 32);
+        assert_eq!(u.cross(&v), Vector3I::from_xyz(-3, 6, -3));
+        assert_eq!(u.cross(&v).dot(&u), 0);
+        assert_eq!(Vector3I::from_xyz(2, 3, 6).magnitude(), 7_f64);
+        assert_eq!(
+            Vector3I::from(Vector3D::from_xyz(1.5, -2.5, 3_f64)),
+            Vector3I::from_xyz(1, -2, 3)
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use ndarray::Array2;
+
+/// Exact squared Euclidean distance in cells from every cell to the closest cell that is
+/// true, e.g. occupied, in linear time (Felzenszwalb and Huttenlocher). Cells that are true
+/// are zero; if no cell is true, every cell is infinite.
+pub fn squared_distance_transform(features: &Array2<bool>) -> Array2<f64> {
+    let (rows, columns) = features.dim();
+    let mut ret: Array2<f64> = features.map(|f| if *f { 0_f64 } else { f64::INFINITY });
+
+    let mut buffer: Vec<f64> = vec![0_f64; rows.max(columns)];
+    for mut column in ret.columns_mut() {
+        transform_line(&column.to_vec(), &mut buffer[..rows]);
+        column
+            .iter_mut()
+            .zip(buffer.iter())
+            .for_each(|(d, b)| *d = *b);
+    }
+    for mut row in ret.rows_mut() {
+        transform_line(&row.to_vec(), &mut buffer[..columns]);
+        row.iter_mut().zip(buffer.iter()).for_each(|(d, b)| *d = *b);
+    }
+
+    return ret;
+}
+
+/// Exact Euclidean distance in cells from every cell to the closest cell that is true.
+pub fn distance_transform(features: &Array2<bool>) -> Array2<f64> {
+    return squared_distance_transform(features).mapv(f64::sqrt);
+}
+
+/// Computes the distance in cells from every cell to the closest cell that is true, e.g.
+/// occupied, so that callers can choose between speed and exactness.
+pub trait DistanceTransform {
+    /// Cells that are true are zero; if no cell is true, every cell is infinite.
+    fn transform(&self, features: &Array2<bool>) -> Array2<f64>;
+}
+
+/// Exact Euclidean distance transform, see `distance_transform`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExactDistanceTransform;
+
+impl DistanceTransform for ExactDistanceTransform {
+    fn transform(&self, features: &Array2<bool>) -> Array2<f64> {
+        return distance_transform(features);
+    }
+}
+
+/// Approximate distance transform in two raster passes, summing the costs of axial and
+/// diagonal steps between neighboring cells. The default costs 1 and sqrt(2) are exact
+/// along axes and diagonals and overestimate other directions by up to 8%.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChamferDistanceTransform {
+    pub axial_cost: f64,
+    pub diagonal_cost: f64,
+}
+
+impl Default for ChamferDistanceTransform {
+    fn default() -> Self {
+        return Self {
+            axial_cost: 1_f64,
+            diagonal_cost: std::f64::consts::SQRT_2,
+        };
+    }
+}
+
+impl DistanceTransform for ChamferDistanceTransform {
+    fn transform(&self, features: &Array2<bool>) -> Array2<f64> {
+        let (rows, columns) = features.dim();
+        let mut ret: Array2<f64> = features.map(|f| if *f { 0_f64 } else { f64::INFINITY });
+
+        for r in 0..rows {
+            for c in 0..columns {
+                self.relax(&mut ret, r, c, 1);
+            }
+        }
+        for r in (0..rows).rev() {
+            for c in (0..columns).rev() {
+                self.relax(&mut ret, r, c, -1);
+            }
+        }
+
+        return ret;
+    }
+}
+
+impl ChamferDistanceTransform {
+    /// Lower the distance of the cell through the neighbors visited before it in a raster
+    /// pass, forward if sign is 1 and backward if sign is -1.
+    fn relax(&self, distances: &mut Array2<f64>, row: usize, column: usize, sign: isize) {
+        let (rows, columns) = distances.dim();
+        let neighbors: [(isize, isize, f64); 4] = [
+            (-1, -1, self.diagonal_cost),
+            (-1, 0, self.axial_cost),
+            (-1, 1, self.diagonal_cost),
+            (0, -1, self.axial_cost),
+        ];
+
+        let mut distance = distances[[row, column]];
+        for (dr, dc, cost) in neighbors.iter() {
+            let (r, c) = (row as isize + sign * dr, column as isize + sign * dc);
+            if r >= 0 && c >= 0 && r < rows as isize && c < columns as isize {
+                distance = distance.min(distances[[r as usize, c as usize]] + cost);
+            }
+        }
+        distances[[row, column]] = distance;
+    }
+}
+
+/// One-dimensional transform: distances[q] = min over p of (q - p)^2 + values[p], from the
+/// lower envelope of the parabolas rooted at the finite values.
+fn transform_line(values: &[f64], distances: &mut [f64]) {
+    // Parabola roots[k] is the lowest from boundaries[k] up to boundaries[k + 1].
+    let mut roots: Vec<usize> = Vec::with_capacity(values.len());
+    let mut boundaries: Vec<f64> = Vec::with_capacity(values.len());
+
+    for (q, value) in values.iter().enumerate() {
+        if value.is_infinite() {
+            continue;
+        }
+
+        loop {
+            let p = match roots.last() {
+                Some(p) => *p,
+                None => {
+                    roots.push(q);
+                    boundaries.push(f64::NEG_INFINITY);
+                    break;
+                }
+            };
+
+            let (pf, qf) = (p as f64, q as f64);
+            let intersection = ((value + qf * qf) - (values[p] + pf * pf)) / (2_f64 * (qf - pf));
+            if intersection > boundaries[boundaries.len() - 1] {
+                roots.push(q);
+                boundaries.push(intersection);
+                break;
+            }
+
+            roots.pop();
+            boundaries.pop();
+        }
+    }
+
+    if roots.is_empty() {
+        distances.fill(f64::INFINITY);
+        return;
+    }
+
+    let mut k = 0;
+    for (q, distance) in distances.iter_mut().enumerate() {
+        while k + 1 < roots.len() && boundaries[k + 1] < q as f64 {
+            k += 1;
+        }
+
+        let offset = q as f64 - roots[k] as f64;
+        *distance = offset * offset + values[roots[k]];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Array2;
+
+    use super::{
+        distance_transform, squared_distance_transform, ChamferDistanceTransform,
+        DistanceTransform, ExactDistanceTransform,
+    };
+
+    #[test]
+    fn matches_brute_force() {
+        let features: Array2<bool> = Array2::from_shape_fn((13, 17), |(r, c)| {
+            (r * 7 + c * 11) % 23 == 0 || (r == 6 && c > 12)
+        });
+        let squared = squared_distance_transform(&features);
+        for ((r, c), d) in squared.indexed_iter() {
+            let expected = features
+                .indexed_iter()
+                .filter(|(_, f)| **f)
+                .map(|((fr, fc), _)| {
+                    let (dr, dc) = (fr as f64 - r as f64, fc as f64 - c as f64);
+                    return dr * dr + dc * dc;
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(*d, expected, "cell ({}, {})", r, c);
+        }
+
+        let single = Array2::from_shape_fn((3, 4), |(r, c)| r == 0 && c == 0);
+        assert_eq!(distance_transform(&single)[[2, 3]], 13_f64.sqrt());
+        assert!(distance_transform(&Array2::from_elem((2, 2), false))
+            .iter()
+            .all(|d| d.is_infinite()));
+    }
+
+    #[test]
+    fn chamfer_bounds_exact() {
+        let features: Array2<bool> = Array2::from_shape_fn((20, 30), |(r, c)| {
+            return (r == 3 && c == 4) || (r == 15 && c > 20) || (r + c == 30);
+        });
+        let transforms: [&dyn DistanceTransform; 2] = [
+            &ExactDistanceTransform,
+            &ChamferDistanceTransform::default(),
+        ];
+        let exact = transforms[0].transform(&features);
+        let chamfer = transforms[1].transform(&features);
+
+        for (e, c) in exact.iter().zip(chamfer.iter()) {
+            assert!(*c >= *e - 1e-9 && *c <= *e * 1.083 + 1e-9, "{} {}", e, c);
+        }
+        assert_eq!(chamfer[[3, 0]], 4_f64);
+        assert!((chamfer[[0, 1]] - 3_f64 * std::f64::consts::SQRT_2).abs() < 1e-9);
+    }
+}
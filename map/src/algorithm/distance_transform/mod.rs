@@ -0,0 +1 @@
+pub mod distance_transform_algorithm;
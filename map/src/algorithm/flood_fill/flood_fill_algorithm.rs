@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+
+use ndarray::Array2;
+
+use crate::grid::connectivity::Connectivity;
+
+/// Region of cells connected to seed whose values satisfy predicate, as (row, column) in
+/// breadth first order starting with the seed. Empty if the seed is outside of the array or
+/// does not satisfy predicate.
+pub fn flood_fill<T, F>(
+    array: &Array2<T>,
+    seed: (usize, usize),
+    connectivity: Connectivity,
+    predicate: F,
+) -> Vec<(usize, usize)>
+where
+    F: Fn(&T) -> bool,
+{
+    let mut visited: Array2<bool> = Array2::from_elem(array.dim(), false);
+    return flood_fill_unvisited(array, seed, connectivity, &predicate, &mut visited);
+}
+
+/// Regions of cells satisfying predicate, each as returned by `flood_fill`. Regions are
+/// ordered by their first cell in row-major order, which is also their seed.
+pub fn connected_components<T, F>(
+    array: &Array2<T>,
+    connectivity: Connectivity,
+    predicate: F,
+) -> Vec<Vec<(usize, usize)>>
+where
+    F: Fn(&T) -> bool,
+{
+    let (height, width) = array.dim();
+    let mut visited: Array2<bool> = Array2::from_elem(array.dim(), false);
+    let mut ret: Vec<Vec<(usize, usize)>> = Vec::new();
+
+    for r in 0..height {
+        for c in 0..width {
+            if !visited[(r, c)] && predicate(&array[(r, c)]) {
+                ret.push(flood_fill_unvisited(
+                    array,
+                    (r, c),
+                    connectivity,
+                    &predicate,
+                    &mut visited,
+                ));
+            }
+        }
+    }
+
+    return ret;
+}
+
+/// Flood fill skipping cells already visited, and marking the filled ones as visited.
+fn flood_fill_unvisited<T, F>(
+    array: &Array2<T>,
+    seed: (usize, usize),
+    connectivity: Connectivity,
+    predicate: &F,
+    visited: &mut Array2<bool>,
+) -> Vec<(usize, usize)>
+where
+    F: Fn(&T) -> bool,
+{
+    let (height, width) = array.dim();
+    if seed.0 >= height || seed.1 >= width || visited[seed] || !predicate(&array[seed]) {
+        return Vec::new();
+    }
+
+    let mut ret: Vec<(usize, usize)> = Vec::new();
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::from([seed]);
+    visited[seed] = true;
+
+    while let Some((r, c)) = queue.pop_front() {
+        ret.push((r, c));
+        for [dr, dc] in connectivity.offsets().iter() {
+            let neighbor = match (r.checked_add_signed(*dr), c.checked_add_signed(*dc)) {
+                (Some(nr), Some(nc)) if nr < height && nc < width => (nr, nc),
+                _ => continue,
+            };
+            if !visited[neighbor] && predicate(&array[neighbor]) {
+                visited[neighbor] = true;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use crate::grid::connectivity::Connectivity;
+
+    use super::{connected_components, flood_fill};
+
+    #[test]
+    fn fills_connected_region() {
+        let array = array![[1, 1, 0, 0], [0, 1, 0, 1], [0, 0, 1, 1], [2, 0, 0, 0],];
+
+        let region = flood_fill(&array, (0, 0), Connectivity::Four, |v| *v == 1);
+        assert_eq!(region, vec![(0, 0), (0, 1), (1, 1)]);
+        let region = flood_fill(&array, (0, 0), Connectivity::Eight, |v| *v == 1);
+        assert_eq!(region.len(), 6);
+        assert!(flood_fill(&array, (0, 2), Connectivity::Four, |v| *v == 1).is_empty());
+        assert!(flood_fill(&array, (4, 0), Connectivity::Four, |v| *v == 1).is_empty());
+
+        let components = connected_components(&array, Connectivity::Four, |v| *v > 0);
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[1][0], (1, 3));
+        assert_eq!(components[2], vec![(3, 0)]);
+    }
+}
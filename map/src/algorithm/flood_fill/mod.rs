@@ -0,0 +1 @@
+pub mod flood_fill_algorithm;
@@ -0,0 +1,226 @@
+use core::numerics::vector2d::Vector2D;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+/// Parameters of the Hough transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HoughOptions {
+    /// Bin size of the line normal angle in radians.
+    pub angle_resolution: f64,
+
+    /// Bin size of the line distance from the origin, in the units of the points.
+    pub rho_resolution: f64,
+
+    /// Fewest points on a detected line.
+    pub min_votes: usize,
+
+    /// Upper bound on the detected lines.
+    pub max_lines: usize,
+
+    /// Lines closer than this many bins in both angle and distance to a stronger line are
+    /// taken as the same line.
+    pub suppression_bins: usize,
+}
+
+impl HoughOptions {
+    /// Options for the occupied cells of a grid map with the given cell size, with one degree
+    /// angle bins and one cell distance bins.
+    pub fn for_cell_size(cell_size: f64) -> Self {
+        return Self {
+            angle_resolution: PI / 180_f64,
+            rho_resolution: cell_size,
+            min_votes: 10,
+            max_lines: 10,
+            suppression_bins: 3,
+        };
+    }
+}
+
+/// Line { p : p.x cos(angle) + p.y sin(angle) = rho } found by the Hough transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HoughLine {
+    /// Angle of the line normal in [0, pi).
+    pub angle: f64,
+    /// Signed distance of the line from the origin.
+    pub rho: f64,
+    /// Number of points on the line.
+    pub votes: usize,
+}
+
+impl HoughLine {
+    /// Angle of the line itself in [pi / 2, 3 pi / 2).
+    pub fn direction(&self) -> f64 {
+        return self.angle + FRAC_PI_2;
+    }
+}
+
+/// Votes of every point for the lines through it, binned by normal angle and distance.
+struct Accumulator {
+    votes: Vec<usize>,
+    angles: usize,
+    rhos: usize,
+    max_rho: f64,
+    rho_resolution: f64,
+}
+
+impl Accumulator {
+    fn new(points: &[Vector2D], options: &HoughOptions) -> Self {
+        // An even number of angle bins, so that perpendicular angles fall into bins as well.
+        let angles = ((PI / options.angle_resolution).round() as usize)
+            .max(2)
+            .div_ceil(2)
+            * 2;
+        let max_rho = points.iter().map(|p| p.x.hypot(p.y)).fold(0_f64, f64::max);
+        let rhos = (2_f64 * max_rho / options.rho_resolution).ceil() as usize + 1;
+
+        let mut ret = Self {
+            votes: vec![0; angles * rhos],
+            angles: angles,
+            rhos: rhos,
+            max_rho: max_rho,
+            rho_resolution: options.rho_resolution,
+        };
+        let sin_cos: Vec<(f64, f64)> = (0..angles).map(|i| ret.angle(i).sin_cos()).collect();
+        for point in points {
+            for (i, (sin, cos)) in sin_cos.iter().enumerate() {
+                let rho = point.x * cos + point.y * sin;
+                let bin = ((rho + max_rho) / options.rho_resolution).round() as usize;
+                ret.votes[i * rhos + bin] += 1;
+            }
+        }
+
+        return ret;
+    }
+
+    fn angle(&self, bin: usize) -> f64 {
+        return bin as f64 * PI / self.angles as f64;
+    }
+
+    fn rho(&self, bin: usize) -> f64 {
+        return bin as f64 * self.rho_resolution - self.max_rho;
+    }
+}
+
+/// Strongest lines through the points, e.g. walls among the occupied cells of a map, with the
+/// most votes first.
+pub fn detect_lines(points: &[Vector2D], options: &HoughOptions) -> Vec<HoughLine> {
+    if points.is_empty() {
+        return Vec::new();
+    }
+
+    let accumulator = Accumulator::new(points, options);
+    let mut bins: Vec<usize> = (0..accumulator.votes.len())
+        .filter(|i| accumulator.votes[*i] >= options.min_votes)
+        .collect();
+    bins.sort_by(|a, b| {
+        accumulator.votes[*b]
+            .cmp(&accumulator.votes[*a])
+            .then(a.cmp(b))
+    });
+
+    let window = options.suppression_bins as i64;
+    let (angles, rhos) = (accumulator.angles as i64, accumulator.rhos as i64);
+    let mut peaks: Vec<(i64, i64)> = Vec::new();
+    for bin in bins {
+        if peaks.len() >= options.max_lines {
+            break;
+        }
+
+        let (angle, rho) = (
+            (bin / accumulator.rhos) as i64,
+            (bin % accumulator.rhos) as i64,
+        );
+        let suppressed = peaks.iter().any(|(peak_angle, peak_rho)| {
+            let near = |da: i64, dr: i64| -> bool { da.abs() <= window && dr.abs() <= window };
+            // Angles wrap around at pi, where the distance changes sign.
+            return near(angle - peak_angle, rho - peak_rho)
+                || near(angle - peak_angle - angles, rhos - 1 - rho - peak_rho)
+                || near(angle - peak_angle + angles, rhos - 1 - rho - peak_rho);
+        });
+        if !suppressed {
+            peaks.push((angle, rho));
+        }
+    }
+
+    return peaks
+        .iter()
+        .map(|(angle, rho)| HoughLine {
+            angle: accumulator.angle(*angle as usize),
+            rho: accumulator.rho(*rho as usize),
+            votes: accumulator.votes[*angle as usize * accumulator.rhos + *rho as usize],
+        })
+        .collect();
+}
+
+/// Rotation in [-pi / 4, pi / 4) of the dominant pair of perpendicular directions among the
+/// points from the axes. Rotating the points by its negative aligns walls, e.g. of a scanned
+/// building, with the axes. Returns `None` if there are no points.
+pub fn dominant_orientation(points: &[Vector2D], options: &HoughOptions) -> Option<f64> {
+    if points.is_empty() {
+        return None;
+    }
+
+    // Squared votes favor angles whose points concentrate on few lines.
+    let accumulator = Accumulator::new(points, options);
+    let half = accumulator.angles / 2;
+    let mut scores: Vec<f64> = vec![0_f64; half];
+    for i in 0..accumulator.angles {
+        let row = &accumulator.votes[i * accumulator.rhos..(i + 1) * accumulator.rhos];
+        scores[i % half] += row.iter().map(|v| (*v as f64).powi(2)).sum::<f64>();
+    }
+
+    let best = (0..half)
+        .max_by(|a, b| scores[*a].total_cmp(&scores[*b]).then(b.cmp(a)))
+        .unwrap();
+    let angle = accumulator.angle(best);
+    return Some(if angle >= FRAC_PI_4 {
+        angle - FRAC_PI_2
+    } else {
+        angle
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+    use std::f64::consts::PI;
+
+    use super::{detect_lines, dominant_orientation, HoughOptions};
+
+    #[test]
+    fn detects_rotated_walls() {
+        // Two perpendicular walls rotated by 10 degrees, and a few scattered points.
+        let rotation = 10_f64.to_radians();
+        let mut points: Vec<Vector2D> = Vec::new();
+        for i in 0..40 {
+            let t = i as f64 * 0.25;
+            points.push(Vector2D::from_xy(t, 2_f64).rotate(rotation));
+            points.push(Vector2D::from_xy(12_f64, t).rotate(rotation));
+        }
+        for i in 0..5 {
+            points.push(Vector2D::from_xy(
+                3_f64 + i as f64,
+                7_f64 - (i * i) as f64 * 0.5,
+            ));
+        }
+
+        let mut options = HoughOptions::for_cell_size(0.25);
+        options.min_votes = 20;
+        let lines = detect_lines(&points, &options);
+        assert_eq!(lines.len(), 2);
+
+        let resolution = PI / 180_f64;
+        let mut angles: Vec<f64> = lines.iter().map(|l| l.angle).collect();
+        angles.sort_by(|a, b| a.total_cmp(b));
+        assert!((angles[0] - rotation).abs() <= resolution);
+        assert!((angles[1] - rotation - PI / 2_f64).abs() <= resolution);
+        let wall = lines.iter().find(|l| l.angle > 1_f64).unwrap();
+        assert!((wall.rho - 2_f64).abs() <= 0.25);
+
+        let orientation = dominant_orientation(&points, &options).unwrap();
+        assert!((orientation - rotation).abs() <= resolution);
+        let rotated: Vec<Vector2D> = points.iter().map(|p| p.rotate(-0.3)).collect();
+        let orientation = dominant_orientation(&rotated, &options).unwrap();
+        assert!((orientation - (rotation - 0.3)).abs() <= resolution);
+        assert_eq!(dominant_orientation(&[], &options), None);
+    }
+}
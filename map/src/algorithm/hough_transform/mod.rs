@@ -0,0 +1 @@
+pub mod hough_transform_algorithm;
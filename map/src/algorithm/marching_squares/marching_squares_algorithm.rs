@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use core::numerics::vector2d::Vector2D;
+use ndarray::Array2;
+
+/// Boundaries of the occupied regions of an occupancy array, found by marching squares over
+/// the cell centers. Each contour is closed, repeating its first point at the end, and runs
+/// between cell centers with x the column and y the row. Contours keep occupied cells on
+/// their right in these coordinates, i.e. on their left with the y-axis pointing up as in
+/// `GridMap`, so outer boundaries run counterclockwise and boundaries of holes clockwise.
+/// Cells outside of the array count as free, and diagonally touching occupied cells form
+/// one region. Points along straight runs are dropped.
+pub fn marching_squares(occupied: &Array2<bool>) -> Vec<Vec<Vector2D>> {
+    let (height, width) = occupied.dim();
+    let is_occupied = |r: i64, c: i64| -> bool {
+        return r >= 0
+            && c >= 0
+            && (r as usize) < height
+            && (c as usize) < width
+            && occupied[(r as usize, c as usize)];
+    };
+
+    // Directed contour segments between edge midpoints, in doubled coordinates so that they
+    // are exact, mapping the start of each segment to its end.
+    let mut next: HashMap<(i64, i64), (i64, i64)> = HashMap::new();
+    for r in -1..height as i64 {
+        for c in -1..width as i64 {
+            // Corners clockwise from top left, and the midpoints of the edges from each
+            // corner to the next one.
+            let corners = [
+                is_occupied(r, c),
+                is_occupied(r, c + 1),
+                is_occupied(r + 1, c + 1),
+                is_occupied(r + 1, c),
+            ];
+            let midpoints = [
+                (2 * r, 2 * c + 1),
+                (2 * r + 1, 2 * c + 2),
+                (2 * r + 2, 2 * c + 1),
+                (2 * r + 1, 2 * c),
+            ];
+
+            let crossings = (0..4)
+                .filter(|i| corners[*i] != corners[(i + 1) % 4])
+                .count();
+            for entry in 0..4 {
+                if corners[entry] || !corners[(entry + 1) % 4] {
+                    continue;
+                }
+                // On a saddle the occupied corners are joined, so the contour turns back
+                // around the free corner beside the entry edge.
+                let exit = if crossings == 4 {
+                    (entry + 3) % 4
+                } else {
+                    (0..4)
+                        .find(|i| corners[*i] && !corners[(i + 1) % 4])
+                        .unwrap()
+                };
+                next.insert(midpoints[entry], midpoints[exit]);
+            }
+        }
+    }
+
+    let mut starts: Vec<(i64, i64)> = next.keys().copied().collect();
+    starts.sort();
+
+    let mut ret: Vec<Vec<Vector2D>> = Vec::new();
+    for start in starts {
+        if !next.contains_key(&start) {
+            continue;
+        }
+
+        let mut contour: Vec<(i64, i64)> = vec![start];
+        let mut current = next.remove(&start).unwrap();
+        while current != start {
+            contour.push(current);
+            current = next.remove(&current).unwrap();
+        }
+
+        // Keep only the corners of the contour, starting from one.
+        let n = contour.len();
+        let is_corner = |i: usize| -> bool {
+            let (a, b, c) = (contour[(i + n - 1) % n], contour[i], contour[(i + 1) % n]);
+            return (b.0 - a.0) * (c.1 - b.1) != (b.1 - a.1) * (c.0 - b.0);
+        };
+        let first = (0..n).find(|i| is_corner(*i)).unwrap_or(0);
+        let mut points: Vec<Vector2D> = (0..n)
+            .map(|i| (first + i) % n)
+            .filter(|i| is_corner(*i))
+            .map(|i| Vector2D::from_xy(contour[i].1 as f64 / 2_f64, contour[i].0 as f64 / 2_f64))
+            .collect();
+        points.push(points[0]);
+        ret.push(points);
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+    use ndarray::Array2;
+
+    use super::marching_squares;
+
+    #[test]
+    fn traces_boundaries() {
+        // Ring of occupied cells around a free cell.
+        let mut occupied: Array2<bool> = Array2::from_elem((5, 5), false);
+        for r in 1..4 {
+            for c in 1..4 {
+                occupied[(r, c)] = (r, c) != (2, 2);
+            }
+        }
+
+        let contours = marching_squares(&occupied);
+        assert_eq!(contours.len(), 2);
+
+        // Outer boundary cutting the corners of the ring, then the diamond around the hole.
+        // Rows count downwards, so the outer boundary has a negative area here.
+        let signed_area = |points: &Vec<Vector2D>| -> f64 {
+            return points
+                .windows(2)
+                .map(|w| w[0].x * w[1].y - w[1].x * w[0].y)
+                .sum::<f64>()
+                / 2_f64;
+        };
+        assert_eq!(contours[0].len(), 9);
+        assert_eq!(contours[0].first(), contours[0].last());
+        assert_eq!(signed_area(&contours[0]), -8.5);
+        assert_eq!(contours[1].len(), 5);
+        assert_eq!(signed_area(&contours[1]), 0.5);
+
+        // Diagonal neighbors form one region.
+        let mut diagonal: Array2<bool> = Array2::from_elem((2, 2), false);
+        diagonal[(0, 0)] = true;
+        diagonal[(1, 1)] = true;
+        assert_eq!(marching_squares(&diagonal).len(), 1);
+        assert!(marching_squares(&Array2::from_elem((3, 3), false)).is_empty());
+    }
+}
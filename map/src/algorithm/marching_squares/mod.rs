@@ -0,0 +1 @@
+pub mod marching_squares_algorithm;
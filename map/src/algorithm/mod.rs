@@ -0,0 +1,5 @@
+pub mod distance_transform;
+pub mod flood_fill;
+pub mod hough_transform;
+pub mod marching_squares;
+pub mod zhang_suen_thinning;
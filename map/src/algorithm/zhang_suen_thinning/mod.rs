@@ -1 +1 @@
-pub mod zhang_suen_thinning_algorithm;
\ No newline at end of file
+pub mod zhang_suen_thinning_algorithm;
@@ -3,7 +3,6 @@
  * https://rosettacode.org/wiki/Zhang-Suen_thinning_algorithm
  */
 
-use image::{Rgb, RgbImage};
 use ndarray::Array2;
 
 static GRID_OFFSETS: [(isize, isize); 8] = [
@@ -79,7 +78,7 @@ impl ZhangSuenThinningAlgorithm {
                 }
 
                 let cond_b = self.compute_condition_b(x, y);
-                if cond_b < 2 || cond_b > 6 {
+                if !(2..=6).contains(&cond_b) {
                     continue;
                 }
 
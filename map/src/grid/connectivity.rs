@@ -0,0 +1,31 @@
+static OFFSETS_4: [[isize; 2]; 4] = [[-1, 0], [0, 1], [1, 0], [0, -1]];
+
+static OFFSETS_8: [[isize; 2]; 8] = [
+    [-1, 0],
+    [-1, 1],
+    [0, 1],
+    [1, 1],
+    [1, 0],
+    [1, -1],
+    [0, -1],
+    [-1, -1],
+];
+
+/// Which cells of a grid count as adjacent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Connectivity {
+    /// Cells sharing a side only.
+    Four,
+    /// Cells sharing a side or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    /// Offsets (row, column) of the adjacent cells, clockwise from the cell above.
+    pub fn offsets(&self) -> &'static [[isize; 2]] {
+        return match self {
+            Connectivity::Four => &OFFSETS_4,
+            Connectivity::Eight => &OFFSETS_8,
+        };
+    }
+}
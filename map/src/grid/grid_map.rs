@@ -1,8 +1,25 @@
-use std::io;
+use core::numerics::{
+    units::{Cells, Meters},
+    vector2d::Vector2D,
+};
 
-use image::{imageops, ColorType, ImageReader};
+use image::{imageops, ImageReader};
+use math::{
+    algorithm::line_traversal::line_traversal_algorithm::SupercoverLine,
+    geometry::{polygon2d::Polygon2D, polyline2d::Polyline2D},
+};
 use ndarray::Array2;
 
+use super::connectivity::Connectivity;
+use crate::algorithm::{
+    distance_transform::distance_transform_algorithm::{DistanceTransform, ExactDistanceTransform},
+    flood_fill::flood_fill_algorithm::flood_fill,
+    hough_transform::hough_transform_algorithm::{
+        detect_lines, dominant_orientation, HoughLine, HoughOptions,
+    },
+    marching_squares::marching_squares_algorithm::marching_squares,
+};
+
 pub struct GridMap {
     cells: Array2<GridMapCell>,
     cell_size: f64,
@@ -42,19 +59,21 @@ impl GridMap {
         let cells: Array2<GridMapCell> =
             Array2::from_shape_fn((image_height as usize, image_width as usize), |(r, c)| {
                 let pixel_value = grayscale_image.get_pixel(c as u32, r as u32).0[0];
-                let cell_state: GridMapCellState;
+                let is_border = r == 0
+                    || c == 0
+                    || (r == image_height as usize - 1)
+                    || (c == image_width as usize - 1);
 
-                if r == 0 || c == 0 || (r == image_height as usize - 1) || (c == image_width as usize - 1) {
-                    cell_state = GridMapCellState::Occupied;
-                } else if occupied_region_color == OccupiedRegionColor::Black
-                    && pixel_value < threshold
+                let cell_state = if is_border
+                    || occupied_region_color == OccupiedRegionColor::Black
+                        && pixel_value < threshold
                     || occupied_region_color == OccupiedRegionColor::White
                         && pixel_value > threshold
                 {
-                    cell_state = GridMapCellState::Occupied;
+                    GridMapCellState::Occupied
                 } else {
-                    cell_state = GridMapCellState::Vacant;
-                }
+                    GridMapCellState::Vacant
+                };
 
                 return GridMapCell { state: cell_state };
             });
@@ -73,18 +92,154 @@ impl GridMap {
         return self.cells.get_mut((row, column));
     }
 
-    pub fn get_by_coordinate(&self, x: f64, y: f64) -> Option<&GridMapCell> {
-        return self.get_by_coordinate_mut(x, y);
+    pub fn get_by_coordinate<T: Into<f64>>(&self, x: T, y: T) -> Option<&GridMapCell> {
+        let (row, column) = self.coordinate_to_cell(x, y)?;
+        return self.cells.get((row, column));
+    }
+
+    pub fn get_by_coordinate_mut<T: Into<f64>>(&mut self, x: T, y: T) -> Option<&mut GridMapCell> {
+        let (row, column) = self.coordinate_to_cell(x, y)?;
+        return self.cells.get_mut((row, column));
     }
 
-    pub fn get_by_coordinate_mut(&self, x: f64, y: f64) -> Option<&GridMapCell> {
+    /// Convert planar coordinate into (row, column) of the cell containing it.
+    /// The origin is at the bottom-left corner of the map, with y-axis pointing up.
+    /// Accepts `f32` coordinates as well, e.g. from a `Pose2F`.
+    pub fn coordinate_to_cell<T: Into<f64>>(&self, x: T, y: T) -> Option<(usize, usize)> {
+        let (x, y): (f64, f64) = (x.into(), y.into());
         let (height, width) = self.cells.dim();
         let (roi_width, roi_height) = (
             width as f64 * self.cell_size,
             height as f64 * self.cell_size,
         );
 
-        todo!();
+        if x < 0_f64 || y < 0_f64 || x >= roi_width || y >= roi_height {
+            return None;
+        }
+
+        let column = (x / self.cell_size).floor() as usize;
+        let row = height - 1 - (y / self.cell_size).floor() as usize;
+        return Some((row, column));
+    }
+
+    /// Convert (row, column) of a cell into planar coordinate of its center.
+    pub fn cell_to_coordinate(&self, row: usize, column: usize) -> (f64, f64) {
+        let height = self.cells.dim().0;
+        let x = self.cell_size * (0.5 + column as f64);
+        let y = self.cell_size * (height as f64 - 0.5 - row as f64);
+        return (x, y);
+    }
+
+    /// Check whether every cell the straight segment between two planar coordinates passes
+    /// through is vacant, including both cells beside a corner it passes exactly through.
+    /// Segments leaving the map are not vacant.
+    pub fn is_segment_vacant(&self, from: &Vector2D, to: &Vector2D) -> bool {
+        return SupercoverLine::new(from / self.cell_size, to / self.cell_size)
+            .all(|cell| self.is_index_vacant(cell.x, cell.y));
+    }
+
+    /// Check the cell at column x and row y counted upwards from the bottom of the map.
+    fn is_index_vacant(&self, x: i64, y: i64) -> bool {
+        let (height, width) = self.cells.dim();
+        if x < 0 || y < 0 || x >= width as i64 || y >= height as i64 {
+            return false;
+        }
+
+        let cell = self
+            .cells
+            .get((height - 1 - y as usize, x as usize))
+            .unwrap();
+        return cell.state == GridMapCellState::Vacant;
+    }
+
+    /// Cells connected to the cell at (row, column) that satisfy predicate, as (row, column)
+    /// in breadth first order, e.g. the vacant area reachable from a robot position.
+    pub fn flood_fill<F>(
+        &self,
+        row: usize,
+        column: usize,
+        connectivity: Connectivity,
+        predicate: F,
+    ) -> Vec<(usize, usize)>
+    where
+        F: Fn(&GridMapCell) -> bool,
+    {
+        return flood_fill(&self.cells, (row, column), connectivity, predicate);
+    }
+
+    /// Distance from the center of every cell to the center of the closest occupied cell,
+    /// zero for occupied cells and infinite if no cell is occupied.
+    pub fn obstacle_distances(&self) -> Array2<f64> {
+        return self.obstacle_distances_with(&ExactDistanceTransform);
+    }
+
+    /// Like `obstacle_distances`, computed by transform, e.g. a faster approximate one.
+    pub fn obstacle_distances_with(&self, transform: &dyn DistanceTransform) -> Array2<f64> {
+        let occupied: Array2<bool> = self.map(|cell| cell.state == GridMapCellState::Occupied);
+        return transform.transform(&occupied).mapv(|d| d * self.cell_size);
+    }
+
+    /// Boundaries of the occupied regions as closed polylines in planar coordinates, running
+    /// between cell centers, e.g. for exporting walls as vectors. Outer boundaries run
+    /// counterclockwise and boundaries of holes clockwise.
+    pub fn obstacle_contours(&self) -> Vec<Polyline2D> {
+        let occupied: Array2<bool> = self.map(|cell| cell.state == GridMapCellState::Occupied);
+        return marching_squares(&occupied)
+            .into_iter()
+            .map(|contour| {
+                let points: Vec<Vector2D> = contour
+                    .iter()
+                    .map(|p| {
+                        Vector2D::from_xy(
+                            self.cell_size * (0.5 + p.x),
+                            self.cell_size * (self.vertical_cells() as f64 - 0.5 - p.y),
+                        )
+                    })
+                    .collect();
+                return Polyline2D::new(points);
+            })
+            .collect();
+    }
+
+    /// Boundaries of the occupied regions as polygons, e.g. for polygon-based collision
+    /// checks. Holes in obstacles are returned as clockwise polygons of their own.
+    pub fn obstacle_polygons(&self) -> Vec<Polygon2D> {
+        return self
+            .obstacle_contours()
+            .into_iter()
+            .map(|mut contour| {
+                contour.points.pop();
+                return Polygon2D::new(contour.points);
+            })
+            .collect();
+    }
+
+    /// Dominant straight lines through the centers of the occupied cells, e.g. walls, in
+    /// planar coordinates.
+    pub fn detect_lines(&self, options: &HoughOptions) -> Vec<HoughLine> {
+        return detect_lines(&self.occupied_cell_centers(), options);
+    }
+
+    /// Rotation in [-pi / 4, pi / 4) of the dominant wall directions from the axes, so that
+    /// rotating the map by its negative aligns the walls with the axes, e.g. before topology
+    /// extraction. Returns `None` if no cell is occupied.
+    pub fn dominant_orientation(&self) -> Option<f64> {
+        return dominant_orientation(
+            &self.occupied_cell_centers(),
+            &HoughOptions::for_cell_size(self.cell_size),
+        );
+    }
+
+    fn occupied_cell_centers(&self) -> Vec<Vector2D> {
+        return self
+            .cells
+            .indexed_iter()
+            .filter(|(_, cell)| cell.state == GridMapCellState::Occupied)
+            .map(|((r, c), _)| {
+                let (x, y) = self.cell_to_coordinate(r, c);
+                return Vector2D::from_xy(x, y);
+            })
+            .collect();
     }
 
     /// Map the cells in grid map using user-defined mapping function.
@@ -107,6 +262,21 @@ impl GridMap {
     pub fn vertical_cells(&self) -> usize {
         return self.cells.dim().0;
     }
+
+    /// Size of a single cell.
+    pub fn cell_size(&self) -> f64 {
+        return self.cell_size;
+    }
+
+    /// Convert a length in the plane into cells of this map.
+    pub fn meters_to_cells(&self, length: Meters) -> Cells {
+        return length.to_cells(self.cell_size);
+    }
+
+    /// Convert a length in cells of this map into the plane.
+    pub fn cells_to_meters(&self, length: Cells) -> Meters {
+        return length.to_meters(self.cell_size);
+    }
 }
 
 #[derive(Clone, Debug)]
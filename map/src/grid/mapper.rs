@@ -0,0 +1,223 @@
+use core::{
+    collections::spatial_hash_map::SpatialHashMap,
+    numerics::{pose2d::Pose2D, vector2d::Vector2D, vector2i::Vector2I},
+};
+
+use math::algorithm::line_traversal::line_traversal_algorithm::BresenhamLine;
+
+use super::probabilistic_grid_map::{probability_to_log_odds, ProbabilisticGridMap};
+
+/// Ranges measured by a planar laser scanner, counterclockwise from angle_min in the frame
+/// of the scanner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaserScan {
+    pub angle_min: f64,
+    pub angle_increment: f64,
+
+    /// Ranges at or beyond max_range, or not finite, mean that the beam hit nothing.
+    pub ranges: Vec<f64>,
+    pub max_range: f64,
+}
+
+impl LaserScan {
+    pub fn new(angle_min: f64, angle_increment: f64, ranges: Vec<f64>, max_range: f64) -> Self {
+        return Self {
+            angle_min: angle_min,
+            angle_increment: angle_increment,
+            ranges: ranges,
+            max_range: max_range,
+        };
+    }
+
+    /// Points hit by the beams in the frame of the scanner, e.g. for scan matching.
+    pub fn points(&self) -> Vec<Vector2D> {
+        return self
+            .ranges
+            .iter()
+            .enumerate()
+            .filter(|(_, range)| self.is_hit(**range))
+            .map(|(i, range)| self.beam_direction(i) * *range)
+            .collect();
+    }
+
+    fn beam_direction(&self, index: usize) -> Vector2D {
+        return Vector2D::from_angle(self.angle_min + index as f64 * self.angle_increment);
+    }
+
+    fn is_hit(&self, range: f64) -> bool {
+        return range.is_finite() && range >= 0_f64 && range < self.max_range;
+    }
+}
+
+/// Parameters of `Mapper`, as occupancy probabilities.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MapperOptions {
+    /// Probability that a cell a beam ends in is occupied.
+    pub hit_probability: f64,
+
+    /// Probability that a cell a beam passes through is occupied.
+    pub miss_probability: f64,
+
+    /// Bounds of the occupancy of a cell, so that it can still change with new evidence,
+    /// e.g. when a door opens.
+    pub min_probability: f64,
+    pub max_probability: f64,
+}
+
+impl Default for MapperOptions {
+    fn default() -> Self {
+        return Self {
+            hit_probability: 0.7,
+            miss_probability: 0.4,
+            min_probability: 0.12,
+            max_probability: 0.97,
+        };
+    }
+}
+
+/// Occupancy grid mapping from laser scans taken at known poses, e.g. from odometry
+/// refined by scan matching. Each scan updates the log-odds of a cell at most once,
+/// marking the cells its beams end in as hits and the cells they pass through as misses.
+/// The map origin is at its bottom-left corner, as for `GridMap`.
+pub struct Mapper {
+    map: ProbabilisticGridMap,
+    options: MapperOptions,
+}
+
+impl Mapper {
+    /// Mapper of an area of width x height cells, all unknown.
+    pub fn new(width: usize, height: usize, cell_size: f64, options: MapperOptions) -> Self {
+        return Self {
+            map: ProbabilisticGridMap::new(width, height, cell_size),
+            options: options,
+        };
+    }
+
+    /// Add a scan taken by a scanner at pose.
+    pub fn integrate(&mut self, pose: &Pose2D, scan: &LaserScan) {
+        let origin = self.cell_of(&pose.position);
+
+        // Hits take precedence over misses of other beams in the same scan.
+        let mut updates: SpatialHashMap<bool> = SpatialHashMap::new();
+        for (i, range) in scan.ranges.iter().enumerate() {
+            let is_hit = scan.is_hit(*range);
+            let length = if is_hit { *range } else { scan.max_range };
+            let end = self.cell_of(&pose.transform_point(&(scan.beam_direction(i) * length)));
+
+            for cell in BresenhamLine::new(origin, end) {
+                if cell != end {
+                    updates.get_or_insert_with(cell, || false);
+                }
+            }
+            if is_hit {
+                updates.insert(end, true);
+            } else {
+                updates.get_or_insert_with(end, || false);
+            }
+        }
+
+        let (hit, miss) = (
+            probability_to_log_odds(self.options.hit_probability),
+            probability_to_log_odds(self.options.miss_probability),
+        );
+        let (min, max) = (
+            probability_to_log_odds(self.options.min_probability),
+            probability_to_log_odds(self.options.max_probability),
+        );
+        let height = self.map.vertical_cells() as i64;
+        for (cell, is_hit) in updates.sorted_entries() {
+            if cell.x < 0 || cell.y < 0 || cell.y >= height {
+                continue;
+            }
+
+            let (row, column) = ((height - 1 - cell.y) as usize, cell.x as usize);
+            if let Some(log_odds) = self.map.get_log_odds(row, column) {
+                let update = if *is_hit { hit } else { miss };
+                self.map
+                    .set_log_odds(row, column, (log_odds + update).clamp(min, max));
+            }
+        }
+    }
+
+    pub fn map(&self) -> &ProbabilisticGridMap {
+        return &self.map;
+    }
+
+    pub fn into_map(self) -> ProbabilisticGridMap {
+        return self.map;
+    }
+
+    /// Cell containing point, as column x and row y counted upwards from the bottom.
+    fn cell_of(&self, point: &Vector2D) -> Vector2I {
+        let cell_size = self.map.cell_size();
+        return Vector2I::from_xy(
+            (point.x / cell_size).floor() as i64,
+            (point.y / cell_size).floor() as i64,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector2d::Vector2D};
+    use std::f64::consts::PI;
+
+    use crate::{
+        grid::{grid_map::GridMapCellState, probabilistic_grid_map::OccupancyThresholdPolicy},
+        topology::topology_generation::topology_extractor::TopologyExtractor,
+    };
+
+    use super::{LaserScan, Mapper, MapperOptions};
+
+    /// Scan of 360 beams inside the walls of the room [1, 9] x [1, 5], with a 6 m range.
+    fn scan_room(pose: &Pose2D) -> LaserScan {
+        let ranges: Vec<f64> = (0..360)
+            .map(|i| {
+                let direction = Vector2D::from_angle(pose.theta + i as f64 * PI / 180_f64);
+                let mut range = f64::INFINITY;
+                for (position, d, bounds) in [
+                    (pose.position.x, direction.x, (1_f64, 9_f64)),
+                    (pose.position.y, direction.y, (1_f64, 5_f64)),
+                ] {
+                    if d > 0_f64 {
+                        range = range.min((bounds.1 - position) / d);
+                    } else if d < 0_f64 {
+                        range = range.min((bounds.0 - position) / d);
+                    }
+                }
+                return if range < 6_f64 { range } else { f64::INFINITY };
+            })
+            .collect();
+        return LaserScan::new(0_f64, PI / 180_f64, ranges, 6_f64);
+    }
+
+    #[test]
+    fn map_room() {
+        let mut mapper = Mapper::new(100, 60, 0.1, MapperOptions::default());
+        for pose in [
+            Pose2D::from_xytheta(3_f64, 3_f64, 0_f64),
+            Pose2D::from_xytheta(7_f64, 3_f64, 1_f64),
+        ] {
+            for _ in 0..3 {
+                mapper.integrate(&pose, &scan_room(&pose));
+            }
+        }
+
+        let policy = OccupancyThresholdPolicy::default();
+        let map = mapper.map();
+        let state = |x: f64, y: f64| {
+            let row = 59 - (y / 0.1).floor() as usize;
+            return map
+                .classify_cell(row, (x / 0.1).floor() as usize, &policy)
+                .unwrap();
+        };
+        assert_eq!(state(5.05, 1.05), GridMapCellState::Occupied);
+        assert_eq!(state(9.05, 3.05), GridMapCellState::Occupied);
+        assert_eq!(state(5_f64, 3_f64), GridMapCellState::Vacant);
+        assert_eq!(map.get_probability(59, 50), Some(0.5));
+
+        // End to end from scans to topology.
+        let topology_map = TopologyExtractor::extract(&map.to_grid_map(&policy));
+        assert!(topology_map.get_node_count() > 0);
+    }
+}
@@ -1 +1,4 @@
+pub mod connectivity;
 pub mod grid_map;
+pub mod mapper;
+pub mod probabilistic_grid_map;
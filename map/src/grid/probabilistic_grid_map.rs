@@ -0,0 +1,213 @@
+use ndarray::Array2;
+
+use super::grid_map::{GridMap, GridMapCellState};
+
+/// Grid map storing the occupancy of each cell as log-odds.
+/// A log-odds of zero (probability of 0.5) means that nothing is known about the cell.
+pub struct ProbabilisticGridMap {
+    log_odds: Array2<f64>,
+    cell_size: f64,
+}
+
+impl ProbabilisticGridMap {
+    /// Create map where every cell is unknown.
+    pub fn new(width: usize, height: usize, cell_size: f64) -> Self {
+        return Self {
+            log_odds: Array2::zeros((height, width)),
+            cell_size: cell_size,
+        };
+    }
+
+    /// Create map from occupancy probabilities laid out as (row, column).
+    pub fn from_probabilities(probabilities: &Array2<f64>, cell_size: f64) -> Self {
+        return Self {
+            log_odds: probabilities.map(|p| probability_to_log_odds(*p)),
+            cell_size: cell_size,
+        };
+    }
+
+    /// Create map from occupancy log-odds laid out as (row, column).
+    pub fn from_log_odds(log_odds: Array2<f64>, cell_size: f64) -> Self {
+        return Self {
+            log_odds: log_odds,
+            cell_size: cell_size,
+        };
+    }
+
+    pub fn get_log_odds(&self, row: usize, column: usize) -> Option<f64> {
+        return self.log_odds.get((row, column)).copied();
+    }
+
+    pub fn set_log_odds(&mut self, row: usize, column: usize, log_odds: f64) -> bool {
+        return match self.log_odds.get_mut((row, column)) {
+            Some(cell) => {
+                *cell = log_odds;
+                true
+            }
+            None => false,
+        };
+    }
+
+    pub fn get_probability(&self, row: usize, column: usize) -> Option<f64> {
+        return self.get_log_odds(row, column).map(log_odds_to_probability);
+    }
+
+    pub fn set_probability(&mut self, row: usize, column: usize, probability: f64) -> bool {
+        return self.set_log_odds(row, column, probability_to_log_odds(probability));
+    }
+
+    /// Number of cells in horizontal direction.
+    pub fn horizontal_cells(&self) -> usize {
+        return self.log_odds.dim().1;
+    }
+
+    /// Number of cells in vertical direction.
+    pub fn vertical_cells(&self) -> usize {
+        return self.log_odds.dim().0;
+    }
+
+    /// Size of a single cell.
+    pub fn cell_size(&self) -> f64 {
+        return self.cell_size;
+    }
+
+    pub fn log_odds(&self) -> &Array2<f64> {
+        return &self.log_odds;
+    }
+
+    /// Classify a single cell according to the threshold policy.
+    pub fn classify_cell(
+        &self,
+        row: usize,
+        column: usize,
+        policy: &OccupancyThresholdPolicy,
+    ) -> Option<GridMapCellState> {
+        return self
+            .get_log_odds(row, column)
+            .map(|l| policy.classify_log_odds(l));
+    }
+
+    /// Classify every cell according to the threshold policy, laid out as (row, column).
+    pub fn classify_cells(&self, policy: &OccupancyThresholdPolicy) -> Array2<GridMapCellState> {
+        return self.log_odds.map(|l| policy.classify_log_odds(*l));
+    }
+
+    /// Binarize the map into a `GridMap` using the given threshold policy.
+    pub fn to_grid_map(&self, policy: &OccupancyThresholdPolicy) -> GridMap {
+        let mut grid_map = GridMap::with_cell_state(
+            self.horizontal_cells(),
+            self.vertical_cells(),
+            self.cell_size,
+            GridMapCellState::Vacant,
+        );
+
+        for ((r, c), state) in self.classify_cells(policy).indexed_iter() {
+            *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = *state;
+        }
+
+        return grid_map;
+    }
+}
+
+/// Policy for turning occupancy probabilities into binary cell states.
+/// Cells with probability at or above `occupied_threshold` are occupied, those at or below
+/// `free_threshold` are vacant, and everything in between is unknown.
+#[derive(Clone, Copy, Debug)]
+pub struct OccupancyThresholdPolicy {
+    pub occupied_threshold: f64,
+    pub free_threshold: f64,
+    pub unknown_cell_policy: UnknownCellPolicy,
+}
+
+impl OccupancyThresholdPolicy {
+    pub fn new(
+        occupied_threshold: f64,
+        free_threshold: f64,
+        unknown_cell_policy: UnknownCellPolicy,
+    ) -> Self {
+        return Self {
+            occupied_threshold: occupied_threshold,
+            free_threshold: free_threshold,
+            unknown_cell_policy: unknown_cell_policy,
+        };
+    }
+
+    /// Classify a cell by its occupancy probability.
+    pub fn classify(&self, probability: f64) -> GridMapCellState {
+        if probability >= self.occupied_threshold {
+            return GridMapCellState::Occupied;
+        } else if probability <= self.free_threshold {
+            return GridMapCellState::Vacant;
+        }
+
+        return match self.unknown_cell_policy {
+            UnknownCellPolicy::Occupied => GridMapCellState::Occupied,
+            UnknownCellPolicy::Vacant => GridMapCellState::Vacant,
+        };
+    }
+
+    /// Classify a cell by its occupancy log-odds.
+    pub fn classify_log_odds(&self, log_odds: f64) -> GridMapCellState {
+        return self.classify(log_odds_to_probability(log_odds));
+    }
+}
+
+impl Default for OccupancyThresholdPolicy {
+    /// Occupied and free thresholds of 0.65 and 0.196 are the defaults of the ROS map_server,
+    /// so that occupancy images saved by it binarize the same way.
+    fn default() -> Self {
+        return Self::new(0.65_f64, 0.196_f64, UnknownCellPolicy::Occupied);
+    }
+}
+
+/// How cells whose occupancy is neither confidently occupied nor free are treated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnknownCellPolicy {
+    Occupied,
+    Vacant,
+}
+
+pub fn probability_to_log_odds(probability: f64) -> f64 {
+    return (probability / (1_f64 - probability)).ln();
+}
+
+pub fn log_odds_to_probability(log_odds: f64) -> f64 {
+    return 1_f64 - 1_f64 / (1_f64 + log_odds.exp());
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use crate::grid::grid_map::GridMapCellState;
+
+    use super::{OccupancyThresholdPolicy, ProbabilisticGridMap, UnknownCellPolicy};
+
+    #[test]
+    fn binarize_with_unknown_policy() {
+        let probabilities = array![[0.9_f64, 0.5_f64, 0.1_f64]];
+        let map = ProbabilisticGridMap::from_probabilities(&probabilities, 0.05_f64);
+
+        let pessimistic = OccupancyThresholdPolicy::new(0.65, 0.2, UnknownCellPolicy::Occupied);
+        let grid_map = map.to_grid_map(&pessimistic);
+        assert_eq!(
+            *grid_map.get_by_cell(0, 0).unwrap().state(),
+            GridMapCellState::Occupied
+        );
+        assert_eq!(
+            *grid_map.get_by_cell(0, 1).unwrap().state(),
+            GridMapCellState::Occupied
+        );
+        assert_eq!(
+            *grid_map.get_by_cell(0, 2).unwrap().state(),
+            GridMapCellState::Vacant
+        );
+
+        let optimistic = OccupancyThresholdPolicy::new(0.65, 0.2, UnknownCellPolicy::Vacant);
+        let grid_map = map.to_grid_map(&optimistic);
+        assert_eq!(
+            *grid_map.get_by_cell(0, 1).unwrap().state(),
+            GridMapCellState::Vacant
+        );
+    }
+}
@@ -1,24 +1,49 @@
-use image::{Rgb, RgbImage};
+use image::{ImageResult, Rgb, RgbImage};
+use math::geometry::ellipse2d::Ellipse2D;
 
-use crate::map::grid::grid_map::{GridMap, GridMapCellState};
+use crate::grid::grid_map::{GridMap, GridMapCellState};
 
 pub struct GridMapExporter;
 
 impl GridMapExporter {
-    pub fn export(grid_map: &GridMap) {
+    pub fn export(grid_map: &GridMap) -> ImageResult<()> {
+        return GridMapExporter::render(grid_map).save("grid_map.png");
+    }
+
+    /// Export the grid map with ellipses, e.g. localization uncertainty, drawn in red over it.
+    pub fn export_with_ellipses(grid_map: &GridMap, ellipses: &[Ellipse2D]) -> ImageResult<()> {
+        let mut img = GridMapExporter::render(grid_map);
+        let height = grid_map.vertical_cells() as i64;
+        let width = grid_map.horizontal_cells() as i64;
+
+        for ellipse in ellipses {
+            for cell in ellipse.rasterize(grid_map.cell_size()) {
+                // Rasterized cells count y upwards from the bottom row of the map.
+                let (row, column) = (height - 1 - cell.y, cell.x);
+                if row >= 0 && row < height && column >= 0 && column < width {
+                    *img.get_pixel_mut(column as u32, row as u32) = Rgb([255, 0, 0]);
+                }
+            }
+        }
+
+        return img.save("grid_map.png");
+    }
+
+    fn render(grid_map: &GridMap) -> RgbImage {
         let width = grid_map.horizontal_cells();
         let height = grid_map.vertical_cells();
         let mut img: RgbImage = RgbImage::new(width as u32, height as u32);
-        
+
         for r in 0..height {
             for c in 0..width {
-                *img.get_pixel_mut(c as u32, r as u32) = match grid_map.get_by_cell(r, c).unwrap().state() {
-                    GridMapCellState::Occupied => Rgb([0, 0, 0]),
-                    GridMapCellState::Vacant => Rgb([255, 255, 255]),
-                };
+                *img.get_pixel_mut(c as u32, r as u32) =
+                    match grid_map.get_by_cell(r, c).unwrap().state() {
+                        GridMapCellState::Occupied => Rgb([0, 0, 0]),
+                        GridMapCellState::Vacant => Rgb([255, 255, 255]),
+                    };
             }
         }
 
-        img.save("grid_map.png");
+        return img;
     }
 }
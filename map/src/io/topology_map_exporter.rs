@@ -1,10 +1,12 @@
-use image::{ImageBuffer, Rgb, RgbImage};
-
-use crate::{
+use core::{
     graph::graph::Graph,
-    map::topology::{topology_edge::TopologyEdge, topology_node::TopologyNode},
+    numerics::{vector2d::Vector2D, vector2i::Vector2I},
 };
 
+use image::{ImageResult, Rgb, RgbImage};
+
+use crate::topology::{topology_edge::TopologyEdge, topology_node::TopologyNode};
+
 type TopologyMap = Graph<TopologyNode, TopologyEdge>;
 
 pub struct TopologyMapExporter;
@@ -16,14 +18,14 @@ impl TopologyMapExporter {
         pixel_size: f64,
         margin_px: usize,
         draw_waypoints: bool,
-    ) {
+    ) -> ImageResult<()> {
         let roi = TopologyMapExporter::get_roi(topology_map);
         let ((x1, y1), (x2, y2)) = roi;
         let image_width = ((x2 - x1) / pixel_size).ceil() as usize + 2 * margin_px;
         let image_height = ((y1 - y2) / pixel_size).ceil() as usize + 2 * margin_px;
         let mut img = RgbImage::new(image_width as u32, image_height as u32);
 
-        for (edge_id, edge) in topology_map.get_edges().iter() {
+        for (_, edge) in topology_map.get_edges().iter() {
             let node1 = topology_map.get_node_by_id(&edge.node1());
             let node2 = topology_map.get_node_by_id(&edge.node2());
 
@@ -49,16 +51,16 @@ impl TopologyMapExporter {
             }
         }
 
-        img.save(file_name);
+        return img.save(file_name);
     }
 
     /// Get the RoI (Region of Interest).
     /// Return value is (x, y) pairs of top-left and down-right.
     fn get_roi(topology_map: &TopologyMap) -> ((f64, f64), (f64, f64)) {
-        let mut top: f64 = std::f64::NEG_INFINITY;
-        let mut bottom: f64 = std::f64::INFINITY;
-        let mut left: f64 = std::f64::INFINITY;
-        let mut right: f64 = std::f64::NEG_INFINITY;
+        let mut top: f64 = f64::NEG_INFINITY;
+        let mut bottom: f64 = f64::INFINITY;
+        let mut left: f64 = f64::INFINITY;
+        let mut right: f64 = f64::NEG_INFINITY;
 
         for (_, node) in topology_map.get_nodes().iter() {
             let pos = node.node_info().position;
@@ -68,7 +70,7 @@ impl TopologyMapExporter {
             right = f64::max(right, pos.x);
         }
 
-        for (_, edge) in topology_map.get_edges() {
+        for edge in topology_map.get_edges().values() {
             for waypoint in edge.edge_info().get_waypoints() {
                 top = f64::max(top, waypoint.y);
                 bottom = f64::min(bottom, waypoint.y);
@@ -137,9 +139,8 @@ impl TopologyMapExporter {
         for point in waypoints.iter() {
             let _point = (point - bottom_left) / pixel_size;
             let pixel: Vector2I = Vector2I::from_xy(_point.x as i64, _point.y as i64) + margin_vec;
-            match img.get_pixel_mut_checked(pixel.x as u32, pixel.y as u32) {
-                Some(px) => *px = Rgb([255, 255, 255]),
-                None => {}
+            if let Some(px) = img.get_pixel_mut_checked(pixel.x as u32, pixel.y as u32) {
+                *px = Rgb([255, 255, 255])
             };
         }
 
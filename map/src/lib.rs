@@ -0,0 +1,4 @@
+pub mod algorithm;
+pub mod grid;
+pub mod io;
+pub mod topology;
@@ -1,3 +1,7 @@
 pub mod topology_edge;
+pub mod topology_edge_index;
+pub mod topology_editing;
 pub mod topology_generation;
 pub mod topology_node;
+pub mod topology_planning;
+pub mod topology_validation;
@@ -1,32 +1,58 @@
-use crate::math::numerics::{vector::Vector, vector2d::Vector2D};
+use core::{
+    graph::edge_weight::EdgeWeight,
+    numerics::{vector2d::Vector2D, vector2f::Vector2F},
+};
 
+use math::geometry::polyline2d::Polyline2D;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TopologyEdge {
-    waypoints: Vec<Vector2D>,
+    waypoints: Polyline2D,
     length: f64,
 }
 
 impl TopologyEdge {
     pub fn from_waypoints(waypoints: Vec<Vector2D>) -> Self {
-        let mut length = 0_f64;
+        return TopologyEdge::from_polyline(Polyline2D::new(waypoints));
+    }
+
+    /// Like `from_waypoints`, widening each waypoint to `Vector2D`.
+    pub fn from_waypoints_f32(waypoints: Vec<Vector2F>) -> Self {
+        return TopologyEdge::from_waypoints(waypoints.into_iter().map(Vector2D::from).collect());
+    }
 
-        for i in 1..waypoints.len() {
-            let p1 = waypoints.get(i - 1).unwrap().clone();
-            let p2 = waypoints.get(i).unwrap().clone();
-            let dist = (p2 - p1).magnitude();
-            length += dist;
-        }
+    pub fn from_polyline(polyline: Polyline2D) -> Self {
+        let length = polyline.length();
 
         return Self {
-            waypoints: waypoints,
+            waypoints: polyline,
             length: length,
         };
     }
 
-    pub fn get_waypoints(&self) -> &Vec<(Vector2D)> {
+    pub fn get_waypoints(&self) -> &Vec<Vector2D> {
+        return &self.waypoints.points;
+    }
+
+    /// Waypoints as polyline, for arc length queries along the edge.
+    pub fn get_polyline(&self) -> &Polyline2D {
         return &self.waypoints;
     }
 
+    /// Replace the waypoints, updating the length accordingly.
+    pub fn set_waypoints(&mut self, waypoints: Vec<Vector2D>) {
+        self.waypoints = Polyline2D::new(waypoints);
+        self.length = self.waypoints.length();
+    }
+
     pub fn get_length(&self) -> f64 {
         return self.length;
     }
 }
+
+impl EdgeWeight for TopologyEdge {
+    /// Length of the edge along its waypoints.
+    fn weight(&self) -> f64 {
+        return self.length;
+    }
+}
@@ -0,0 +1,201 @@
+use core::{
+    graph::{edge::EdgeId, graph::Graph},
+    numerics::{vector::Vector, vector2d::Vector2D},
+};
+
+use math::geometry::{aabb2d::AABB2D, line_segment2d::LineSegment2D, segment_r_tree::SegmentRTree};
+
+use crate::topology::{topology_edge::TopologyEdge, topology_node::TopologyNode};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+/// Point of a topology edge closest to a query position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeProjection {
+    pub edge_id: EdgeId,
+    pub point: Vector2D,
+
+    /// Arc length of point along the waypoints of the edge.
+    pub arc_length: f64,
+    pub distance: f64,
+}
+
+/// Segment of an edge and the arc length of the edge at its start.
+#[derive(Clone, Copy, Debug)]
+struct EdgeSegment {
+    edge_id: EdgeId,
+    arc_length: f64,
+}
+
+/// Spatial index of the edges of a topology map, e.g. to find the edge a robot is on.
+/// Edges without waypoints are indexed as the straight line between their nodes. The index
+/// does not follow changes of the map, so rebuild it after editing.
+pub struct TopologyEdgeIndex {
+    tree: SegmentRTree<EdgeSegment>,
+}
+
+impl TopologyEdgeIndex {
+    pub fn new(topology_map: &TopologyMap) -> Self {
+        let mut edge_ids: Vec<EdgeId> = topology_map.get_edges().keys().copied().collect();
+        edge_ids.sort();
+
+        let mut entries: Vec<(LineSegment2D, EdgeSegment)> = Vec::new();
+        for edge_id in edge_ids {
+            let edge = topology_map.get_edge_by_id(&edge_id).unwrap();
+            let mut waypoints = edge.edge_info().get_waypoints().clone();
+            if waypoints.is_empty() {
+                waypoints = [edge.node1(), edge.node2()]
+                    .iter()
+                    .map(|id| {
+                        topology_map
+                            .get_node_by_id(id)
+                            .unwrap()
+                            .node_info()
+                            .position
+                    })
+                    .collect();
+            }
+            if waypoints.len() == 1 {
+                waypoints.push(waypoints[0]);
+            }
+
+            let mut arc_length = 0_f64;
+            for pair in waypoints.windows(2) {
+                let segment = LineSegment2D::new(pair[0], pair[1]);
+                entries.push((
+                    segment,
+                    EdgeSegment {
+                        edge_id: edge_id,
+                        arc_length: arc_length,
+                    },
+                ));
+                arc_length += segment.length();
+            }
+        }
+
+        return Self {
+            tree: SegmentRTree::new(entries),
+        };
+    }
+
+    /// Edge closest to position. Ties go to the edge with the lowest ID.
+    /// Returns `None` if the map has no edges.
+    pub fn nearest_edge(&self, position: &Vector2D) -> Option<EdgeProjection> {
+        let (segment, edge_segment) = self.tree.nearest(position)?;
+        return Some(TopologyEdgeIndex::project(segment, edge_segment, position));
+    }
+
+    /// Edges passing within distance of position, each with its closest point, from the
+    /// closest edge.
+    pub fn edges_within(&self, position: &Vector2D, distance: f64) -> Vec<EdgeProjection> {
+        let mut ret: Vec<EdgeProjection> = Vec::new();
+        for (segment, edge_segment) in self.tree.within_distance(position, distance) {
+            if ret.iter().all(|p| p.edge_id != edge_segment.edge_id) {
+                ret.push(TopologyEdgeIndex::project(segment, edge_segment, position));
+            }
+        }
+
+        return ret;
+    }
+
+    /// Edges with a segment whose bounding box intersects region, sorted by ID.
+    pub fn edges_in(&self, region: &AABB2D) -> Vec<EdgeId> {
+        let mut ret: Vec<EdgeId> = self
+            .tree
+            .query(region)
+            .into_iter()
+            .map(|(_, edge_segment)| edge_segment.edge_id)
+            .collect();
+        ret.dedup();
+        return ret;
+    }
+
+    fn project(
+        segment: &LineSegment2D,
+        edge_segment: &EdgeSegment,
+        position: &Vector2D,
+    ) -> EdgeProjection {
+        let point = segment.closest_point(position);
+        return EdgeProjection {
+            edge_id: edge_segment.edge_id,
+            point: point,
+            arc_length: edge_segment.arc_length + (point - segment.start).magnitude(),
+            distance: (point - position).magnitude(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use math::geometry::aabb2d::AABB2D;
+
+    use crate::topology::{
+        topology_edge::TopologyEdge,
+        topology_node::{TopologyNode, TopologyNodeType},
+    };
+
+    use super::{TopologyEdgeIndex, TopologyMap};
+
+    #[test]
+    fn nearest_edge() {
+        // L-shaped corridor from (0, 0) over (10, 0) to (10, 10), and a spur without waypoints.
+        let mut topology_map = TopologyMap::new(false, false);
+        let a = topology_map.add_node(TopologyNode::new(
+            TopologyNodeType::Waypoint,
+            Vector2D::from_xy(0_f64, 0_f64),
+        ));
+        let b = topology_map.add_node(TopologyNode::new(
+            TopologyNodeType::Waypoint,
+            Vector2D::from_xy(10_f64, 10_f64),
+        ));
+        let c = topology_map.add_node(TopologyNode::new(
+            TopologyNodeType::Waypoint,
+            Vector2D::from_xy(0_f64, 6_f64),
+        ));
+        let corridor = topology_map
+            .add_edge(
+                a,
+                b,
+                TopologyEdge::from_waypoints(vec![
+                    Vector2D::from_xy(0_f64, 0_f64),
+                    Vector2D::from_xy(10_f64, 0_f64),
+                    Vector2D::from_xy(10_f64, 10_f64),
+                ]),
+            )
+            .unwrap();
+        let spur = topology_map
+            .add_edge(a, c, TopologyEdge::from_waypoints(Vec::new()))
+            .unwrap();
+
+        let index = TopologyEdgeIndex::new(&topology_map);
+        let projection = index
+            .nearest_edge(&Vector2D::from_xy(9_f64, 4_f64))
+            .unwrap();
+        assert_eq!(projection.edge_id, corridor);
+        assert_eq!(projection.point, Vector2D::from_xy(10_f64, 4_f64));
+        assert_eq!(projection.arc_length, 14_f64);
+        assert_eq!(projection.distance, 1_f64);
+
+        assert_eq!(
+            index
+                .nearest_edge(&Vector2D::from_xy(-1_f64, 5_f64))
+                .unwrap()
+                .edge_id,
+            spur
+        );
+        let within = index.edges_within(&Vector2D::from_xy(1_f64, 1_f64), 2_f64);
+        assert_eq!(
+            within.iter().map(|p| p.edge_id).collect::<Vec<_>>(),
+            vec![corridor, spur]
+        );
+        assert_eq!(
+            index.edges_in(&AABB2D::new(
+                Vector2D::from_xy(9_f64, 5_f64),
+                Vector2D::from_xy(11_f64, 6_f64)
+            )),
+            vec![corridor]
+        );
+    }
+}
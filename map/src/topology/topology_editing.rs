@@ -0,0 +1,351 @@
+use core::{
+    graph::{edge::EdgeId, graph::Graph, graph_error::GraphError, node::NodeId},
+    numerics::vector2d::Vector2D,
+};
+
+use crate::topology::{
+    topology_edge::TopologyEdge,
+    topology_node::{TopologyNode, TopologyNodeType},
+};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TopologyEditError {
+    NoSuchNode,
+    NoSuchEdge,
+    InvalidWaypointIndex,
+
+    /// Edge would violate the options of the topology map, e.g. run parallel to another edge.
+    EdgeNotAllowed,
+}
+
+impl From<GraphError> for TopologyEditError {
+    fn from(value: GraphError) -> Self {
+        return match value {
+            GraphError::NoSuchEdge => TopologyEditError::NoSuchEdge,
+            GraphError::SelfLoopNotAllowed { .. } | GraphError::ParallelEdgeNotAllowed { .. } => {
+                TopologyEditError::EdgeNotAllowed
+            }
+            _ => TopologyEditError::NoSuchNode,
+        };
+    }
+}
+
+/// Editing operations which keep waypoint lists and node types consistent with the structure.
+/// Node types are re-derived from the degree (isolated node → island, degree 1 → endpoint,
+/// degree 2 → waypoint, higher → intersection) whenever an edit changes the degree of a node.
+/// Connector, narrow passage, boundary and custom nodes keep their type.
+pub trait TopologyEditing {
+    /// Move node, dragging the matching end of every connected edge along.
+    fn move_node(&mut self, node_id: NodeId, position: Vector2D) -> Result<(), TopologyEditError>;
+
+    /// Split edge at one of its inner waypoints, returning the ID of the inserted node.
+    /// The topology map is left unchanged if the two halves would violate its options.
+    fn split_edge(
+        &mut self,
+        edge_id: EdgeId,
+        waypoint_index: usize,
+    ) -> Result<NodeId, TopologyEditError>;
+
+    fn delete_edge(&mut self, edge_id: EdgeId) -> Result<(), TopologyEditError>;
+
+    /// Insert an isolated node, returning its ID.
+    fn insert_node(&mut self, position: Vector2D) -> NodeId;
+
+    /// Insert edge between two nodes, returning its ID.
+    /// Node positions are added to both ends of the waypoints unless they are already there.
+    fn insert_edge(
+        &mut self,
+        node1_id: NodeId,
+        node2_id: NodeId,
+        waypoints: Vec<Vector2D>,
+    ) -> Result<EdgeId, TopologyEditError>;
+}
+
+impl TopologyEditing for TopologyMap {
+    fn move_node(&mut self, node_id: NodeId, position: Vector2D) -> Result<(), TopologyEditError> {
+        let node = match self.get_node_by_id_mut(&node_id) {
+            Some(n) => n,
+            None => return Err(TopologyEditError::NoSuchNode),
+        };
+        node.node_info_mut().position = position;
+
+        let mut edge_ids: Vec<EdgeId> = node.connected_edges().keys().copied().collect();
+        edge_ids.sort();
+
+        for edge_id in edge_ids {
+            let edge = self.get_edge_by_id_mut(&edge_id).unwrap();
+            let (node1, node2) = (edge.node1(), edge.node2());
+            let mut waypoints = edge.edge_info().get_waypoints().clone();
+
+            if waypoints.is_empty() {
+                continue;
+            }
+
+            if node1 == node_id {
+                *waypoints.first_mut().unwrap() = position;
+            }
+
+            if node2 == node_id {
+                *waypoints.last_mut().unwrap() = position;
+            }
+
+            edge.edge_info_mut().set_waypoints(waypoints);
+        }
+
+        return Ok(());
+    }
+
+    fn split_edge(
+        &mut self,
+        edge_id: EdgeId,
+        waypoint_index: usize,
+    ) -> Result<NodeId, TopologyEditError> {
+        let edge = match self.get_edge_by_id(&edge_id) {
+            Some(e) => e,
+            None => return Err(TopologyEditError::NoSuchEdge),
+        };
+        let waypoints = edge.edge_info().get_waypoints().clone();
+
+        if waypoint_index == 0 || waypoint_index + 1 >= waypoints.len() {
+            return Err(TopologyEditError::InvalidWaypointIndex);
+        }
+
+        let (node1, node2) = (edge.node1(), edge.node2());
+        let (can_move_forward, can_move_backward) =
+            (edge.can_move_forward(), edge.can_move_backward());
+        let floor = self.get_node_by_id(&node1).unwrap().node_info().floor;
+
+        // Both halves of a split self-loop connect the same two nodes, and only run in
+        // opposite directions in a directed graph.
+        let options = self.options();
+        if node1 == node2 && !options.allow_parallel_edges && !options.directed {
+            return Err(TopologyEditError::EdgeNotAllowed);
+        }
+
+        self.remove_edge(&edge_id)?;
+
+        let new_node_id = self.add_node(TopologyNode {
+            node_type: TopologyNodeType::Waypoint,
+            position: waypoints[waypoint_index],
+            floor: floor,
+            confidence: 1_f64,
+        });
+        self.add_directed_edge(
+            node1,
+            new_node_id,
+            can_move_forward,
+            can_move_backward,
+            TopologyEdge::from_waypoints(waypoints[..=waypoint_index].to_vec()),
+        )?;
+        self.add_directed_edge(
+            new_node_id,
+            node2,
+            can_move_forward,
+            can_move_backward,
+            TopologyEdge::from_waypoints(waypoints[waypoint_index..].to_vec()),
+        )?;
+
+        return Ok(new_node_id);
+    }
+
+    fn delete_edge(&mut self, edge_id: EdgeId) -> Result<(), TopologyEditError> {
+        let (node1, node2) = match self.get_edge_by_id(&edge_id) {
+            Some(e) => (e.node1(), e.node2()),
+            None => return Err(TopologyEditError::NoSuchEdge),
+        };
+
+        self.remove_edge(&edge_id)?;
+        refresh_node_type(self, node1);
+        refresh_node_type(self, node2);
+        return Ok(());
+    }
+
+    fn insert_node(&mut self, position: Vector2D) -> NodeId {
+        return self.add_node(TopologyNode::new(TopologyNodeType::Island, position));
+    }
+
+    fn insert_edge(
+        &mut self,
+        node1_id: NodeId,
+        node2_id: NodeId,
+        waypoints: Vec<Vector2D>,
+    ) -> Result<EdgeId, TopologyEditError> {
+        let p1 = match self.get_node_by_id(&node1_id) {
+            Some(n) => n.node_info().position,
+            None => return Err(TopologyEditError::NoSuchNode),
+        };
+        let p2 = match self.get_node_by_id(&node2_id) {
+            Some(n) => n.node_info().position,
+            None => return Err(TopologyEditError::NoSuchNode),
+        };
+
+        let mut _waypoints: Vec<Vector2D> = Vec::with_capacity(waypoints.len() + 2);
+        if waypoints.first() != Some(&p1) {
+            _waypoints.push(p1);
+        }
+        _waypoints.extend(waypoints);
+        if _waypoints.last() != Some(&p2) {
+            _waypoints.push(p2);
+        }
+
+        let edge_id =
+            self.add_edge(node1_id, node2_id, TopologyEdge::from_waypoints(_waypoints))?;
+        refresh_node_type(self, node1_id);
+        refresh_node_type(self, node2_id);
+        return Ok(edge_id);
+    }
+}
+
+/// Re-derive the type of a node from its degree.
+fn refresh_node_type(topology_map: &mut TopologyMap, node_id: NodeId) {
+    let node = match topology_map.get_node_by_id_mut(&node_id) {
+        Some(n) => n,
+        None => return,
+    };
+
+    let node_type = match node.degree() {
+        0 => TopologyNodeType::Island,
+        1 => TopologyNodeType::Endpoint,
+        2 => TopologyNodeType::Waypoint,
+        _ => TopologyNodeType::Intersection,
+    };
+
+    match node.node_info().node_type {
+        TopologyNodeType::Connector(_)
+        | TopologyNodeType::NarrowPassage
+        | TopologyNodeType::Boundary
+        | TopologyNodeType::Custom(_) => {}
+        _ => node.node_info_mut().node_type = node_type,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{graph::graph_options::GraphOptions, numerics::vector2d::Vector2D};
+
+    use crate::topology::topology_node::TopologyNodeType;
+
+    use super::{TopologyEditError, TopologyEditing, TopologyMap};
+
+    #[test]
+    fn edit_keeps_waypoints_and_types_consistent() {
+        let mut topology_map = TopologyMap::new(false, false);
+        let n1 = topology_map.insert_node(Vector2D::from_xy(0_f64, 0_f64));
+        let n2 = topology_map.insert_node(Vector2D::from_xy(4_f64, 0_f64));
+        let e1 = topology_map
+            .insert_edge(
+                n1,
+                n2,
+                vec![
+                    Vector2D::from_xy(1_f64, 0_f64),
+                    Vector2D::from_xy(2_f64, 0_f64),
+                ],
+            )
+            .unwrap();
+        assert_eq!(
+            topology_map
+                .get_edge_by_id(&e1)
+                .unwrap()
+                .edge_info()
+                .get_waypoints()
+                .len(),
+            4
+        );
+
+        topology_map
+            .move_node(n2, Vector2D::from_xy(3_f64, 0_f64))
+            .unwrap();
+        let edge = topology_map.get_edge_by_id(&e1).unwrap();
+        assert_eq!(edge.edge_info().get_length(), 3_f64);
+
+        assert_eq!(
+            topology_map.split_edge(e1, 0),
+            Err(TopologyEditError::InvalidWaypointIndex)
+        );
+        let n3 = topology_map.split_edge(e1, 2).unwrap();
+        assert_eq!(topology_map.get_edge_count(), 2);
+        assert_eq!(topology_map.get_node_by_id(&n3).unwrap().degree(), 2);
+
+        let e2 = *topology_map
+            .get_node_by_id(&n1)
+            .unwrap()
+            .connected_edges()
+            .keys()
+            .next()
+            .unwrap();
+        topology_map.delete_edge(e2).unwrap();
+        assert_eq!(
+            topology_map
+                .get_node_by_id(&n1)
+                .unwrap()
+                .node_info()
+                .node_type,
+            TopologyNodeType::Island
+        );
+        assert_eq!(
+            topology_map
+                .get_node_by_id(&n3)
+                .unwrap()
+                .node_info()
+                .node_type,
+            TopologyNodeType::Endpoint
+        );
+    }
+
+    #[test]
+    fn failed_split_leaves_map_unchanged() {
+        let mut topology_map = TopologyMap::with_options(GraphOptions {
+            allow_self_loops: true,
+            ..GraphOptions::simple()
+        });
+        let n1 = topology_map.insert_node(Vector2D::from_xy(0_f64, 0_f64));
+        let e1 = topology_map
+            .insert_edge(
+                n1,
+                n1,
+                vec![
+                    Vector2D::from_xy(0_f64, 0_f64),
+                    Vector2D::from_xy(1_f64, 0_f64),
+                    Vector2D::from_xy(1_f64, 1_f64),
+                    Vector2D::from_xy(0_f64, 0_f64),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            topology_map.split_edge(e1, 1),
+            Err(TopologyEditError::EdgeNotAllowed)
+        );
+        assert_eq!(topology_map.get_node_count(), 1);
+        assert_eq!(topology_map.get_edge_count(), 1);
+        assert_eq!(
+            topology_map
+                .get_edge_by_id(&e1)
+                .unwrap()
+                .edge_info()
+                .get_waypoints()
+                .len(),
+            4
+        );
+
+        let mut directed_map = TopologyMap::with_options(GraphOptions {
+            allow_self_loops: true,
+            ..GraphOptions::simple().directed(true)
+        });
+        let n1 = directed_map.insert_node(Vector2D::from_xy(0_f64, 0_f64));
+        let e1 = directed_map
+            .insert_edge(
+                n1,
+                n1,
+                vec![
+                    Vector2D::from_xy(1_f64, 0_f64),
+                    Vector2D::from_xy(1_f64, 1_f64),
+                ],
+            )
+            .unwrap();
+        assert!(directed_map.split_edge(e1, 1).is_ok());
+        assert_eq!(directed_map.get_edge_count(), 2);
+    }
+}
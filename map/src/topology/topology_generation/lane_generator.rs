@@ -0,0 +1,234 @@
+use core::{
+    graph::{
+        edge::{Edge, EdgeId},
+        graph::Graph,
+        graph_error::GraphError,
+        graph_options::GraphOptions,
+        node::{Node, NodeId},
+    },
+    numerics::{vector::Vector, vector2d::Vector2D},
+};
+use std::collections::HashMap;
+
+use crate::{
+    grid::grid_map::{GridMap, GridMapCellState},
+    topology::{topology_edge::TopologyEdge, topology_node::TopologyNode},
+};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+/// IDs of the forward and backward lanes generated for each edge of the topology map.
+pub type EdgeLanes = HashMap<EdgeId, (EdgeId, EdgeId)>;
+
+/// Generates a pair of one-way travel lanes for every edge, offset laterally from the skeleton.
+/// Traffic keeps to the right: the lane running from `node1` to `node2` lies on the right side of
+/// the edge, and the opposite lane on its left. Offsets are clipped so that lanes stay clear of
+/// occupied cells. Positions are assumed to be planar coordinates of the grid map.
+pub struct LaneGenerator {
+    lane_offset: f64,
+}
+
+impl LaneGenerator {
+    pub fn new(lane_offset: f64) -> Self {
+        return Self {
+            lane_offset: lane_offset,
+        };
+    }
+
+    /// Generate lane map.
+    /// Returns the lane map and, for each original edge ID, the IDs of its forward
+    /// (`node1` to `node2`) and backward lanes. The lane map keeps the options of the topology
+    /// map, e.g. self-loops left by extraction, but always allows the parallel lanes.
+    pub fn generate(
+        &self,
+        topology_map: &TopologyMap,
+        grid_map: &GridMap,
+    ) -> Result<(TopologyMap, EdgeLanes), GraphError> {
+        let mut ret: TopologyMap = TopologyMap::with_options(GraphOptions {
+            allow_parallel_edges: true,
+            ..topology_map.options()
+        });
+        ret.reserve(
+            topology_map.get_node_count(),
+            2 * topology_map.get_edge_count(),
+        );
+        let mut old_to_new_id_map: HashMap<NodeId, NodeId> = HashMap::new();
+        let mut lanes: EdgeLanes = HashMap::new();
+
+        let mut nodes: Vec<&Node<TopologyNode>> = topology_map.get_nodes().values().collect();
+        let mut edges: Vec<&Edge<TopologyEdge>> = topology_map.get_edges().values().collect();
+        nodes.sort_by(|n1, n2| NodeId::cmp(&n1.get_id(), &n2.get_id()));
+        edges.sort_by(|e1, e2| EdgeId::cmp(&e1.get_id(), &e2.get_id()));
+
+        for node in nodes {
+            let new_node_id = ret.add_node(node.node_info().clone());
+            old_to_new_id_map.insert(node.get_id(), new_node_id);
+        }
+
+        for edge in edges {
+            let node1 = *old_to_new_id_map.get(&edge.node1()).unwrap();
+            let node2 = *old_to_new_id_map.get(&edge.node2()).unwrap();
+            let pos1 = ret.get_node_by_id(&node1).unwrap().node_info().position;
+            let pos2 = ret.get_node_by_id(&node2).unwrap().node_info().position;
+
+            let mut centerline = edge.edge_info().get_waypoints().clone();
+            if centerline.is_empty() {
+                centerline = vec![pos1, pos2];
+            }
+
+            let mut forward_lane = vec![pos1];
+            forward_lane.extend(self.offset_waypoints(&centerline, grid_map, 1_f64));
+            forward_lane.push(pos2);
+
+            let mut backward_lane = vec![pos1];
+            backward_lane.extend(self.offset_waypoints(&centerline, grid_map, -1_f64));
+            backward_lane.push(pos2);
+
+            let forward_id = ret.add_directed_edge(
+                node1,
+                node2,
+                true,
+                false,
+                TopologyEdge::from_waypoints(forward_lane),
+            )?;
+            let backward_id = ret.add_directed_edge(
+                node1,
+                node2,
+                false,
+                true,
+                TopologyEdge::from_waypoints(backward_lane),
+            )?;
+            lanes.insert(edge.get_id(), (forward_id, backward_id));
+        }
+
+        return Ok((ret, lanes));
+    }
+
+    /// Offset waypoints to the right (`side` = 1) or left (`side` = -1) of the travel direction.
+    fn offset_waypoints(
+        &self,
+        waypoints: &Vec<Vector2D>,
+        grid_map: &GridMap,
+        side: f64,
+    ) -> Vec<Vector2D> {
+        let mut ret: Vec<Vector2D> = Vec::with_capacity(waypoints.len());
+
+        for i in 0..waypoints.len() {
+            let prev = waypoints[i.saturating_sub(1)];
+            let next = waypoints[usize::min(i + 1, waypoints.len() - 1)];
+            let direction = next - prev;
+
+            if direction.magnitude() == 0_f64 {
+                ret.push(waypoints[i]);
+                continue;
+            }
+
+            let unit = direction.unit_vector();
+            let normal = Vector2D::from_xy(unit.y, -unit.x) * side;
+            let offset = self.clip_offset(grid_map, &waypoints[i], &normal);
+            ret.push(waypoints[i] + normal * offset);
+        }
+
+        return ret;
+    }
+
+    /// Find the largest offset up to the lane offset along which the cells are vacant.
+    fn clip_offset(&self, grid_map: &GridMap, origin: &Vector2D, normal: &Vector2D) -> f64 {
+        let step = grid_map.cell_size() / 2_f64;
+        let mut offset = 0_f64;
+
+        while offset < self.lane_offset {
+            let next_offset = f64::min(offset + step, self.lane_offset);
+            let point = origin + normal * next_offset;
+
+            match grid_map.get_by_coordinate(point.x, point.y) {
+                Some(cell) if *cell.state() == GridMapCellState::Vacant => {}
+                _ => break,
+            };
+
+            offset = next_offset;
+        }
+
+        return offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::{
+        grid::grid_map::{GridMap, GridMapCellState},
+        topology::{
+            topology_edge::TopologyEdge,
+            topology_node::{TopologyNode, TopologyNodeType},
+        },
+    };
+
+    use super::{LaneGenerator, TopologyMap};
+
+    #[test]
+    fn lanes_are_offset_and_clipped() {
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        for x in 0..10 {
+            *grid_map
+                .get_by_coordinate_mut(x as f64 + 0.5, 6.5)
+                .unwrap()
+                .state_mut() = GridMapCellState::Occupied;
+        }
+
+        let mut topology_map = TopologyMap::new(false, false);
+        let p1 = Vector2D::from_xy(1_f64, 5_f64);
+        let p2 = Vector2D::from_xy(9_f64, 5_f64);
+        let n1 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p1));
+        let n2 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p2));
+        let e1 = topology_map
+            .add_edge(n1, n2, TopologyEdge::from_waypoints(vec![p1, p2]))
+            .unwrap();
+
+        let (lane_map, lanes) = LaneGenerator::new(2_f64)
+            .generate(&topology_map, &grid_map)
+            .unwrap();
+        assert_eq!(lane_map.get_edge_count(), 2);
+
+        let (forward_id, backward_id) = *lanes.get(&e1).unwrap();
+        let forward = lane_map.get_edge_by_id(&forward_id).unwrap();
+        let backward = lane_map.get_edge_by_id(&backward_id).unwrap();
+        assert!(forward.can_move_forward() && !forward.can_move_backward());
+        assert_eq!(forward.edge_info().get_waypoints()[1].y, 3_f64);
+        assert_eq!(backward.edge_info().get_waypoints()[1].y, 5.5);
+    }
+
+    #[test]
+    fn self_loops_of_extracted_maps_are_kept() {
+        let grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+
+        // Extraction leaves a self-loop when both sides of a loop meet at the same node.
+        let mut topology_map = TopologyMap::new(true, true);
+        let p1 = Vector2D::from_xy(5_f64, 2_f64);
+        let n1 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p1));
+        let e1 = topology_map
+            .add_edge(
+                n1,
+                n1,
+                TopologyEdge::from_waypoints(vec![
+                    p1,
+                    Vector2D::from_xy(8_f64, 5_f64),
+                    Vector2D::from_xy(5_f64, 8_f64),
+                    Vector2D::from_xy(2_f64, 5_f64),
+                    p1,
+                ]),
+            )
+            .unwrap();
+
+        let (lane_map, lanes) = LaneGenerator::new(0.5)
+            .generate(&topology_map, &grid_map)
+            .unwrap();
+        let (forward_id, backward_id) = *lanes.get(&e1).unwrap();
+        assert_eq!(lane_map.get_edge_count(), 2);
+        for lane_id in [forward_id, backward_id] {
+            let lane = lane_map.get_edge_by_id(&lane_id).unwrap();
+            assert_eq!(lane.node1(), lane.node2());
+        }
+    }
+}
@@ -1,3 +1,8 @@
+pub mod lane_generator;
+pub mod multi_floor_topology_builder;
+pub mod narrow_passage_detector;
+pub mod node_classifier;
+pub mod region_of_interest;
 pub mod topology_coordinate_converter;
 pub mod topology_extractor;
 pub mod topology_vectorizer;
@@ -0,0 +1,259 @@
+use core::{
+    collections::kd_tree::KdTree,
+    graph::{
+        edge::{Edge, EdgeId},
+        graph::Graph,
+        graph_options::GraphOptions,
+        node::{Node, NodeId},
+    },
+    numerics::vector2d::Vector2D,
+};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    grid::grid_map::GridMap,
+    topology::{
+        topology_edge::TopologyEdge,
+        topology_generation::topology_extractor::TopologyExtractor,
+        topology_node::{FloorConnectorType, TopologyNode, TopologyNodeType},
+    },
+};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+/// Declaration of an elevator or staircase stop on a single floor.
+#[derive(Clone, Copy, Debug)]
+pub struct FloorConnector {
+    /// Connectors sharing the same shaft ID are linked between adjacent floors.
+    pub shaft_id: u32,
+    pub floor: i32,
+    pub position: Vector2D,
+    pub connector_type: FloorConnectorType,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultiFloorTopologyError {
+    DuplicateFloor,
+    NoSuchFloor,
+    EmptyFloor,
+}
+
+/// Combines per-floor topology maps into a single multi-level topology map.
+/// Each connector becomes a node linked to the closest node on its floor, and connectors of
+/// the same shaft are linked between consecutive floors.
+pub struct MultiFloorTopologyBuilder {
+    floors: BTreeMap<i32, TopologyMap>,
+    connectors: Vec<FloorConnector>,
+}
+
+impl MultiFloorTopologyBuilder {
+    pub fn new() -> Self {
+        return Self {
+            floors: BTreeMap::new(),
+            connectors: Vec::new(),
+        };
+    }
+
+    pub fn add_floor(
+        &mut self,
+        floor: i32,
+        topology_map: TopologyMap,
+    ) -> Result<(), MultiFloorTopologyError> {
+        if self.floors.contains_key(&floor) {
+            return Err(MultiFloorTopologyError::DuplicateFloor);
+        }
+
+        self.floors.insert(floor, topology_map);
+        return Ok(());
+    }
+
+    /// Extract topology of the floor from grid map and add it.
+    pub fn add_floor_from_grid_map(
+        &mut self,
+        floor: i32,
+        grid_map: &GridMap,
+    ) -> Result<(), MultiFloorTopologyError> {
+        return self.add_floor(floor, TopologyExtractor::extract(grid_map));
+    }
+
+    pub fn add_connector(&mut self, connector: FloorConnector) {
+        self.connectors.push(connector);
+    }
+
+    pub fn build(self) -> Result<TopologyMap, MultiFloorTopologyError> {
+        let mut ret: TopologyMap = TopologyMap::with_options(GraphOptions::multigraph());
+        let mut floor_nodes: HashMap<i32, KdTree<NodeId>> = HashMap::new();
+
+        for connector in self.connectors.iter() {
+            if !self.floors.contains_key(&connector.floor) {
+                return Err(MultiFloorTopologyError::NoSuchFloor);
+            }
+        }
+
+        for (floor, topology_map) in self.floors.iter() {
+            let node_ids = MultiFloorTopologyBuilder::copy_floor(&mut ret, *floor, topology_map);
+            let index = KdTree::from_points(
+                node_ids
+                    .into_iter()
+                    .map(|id| (ret.get_node_by_id(&id).unwrap().node_info().position, id))
+                    .collect(),
+            );
+            floor_nodes.insert(*floor, index);
+        }
+
+        // Add connector nodes and attach them to the closest node on the same floor.
+        let mut shafts: BTreeMap<u32, Vec<(i32, NodeId)>> = BTreeMap::new();
+        for connector in self.connectors.iter() {
+            let (nearest_position, nearest_node_id) = match floor_nodes
+                .get(&connector.floor)
+                .unwrap()
+                .nearest(&connector.position)
+            {
+                Some((position, id)) => (*position, *id),
+                None => return Err(MultiFloorTopologyError::EmptyFloor),
+            };
+
+            let connector_node_id = ret.add_node(TopologyNode {
+                node_type: TopologyNodeType::Connector(connector.connector_type),
+                position: connector.position,
+                floor: connector.floor,
+                confidence: 1_f64,
+            });
+            ret.add_edge(
+                nearest_node_id,
+                connector_node_id,
+                TopologyEdge::from_waypoints(vec![nearest_position, connector.position]),
+            )
+            .expect("Error while attaching connector to floor.");
+
+            shafts
+                .entry(connector.shaft_id)
+                .or_default()
+                .push((connector.floor, connector_node_id));
+        }
+
+        // Link stops of the same shaft between consecutive floors.
+        for (_, stops) in shafts.iter_mut() {
+            stops.sort();
+
+            for i in 1..stops.len() {
+                let (_, lower_node_id) = stops[i - 1];
+                let (_, upper_node_id) = stops[i];
+                let lower_position = ret
+                    .get_node_by_id(&lower_node_id)
+                    .unwrap()
+                    .node_info()
+                    .position;
+                let upper_position = ret
+                    .get_node_by_id(&upper_node_id)
+                    .unwrap()
+                    .node_info()
+                    .position;
+
+                ret.add_edge(
+                    lower_node_id,
+                    upper_node_id,
+                    TopologyEdge::from_waypoints(vec![lower_position, upper_position]),
+                )
+                .expect("Error while linking connectors.");
+            }
+        }
+
+        return Ok(ret);
+    }
+
+    /// Copy all entities of a single floor, returning the new IDs of the copied nodes.
+    fn copy_floor(ret: &mut TopologyMap, floor: i32, topology_map: &TopologyMap) -> Vec<NodeId> {
+        let mut old_to_new_id_map: HashMap<NodeId, NodeId> = HashMap::new();
+        let mut nodes: Vec<&Node<TopologyNode>> = topology_map.get_nodes().values().collect();
+        let mut edges: Vec<&Edge<TopologyEdge>> = topology_map.get_edges().values().collect();
+        nodes.sort_by(|n1, n2| NodeId::cmp(&n1.get_id(), &n2.get_id()));
+        edges.sort_by(|e1, e2| EdgeId::cmp(&e1.get_id(), &e2.get_id()));
+
+        for node in nodes.iter() {
+            let new_node_id = ret.add_node(TopologyNode {
+                node_type: node.node_info().node_type.clone(),
+                position: node.node_info().position,
+                floor: floor,
+                confidence: node.node_info().confidence,
+            });
+            old_to_new_id_map.insert(node.get_id(), new_node_id);
+        }
+
+        for edge in edges {
+            ret.add_directed_edge(
+                *old_to_new_id_map.get(&edge.node1()).unwrap(),
+                *old_to_new_id_map.get(&edge.node2()).unwrap(),
+                edge.can_move_forward(),
+                edge.can_move_backward(),
+                TopologyEdge::from_waypoints(edge.edge_info().get_waypoints().clone()),
+            )
+            .expect("Error while copying edge of floor.");
+        }
+
+        return nodes
+            .iter()
+            .map(|n| *old_to_new_id_map.get(&n.get_id()).unwrap())
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::topology::{
+        topology_edge::TopologyEdge,
+        topology_node::{FloorConnectorType, TopologyNode, TopologyNodeType},
+    };
+
+    use super::{FloorConnector, MultiFloorTopologyBuilder, MultiFloorTopologyError, TopologyMap};
+
+    fn corridor(length: f64) -> TopologyMap {
+        let mut topology_map = TopologyMap::new(false, false);
+        let p1 = Vector2D::from_xy(0_f64, 0_f64);
+        let p2 = Vector2D::from_xy(length, 0_f64);
+        let n1 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p1));
+        let n2 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p2));
+        topology_map
+            .add_edge(n1, n2, TopologyEdge::from_waypoints(vec![p1, p2]))
+            .unwrap();
+        return topology_map;
+    }
+
+    #[test]
+    fn connectors_link_floors() {
+        let mut builder = MultiFloorTopologyBuilder::new();
+        builder.add_floor(0, corridor(10_f64)).unwrap();
+        builder.add_floor(1, corridor(20_f64)).unwrap();
+        assert_eq!(
+            builder.add_floor(1, corridor(5_f64)),
+            Err(MultiFloorTopologyError::DuplicateFloor)
+        );
+
+        for floor in 0..2 {
+            builder.add_connector(FloorConnector {
+                shaft_id: 1,
+                floor: floor,
+                position: Vector2D::from_xy(9_f64, 1_f64),
+                connector_type: FloorConnectorType::Elevator,
+            });
+        }
+
+        let topology_map = builder.build().unwrap();
+        assert_eq!(topology_map.get_node_count(), 6);
+        assert_eq!(topology_map.get_edge_count(), 5);
+
+        let connectors: Vec<_> = topology_map
+            .get_nodes()
+            .values()
+            .filter(|n| {
+                n.node_info().node_type == TopologyNodeType::Connector(FloorConnectorType::Elevator)
+            })
+            .collect();
+        assert_eq!(connectors.len(), 2);
+        for connector in connectors {
+            assert_eq!(connector.degree(), 2);
+        }
+    }
+}
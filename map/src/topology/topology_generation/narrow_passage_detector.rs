@@ -0,0 +1,209 @@
+use core::{
+    graph::{edge::EdgeId, graph::Graph, node::NodeId},
+    numerics::vector2d::Vector2D,
+};
+
+use crate::{
+    grid::grid_map::{GridMap, GridMapCellState},
+    topology::{
+        topology_edge::TopologyEdge,
+        topology_editing::TopologyEditing,
+        topology_node::{TopologyNode, TopologyNodeType},
+    },
+};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+/// Chokepoint node inserted by `NarrowPassageDetector`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NarrowPassage {
+    pub node_id: NodeId,
+
+    /// Distance from the node to the closest occupied (or out-of-map) cell.
+    pub clearance: f64,
+}
+
+/// Detects chokepoints along the edges where the corridor clearance falls below a threshold.
+/// Every run of consecutive inner waypoints below the threshold is marked by splitting the edge
+/// at the narrowest waypoint of the run and inserting a narrow passage node there.
+/// Positions are assumed to be planar coordinates of the grid map.
+pub struct NarrowPassageDetector {
+    clearance_threshold: f64,
+}
+
+impl NarrowPassageDetector {
+    pub fn new(clearance_threshold: f64) -> Self {
+        return Self {
+            clearance_threshold: clearance_threshold,
+        };
+    }
+
+    /// Insert narrow passage nodes into topology map, returning them in order of the edge IDs.
+    pub fn annotate(
+        &self,
+        topology_map: &mut TopologyMap,
+        grid_map: &GridMap,
+    ) -> Vec<NarrowPassage> {
+        let mut ret: Vec<NarrowPassage> = Vec::new();
+        let mut edge_ids: Vec<EdgeId> = topology_map.get_edges().keys().copied().collect();
+        edge_ids.sort();
+
+        for edge_id in edge_ids {
+            let waypoints = topology_map
+                .get_edge_by_id(&edge_id)
+                .unwrap()
+                .edge_info()
+                .get_waypoints()
+                .clone();
+            let mut chokepoints: Vec<(usize, f64)> = Vec::new();
+            let mut narrowest: Option<(usize, f64)> = None;
+
+            for i in 1..waypoints.len().saturating_sub(1) {
+                let clearance = self.compute_clearance(grid_map, &waypoints[i]);
+
+                if clearance < self.clearance_threshold {
+                    if narrowest.is_none() || clearance < narrowest.unwrap().1 {
+                        narrowest = Some((i, clearance));
+                    }
+                } else if let Some(chokepoint) = narrowest.take() {
+                    chokepoints.push(chokepoint);
+                }
+            }
+
+            if let Some(chokepoint) = narrowest {
+                chokepoints.push(chokepoint);
+            }
+
+            // Split from the back, so that the remaining waypoint indices stay valid.
+            let mut _edge_id = edge_id;
+            let mut passages: Vec<NarrowPassage> = Vec::new();
+
+            for (waypoint_index, clearance) in chokepoints.into_iter().rev() {
+                let node_id = topology_map
+                    .split_edge(_edge_id, waypoint_index)
+                    .expect("Error while splitting edge at narrow passage.");
+                let node = topology_map.get_node_by_id_mut(&node_id).unwrap();
+                node.node_info_mut().node_type = TopologyNodeType::NarrowPassage;
+                let connected_edge_ids: Vec<EdgeId> =
+                    node.connected_edges().keys().copied().collect();
+
+                _edge_id = connected_edge_ids
+                    .into_iter()
+                    .find(|id| topology_map.get_edge_by_id(id).unwrap().node2() == node_id)
+                    .unwrap();
+                passages.push(NarrowPassage {
+                    node_id: node_id,
+                    clearance: clearance,
+                });
+            }
+
+            passages.reverse();
+            ret.extend(passages);
+        }
+
+        return ret;
+    }
+
+    /// Distance from point to the closest cell which is not vacant, capped at the threshold.
+    /// Cells outside of the map are considered occupied.
+    fn compute_clearance(&self, grid_map: &GridMap, point: &Vector2D) -> f64 {
+        let cell_size = grid_map.cell_size();
+        let (width, height) = (
+            grid_map.horizontal_cells() as isize,
+            grid_map.vertical_cells() as isize,
+        );
+        let r = self.clearance_threshold;
+        let mut clearance = self.clearance_threshold;
+
+        let (i_min, i_max) = (
+            ((point.x - r) / cell_size).floor() as isize,
+            ((point.x + r) / cell_size).floor() as isize,
+        );
+        let (j_min, j_max) = (
+            ((point.y - r) / cell_size).floor() as isize,
+            ((point.y + r) / cell_size).floor() as isize,
+        );
+
+        for j in j_min..=j_max {
+            for i in i_min..=i_max {
+                let is_occupied = if i < 0 || j < 0 || i >= width || j >= height {
+                    true
+                } else {
+                    let cell = grid_map
+                        .get_by_cell((height - 1 - j) as usize, i as usize)
+                        .unwrap();
+                    *cell.state() != GridMapCellState::Vacant
+                };
+
+                if !is_occupied {
+                    continue;
+                }
+
+                let dx = f64::max(
+                    (point.x - (i as f64 + 0.5) * cell_size).abs() - cell_size / 2_f64,
+                    0_f64,
+                );
+                let dy = f64::max(
+                    (point.y - (j as f64 + 0.5) * cell_size).abs() - cell_size / 2_f64,
+                    0_f64,
+                );
+                clearance = f64::min(clearance, f64::hypot(dx, dy));
+            }
+        }
+
+        return clearance;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::{
+        grid::grid_map::{GridMap, GridMapCellState},
+        topology::{
+            topology_edge::TopologyEdge,
+            topology_node::{TopologyNode, TopologyNodeType},
+        },
+    };
+
+    use super::{NarrowPassage, NarrowPassageDetector, TopologyMap};
+
+    #[test]
+    fn doorway_is_marked() {
+        let mut grid_map = GridMap::with_cell_state(20, 7, 1_f64, GridMapCellState::Occupied);
+        for x in 0..20 {
+            for y in 1..6 {
+                if x == 10 && y != 3 {
+                    continue;
+                }
+
+                *grid_map
+                    .get_by_coordinate_mut(x as f64 + 0.5, y as f64 + 0.5)
+                    .unwrap()
+                    .state_mut() = GridMapCellState::Vacant;
+            }
+        }
+
+        let mut topology_map = TopologyMap::new(false, false);
+        let waypoints: Vec<Vector2D> = (0..20)
+            .map(|x| Vector2D::from_xy(x as f64 + 0.5, 3.5))
+            .collect();
+        let n1 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, waypoints[0]));
+        let n2 =
+            topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, waypoints[19]));
+        topology_map
+            .add_edge(n1, n2, TopologyEdge::from_waypoints(waypoints))
+            .unwrap();
+
+        let passages = NarrowPassageDetector::new(1_f64).annotate(&mut topology_map, &grid_map);
+        assert_eq!(passages.len(), 1);
+        let NarrowPassage { node_id, clearance } = passages[0];
+        assert_eq!(clearance, 0.5);
+        assert_eq!(topology_map.get_edge_count(), 2);
+
+        let node = topology_map.get_node_by_id(&node_id).unwrap();
+        assert_eq!(node.node_info().node_type, TopologyNodeType::NarrowPassage);
+        assert_eq!(node.node_info().position, Vector2D::from_xy(10.5, 3.5));
+    }
+}
@@ -0,0 +1,75 @@
+use ndarray::Array2;
+
+use crate::topology::topology_node::TopologyNodeType;
+
+/// Decides whether a skeleton pixel becomes a topology node, and of which type.
+/// Implement this to add custom node types (doorways, charger locations, ...) during extraction.
+pub trait NodeClassifier {
+    /// Classify the skeleton pixel described by `neighborhood`.
+    /// Returns `None` if the pixel is an ordinary part of an edge.
+    fn classify(&self, neighborhood: &PixelNeighborhood) -> Option<TopologyNodeType>;
+}
+
+/// Local information about a single skeleton pixel handed to a `NodeClassifier`.
+pub struct PixelNeighborhood<'a> {
+    /// Position of the pixel in (x, y).
+    pub position: (usize, usize),
+
+    /// Whether each of the 8 surrounding pixels belongs to the skeleton,
+    /// clockwise starting from the pixel above.
+    pub rim: [bool; 8],
+
+    /// Number of adjacent skeleton pixels minus the number of contiguous pixel pairs on the rim.
+    /// Endpoints score 1 or less, intersections score 3 or more.
+    pub score: i32,
+
+    /// Thinned skeleton, laid out as (y, x).
+    pub skeleton: &'a Array2<bool>,
+
+    /// Traversable cells before thinning, laid out as (y, x).
+    pub occupancy_map: &'a Array2<bool>,
+}
+
+impl PixelNeighborhood<'_> {
+    /// Chebyshev distance from the pixel to the closest cell which is not traversable
+    /// (or lies outside of the map), which approximates the half-width of the corridor.
+    pub fn clearance(&self) -> usize {
+        let (map_height, map_width) = self.occupancy_map.dim();
+        let (x, y) = (self.position.0 as isize, self.position.1 as isize);
+        let mut radius: isize = 1;
+
+        loop {
+            for dy in -radius..=radius {
+                for dx in -radius..=radius {
+                    let (cx, cy) = (x + dx, y + dy);
+
+                    if cx < 0
+                        || cy < 0
+                        || cx >= map_width as isize
+                        || cy >= map_height as isize
+                        || !*self.occupancy_map.get((cy as usize, cx as usize)).unwrap()
+                    {
+                        return radius as usize;
+                    }
+                }
+            }
+
+            radius += 1;
+        }
+    }
+}
+
+/// Default classifier which detects endpoints and intersections from the pixel score.
+pub struct PixelScoreClassifier;
+
+impl NodeClassifier for PixelScoreClassifier {
+    fn classify(&self, neighborhood: &PixelNeighborhood) -> Option<TopologyNodeType> {
+        if neighborhood.score <= 1 {
+            return Some(TopologyNodeType::Endpoint);
+        } else if neighborhood.score >= 3 {
+            return Some(TopologyNodeType::Intersection);
+        }
+
+        return None;
+    }
+}
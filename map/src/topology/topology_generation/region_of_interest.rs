@@ -0,0 +1,127 @@
+use core::numerics::vector2d::Vector2D;
+
+use math::geometry::polygon2d::Polygon2D;
+use ndarray::Array2;
+
+use crate::topology::{
+    topology_generation::node_classifier::{NodeClassifier, PixelNeighborhood},
+    topology_node::TopologyNodeType,
+};
+
+/// Part of the map to restrict topology extraction to, in image coordinates (x, y).
+#[derive(Clone, Debug)]
+pub enum RegionOfInterest {
+    /// Axis-aligned rectangle of cells, starting at the top-left cell (x, y).
+    Rect {
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    },
+
+    /// Cells whose position lies inside the polygon or on its boundary are included.
+    Polygon(Polygon2D),
+
+    /// Mask laid out as (y, x), where included cells are set.
+    Mask(Array2<bool>),
+}
+
+impl RegionOfInterest {
+    /// Rasterize the region into a mask of the given (height, width).
+    pub fn to_mask(&self, dim: (usize, usize)) -> Array2<bool> {
+        return match self {
+            RegionOfInterest::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => Array2::from_shape_fn(dim, |(r, c)| {
+                c >= *x && c < x + width && r >= *y && r < y + height
+            }),
+            RegionOfInterest::Polygon(polygon) => Array2::from_shape_fn(dim, |(r, c)| {
+                polygon.contains(&Vector2D::from_xy(c as f64, r as f64))
+            }),
+            RegionOfInterest::Mask(mask) => {
+                Array2::from_shape_fn(dim, |(r, c)| *mask.get((r, c)).unwrap_or(&false))
+            }
+        };
+    }
+}
+
+/// Turns skeleton endpoints where the corridor continues outside of the region into
+/// boundary nodes, deferring everything else to the inner classifier.
+pub(crate) struct BoundaryClassifier<'a> {
+    pub inner: &'a dyn NodeClassifier,
+
+    /// Region of interest laid out as (y, x).
+    pub mask: &'a Array2<bool>,
+
+    /// Traversable cells of the whole map, laid out as (y, x).
+    pub full_occupancy_map: &'a Array2<bool>,
+}
+
+impl NodeClassifier for BoundaryClassifier<'_> {
+    fn classify(&self, neighborhood: &PixelNeighborhood) -> Option<TopologyNodeType> {
+        let node_type = self.inner.classify(neighborhood);
+
+        if node_type == Some(TopologyNodeType::Endpoint) && self.is_cut_by_region(neighborhood) {
+            return Some(TopologyNodeType::Boundary);
+        }
+
+        return node_type;
+    }
+}
+
+impl BoundaryClassifier<'_> {
+    /// Check whether a traversable cell outside of the region lies within the corridor
+    /// half-width of the endpoint, i.e. the skeleton was cut short by the region border.
+    fn is_cut_by_region(&self, neighborhood: &PixelNeighborhood) -> bool {
+        let (map_height, map_width) = neighborhood.occupancy_map.dim();
+        let (x, y) = (
+            neighborhood.position.0 as isize,
+            neighborhood.position.1 as isize,
+        );
+
+        let radius = neighborhood.clearance() as isize;
+
+        for dy in -(radius + 1)..=(radius + 1) {
+            for dx in -(radius + 1)..=(radius + 1) {
+                let (cx, cy) = (x + dx, y + dy);
+
+                if cx < 0 || cy < 0 || cx >= map_width as isize || cy >= map_height as isize {
+                    continue;
+                }
+
+                let pos = (cy as usize, cx as usize);
+                if !*self.mask.get(pos).unwrap() && *self.full_occupancy_map.get(pos).unwrap() {
+                    return true;
+                }
+            }
+        }
+
+        return false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use math::geometry::polygon2d::Polygon2D;
+
+    use super::RegionOfInterest;
+
+    #[test]
+    fn polygon_mask_includes_boundary() {
+        let region = RegionOfInterest::Polygon(Polygon2D::new(vec![
+            Vector2D::from_xy(1_f64, 1_f64),
+            Vector2D::from_xy(4_f64, 1_f64),
+            Vector2D::from_xy(1_f64, 4_f64),
+        ]));
+
+        let mask = region.to_mask((6, 6));
+        assert!(mask[(1, 1)] && mask[(1, 4)] && mask[(2, 2)] && mask[(2, 3)]);
+        assert!(!mask[(0, 0)] && !mask[(3, 3)] && !mask[(5, 1)]);
+        assert_eq!(mask.iter().filter(|m| **m).count(), 10);
+    }
+}
@@ -1,9 +1,14 @@
-use crate::{
-    graph::{edge::Edge, graph::Graph, node::Node},
-    map::topology::{self, topology_edge::TopologyEdge, topology_node::TopologyNode},
-    math::numerics::vector2d::Vector2D,
+use core::{
+    graph::{
+        edge::{Edge, EdgeId},
+        graph::Graph,
+        node::{Node, NodeId},
+    },
+    numerics::vector2d::Vector2D,
 };
 
+use crate::topology::{topology_edge::TopologyEdge, topology_node::TopologyNode};
+
 type TopologyMap = Graph<TopologyNode, TopologyEdge>;
 
 pub struct TopologyCoordinateConverter {
@@ -20,18 +25,19 @@ impl TopologyCoordinateConverter {
     }
 
     pub fn image_to_planar(&self, topology_map: &TopologyMap) -> TopologyMap {
-        let mut ret: TopologyMap = TopologyMap::new(false, false);
-        let mut nodes: Vec<&Node<TopologyNode>> =
-            topology_map.get_nodes().iter().map(|(_, n)| n).collect();
-        let mut edges: Vec<&Edge<TopologyEdge>> =
-            topology_map.get_edges().iter().map(|(_, e)| e).collect();
-        nodes.sort_by(|n1, n2| u32::cmp(&n1.get_id(), &n2.get_id()));
-        edges.sort_by(|e1, e2| u32::cmp(&e1.get_id(), &e2.get_id()));
+        let mut ret: TopologyMap = TopologyMap::with_options(topology_map.options());
+        ret.reserve(topology_map.get_node_count(), topology_map.get_edge_count());
+        let mut nodes: Vec<&Node<TopologyNode>> = topology_map.get_nodes().values().collect();
+        let mut edges: Vec<&Edge<TopologyEdge>> = topology_map.get_edges().values().collect();
+        nodes.sort_by(|n1, n2| NodeId::cmp(&n1.get_id(), &n2.get_id()));
+        edges.sort_by(|e1, e2| EdgeId::cmp(&e1.get_id(), &e2.get_id()));
 
         for node in nodes {
             let new_node_id = ret.add_node(TopologyNode {
                 node_type: node.node_info().node_type.clone(),
                 position: self.convert_point_image_to_planar(&node.node_info().position),
+                floor: node.node_info().floor,
+                confidence: node.node_info().confidence,
             });
             assert_eq!(node.get_id(), new_node_id);
         }
@@ -39,17 +45,19 @@ impl TopologyCoordinateConverter {
         for edge in edges {
             let n1 = edge.node1();
             let n2 = edge.node2();
-            let new_edge_id = ret.add_edge(
-                n1,
-                n2,
-                TopologyEdge::from_waypoints(
-                    edge.edge_info()
-                        .get_waypoints()
-                        .iter()
-                        .map(|p| self.convert_point_image_to_planar(p))
-                        .collect(),
-                ),
-            ).unwrap();
+            let new_edge_id = ret
+                .add_edge(
+                    n1,
+                    n2,
+                    TopologyEdge::from_waypoints(
+                        edge.edge_info()
+                            .get_waypoints()
+                            .iter()
+                            .map(|p| self.convert_point_image_to_planar(p))
+                            .collect(),
+                    ),
+                )
+                .unwrap();
             assert_eq!(edge.get_id(), new_edge_id);
         }
 
@@ -1,22 +1,31 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
+
+use core::{
+    collections::spatial_hash_map::SpatialHashMap,
+    graph::{graph::Graph, node::NodeId},
+    numerics::{vector2d::Vector2D, vector2i::Vector2I},
+};
 
-use image::{ColorType, Rgb, RgbImage};
 use ndarray::Array2;
 
 use crate::{
-    algorithms::zhang_suen_thinning::{
-        self,
-        zhang_suen_thinning_algorithm::{self, ZhangSuenThinningAlgorithm},
+    algorithm::{
+        flood_fill::flood_fill_algorithm::connected_components,
+        zhang_suen_thinning::zhang_suen_thinning_algorithm::ZhangSuenThinningAlgorithm,
     },
-    graph::graph::Graph,
-    map::{
-        grid::grid_map::{GridMap, GridMapCellState},
-        topology::{
-            topology_edge::TopologyEdge,
-            topology_node::{TopologyNode, TopologyNodeType},
+    grid::{
+        connectivity::Connectivity,
+        grid_map::{GridMap, GridMapCellState},
+        probabilistic_grid_map::{OccupancyThresholdPolicy, ProbabilisticGridMap},
+    },
+    topology::{
+        topology_edge::TopologyEdge,
+        topology_generation::{
+            node_classifier::{NodeClassifier, PixelNeighborhood, PixelScoreClassifier},
+            region_of_interest::{BoundaryClassifier, RegionOfInterest},
         },
+        topology_node::{TopologyNode, TopologyNodeType},
     },
-    math::numerics::{vector2d::Vector2D, vector2i::Vector2I},
 };
 
 type TopologyMap = Graph<TopologyNode, TopologyEdge>;
@@ -34,88 +43,124 @@ static GRID_OFFSETS_RIM: [[isize; 2]; 8] = [
 
 pub struct TopologyExtractor {}
 
+/// Map the topology is extracted from, e.g. a `GridMap` or a `ThresholdedGridMap`.
+pub trait TraversableMap {
+    /// Cells a robot can pass through, laid out as (y, x).
+    fn traversable_cells(&self) -> Array2<bool>;
+}
+
+impl TraversableMap for GridMap {
+    fn traversable_cells(&self) -> Array2<bool> {
+        return self.map(|cell| *cell.state() == GridMapCellState::Vacant);
+    }
+}
+
+/// Probabilistic map binarized by a threshold policy, including its unknown cells.
+pub struct ThresholdedGridMap<'a> {
+    pub map: &'a ProbabilisticGridMap,
+    pub policy: OccupancyThresholdPolicy,
+}
+
+impl TraversableMap for ThresholdedGridMap<'_> {
+    fn traversable_cells(&self) -> Array2<bool> {
+        return self
+            .map
+            .classify_cells(&self.policy)
+            .map(|state| *state == GridMapCellState::Vacant);
+    }
+}
+
+/// Parameters of `TopologyExtractor::extract_with_options`.
+#[derive(Clone, Copy)]
+pub struct TopologyExtractionOptions<'a> {
+    /// Decides which skeleton pixels become nodes.
+    pub classifier: &'a dyn NodeClassifier,
+
+    /// Extract only within the region. Endpoints where the skeleton leaves the region become
+    /// boundary nodes, so that topology maps of adjacent regions can be stitched together.
+    pub region: Option<&'a RegionOfInterest>,
+}
+
+impl Default for TopologyExtractionOptions<'_> {
+    fn default() -> Self {
+        return Self {
+            classifier: &PixelScoreClassifier,
+            region: None,
+        };
+    }
+}
+
 impl TopologyExtractor {
-    pub fn extract(grid_map: &GridMap) -> TopologyMap {
+    pub fn extract(map: &dyn TraversableMap) -> TopologyMap {
+        return TopologyExtractor::extract_with_options(map, &TopologyExtractionOptions::default());
+    }
+
+    pub fn extract_with_options(
+        map: &dyn TraversableMap,
+        options: &TopologyExtractionOptions,
+    ) -> TopologyMap {
+        let occupancy_map: Array2<bool> = map.traversable_cells();
+        let region = match options.region {
+            Some(region) => region,
+            None => {
+                return TopologyExtractor::extract_from_occupancy(
+                    &occupancy_map,
+                    options.classifier,
+                )
+            }
+        };
+
+        let mask: Array2<bool> = region.to_mask(occupancy_map.dim());
+        let masked_occupancy_map: Array2<bool> =
+            Array2::from_shape_fn(occupancy_map.dim(), |pos| occupancy_map[pos] && mask[pos]);
+        let classifier = BoundaryClassifier {
+            inner: options.classifier,
+            mask: &mask,
+            full_occupancy_map: &occupancy_map,
+        };
+        return TopologyExtractor::extract_from_occupancy(&masked_occupancy_map, &classifier);
+    }
+
+    /// Extract topology from map of traversable cells.
+    fn extract_from_occupancy(
+        occupancy_map: &Array2<bool>,
+        classifier: &dyn NodeClassifier,
+    ) -> TopologyMap {
         let mut thinning = ZhangSuenThinningAlgorithm::new();
-        let occupancy_map: Array2<bool> =
-            grid_map.map(|cell| *cell.state() == GridMapCellState::Vacant);
-        let thinned_occupancy_map: Array2<bool> = thinning.run(&occupancy_map);
+        let thinned_occupancy_map: Array2<bool> = thinning.run(occupancy_map);
         let mut topology_map: TopologyMap = Graph::new(true, true);
         let mut bfs_queue: VecDeque<BfsData> = VecDeque::new();
 
-        // DEBUG
-        let (map_height, map_width) = thinned_occupancy_map.dim();
-        let img: RgbImage = RgbImage::from_fn(map_width as u32, map_height as u32, |x, y| {
-            if *thinned_occupancy_map.get((y as usize, x as usize)).unwrap() {
-                return Rgb([255, 255, 255]);
-            } else {
-                return Rgb([0, 0, 0]);
-            }
-        });
-        img.save("thinned.png");
-
         let seed_points = TopologyExtractor::find_seed_points(&thinned_occupancy_map);
         TopologyExtractor::find_nodes(
             &thinned_occupancy_map,
+            occupancy_map,
+            classifier,
             &seed_points,
             &mut topology_map,
             &mut bfs_queue,
         );
+
+        // Skeletons are mostly trees, having about as many edges as nodes.
+        topology_map.reserve(0, topology_map.get_node_count());
         TopologyExtractor::find_edges(&thinned_occupancy_map, &mut topology_map, &mut bfs_queue);
         return topology_map;
     }
 
+    /// Find one seed point per connected skeleton component.
+    /// Points are scanned in row-major order so that the seed points, and hence the node IDs
+    /// assigned from them, are identical between runs.
     fn find_seed_points(thinned_occupancy_map: &Array2<bool>) -> Vec<(usize, usize)> {
-        let (map_height, map_width) = thinned_occupancy_map.dim();
-        let mut seed_points: Vec<(usize, usize)> = Vec::new();
-        let mut unvisited_points: HashSet<(usize, usize)> = HashSet::new();
-
-        for x in 0..map_width {
-            for y in 0..map_height {
-                if *thinned_occupancy_map.get((y, x)).unwrap() {
-                    unvisited_points.insert((x, y));
-                }
-            }
-        }
-
-        while !unvisited_points.is_empty() {
-            let seed_point = unvisited_points.iter().next().unwrap().clone();
-            let mut bfs_queue: VecDeque<(usize, usize)> = VecDeque::new();
-            let mut connected_points = 0;
-            
-            unvisited_points.remove(&seed_point);
-            bfs_queue.push_back(seed_point);
-            connected_points += 1;
-
-            while !bfs_queue.is_empty() {
-                let point = bfs_queue.pop_front().unwrap();
-
-                for i in 0..GRID_OFFSETS_RIM.len() {
-                    match TopologyExtractor::get_neighboring_pos(point, (map_width, map_height), i)
-                    {
-                        Some(pos) => {
-                            if *thinned_occupancy_map.get((pos.1, pos.0)).unwrap()
-                                && unvisited_points.contains(&pos)
-                            {
-                                unvisited_points.remove(&pos);
-                                bfs_queue.push_back(pos);
-                                connected_points += 1;
-                            }
-                        }
-                        None => {}
-                    };
-                }
-            }
-
-            seed_points.push(seed_point);
-            println!("Seed Point #{}: {:?}, {} points connected.", seed_points.len(), &seed_point, connected_points);
-        }
-
-        return seed_points;
+        return connected_components(thinned_occupancy_map, Connectivity::Eight, |p| *p)
+            .iter()
+            .map(|component| (component[0].1, component[0].0))
+            .collect();
     }
 
     fn find_nodes(
         thinned_occupancy_map: &Array2<bool>,
+        occupancy_map: &Array2<bool>,
+        classifier: &dyn NodeClassifier,
         seed_points: &Vec<(usize, usize)>,
         topology_map: &mut TopologyMap,
         bfs_queue: &mut VecDeque<BfsData>,
@@ -124,35 +169,30 @@ impl TopologyExtractor {
 
         for seed_point in seed_points {
             let mut seed_queue: VecDeque<(usize, usize)> = VecDeque::new();
-            let mut visited_points: HashSet<(usize, usize)> = HashSet::new();
+            let mut visited_points: SpatialHashMap<()> = SpatialHashMap::new();
             let mut node_count = 0;
             let mut recent_point: (usize, usize) = (0, 0);
             seed_queue.push_back(*seed_point);
-            visited_points.insert(*seed_point);
+            visited_points.insert(Vector2I::from(*seed_point), ());
 
             while !seed_queue.is_empty() {
                 recent_point = seed_queue.pop_front().unwrap();
                 let (x, y) = recent_point;
-                let score = TopologyExtractor::compute_pixel_score(thinned_occupancy_map, x, y);
-
-                if score <= 1 {
-                    let node_id = topology_map.add_node(TopologyNode {
-                        node_type: TopologyNodeType::Endpoint,
-                        position: Vector2D::from_xy(x as f64, y as f64),
-                    });
-                    println!("Node {}: ({}, {}) => Endpoint", node_id, x, y);
-                    bfs_queue.push_back(BfsData {
-                        pos: (x, y),
-                        prev_pos: (x, y),
-                        root_node: node_id,
-                    });
-                    node_count += 1;
-                } else if score >= 3 {
+                let neighborhood = PixelNeighborhood {
+                    position: (x, y),
+                    rim: TopologyExtractor::get_rim(thinned_occupancy_map, (x, y)),
+                    score: TopologyExtractor::compute_pixel_score(thinned_occupancy_map, x, y),
+                    skeleton: thinned_occupancy_map,
+                    occupancy_map: occupancy_map,
+                };
+
+                if let Some(node_type) = classifier.classify(&neighborhood) {
                     let node_id = topology_map.add_node(TopologyNode {
-                        node_type: TopologyNodeType::Intersection,
+                        node_type: node_type,
                         position: Vector2D::from_xy(x as f64, y as f64),
+                        floor: 0,
+                        confidence: TopologyExtractor::compute_confidence(&neighborhood),
                     });
-                    println!("Node {}: ({}, {}) => Intersection", node_id, x, y);
                     bfs_queue.push_back(BfsData {
                         pos: (x, y),
                         prev_pos: (x, y),
@@ -162,19 +202,17 @@ impl TopologyExtractor {
                 }
 
                 for i in 0..GRID_OFFSETS_RIM.len() {
-                    match TopologyExtractor::get_neighboring_pos((x, y), (map_width, map_height), i)
+                    if let Some(neighbor_pos) =
+                        TopologyExtractor::get_neighboring_pos((x, y), (map_width, map_height), i)
                     {
-                        Some(neighbor_pos) => {
-                            if *thinned_occupancy_map
-                                .get((neighbor_pos.1, neighbor_pos.0))
-                                .unwrap()
-                                && !visited_points.contains(&neighbor_pos)
-                            {
-                                seed_queue.push_back(neighbor_pos);
-                                visited_points.insert(neighbor_pos);
-                            }
+                        if *thinned_occupancy_map
+                            .get((neighbor_pos.1, neighbor_pos.0))
+                            .unwrap()
+                            && !visited_points.contains(&Vector2I::from(neighbor_pos))
+                        {
+                            seed_queue.push_back(neighbor_pos);
+                            visited_points.insert(Vector2I::from(neighbor_pos), ());
                         }
-                        None => {}
                     };
                 }
             }
@@ -183,9 +221,19 @@ impl TopologyExtractor {
                 continue;
             }
 
+            let (x, y) = recent_point;
+            let neighborhood = PixelNeighborhood {
+                position: (x, y),
+                rim: TopologyExtractor::get_rim(thinned_occupancy_map, (x, y)),
+                score: TopologyExtractor::compute_pixel_score(thinned_occupancy_map, x, y),
+                skeleton: thinned_occupancy_map,
+                occupancy_map: occupancy_map,
+            };
             let node_id = topology_map.add_node(TopologyNode {
                 node_type: TopologyNodeType::Waypoint,
-                position: Vector2D::from_xy(recent_point.0 as f64, recent_point.1 as f64),
+                position: Vector2D::from_xy(x as f64, y as f64),
+                floor: 0,
+                confidence: TopologyExtractor::compute_confidence(&neighborhood),
             });
             bfs_queue.push_back(BfsData {
                 pos: recent_point,
@@ -205,7 +253,6 @@ impl TopologyExtractor {
             Array2::from_shape_fn((map_height, map_width), |(y, x)| ExplorationData {
                 cell_state: CellState::Unvisited,
                 root_node: None,
-                pos: (x, y),
                 prev_pos: (x, y),
             });
 
@@ -247,16 +294,14 @@ impl TopologyExtractor {
                     continue;
                 }
 
-                let dx = GRID_OFFSETS_RIM[neighbor][0];
-                let dy = GRID_OFFSETS_RIM[neighbor][1];
-                let x: isize = (data.pos.0 as isize + dx);
-                let y: isize = (data.pos.1 as isize + dy);
-
-                if x < 0 || x >= map_width as isize || y < 0 || y >= map_height as isize {
-                    continue;
-                }
-
-                let neighbor_pos = (x as usize, y as usize);
+                let neighbor_pos = match TopologyExtractor::get_neighboring_pos(
+                    data.pos,
+                    (map_width, map_height),
+                    neighbor,
+                ) {
+                    Some(pos) => pos,
+                    None => continue,
+                };
 
                 if neighbor_pos == data.prev_pos {
                     continue;
@@ -280,30 +325,54 @@ impl TopologyExtractor {
         }
     }
 
+    /// Estimate how stable the skeleton is around a node.
+    /// Both a wide corridor and a pixel score far from that of an ordinary edge pixel (2) raise
+    /// the confidence, while nodes stemming from thin noise score low.
+    fn compute_confidence(neighborhood: &PixelNeighborhood) -> f64 {
+        let clearance = neighborhood.clearance() as i32;
+        let score_margin = (neighborhood.score - 2).abs();
+        let width_factor = 1_f64 - 0.5_f64.powi(clearance);
+        let margin_factor = 1_f64 - 0.5_f64.powi(score_margin + 1);
+        return width_factor * margin_factor;
+    }
+
+    fn get_rim(thinned_occupancy_map: &Array2<bool>, pos: (usize, usize)) -> [bool; 8] {
+        let (map_height, map_width) = thinned_occupancy_map.dim();
+        let mut rim = [false; 8];
+
+        for i in 0..GRID_OFFSETS_RIM.len() {
+            if let Some((x, y)) =
+                TopologyExtractor::get_neighboring_pos(pos, (map_width, map_height), i)
+            {
+                rim[i] = *thinned_occupancy_map.get((y, x)).unwrap();
+            }
+        }
+
+        return rim;
+    }
+
     fn get_visit_mask(thinned_occupancy_map: &Array2<bool>, pos: (usize, usize)) -> [bool; 8] {
         let (map_height, map_width) = thinned_occupancy_map.dim();
         let mut visit_mask = [false; 8];
 
         for i in 0..8 {
-            match TopologyExtractor::get_neighboring_pos(pos, (map_width, map_height), i) {
-                Some((x, y)) => {
-                    if *thinned_occupancy_map.get((y, x)).unwrap() {
-                        *visit_mask.get_mut(i).unwrap() = true;
-                    }
+            if let Some((x, y)) =
+                TopologyExtractor::get_neighboring_pos(pos, (map_width, map_height), i)
+            {
+                if *thinned_occupancy_map.get((y, x)).unwrap() {
+                    *visit_mask.get_mut(i).unwrap() = true;
                 }
-                None => {}
             }
         }
 
         for i in 0..4 {
-            match TopologyExtractor::get_neighboring_pos(pos, (map_width, map_height), 2 * i) {
-                Some((x, y)) => {
-                    if *thinned_occupancy_map.get((y, x)).unwrap() {
-                        *visit_mask.get_mut((8 + 2 * i - 1) % 8).unwrap() = false;
-                        *visit_mask.get_mut((8 + 2 * i + 1) % 8).unwrap() = false;
-                    }
+            if let Some((x, y)) =
+                TopologyExtractor::get_neighboring_pos(pos, (map_width, map_height), 2 * i)
+            {
+                if *thinned_occupancy_map.get((y, x)).unwrap() {
+                    *visit_mask.get_mut((8 + 2 * i - 1) % 8).unwrap() = false;
+                    *visit_mask.get_mut((8 + 2 * i + 1) % 8).unwrap() = false;
                 }
-                None => {}
             };
         }
 
@@ -323,7 +392,7 @@ impl TopologyExtractor {
         }
 
         for i in 0..GRID_OFFSETS_RIM.len() {
-            let i1 = (i + 0) % GRID_OFFSETS_RIM.len();
+            let i1 = i % GRID_OFFSETS_RIM.len();
             let i2 = (i + 1) % GRID_OFFSETS_RIM.len();
 
             if let Some(false) = thinned_occupancy_map.get((
@@ -406,8 +475,8 @@ impl TopologyExtractor {
         }
 
         let mut waypoints: Vec<Vector2D> = Vec::new();
-        let lower_group: u32;
-        let upper_group: u32;
+        let lower_group: NodeId;
+        let upper_group: NodeId;
 
         if this_side_root < other_side_root {
             lower_group = this_side_root;
@@ -439,21 +508,22 @@ impl TopologyExtractor {
         dim: (usize, usize),
         offset_index: usize,
     ) -> Option<(usize, usize)> {
-        let x = pos.0 as isize + GRID_OFFSETS_RIM[offset_index][0];
-        let y = pos.1 as isize + GRID_OFFSETS_RIM[offset_index][1];
+        let offset = GRID_OFFSETS_RIM[offset_index];
+        let neighbor = Vector2I::from(pos) + Vector2I::from_xy(offset[0] as i64, offset[1] as i64);
+        let (x, y) = <(usize, usize)>::try_from(neighbor).ok()?;
 
-        if x < 0 || x >= dim.0 as isize || y < 0 || y >= dim.1 as isize {
+        if x >= dim.0 || y >= dim.1 {
             return None;
         }
 
-        return Some((x as usize, y as usize));
+        return Some((x, y));
     }
 }
 
 #[derive(Clone)]
 struct BfsData {
     /// ID of root node.
-    pub root_node: u32,
+    pub root_node: NodeId,
 
     /// Position of cell in (x, y).
     pub pos: (usize, usize),
@@ -472,7 +542,229 @@ enum CellState {
 #[derive(Clone)]
 struct ExplorationData {
     pub cell_state: CellState,
-    pub root_node: Option<u32>,
-    pub pos: (usize, usize),
+    pub root_node: Option<NodeId>,
     pub prev_pos: (usize, usize),
 }
+
+#[cfg(test)]
+mod tests {
+    use core::graph::{edge::EdgeId, node::NodeId};
+
+    use ndarray::Array2;
+
+    use crate::{
+        grid::{
+            grid_map::{GridMap, GridMapCellState},
+            probabilistic_grid_map::{OccupancyThresholdPolicy, ProbabilisticGridMap},
+        },
+        topology::{
+            topology_generation::{
+                node_classifier::{NodeClassifier, PixelNeighborhood, PixelScoreClassifier},
+                region_of_interest::RegionOfInterest,
+            },
+            topology_node::TopologyNodeType,
+        },
+    };
+
+    use super::{ThresholdedGridMap, TopologyExtractionOptions, TopologyExtractor};
+
+    struct DoorwayClassifier {
+        x: usize,
+    }
+
+    impl NodeClassifier for DoorwayClassifier {
+        fn classify(&self, neighborhood: &PixelNeighborhood) -> Option<TopologyNodeType> {
+            if neighborhood.position.0 == self.x && neighborhood.score == 2 {
+                return Some(TopologyNodeType::Custom(7));
+            }
+
+            return PixelScoreClassifier.classify(neighborhood);
+        }
+    }
+
+    /// Build a map with two disjoint corridors, one of which is T-shaped.
+    fn build_grid_map() -> GridMap {
+        let mut grid_map = GridMap::with_cell_state(40, 30, 1_f64, GridMapCellState::Occupied);
+
+        for c in 3..37 {
+            for r in 4..7 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Vacant;
+            }
+        }
+
+        for r in 7..16 {
+            for c in 18..21 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Vacant;
+            }
+        }
+
+        for c in 5..30 {
+            for r in 22..25 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Vacant;
+            }
+        }
+
+        return grid_map;
+    }
+
+    #[test]
+    fn extraction_is_deterministic() {
+        let grid_map = build_grid_map();
+        let mut outputs = Vec::new();
+
+        for _ in 0..5 {
+            let topology_map = TopologyExtractor::extract(&grid_map);
+            let mut nodes: Vec<(NodeId, f64, f64)> = topology_map
+                .get_nodes()
+                .values()
+                .map(|n| {
+                    (
+                        n.get_id(),
+                        n.node_info().position.x,
+                        n.node_info().position.y,
+                    )
+                })
+                .collect();
+            let mut edges: Vec<(EdgeId, NodeId, NodeId)> = topology_map
+                .get_edges()
+                .values()
+                .map(|e| (e.get_id(), e.node1(), e.node2()))
+                .collect();
+            nodes.sort_by_key(|n| n.0);
+            edges.sort();
+            outputs.push((nodes, edges));
+        }
+
+        assert!(!outputs[0].0.is_empty());
+        assert!(!outputs[0].1.is_empty());
+        for output in outputs.iter().skip(1) {
+            assert_eq!(&outputs[0], output);
+        }
+    }
+
+    #[test]
+    fn custom_classifier_splits_edges() {
+        let mut grid_map = GridMap::with_cell_state(40, 9, 1_f64, GridMapCellState::Occupied);
+        for c in 3..37 {
+            for r in 3..6 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Vacant;
+            }
+        }
+
+        let plain = TopologyExtractor::extract(&grid_map);
+        let custom = TopologyExtractor::extract_with_options(
+            &grid_map,
+            &TopologyExtractionOptions {
+                classifier: &DoorwayClassifier { x: 20 },
+                ..TopologyExtractionOptions::default()
+            },
+        );
+
+        let doorways: Vec<NodeId> = custom
+            .get_nodes()
+            .values()
+            .filter(|n| n.node_info().node_type == TopologyNodeType::Custom(7))
+            .map(|n| n.get_id())
+            .collect();
+        assert_eq!(doorways.len(), 1);
+        assert_eq!(custom.get_node_by_id(&doorways[0]).unwrap().degree(), 2);
+        assert_eq!(custom.get_edge_count(), plain.get_edge_count() + 1);
+    }
+
+    #[test]
+    fn region_cut_produces_boundary_nodes() {
+        let grid_map = build_grid_map();
+        let region = RegionOfInterest::Rect {
+            x: 0,
+            y: 0,
+            width: 15,
+            height: 30,
+        };
+
+        let topology_map = TopologyExtractor::extract_with_options(
+            &grid_map,
+            &TopologyExtractionOptions {
+                region: Some(&region),
+                ..TopologyExtractionOptions::default()
+            },
+        );
+        let mut node_types: Vec<TopologyNodeType> = topology_map
+            .get_nodes()
+            .values()
+            .map(|n| n.node_info().node_type.clone())
+            .collect();
+        node_types.sort_by_key(|t| format!("{:?}", t));
+
+        assert!(topology_map
+            .get_nodes()
+            .values()
+            .all(|n| n.node_info().position.x < 15_f64));
+        assert_eq!(
+            node_types,
+            vec![
+                TopologyNodeType::Boundary,
+                TopologyNodeType::Boundary,
+                TopologyNodeType::Endpoint,
+                TopologyNodeType::Endpoint,
+            ]
+        );
+    }
+
+    #[test]
+    fn probabilistic_map_within_region() {
+        let grid_map = build_grid_map();
+        let probabilities: Array2<f64> = grid_map.map(|cell| match cell.state() {
+            GridMapCellState::Occupied => 0.9,
+            GridMapCellState::Vacant => 0.1,
+        });
+        let map = ProbabilisticGridMap::from_probabilities(&probabilities, 1_f64);
+        let thresholded = ThresholdedGridMap {
+            map: &map,
+            policy: OccupancyThresholdPolicy::default(),
+        };
+        let region = RegionOfInterest::Rect {
+            x: 0,
+            y: 0,
+            width: 15,
+            height: 30,
+        };
+        let options = TopologyExtractionOptions {
+            region: Some(&region),
+            ..TopologyExtractionOptions::default()
+        };
+
+        let expected = TopologyExtractor::extract_with_options(&grid_map, &options);
+        let topology_map = TopologyExtractor::extract_with_options(&thresholded, &options);
+        assert_eq!(topology_map.get_node_count(), expected.get_node_count());
+        assert_eq!(topology_map.get_edge_count(), expected.get_edge_count());
+        assert!(topology_map
+            .get_nodes()
+            .values()
+            .any(|n| n.node_info().node_type == TopologyNodeType::Boundary));
+    }
+
+    #[test]
+    fn wide_corridors_yield_confident_nodes() {
+        let mut grid_map = GridMap::with_cell_state(40, 20, 1_f64, GridMapCellState::Occupied);
+        for c in 3..37 {
+            *grid_map.get_by_cell_mut(3, c).unwrap().state_mut() = GridMapCellState::Vacant;
+
+            for r in 8..17 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Vacant;
+            }
+        }
+
+        let topology_map = TopologyExtractor::extract(&grid_map);
+        assert_eq!(topology_map.get_node_count(), 4);
+
+        for node in topology_map.get_nodes().values() {
+            let confidence = node.node_info().confidence;
+
+            if node.node_info().position.y < 5_f64 {
+                assert!(confidence < 0.5);
+            } else {
+                assert!(confidence > 0.5 && confidence < 1_f64);
+            }
+        }
+    }
+}
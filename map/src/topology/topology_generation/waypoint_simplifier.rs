@@ -1,9 +1,6 @@
-use std::collections::VecDeque;
+use core::numerics::{vector::Vector, vector2d::Vector2D};
 
-use crate::math::{
-    geometry::geometry_solver::GeometrySolver,
-    numerics::{vector::Vector, vector2d::Vector2D, vector3d::Vector3D},
-};
+use math::geometry::geometry_solver::GeometrySolver;
 
 pub struct WaypointSimplifier {
     allowed_deviation: f64,
@@ -18,7 +15,7 @@ impl WaypointSimplifier {
 
     pub fn simplify(&self, waypoints: &Vec<Vector2D>) -> Vec<Vector2D> {
         if waypoints.len() < 3 {
-            return waypoints.iter().map(|p| p.clone()).collect();
+            return waypoints.to_vec();
         }
 
         let mut divisions: Vec<bool> = vec![false; waypoints.len()];
@@ -30,7 +27,7 @@ impl WaypointSimplifier {
             .iter()
             .enumerate()
             .filter(|(i, _)| *divisions.get(*i).unwrap())
-            .map(|(i, p)| p.clone())
+            .map(|(_, p)| p.clone())
             .collect();
         return simplified;
     }
@@ -46,28 +43,14 @@ impl WaypointSimplifier {
             return None;
         }
 
-        let (max_deviation_index, max_deviation) = match self.find_max_deviation(waypoints, start_index, end_index) {
-            Some(p) => p,
-            None => return None,
-        };
-
-        // Find the deviation point on the left side.
-        let left_dev = self.simplify_dfs(
-            waypoints,
-            divisions,
-            start_index,
-            max_deviation_index,
-        );
+        let (max_deviation_index, _) =
+            self.find_max_deviation(waypoints, start_index, end_index)?;
 
         // Find the deviation point on the left side.
-        let right_dev = self.simplify_dfs(
-            waypoints,
-            divisions,
-            max_deviation_index,
-            end_index,
-        );
+        self.simplify_dfs(waypoints, divisions, start_index, max_deviation_index);
 
-        if left_dev.is_some() && right_dev.is_some() {}
+        // Find the deviation point on the right side.
+        self.simplify_dfs(waypoints, divisions, max_deviation_index, end_index);
 
         *divisions.get_mut(max_deviation_index).unwrap() = true;
         return Some(max_deviation_index);
@@ -80,7 +63,6 @@ impl WaypointSimplifier {
         end_index: usize,
     ) -> Option<(usize, f64)> {
         let solver = GeometrySolver::new(1e-9);
-        let mut max_deviation_pair: Option<(usize, f64)> = None;
         let mut max_deviation = 0_f64;
         let mut max_deviation_index: Option<usize> = None;
 
@@ -89,7 +71,7 @@ impl WaypointSimplifier {
             let start_point = points.get(start_index).unwrap().clone();
             let end_point = points.get(end_index).unwrap().clone();
 
-            let distance = match &start_point == &end_point {
+            let distance = match start_point == end_point {
                 true => (point - start_point).magnitude(),
                 false => {
                     let (dist, _) = solver.point_to_line_distance(
@@ -108,10 +90,6 @@ impl WaypointSimplifier {
             }
         }
 
-        if max_deviation_index.is_some() {
-            return Some((max_deviation_index.unwrap(), max_deviation));
-        } else {
-            return None;
-        }
+        return max_deviation_index.map(|index| (index, max_deviation));
     }
 }
@@ -1,15 +1,55 @@
-use crate::math::numerics::{vector2d::Vector2D, vector2i::Vector2I};
+use core::numerics::vector2d::Vector2D;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TopologyNode {
     pub node_type: TopologyNodeType,
     pub position: Vector2D,
+
+    /// Floor the node belongs to. Single-floor maps use floor 0.
+    pub floor: i32,
+
+    /// Confidence of the node in [0, 1], reflecting how stable the skeleton is around it.
+    /// Nodes which do not stem from the skeleton have a confidence of 1.
+    pub confidence: f64,
 }
 
-#[derive(Clone)]
+impl TopologyNode {
+    /// `position` may also be a `Vector2F`, which is widened to double precision.
+    pub fn new<P: Into<Vector2D>>(node_type: TopologyNodeType, position: P) -> Self {
+        return Self {
+            node_type: node_type,
+            position: position.into(),
+            floor: 0,
+            confidence: 1_f64,
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TopologyNodeType {
     Island,
     Endpoint,
     Waypoint,
     Intersection,
+
+    /// Node linking floors together, e.g. an elevator or a staircase.
+    Connector(FloorConnectorType),
+
+    /// Chokepoint where the corridor clearance falls below a threshold, e.g. a doorway.
+    NarrowPassage,
+
+    /// Node where the skeleton leaves the region of interest the topology was extracted from.
+    Boundary,
+
+    /// User-defined node type, tagged by an arbitrary identifier.
+    Custom(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FloorConnectorType {
+    Elevator,
+    Stairs,
 }
@@ -0,0 +1,192 @@
+use core::{
+    graph::{
+        edge::Edge, edge_weight::EdgeWeight, graph::Graph, graph_error::GraphError, node::NodeId,
+        path_result::PathResult,
+    },
+    numerics::{vector::Vector, vector2d::Vector2D},
+};
+
+use crate::topology::{topology_edge::TopologyEdge, topology_node::TopologyNode};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+pub trait TopologyPlanning {
+    /// Find the shortest route between two nodes, measured by the edge lengths.
+    /// Edges without waypoints are as long as the straight line between their nodes.
+    fn plan_route(&self, from: NodeId, to: NodeId) -> Result<PathResult, GraphError>;
+
+    /// Flatten route into the waypoints to follow, from its first node to its last.
+    /// Edges without waypoints contribute the positions of their nodes.
+    fn route_waypoints(&self, path: &PathResult) -> Vec<Vector2D>;
+}
+
+impl TopologyPlanning for TopologyMap {
+    fn plan_route(&self, from: NodeId, to: NodeId) -> Result<PathResult, GraphError> {
+        let goal = match self.get_node_by_id(&to) {
+            Some(n) => n.node_info().position,
+            None => return Err(GraphError::NoSuchNode),
+        };
+
+        // Straight-line distance never exceeds the length of an edge between two nodes.
+        return self.a_star(
+            from,
+            to,
+            |e| edge_length(self, e),
+            |n| (n.node_info().position - goal).magnitude(),
+        );
+    }
+
+    fn route_waypoints(&self, path: &PathResult) -> Vec<Vector2D> {
+        let mut ret: Vec<Vector2D> = Vec::new();
+
+        if let Some(node) = path.start().and_then(|id| self.get_node_by_id(&id)) {
+            ret.push(node.node_info().position);
+        }
+
+        for (i, edge_id) in path.edges.iter().enumerate() {
+            let edge = match self.get_edge_by_id(edge_id) {
+                Some(e) => e,
+                None => continue,
+            };
+
+            let mut waypoints = edge.edge_info().get_waypoints().clone();
+            if waypoints.is_empty() {
+                waypoints = vec![
+                    self.get_node_by_id(&edge.node1())
+                        .unwrap()
+                        .node_info()
+                        .position,
+                    self.get_node_by_id(&edge.node2())
+                        .unwrap()
+                        .node_info()
+                        .position,
+                ];
+            }
+
+            if edge.node1() != path.nodes[i] {
+                waypoints.reverse();
+            }
+
+            // Consecutive edges share the waypoint at their common node.
+            for waypoint in waypoints {
+                if ret.last() != Some(&waypoint) {
+                    ret.push(waypoint);
+                }
+            }
+        }
+
+        return ret;
+    }
+}
+
+/// Length of edge along its waypoints, or between its nodes if it has none.
+fn edge_length(topology_map: &TopologyMap, edge: &Edge<TopologyEdge>) -> f64 {
+    if !edge.edge_info().get_waypoints().is_empty() {
+        return edge.edge_info().weight();
+    }
+
+    let position = |id: NodeId| {
+        topology_map
+            .get_node_by_id(&id)
+            .unwrap()
+            .node_info()
+            .position
+    };
+    return (position(edge.node2()) - position(edge.node1())).magnitude();
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{graph::node::NodeId, numerics::vector2d::Vector2D};
+
+    use crate::topology::{
+        topology_edge::TopologyEdge,
+        topology_node::{TopologyNode, TopologyNodeType},
+    };
+
+    use super::{TopologyMap, TopologyPlanning};
+
+    #[test]
+    fn plans_shortest_route() {
+        let mut topology_map = TopologyMap::new(false, false);
+        let points = [
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(5_f64, 0_f64),
+            Vector2D::from_xy(10_f64, 0_f64),
+            Vector2D::from_xy(5_f64, 8_f64),
+        ];
+        let n: Vec<NodeId> = points
+            .iter()
+            .map(|p| topology_map.add_node(TopologyNode::new(TopologyNodeType::Waypoint, *p)))
+            .collect();
+
+        topology_map
+            .add_edge(
+                n[0],
+                n[1],
+                TopologyEdge::from_waypoints(vec![
+                    points[0],
+                    Vector2D::from_xy(2_f64, 0_f64),
+                    points[1],
+                ]),
+            )
+            .unwrap();
+        for (i, j) in [(2, 1), (0, 3), (3, 2)] {
+            topology_map
+                .add_edge(
+                    n[i],
+                    n[j],
+                    TopologyEdge::from_waypoints(vec![points[i], points[j]]),
+                )
+                .unwrap();
+        }
+
+        let route = topology_map.plan_route(n[0], n[2]).unwrap();
+        assert_eq!(route.nodes, vec![n[0], n[1], n[2]]);
+        assert_eq!(route.cost, 10_f64);
+        assert_eq!(
+            topology_map.route_waypoints(&route),
+            vec![
+                points[0],
+                Vector2D::from_xy(2_f64, 0_f64),
+                points[1],
+                points[2]
+            ]
+        );
+    }
+
+    #[test]
+    fn edges_without_waypoints_span_their_nodes() {
+        let mut topology_map = TopologyMap::new(false, false);
+        let points = [
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(5_f64, 10_f64),
+            Vector2D::from_xy(10_f64, 0_f64),
+        ];
+        let n: Vec<NodeId> = points
+            .iter()
+            .map(|p| topology_map.add_node(TopologyNode::new(TopologyNodeType::Waypoint, *p)))
+            .collect();
+
+        // Detour through the far node along edges without waypoints, as left by vectorization.
+        for (i, j) in [(0, 1), (1, 2)] {
+            topology_map
+                .add_edge(n[i], n[j], TopologyEdge::from_waypoints(Vec::new()))
+                .unwrap();
+        }
+        topology_map
+            .add_edge(
+                n[0],
+                n[2],
+                TopologyEdge::from_waypoints(vec![
+                    points[0],
+                    Vector2D::from_xy(5_f64, 2_f64),
+                    points[2],
+                ]),
+            )
+            .unwrap();
+
+        let route = topology_map.plan_route(n[0], n[2]).unwrap();
+        assert_eq!(route.nodes, vec![n[0], n[2]]);
+    }
+}
@@ -0,0 +1,186 @@
+use core::{
+    graph::{edge::EdgeId, graph::Graph, node::NodeId},
+    numerics::{vector::Vector, vector2d::Vector2D},
+};
+
+use crate::{
+    grid::grid_map::{GridMap, GridMapCellState},
+    topology::{topology_edge::TopologyEdge, topology_node::TopologyNode},
+};
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TopologyIssue {
+    /// Edge refers to a node which does not exist.
+    DanglingEdge { edge_id: EdgeId, node_id: NodeId },
+
+    /// Node without any connected edge.
+    OrphanNode { node_id: NodeId },
+
+    /// Part of the edge between waypoint `waypoint_index` and the next one lies in an
+    /// occupied (or out-of-map) cell.
+    WaypointInOccupiedCell {
+        edge_id: EdgeId,
+        waypoint_index: usize,
+    },
+
+    /// First or last waypoint of the edge is farther from its node than the tolerance.
+    EndpointMismatch {
+        edge_id: EdgeId,
+        node_id: NodeId,
+        distance: f64,
+    },
+}
+
+pub trait TopologyValidation {
+    /// Check the topology map for structural and geometric inconsistencies.
+    /// When `grid_map` is given, waypoints are checked against its occupied cells, assuming
+    /// positions are planar coordinates of the grid map.
+    /// Issues are listed in order of the entity IDs.
+    fn validate(&self, grid_map: Option<&GridMap>, tolerance: f64) -> Vec<TopologyIssue>;
+}
+
+impl TopologyValidation for TopologyMap {
+    fn validate(&self, grid_map: Option<&GridMap>, tolerance: f64) -> Vec<TopologyIssue> {
+        let mut issues: Vec<TopologyIssue> = Vec::new();
+
+        let mut node_ids: Vec<NodeId> = self.get_nodes().keys().copied().collect();
+        node_ids.sort();
+        for node_id in node_ids {
+            if self.get_node_by_id(&node_id).unwrap().degree() == 0 {
+                issues.push(TopologyIssue::OrphanNode { node_id: node_id });
+            }
+        }
+
+        let mut edge_ids: Vec<EdgeId> = self.get_edges().keys().copied().collect();
+        edge_ids.sort();
+        for edge_id in edge_ids {
+            let edge = self.get_edge_by_id(&edge_id).unwrap();
+            let waypoints = edge.edge_info().get_waypoints();
+            let mut has_dangling_end = false;
+
+            for node_id in [edge.node1(), edge.node2()] {
+                if self.get_node_by_id(&node_id).is_none() {
+                    issues.push(TopologyIssue::DanglingEdge {
+                        edge_id: edge_id,
+                        node_id: node_id,
+                    });
+                    has_dangling_end = true;
+                }
+            }
+
+            if !has_dangling_end && !waypoints.is_empty() {
+                let ends = [
+                    (edge.node1(), waypoints.first().unwrap()),
+                    (edge.node2(), waypoints.last().unwrap()),
+                ];
+
+                for (node_id, waypoint) in ends {
+                    let position = self.get_node_by_id(&node_id).unwrap().node_info().position;
+                    let distance = (position - waypoint).magnitude();
+
+                    if distance > tolerance {
+                        issues.push(TopologyIssue::EndpointMismatch {
+                            edge_id: edge_id,
+                            node_id: node_id,
+                            distance: distance,
+                        });
+                    }
+                }
+            }
+
+            if let Some(grid_map) = grid_map {
+                for i in 0..waypoints.len() {
+                    let p1 = waypoints[i];
+                    let p2 = waypoints.get(i + 1).copied().unwrap_or(p1);
+
+                    if !is_segment_vacant(grid_map, &p1, &p2) {
+                        issues.push(TopologyIssue::WaypointInOccupiedCell {
+                            edge_id: edge_id,
+                            waypoint_index: i,
+                        });
+                    }
+                }
+            }
+        }
+
+        return issues;
+    }
+}
+
+/// Check the cells along a segment, sampled at intervals of half the cell size.
+fn is_segment_vacant(grid_map: &GridMap, p1: &Vector2D, p2: &Vector2D) -> bool {
+    let length = (p2 - p1).magnitude();
+    let steps = (2_f64 * length / grid_map.cell_size()).ceil() as usize;
+
+    for step in 0..=steps {
+        let t = match steps {
+            0 => 0_f64,
+            _ => step as f64 / steps as f64,
+        };
+        let point = p1 + (p2 - p1) * t;
+
+        match grid_map.get_by_coordinate(point.x, point.y) {
+            Some(cell) if *cell.state() == GridMapCellState::Vacant => {}
+            _ => return false,
+        };
+    }
+
+    return true;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::{
+        grid::grid_map::{GridMap, GridMapCellState},
+        topology::{
+            topology_edge::TopologyEdge,
+            topology_node::{TopologyNode, TopologyNodeType},
+        },
+    };
+
+    use super::{TopologyIssue, TopologyMap, TopologyValidation};
+
+    #[test]
+    fn detects_issues() {
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        *grid_map
+            .get_by_coordinate_mut(5.5, 2.5)
+            .unwrap()
+            .state_mut() = GridMapCellState::Occupied;
+
+        let mut topology_map = TopologyMap::new(false, false);
+        let p1 = Vector2D::from_xy(1.5, 2.5);
+        let p2 = Vector2D::from_xy(8.5, 2.5);
+        let n1 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p1));
+        let n2 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p2));
+        let n3 = topology_map.add_node(TopologyNode::new(TopologyNodeType::Endpoint, p2));
+        let e1 = topology_map
+            .add_edge(
+                n1,
+                n2,
+                TopologyEdge::from_waypoints(vec![p1, Vector2D::from_xy(8_f64, 2.5)]),
+            )
+            .unwrap();
+
+        let issues = topology_map.validate(Some(&grid_map), 0.1);
+        assert_eq!(
+            issues,
+            vec![
+                TopologyIssue::OrphanNode { node_id: n3 },
+                TopologyIssue::EndpointMismatch {
+                    edge_id: e1,
+                    node_id: n2,
+                    distance: 0.5
+                },
+                TopologyIssue::WaypointInOccupiedCell {
+                    edge_id: e1,
+                    waypoint_index: 0
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+
+use core::numerics::vector2d::Vector2D;
+
+use crate::geometry::polygon2d::Polygon2D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConvexDecompositionError {
+    /// The polygon has fewer than three distinct, non-collinear vertices.
+    DegeneratePolygon,
+    /// The boundary intersects itself, so it cannot be triangulated.
+    NotSimple,
+}
+
+impl fmt::Display for ConvexDecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ConvexDecompositionError::DegeneratePolygon => write!(f, "polygon has no area"),
+            ConvexDecompositionError::NotSimple => write!(f, "polygon is not simple"),
+        };
+    }
+}
+
+impl std::error::Error for ConvexDecompositionError {}
+
+/// Split a simple polygon into convex pieces covering it without overlap.
+/// Triangulates by ear clipping and then removes diagonals whose removal keeps both sides
+/// convex (Hertel-Mehlhorn), which yields at most four times the optimal number of pieces.
+/// Pieces are returned with counterclockwise vertices.
+pub fn decompose_into_convex(
+    polygon: &Polygon2D,
+) -> Result<Vec<Polygon2D>, ConvexDecompositionError> {
+    let vertices = counterclockwise_vertices(polygon);
+    if vertices.len() < 3 {
+        return Err(ConvexDecompositionError::DegeneratePolygon);
+    }
+
+    let convex = Polygon2D::new(vertices.clone());
+    if convex.is_convex() {
+        return Ok(vec![convex]);
+    }
+
+    let mut pieces = triangulate(&vertices)?;
+    merge_pieces(&vertices, &mut pieces);
+
+    return Ok(pieces
+        .into_iter()
+        .map(|piece| Polygon2D::new(piece.iter().map(|i| vertices[*i]).collect()))
+        .collect());
+}
+
+/// Vertices in counterclockwise order without repeated or collinear vertices.
+fn counterclockwise_vertices(polygon: &Polygon2D) -> Vec<Vector2D> {
+    let mut ret: Vec<Vector2D> = polygon.vertices.clone();
+    if polygon.signed_area() < 0_f64 {
+        ret.reverse();
+    }
+
+    let mut i = 0;
+    while ret.len() >= 3 && i < ret.len() {
+        let n = ret.len();
+        let prev = ret[(i + n - 1) % n];
+        let next = ret[(i + 1) % n];
+        if turn(&prev, &ret[i], &next) == 0_f64 {
+            ret.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+
+    return ret;
+}
+
+/// Positive if the path a, b, c turns left.
+fn turn(a: &Vector2D, b: &Vector2D, c: &Vector2D) -> f64 {
+    return (b - a).cross(&(c - b));
+}
+
+fn in_triangle(point: &Vector2D, a: &Vector2D, b: &Vector2D, c: &Vector2D) -> bool {
+    return turn(a, b, point) >= 0_f64 && turn(b, c, point) >= 0_f64 && turn(c, a, point) >= 0_f64;
+}
+
+/// Ear clipping of counterclockwise vertices into triangles of vertex indices.
+fn triangulate(vertices: &[Vector2D]) -> Result<Vec<Vec<usize>>, ConvexDecompositionError> {
+    let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+    let mut ret: Vec<Vec<usize>> = Vec::new();
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let mut ear: Option<usize> = None;
+
+        for i in 0..n {
+            let a = remaining[(i + n - 1) % n];
+            let b = remaining[i];
+            let c = remaining[(i + 1) % n];
+            if turn(&vertices[a], &vertices[b], &vertices[c]) <= 0_f64 {
+                continue;
+            }
+
+            let blocked = remaining.iter().any(|j| {
+                let p = &vertices[*j];
+                return *p != vertices[a]
+                    && *p != vertices[b]
+                    && *p != vertices[c]
+                    && in_triangle(p, &vertices[a], &vertices[b], &vertices[c]);
+            });
+            if !blocked {
+                ear = Some(i);
+                break;
+            }
+        }
+
+        let i = match ear {
+            Some(i) => i,
+            None => return Err(ConvexDecompositionError::NotSimple),
+        };
+        ret.push(vec![
+            remaining[(i + n - 1) % n],
+            remaining[i],
+            remaining[(i + 1) % n],
+        ]);
+        remaining.remove(i);
+    }
+
+    if turn(
+        &vertices[remaining[0]],
+        &vertices[remaining[1]],
+        &vertices[remaining[2]],
+    ) <= 0_f64
+    {
+        return Err(ConvexDecompositionError::NotSimple);
+    }
+
+    ret.push(remaining);
+    return Ok(ret);
+}
+
+/// Repeatedly join two pieces sharing a diagonal while the union stays convex.
+fn merge_pieces(vertices: &[Vector2D], pieces: &mut Vec<Vec<usize>>) {
+    let mut merged = true;
+    while merged {
+        merged = false;
+
+        'search: for i in 0..pieces.len() {
+            for j in (i + 1)..pieces.len() {
+                if let Some(union) = join(&pieces[i], &pieces[j]) {
+                    let polygon = Polygon2D::new(union.iter().map(|k| vertices[*k]).collect());
+                    if polygon.is_convex() {
+                        pieces[i] = union;
+                        pieces.remove(j);
+                        merged = true;
+                        break 'search;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Union of two counterclockwise pieces across an edge they share, if any.
+fn join(first: &[usize], second: &[usize]) -> Option<Vec<usize>> {
+    let n = first.len();
+    let m = second.len();
+
+    for k in 0..n {
+        let a = first[k];
+        let b = first[(k + 1) % n];
+
+        // The shared edge runs in opposite directions in both pieces.
+        if let Some(l) = (0..m).find(|l| second[*l] == b && second[(l + 1) % m] == a) {
+            let mut ret: Vec<usize> = (0..n).map(|o| first[(k + 1 + o) % n]).collect();
+            ret.extend((2..m).map(|o| second[(l + o) % m]));
+            return Some(ret);
+        }
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::geometry::polygon2d::Polygon2D;
+
+    use super::{decompose_into_convex, ConvexDecompositionError};
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon2D {
+        return Polygon2D::new(
+            points
+                .iter()
+                .map(|(x, y)| Vector2D::from_xy(*x, *y))
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn decompose_polygons() {
+        let square = polygon(&[
+            (0_f64, 0_f64),
+            (0_f64, 2_f64),
+            (2_f64, 2_f64),
+            (2_f64, 0_f64),
+        ]);
+        let pieces = decompose_into_convex(&square).unwrap();
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].signed_area(), 4_f64);
+
+        let u_shape = polygon(&[
+            (0_f64, 0_f64),
+            (3_f64, 0_f64),
+            (3_f64, 3_f64),
+            (2_f64, 3_f64),
+            (2_f64, 1_f64),
+            (1_f64, 1_f64),
+            (1_f64, 3_f64),
+            (0_f64, 3_f64),
+        ]);
+        let pieces = decompose_into_convex(&u_shape).unwrap();
+        assert!(pieces.len() <= 4);
+        assert!(pieces.iter().all(|p| p.is_convex()));
+        assert_eq!(pieces.iter().map(|p| p.area()).sum::<f64>(), 7_f64);
+
+        let line = polygon(&[(0_f64, 0_f64), (1_f64, 0_f64), (2_f64, 0_f64)]);
+        assert_eq!(
+            decompose_into_convex(&line),
+            Err(ConvexDecompositionError::DegeneratePolygon)
+        );
+
+        let bowtie = polygon(&[
+            (0_f64, 0_f64),
+            (2_f64, 2_f64),
+            (2_f64, 0_f64),
+            (0_f64, 2_f64),
+        ]);
+        assert!(decompose_into_convex(&bowtie).is_err());
+    }
+}
@@ -0,0 +1 @@
+pub mod convex_decomposition_algorithm;
@@ -1,13 +1,14 @@
 use std::{cmp::Ordering, collections::LinkedList, iter::zip};
 
-use crate::math::numerics::{vector2d::Vector2D, vector3d::Vector3D};
+use core::numerics::{vector2d::Vector2D, vector3d::Vector3D};
 
+#[allow(clippy::result_unit_err)]
 pub fn get_convex_hull(points: &[Vector2D]) -> Result<Vec<Vector2D>, ()> {
     if points.len() < 3 {
         return Err(());
     }
 
-    let mut pivot_point = points.get(0).unwrap();
+    let mut pivot_point = points.first().unwrap();
     let mut pivot_point_index: usize = 0;
 
     for (i, point) in zip(0..points.len(), points.iter()) {
@@ -48,7 +49,7 @@ pub fn get_convex_hull(points: &[Vector2D]) -> Result<Vec<Vector2D>, ()> {
         z: 0_f64,
     });
 
-    for (it, point) in zip(0..sorted_points.len(), sorted_points.into_iter()) {
+    for (it, point) in zip(0..sorted_points.len(), sorted_points) {
         if it == 0 {
             stack.push_back(point);
             continue;
@@ -75,3 +76,82 @@ pub fn get_convex_hull(points: &[Vector2D]) -> Result<Vec<Vector2D>, ()> {
         stack.into_iter().map(|v| Vector2D { x: v.x, y: v.y }),
     ));
 }
+
+/// Convex hull by Andrew's monotone chain, as vertices in counterclockwise order starting
+/// from the lowest of the leftmost points. Collinear points on the hull are left out. Fewer
+/// than three distinct points, or only collinear ones, give the extreme points only.
+pub fn monotone_chain_convex_hull(points: &[Vector2D]) -> Vec<Vector2D> {
+    let mut sorted: Vec<Vector2D> = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Whether a, b, c turn counterclockwise.
+    let turns_left = |a: &Vector2D, b: &Vector2D, c: &Vector2D| -> bool {
+        return (b - a).cross(&(c - a)) > 0_f64;
+    };
+
+    let mut lower: Vec<Vector2D> = Vec::new();
+    for point in sorted.iter() {
+        while lower.len() >= 2
+            && !turns_left(&lower[lower.len() - 2], &lower[lower.len() - 1], point)
+        {
+            lower.pop();
+        }
+        lower.push(*point);
+    }
+
+    let mut upper: Vec<Vector2D> = Vec::new();
+    for point in sorted.iter().rev() {
+        while upper.len() >= 2
+            && !turns_left(&upper[upper.len() - 2], &upper[upper.len() - 1], point)
+        {
+            upper.pop();
+        }
+        upper.push(*point);
+    }
+
+    // The last point of each chain is the first of the other.
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    return lower;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::monotone_chain_convex_hull;
+
+    #[test]
+    fn monotone_chain() {
+        let points: Vec<Vector2D> = [
+            (0, 0),
+            (2, 0),
+            (1, 0),
+            (2, 2),
+            (1, 1),
+            (0, 2),
+            (1, 3),
+            (0, 1),
+            (2, 2),
+        ]
+        .iter()
+        .map(|(x, y)| Vector2D::from_xy(*x as f64, *y as f64))
+        .collect();
+
+        let hull = monotone_chain_convex_hull(&points);
+        let expected: Vec<Vector2D> = [(0, 0), (2, 0), (2, 2), (1, 3), (0, 2)]
+            .iter()
+            .map(|(x, y)| Vector2D::from_xy(*x as f64, *y as f64))
+            .collect();
+        assert_eq!(hull, expected);
+
+        let collinear = monotone_chain_convex_hull(&points[0..3]);
+        assert_eq!(collinear, vec![points[0], points[1]]);
+        assert!(monotone_chain_convex_hull(&[]).is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use core::numerics::{vector::Vector, vector2d::Vector2D};
+use std::collections::VecDeque;
+
+/// Result of DBSCAN clustering.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DbscanClusters {
+    /// Cluster of each point, or `None` for noise.
+    pub labels: Vec<Option<usize>>,
+    /// Indices of the points of each cluster, ascending. Clusters are numbered in the order of
+    /// their first point.
+    pub clusters: Vec<Vec<usize>>,
+}
+
+impl DbscanClusters {
+    /// Indices of the points in no cluster, ascending.
+    pub fn noise(&self) -> Vec<usize> {
+        return (0..self.labels.len())
+            .filter(|i| self.labels[*i].is_none())
+            .collect();
+    }
+}
+
+/// DBSCAN with the Euclidean distance, e.g. to group frontier cells or scan endpoints.
+pub fn dbscan(points: &[Vector2D], epsilon: f64, min_points: usize) -> DbscanClusters {
+    return dbscan_with_metric(points, epsilon, min_points, |a, b| (a - b).magnitude());
+}
+
+/// Density-based clustering by Ester et al. A point with at least min_points points within
+/// epsilon of it, itself included, is a core point; clusters are the core points connected
+/// through each other's neighborhoods together with the points in those neighborhoods. Other
+/// points are noise. distance_fn gives the distance between two points and must be symmetric,
+/// e.g. a Manhattan distance or one penalizing vertical offsets.
+pub fn dbscan_with_metric<F>(
+    points: &[Vector2D],
+    epsilon: f64,
+    min_points: usize,
+    distance_fn: F,
+) -> DbscanClusters
+where
+    F: Fn(&Vector2D, &Vector2D) -> f64,
+{
+    let neighbors = |i: usize| -> Vec<usize> {
+        return (0..points.len())
+            .filter(|j| distance_fn(&points[i], &points[*j]) <= epsilon)
+            .collect();
+    };
+
+    let mut labels: Vec<Option<usize>> = vec![None; points.len()];
+    let mut visited: Vec<bool> = vec![false; points.len()];
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+
+    for i in 0..points.len() {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+
+        let seeds = neighbors(i);
+        if seeds.len() < min_points {
+            continue;
+        }
+
+        let cluster = clusters.len();
+        let mut members: Vec<usize> = vec![i];
+        labels[i] = Some(cluster);
+        let mut queue: VecDeque<usize> = VecDeque::from(seeds);
+
+        while let Some(j) = queue.pop_front() {
+            // Border points claimed as noise before join the cluster, but do not expand it.
+            if labels[j].is_none() {
+                labels[j] = Some(cluster);
+                members.push(j);
+            }
+            if visited[j] {
+                continue;
+            }
+            visited[j] = true;
+
+            let expansion = neighbors(j);
+            if expansion.len() >= min_points {
+                queue.extend(expansion);
+            }
+        }
+
+        members.sort();
+        clusters.push(members);
+    }
+
+    return DbscanClusters {
+        labels: labels,
+        clusters: clusters,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::{dbscan, dbscan_with_metric};
+
+    #[test]
+    fn clusters_dense_groups() {
+        let mut points: Vec<Vector2D> = Vec::new();
+        for i in 0..5 {
+            points.push(Vector2D::from_xy(i as f64 * 0.5, 0_f64));
+            points.push(Vector2D::from_xy(10_f64, i as f64 * 0.5));
+        }
+        points.push(Vector2D::from_xy(5_f64, 5_f64));
+        // Border point within reach of the end of the first group only.
+        points.push(Vector2D::from_xy(2.5, 0_f64));
+
+        let result = dbscan(&points, 0.6, 3);
+        assert_eq!(result.clusters.len(), 2);
+        assert_eq!(result.clusters[0], vec![0, 2, 4, 6, 8, 11]);
+        assert_eq!(result.clusters[1], vec![1, 3, 5, 7, 9]);
+        assert_eq!(result.noise(), vec![10]);
+        assert_eq!(result.labels[11], Some(0));
+
+        // With the Chebyshev distance and a large epsilon, everything forms one cluster.
+        let chebyshev = |a: &Vector2D, b: &Vector2D| (a.x - b.x).abs().max((a.y - b.y).abs());
+        let result = dbscan_with_metric(&points, 7.5, 3, chebyshev);
+        assert_eq!(result.clusters.len(), 1);
+        assert!(result.noise().is_empty());
+    }
+}
@@ -0,0 +1 @@
+pub mod dbscan_algorithm;
@@ -0,0 +1,324 @@
+use core::{
+    collections::kd_tree::KdTree,
+    numerics::{
+        matrix3::Matrix3,
+        pose2d::{normalize_angle, Pose2D},
+        vector::Vector,
+        vector2d::Vector2D,
+        vector3d::Vector3D,
+    },
+};
+use std::{f64::consts::FRAC_PI_2, fmt};
+
+use crate::statistics::covariance;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IcpError {
+    /// The scan or the reference has too few points to determine a pose.
+    InsufficientPoints,
+    /// Too few scan points are within the correspondence distance of the reference.
+    NoCorrespondences,
+}
+
+impl fmt::Display for IcpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            IcpError::InsufficientPoints => write!(f, "too few points to align"),
+            IcpError::NoCorrespondences => write!(f, "too few corresponding points"),
+        };
+    }
+}
+
+impl std::error::Error for IcpError {}
+
+/// Error minimized by `Icp`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IcpMetric {
+    /// Distance between corresponding points.
+    PointToPoint,
+    /// Distance along the reference normal, letting scan points slide along walls. Usually
+    /// converges in fewer iterations on structured environments.
+    PointToPlane,
+}
+
+/// Parameters of `Icp`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IcpOptions {
+    pub metric: IcpMetric,
+    pub max_iterations: usize,
+
+    /// Scan points farther than this from the reference are ignored as outliers.
+    pub max_correspondence_distance: f64,
+
+    /// Iteration stops once a step moves less than this, in meters.
+    pub translation_tolerance: f64,
+
+    /// Iteration stops once a step turns less than this, in radians.
+    pub rotation_tolerance: f64,
+
+    /// Number of reference points fitted to estimate each reference normal.
+    pub normal_neighbors: usize,
+}
+
+impl Default for IcpOptions {
+    fn default() -> Self {
+        return Self {
+            metric: IcpMetric::PointToPlane,
+            max_iterations: 30,
+            max_correspondence_distance: 1_f64,
+            translation_tolerance: 1e-6,
+            rotation_tolerance: 1e-6,
+            normal_neighbors: 5,
+        };
+    }
+}
+
+/// Outcome of `Icp::align`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IcpResult {
+    /// Pose of the scan frame in the reference frame.
+    pub pose: Pose2D,
+
+    /// Fraction of scan points within the correspondence distance of the reference.
+    pub fitness: f64,
+
+    /// Root mean square distance of the corresponding scan points to the reference.
+    pub rmse: f64,
+    pub iterations: usize,
+
+    /// Whether the steps fell below the tolerances before the iteration limit.
+    pub converged: bool,
+}
+
+/// Iterative closest point alignment of scans against a fixed reference point set, e.g.
+/// points sampled from the walls of a map or a previous scan. The reference is indexed
+/// once, so many scans can be aligned against it.
+pub struct Icp {
+    options: IcpOptions,
+    reference: Vec<Vector2D>,
+    normals: Vec<Vector2D>,
+    index: KdTree<usize>,
+}
+
+impl Icp {
+    pub fn new(reference: Vec<Vector2D>, options: IcpOptions) -> Self {
+        let index =
+            KdTree::from_points(reference.iter().copied().zip(0..reference.len()).collect());
+        let normals: Vec<Vector2D> = reference
+            .iter()
+            .map(|p| {
+                let neighbors: Vec<Vector2D> = index
+                    .k_nearest(p, options.normal_neighbors)
+                    .into_iter()
+                    .map(|(q, _)| *q)
+                    .collect();
+                return estimate_normal(&neighbors);
+            })
+            .collect();
+
+        return Self {
+            options: options,
+            reference: reference,
+            normals: normals,
+            index: index,
+        };
+    }
+
+    /// Refine initial_pose, the estimated pose of the scan frame in the reference frame,
+    /// so that the scan points given in the scan frame match the reference.
+    pub fn align(&self, scan: &[Vector2D], initial_pose: &Pose2D) -> Result<IcpResult, IcpError> {
+        let min_points = match self.options.metric {
+            IcpMetric::PointToPoint => 2,
+            IcpMetric::PointToPlane => 3,
+        };
+        if scan.len() < min_points || self.reference.len() < min_points {
+            return Err(IcpError::InsufficientPoints);
+        }
+
+        let mut pose = *initial_pose;
+        let mut iterations = 0;
+        let mut converged = false;
+        while iterations < self.options.max_iterations {
+            iterations += 1;
+            let pairs = self.correspondences(scan, &pose);
+            if pairs.len() < min_points {
+                return Err(IcpError::NoCorrespondences);
+            }
+
+            let step = match self.options.metric {
+                IcpMetric::PointToPoint => self.point_to_point_step(&pairs),
+                IcpMetric::PointToPlane => self
+                    .point_to_plane_step(&pairs)
+                    .unwrap_or_else(|| self.point_to_point_step(&pairs)),
+            };
+            pose = step.compose(&pose);
+            pose.theta = normalize_angle(pose.theta);
+
+            if step.position.magnitude() < self.options.translation_tolerance
+                && step.theta.abs() < self.options.rotation_tolerance
+            {
+                converged = true;
+                break;
+            }
+        }
+
+        let pairs = self.correspondences(scan, &pose);
+        if pairs.is_empty() {
+            return Err(IcpError::NoCorrespondences);
+        }
+        let squared_error: f64 = pairs
+            .iter()
+            .map(|(p, i)| {
+                let d = p - self.reference[*i];
+                return d.dot(&d);
+            })
+            .sum();
+
+        return Ok(IcpResult {
+            pose: pose,
+            fitness: pairs.len() as f64 / scan.len() as f64,
+            rmse: (squared_error / pairs.len() as f64).sqrt(),
+            iterations: iterations,
+            converged: converged,
+        });
+    }
+
+    /// Scan points mapped into the reference frame, with the index of their closest
+    /// reference point, if within the correspondence distance.
+    fn correspondences(&self, scan: &[Vector2D], pose: &Pose2D) -> Vec<(Vector2D, usize)> {
+        return scan
+            .iter()
+            .filter_map(|p| {
+                let p = pose.transform_point(p);
+                let (q, i) = self.index.nearest(&p)?;
+                if (q - p).magnitude() > self.options.max_correspondence_distance {
+                    return None;
+                }
+                return Some((p, *i));
+            })
+            .collect();
+    }
+
+    /// Rigid motion minimizing the squared distances between the pairs, in closed form.
+    fn point_to_point_step(&self, pairs: &[(Vector2D, usize)]) -> Pose2D {
+        let n = pairs.len() as f64;
+        let source_mean = pairs.iter().fold(Vector2D::zero(), |sum, (p, _)| sum + p) / n;
+        let target_mean = pairs
+            .iter()
+            .fold(Vector2D::zero(), |sum, (_, i)| sum + self.reference[*i])
+            / n;
+
+        let (mut sin_sum, mut cos_sum) = (0_f64, 0_f64);
+        for (p, i) in pairs.iter() {
+            let (a, b) = (p - source_mean, self.reference[*i] - target_mean);
+            sin_sum += a.cross(&b);
+            cos_sum += a.dot(&b);
+        }
+
+        let theta = sin_sum.atan2(cos_sum);
+        return Pose2D::new(target_mean - source_mean.rotate(theta), theta);
+    }
+
+    /// Rigid motion minimizing the squared distances along the reference normals, with the
+    /// rotation linearized. Returns `None` if the pairs do not constrain every direction,
+    /// e.g. along a single straight wall.
+    fn point_to_plane_step(&self, pairs: &[(Vector2D, usize)]) -> Option<Pose2D> {
+        let mut a = [[0_f64; 3]; 3];
+        let mut b = [0_f64; 3];
+        for (p, i) in pairs.iter() {
+            let normal = self.normals[*i];
+            let jacobian = [p.perpendicular().dot(&normal), normal.x, normal.y];
+            let residual = (p - self.reference[*i]).dot(&normal);
+            for r in 0..3 {
+                for c in 0..3 {
+                    a[r][c] += jacobian[r] * jacobian[c];
+                }
+                b[r] -= jacobian[r] * residual;
+            }
+        }
+
+        let x = Matrix3::from_rows(a).inverse()? * Vector3D::from_xyz(b[0], b[1], b[2]);
+        if !(x.x.is_finite() && x.y.is_finite() && x.z.is_finite()) {
+            return None;
+        }
+        return Some(Pose2D::from_xytheta(x.y, x.z, x.x));
+    }
+}
+
+/// Unit normal of the line best fitting points, perpendicular to their principal axis.
+fn estimate_normal(points: &[Vector2D]) -> Vector2D {
+    let matrix = match covariance(points) {
+        Some(m) => m,
+        None => return Vector2D::from_xy(1_f64, 0_f64),
+    };
+
+    let r = &matrix.rows;
+    let axis = 0.5 * (2_f64 * r[0][1]).atan2(r[0][0] - r[1][1]);
+    return Vector2D::from_angle(axis + FRAC_PI_2);
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+
+    use super::{Icp, IcpError, IcpMetric, IcpOptions};
+
+    #[test]
+    fn align_room() {
+        // Walls of an 8 m x 5 m room with a pillar, sampled every 0.1 m.
+        let mut reference: Vec<Vector2D> = Vec::new();
+        for i in 0..80 {
+            let x = i as f64 * 0.1;
+            reference.push(Vector2D::from_xy(x, 0_f64));
+            reference.push(Vector2D::from_xy(x, 5_f64));
+        }
+        for i in 0..50 {
+            let y = i as f64 * 0.1;
+            reference.push(Vector2D::from_xy(0_f64, y));
+            reference.push(Vector2D::from_xy(8_f64, y));
+        }
+        for i in 0..10 {
+            reference.push(Vector2D::from_xy(5_f64, 2_f64 + i as f64 * 0.1));
+        }
+
+        // Initial guess off by a centimeter and a tenth of a degree, e.g. from odometry.
+        let truth = Pose2D::from_xytheta(0.3, -0.2, 0.08);
+        let guess = Pose2D::from_xytheta(0.31, -0.19, 0.078);
+        let scan: Vec<Vector2D> = reference
+            .iter()
+            .step_by(3)
+            .map(|p| truth.inverse().transform_point(p))
+            .collect();
+
+        for metric in [IcpMetric::PointToPoint, IcpMetric::PointToPlane] {
+            let icp = Icp::new(
+                reference.clone(),
+                IcpOptions {
+                    metric: metric,
+                    max_iterations: 100,
+                    ..IcpOptions::default()
+                },
+            );
+            let result = icp.align(&scan, &guess).unwrap();
+            assert!(result.converged, "{:?}", metric);
+            assert!((result.pose.position - truth.position).magnitude() < 1e-3);
+            assert!((result.pose.theta - truth.theta).abs() < 1e-3);
+            assert_eq!(result.fitness, 1_f64);
+            assert!(result.rmse < 1e-3);
+        }
+
+        // Sliding along the walls, point to plane converges even without a guess.
+        let icp = Icp::new(reference, IcpOptions::default());
+        let result = icp.align(&scan, &Pose2D::identity()).unwrap();
+        assert!((result.pose.position - truth.position).magnitude() < 1e-3);
+
+        assert_eq!(
+            icp.align(&scan[..2], &Pose2D::identity()),
+            Err(IcpError::InsufficientPoints)
+        );
+        assert_eq!(
+            icp.align(&scan, &Pose2D::from_xytheta(100_f64, 0_f64, 0_f64)),
+            Err(IcpError::NoCorrespondences)
+        );
+    }
+}
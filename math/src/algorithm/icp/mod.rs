@@ -0,0 +1 @@
+pub mod icp_algorithm;
@@ -0,0 +1,220 @@
+use core::numerics::{vector2d::Vector2D, vector2i::Vector2I};
+
+/// Relative tolerance for a segment passing exactly through a cell corner.
+const CORNER_EPSILON: f64 = 1e-9;
+
+/// Cells of the Bresenham line between two cells, both included. Consecutive cells are
+/// 8-connected and there is exactly one cell per step along the major axis, which suits
+/// rasterizing lines, e.g. drawing into a map.
+pub struct BresenhamLine {
+    current: Vector2I,
+    end: Vector2I,
+    delta: Vector2I,
+    step: Vector2I,
+    error: i64,
+    done: bool,
+}
+
+impl BresenhamLine {
+    pub fn new(from: Vector2I, to: Vector2I) -> Self {
+        let delta = Vector2I::from_xy((to.x - from.x).abs(), -(to.y - from.y).abs());
+        return Self {
+            current: from,
+            end: to,
+            delta: delta,
+            step: Vector2I::from_xy((to.x - from.x).signum(), (to.y - from.y).signum()),
+            error: delta.x + delta.y,
+            done: false,
+        };
+    }
+}
+
+impl Iterator for BresenhamLine {
+    type Item = Vector2I;
+
+    fn next(&mut self) -> Option<Vector2I> {
+        if self.done {
+            return None;
+        }
+
+        let ret = self.current;
+        if self.current == self.end {
+            self.done = true;
+            return Some(ret);
+        }
+
+        let doubled_error = 2 * self.error;
+        if doubled_error >= self.delta.y {
+            self.error += self.delta.y;
+            self.current.x += self.step.x;
+        }
+        if doubled_error <= self.delta.x {
+            self.error += self.delta.x;
+            self.current.y += self.step.y;
+        }
+
+        return Some(ret);
+    }
+}
+
+/// Progress along one axis while walking the cells crossed by a segment.
+struct TraversalAxis {
+    step: i64,
+    t_delta: f64,
+    /// Fraction of the segment at which the next cell border on this axis is crossed.
+    t_max: f64,
+}
+
+impl TraversalAxis {
+    /// Axis of a segment starting at start (in cells) and extending by delta.
+    fn new(start: f64, delta: f64) -> Self {
+        if delta == 0_f64 {
+            return Self {
+                step: 0,
+                t_delta: f64::INFINITY,
+                t_max: f64::INFINITY,
+            };
+        }
+
+        let border = if delta > 0_f64 {
+            start.floor() + 1_f64
+        } else {
+            start.floor()
+        };
+        return Self {
+            step: delta.signum() as i64,
+            t_delta: 1_f64 / delta.abs(),
+            t_max: (border - start) / delta,
+        };
+    }
+}
+
+/// Every cell a segment touches, in the order it enters them, with cells being unit squares
+/// and cell (x, y) covering [x, x + 1) x [y, y + 1). Where the segment passes exactly
+/// through a cell corner, both cells beside the corner are included before the diagonal one,
+/// so the cells are 4-connected and none the segment touches is missed, e.g. for line of
+/// sight checks and raytracing.
+pub struct SupercoverLine {
+    cell: Vector2I,
+    axis_x: TraversalAxis,
+    axis_y: TraversalAxis,
+    /// Borders still to be crossed.
+    remaining: i64,
+    /// Cells to return before crossing the next border, in reverse order.
+    pending: Vec<Vector2I>,
+}
+
+impl SupercoverLine {
+    /// Cells touched by the segment between two points given in cell units.
+    pub fn new(from: Vector2D, to: Vector2D) -> Self {
+        let cell = Vector2I::from_xy(from.x.floor() as i64, from.y.floor() as i64);
+        let end = Vector2I::from_xy(to.x.floor() as i64, to.y.floor() as i64);
+        return Self {
+            cell: cell,
+            axis_x: TraversalAxis::new(from.x, to.x - from.x),
+            axis_y: TraversalAxis::new(from.y, to.y - from.y),
+            remaining: (end.x - cell.x).abs() + (end.y - cell.y).abs(),
+            pending: vec![cell],
+        };
+    }
+
+    /// Cells touched by the segment between the centers of two cells.
+    pub fn between_cells(from: Vector2I, to: Vector2I) -> Self {
+        return SupercoverLine::new(
+            Vector2D::from_xy(from.x as f64 + 0.5, from.y as f64 + 0.5),
+            Vector2D::from_xy(to.x as f64 + 0.5, to.y as f64 + 0.5),
+        );
+    }
+}
+
+impl Iterator for SupercoverLine {
+    type Item = Vector2I;
+
+    fn next(&mut self) -> Option<Vector2I> {
+        if let Some(cell) = self.pending.pop() {
+            return Some(cell);
+        }
+        if self.remaining <= 0 {
+            return None;
+        }
+
+        let (t_x, t_y) = (self.axis_x.t_max, self.axis_y.t_max);
+        let corner = self.remaining >= 2
+            && t_x.is_finite()
+            && t_y.is_finite()
+            && (t_x - t_y).abs() <= CORNER_EPSILON * t_x.max(t_y).max(1_f64);
+
+        if corner {
+            let side_x = Vector2I::from_xy(self.cell.x + self.axis_x.step, self.cell.y);
+            let side_y = Vector2I::from_xy(self.cell.x, self.cell.y + self.axis_y.step);
+            self.cell = Vector2I::from_xy(side_x.x, side_y.y);
+            self.axis_x.t_max += self.axis_x.t_delta;
+            self.axis_y.t_max += self.axis_y.t_delta;
+            self.remaining -= 2;
+            self.pending = vec![self.cell, side_y];
+            return Some(side_x);
+        }
+
+        if t_x < t_y {
+            self.cell.x += self.axis_x.step;
+            self.axis_x.t_max += self.axis_x.t_delta;
+        } else {
+            self.cell.y += self.axis_y.step;
+            self.axis_y.t_max += self.axis_y.t_delta;
+        }
+        self.remaining -= 1;
+
+        return Some(self.cell);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector2d::Vector2D, vector2i::Vector2I};
+
+    use super::{BresenhamLine, SupercoverLine};
+
+    fn cells(list: &[(i64, i64)]) -> Vec<Vector2I> {
+        return list
+            .iter()
+            .map(|(x, y)| Vector2I::from_xy(*x, *y))
+            .collect();
+    }
+
+    #[test]
+    fn traverses_cells() {
+        let line: Vec<Vector2I> =
+            BresenhamLine::new(Vector2I::from_xy(0, 0), Vector2I::from_xy(5, 2)).collect();
+        assert_eq!(
+            line,
+            cells(&[(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)])
+        );
+        let steep: Vec<Vector2I> =
+            BresenhamLine::new(Vector2I::from_xy(1, 1), Vector2I::from_xy(-1, -5)).collect();
+        assert_eq!(steep.len(), 7);
+        assert_eq!(steep.last(), Some(&Vector2I::from_xy(-1, -5)));
+        assert_eq!(
+            BresenhamLine::new(Vector2I::from_xy(3, 3), Vector2I::from_xy(3, 3)).count(),
+            1
+        );
+
+        // Through the corners of the diagonal cells.
+        let diagonal: Vec<Vector2I> =
+            SupercoverLine::between_cells(Vector2I::from_xy(0, 0), Vector2I::from_xy(2, 2))
+                .collect();
+        assert_eq!(
+            diagonal,
+            cells(&[(0, 0), (1, 0), (0, 1), (1, 1), (2, 1), (1, 2), (2, 2)])
+        );
+
+        let line: Vec<Vector2I> =
+            SupercoverLine::new(Vector2D::from_xy(0.2, 0.5), Vector2D::from_xy(-2.7, 1.2))
+                .collect();
+        assert_eq!(line, cells(&[(0, 0), (-1, 0), (-2, 0), (-2, 1), (-3, 1)]));
+
+        let horizontal: Vec<Vector2I> =
+            SupercoverLine::between_cells(Vector2I::from_xy(0, 0), Vector2I::from_xy(3, 0))
+                .collect();
+        assert_eq!(horizontal, cells(&[(0, 0), (1, 0), (2, 0), (3, 0)]));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod convex_decomposition;
+pub mod convex_hull;
+pub mod dbscan;
+pub mod icp;
+pub mod line_traversal;
+#[cfg(feature = "rand")]
+pub mod ransac_line;
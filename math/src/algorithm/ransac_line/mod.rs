@@ -0,0 +1 @@
+pub mod ransac_line_algorithm;
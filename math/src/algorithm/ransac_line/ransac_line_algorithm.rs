@@ -0,0 +1,164 @@
+use core::numerics::vector2d::Vector2D;
+
+use rand::Rng;
+
+use crate::{
+    geometry::line_segment2d::LineSegment2D,
+    statistics::{covariance, mean},
+};
+
+/// Parameters of `extract_lines`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RansacLineOptions {
+    /// Candidate lines drawn per extracted line.
+    pub max_iterations: usize,
+
+    /// Largest distance of an inlier from its line.
+    pub inlier_threshold: f64,
+
+    /// Fewest inliers of an extracted line. Extraction stops when no line has as many.
+    pub min_inliers: usize,
+
+    /// Upper bound on the extracted lines.
+    pub max_lines: usize,
+}
+
+impl Default for RansacLineOptions {
+    fn default() -> Self {
+        return Self {
+            max_iterations: 100,
+            inlier_threshold: 0.05,
+            min_inliers: 10,
+            max_lines: 10,
+        };
+    }
+}
+
+/// Line found among the points, e.g. a wall in a laser scan.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineFit {
+    /// Fitted line clipped to the extent of its inliers.
+    pub segment: LineSegment2D,
+    /// Indices of the points on the line, ascending.
+    pub inliers: Vec<usize>,
+}
+
+/// Extract lines one after another by RANSAC: the line through two random points with the most
+/// points near it is refitted to those points by total least squares, and they are removed
+/// before searching for the next line. Lines are returned with the most inliers first.
+pub fn extract_lines<R: Rng>(
+    rng: &mut R,
+    points: &[Vector2D],
+    options: &RansacLineOptions,
+) -> Vec<LineFit> {
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut ret: Vec<LineFit> = Vec::new();
+
+    while ret.len() < options.max_lines && remaining.len() >= options.min_inliers.max(2) {
+        let mut best: Vec<usize> = Vec::new();
+        for _ in 0..options.max_iterations {
+            let a = remaining[rng.gen_range(0..remaining.len())];
+            let b = remaining[rng.gen_range(0..remaining.len())];
+            if points[a] == points[b] {
+                continue;
+            }
+
+            let direction = (points[b] - points[a]).unit_vector();
+            let inliers = inliers_of(points, &remaining, &points[a], &direction, options);
+            if inliers.len() > best.len() {
+                best = inliers;
+            }
+        }
+        if best.len() < options.min_inliers.max(2) {
+            break;
+        }
+
+        let (origin, direction) = fit_line(points, &best);
+        let inliers = inliers_of(points, &remaining, &origin, &direction, options);
+        let inliers = if inliers.len() >= best.len() {
+            inliers
+        } else {
+            best
+        };
+
+        let extent = |i: &usize| -> f64 { (points[*i] - origin).dot(&direction) };
+        let min = inliers.iter().map(extent).fold(f64::INFINITY, f64::min);
+        let max = inliers.iter().map(extent).fold(f64::NEG_INFINITY, f64::max);
+        remaining.retain(|i| inliers.binary_search(i).is_err());
+        ret.push(LineFit {
+            segment: LineSegment2D::new(origin + direction * min, origin + direction * max),
+            inliers: inliers,
+        });
+    }
+
+    return ret;
+}
+
+/// Points among candidates within the inlier threshold of the line, ascending.
+fn inliers_of(
+    points: &[Vector2D],
+    candidates: &[usize],
+    origin: &Vector2D,
+    direction: &Vector2D,
+    options: &RansacLineOptions,
+) -> Vec<usize> {
+    return candidates
+        .iter()
+        .copied()
+        .filter(|i| (points[*i] - origin).cross(direction).abs() <= options.inlier_threshold)
+        .collect();
+}
+
+/// Total least squares line through the points, as a point on it and its unit direction.
+fn fit_line(points: &[Vector2D], indices: &[usize]) -> (Vector2D, Vector2D) {
+    let selected: Vec<Vector2D> = indices.iter().map(|i| points[*i]).collect();
+    let center = mean(&selected).unwrap();
+    let rows = covariance(&selected).unwrap().rows;
+
+    // Direction of the principal axis of the covariance.
+    let angle = 0.5 * (2_f64 * rows[0][1]).atan2(rows[0][0] - rows[1][1]);
+    return (center, Vector2D::from_angle(angle));
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{extract_lines, RansacLineOptions};
+
+    #[test]
+    fn extracts_walls() {
+        // Two walls meeting at a corner, with noise and scattered outliers.
+        let mut points: Vec<Vector2D> = Vec::new();
+        for i in 0..50 {
+            let noise = ((i * 7919) % 11) as f64 * 0.002 - 0.01;
+            points.push(Vector2D::from_xy(i as f64 * 0.1, noise));
+            points.push(Vector2D::from_xy(noise, 1_f64 + i as f64 * 0.05));
+        }
+        for i in 0..10 {
+            points.push(Vector2D::from_xy(
+                1_f64 + i as f64 * 0.3,
+                1_f64 + (i % 3) as f64,
+            ));
+        }
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let lines = extract_lines(&mut rng, &points, &RansacLineOptions::default());
+        assert_eq!(lines.len(), 2);
+
+        // The wall points at the corner may go to either line.
+        assert_eq!(lines[0].inliers.len() + lines[1].inliers.len(), 100);
+        for line in lines.iter() {
+            assert!(line.inliers.len() >= 49);
+            let direction = (line.segment.end - line.segment.start).unit_vector();
+            assert!(direction.x.abs() > 0.999 || direction.y.abs() > 0.999);
+        }
+        let horizontal = lines
+            .iter()
+            .find(|l| (l.segment.end.y - l.segment.start.y).abs() < 0.1)
+            .unwrap();
+        assert!(horizontal.segment.length() > 4.75);
+    }
+}
@@ -0,0 +1,51 @@
+use core::numerics::pose2d;
+use std::f64::consts::PI;
+
+/// Wrap an angle (radians) into (-pi, pi].
+pub fn normalize(angle: f64) -> f64 {
+    return pose2d::normalize_angle(angle);
+}
+
+/// Wrap an angle (radians) into [0, 2 pi).
+pub fn normalize_positive(angle: f64) -> f64 {
+    return angle.rem_euclid(2_f64 * PI);
+}
+
+/// Signed rotation in (-pi, pi] which turns `from` into `to` the short way round.
+pub fn shortest_difference(from: f64, to: f64) -> f64 {
+    return normalize(to - from);
+}
+
+/// Interpolate between two angles along the shorter arc, with t = 0 giving `from` and t = 1 `to`.
+/// The result is normalized.
+pub fn lerp(from: f64, to: f64, t: f64) -> f64 {
+    return normalize(from + shortest_difference(from, to) * t);
+}
+
+pub fn deg_to_rad(degrees: f64) -> f64 {
+    return degrees.to_radians();
+}
+
+pub fn rad_to_deg(radians: f64) -> f64 {
+    return radians.to_degrees();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{deg_to_rad, lerp, normalize, normalize_positive, rad_to_deg, shortest_difference};
+
+    #[test]
+    fn wraps_around() {
+        assert_eq!(normalize(-PI), PI);
+        assert!((normalize(deg_to_rad(270_f64)) - deg_to_rad(-90_f64)).abs() < 1e-9);
+        assert!((normalize_positive(deg_to_rad(-90_f64)) - deg_to_rad(270_f64)).abs() < 1e-9);
+
+        let (from, to) = (deg_to_rad(170_f64), deg_to_rad(-170_f64));
+        assert!((rad_to_deg(shortest_difference(from, to)) - 20_f64).abs() < 1e-9);
+        assert!((rad_to_deg(shortest_difference(to, from)) + 20_f64).abs() < 1e-9);
+        assert!((lerp(from, to, 0.5) - PI).abs() < 1e-9);
+        assert!((rad_to_deg(lerp(from, to, 0.75)) + 175_f64).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,368 @@
+use core::numerics::{
+    matrix3::Matrix3,
+    pose2d::{normalize_angle, Pose2D},
+    vector2d::Vector2D,
+};
+use std::fmt;
+
+use crate::motion::kinematic_model::DifferentialDriveControl;
+
+/// Matrix of R rows and C columns, stored row by row.
+pub type Matrix<const R: usize, const C: usize> = [[f64; C]; R];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KalmanFilterError {
+    /// The innovation covariance is singular, e.g. a measurement without noise of a state
+    /// that is known exactly.
+    SingularInnovation,
+}
+
+impl fmt::Display for KalmanFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            KalmanFilterError::SingularInnovation => {
+                write!(f, "innovation covariance is singular")
+            }
+        };
+    }
+}
+
+impl std::error::Error for KalmanFilterError {}
+
+/// Extended Kalman filter of a state of N values with Gaussian uncertainty. The models are
+/// given per call as functions returning their value and Jacobian at the current state, so
+/// one filter can take several kinds of measurements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendedKalmanFilter<const N: usize> {
+    pub state: [f64; N],
+    pub covariance: Matrix<N, N>,
+}
+
+impl<const N: usize> ExtendedKalmanFilter<N> {
+    pub fn new(state: [f64; N], covariance: Matrix<N, N>) -> Self {
+        return Self {
+            state: state,
+            covariance: covariance,
+        };
+    }
+
+    /// Propagate the state through model, returning the next state and its Jacobian
+    /// with respect to the state, and add the process noise covariance.
+    pub fn predict<F>(&mut self, model: F, process_noise: &Matrix<N, N>)
+    where
+        F: FnOnce(&[f64; N]) -> ([f64; N], Matrix<N, N>),
+    {
+        let (state, jacobian) = model(&self.state);
+        self.state = state;
+        self.covariance = add(
+            &multiply(
+                &multiply(&jacobian, &self.covariance),
+                &transpose(&jacobian),
+            ),
+            process_noise,
+        );
+    }
+
+    /// Correct the state with a measurement of M values, where model returns the expected
+    /// measurement and its Jacobian with respect to the state. The covariance is updated in
+    /// Joseph form, which keeps it symmetric and positive semi-definite.
+    pub fn update<const M: usize, H>(
+        &mut self,
+        measurement: &[f64; M],
+        model: H,
+        measurement_noise: &Matrix<M, M>,
+    ) -> Result<(), KalmanFilterError>
+    where
+        H: FnOnce(&[f64; N]) -> ([f64; M], Matrix<M, N>),
+    {
+        let (expected, jacobian) = model(&self.state);
+        let jacobian_t = transpose(&jacobian);
+        let p_ht = multiply(&self.covariance, &jacobian_t);
+        let innovation_covariance = add(&multiply(&jacobian, &p_ht), measurement_noise);
+        let gain = match inverse(&innovation_covariance) {
+            Some(inv) => multiply(&p_ht, &inv),
+            None => return Err(KalmanFilterError::SingularInnovation),
+        };
+
+        for i in 0..N {
+            for j in 0..M {
+                self.state[i] += gain[i][j] * (measurement[j] - expected[j]);
+            }
+        }
+
+        let mut i_kh = multiply(&gain, &jacobian);
+        for (i, row) in i_kh.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = if i == j { 1_f64 } else { 0_f64 } - *value;
+            }
+        }
+        self.covariance = add(
+            &multiply(&multiply(&i_kh, &self.covariance), &transpose(&i_kh)),
+            &multiply(&multiply(&gain, measurement_noise), &transpose(&gain)),
+        );
+        return Ok(());
+    }
+}
+
+/// Noise of the odometry of `DifferentialDriveEkf`, as standard deviations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OdometryNoise {
+    /// Error of the distance driven, per meter.
+    pub translation_per_meter: f64,
+    /// Error of the rotation, per radian turned.
+    pub rotation_per_radian: f64,
+    /// Error of the rotation, per meter driven, e.g. from wheel slip.
+    pub rotation_per_meter: f64,
+}
+
+impl Default for OdometryNoise {
+    fn default() -> Self {
+        return Self {
+            translation_per_meter: 0.05,
+            rotation_per_radian: 0.05,
+            rotation_per_meter: 0.02,
+        };
+    }
+}
+
+/// Pose filter of a differential drive robot with state (x, y, theta), predicting from
+/// odometry and correcting with absolute headings, e.g. from a compass or gyroscope, and
+/// positions, e.g. from scan matching.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DifferentialDriveEkf {
+    filter: ExtendedKalmanFilter<3>,
+    noise: OdometryNoise,
+}
+
+impl DifferentialDriveEkf {
+    pub fn new(pose: Pose2D, covariance: Matrix3, noise: OdometryNoise) -> Self {
+        return Self {
+            filter: ExtendedKalmanFilter::new(
+                [pose.position.x, pose.position.y, pose.theta],
+                covariance.rows,
+            ),
+            noise: noise,
+        };
+    }
+
+    pub fn pose(&self) -> Pose2D {
+        let [x, y, theta] = self.filter.state;
+        return Pose2D::from_xytheta(x, y, theta);
+    }
+
+    /// Covariance of (x, y, theta).
+    pub fn covariance(&self) -> Matrix3 {
+        return Matrix3::from_rows(self.filter.covariance);
+    }
+
+    /// Move by the odometry velocities held for dt seconds, along the heading halfway
+    /// through the turn.
+    pub fn predict(&mut self, control: &DifferentialDriveControl, dt: f64) {
+        let (distance, rotation) = (control.linear * dt, control.angular * dt);
+        let translation_std_dev = self.noise.translation_per_meter * distance.abs();
+        let rotation_std_dev = self.noise.rotation_per_radian * rotation.abs()
+            + self.noise.rotation_per_meter * distance.abs();
+
+        let [_, _, theta] = self.filter.state;
+        let (sin, cos) = (theta + rotation / 2_f64).sin_cos();
+
+        // Jacobian of the motion with respect to (distance, rotation), mapping the odometry
+        // noise into the state.
+        let g: Matrix<3, 2> = [
+            [cos, -distance * sin / 2_f64],
+            [sin, distance * cos / 2_f64],
+            [0_f64, 1_f64],
+        ];
+        let odometry_covariance: Matrix<2, 2> = [
+            [translation_std_dev * translation_std_dev, 0_f64],
+            [0_f64, rotation_std_dev * rotation_std_dev],
+        ];
+        let process_noise = multiply(&multiply(&g, &odometry_covariance), &transpose(&g));
+
+        self.filter.predict(
+            |s| {
+                let state = [
+                    s[0] + distance * cos,
+                    s[1] + distance * sin,
+                    normalize_angle(s[2] + rotation),
+                ];
+                let jacobian = [
+                    [1_f64, 0_f64, -distance * sin],
+                    [0_f64, 1_f64, distance * cos],
+                    [0_f64, 0_f64, 1_f64],
+                ];
+                return (state, jacobian);
+            },
+            &process_noise,
+        );
+    }
+
+    /// Correct with an absolute heading. The difference to the estimate is wrapped, so
+    /// headings on either side of pi agree.
+    pub fn update_heading(&mut self, heading: f64, std_dev: f64) -> Result<(), KalmanFilterError> {
+        let theta = self.filter.state[2];
+        let measurement = [theta + normalize_angle(heading - theta)];
+        let ret = self.filter.update(
+            &measurement,
+            |s| ([s[2]], [[0_f64, 0_f64, 1_f64]]),
+            &[[std_dev * std_dev]],
+        );
+        self.filter.state[2] = normalize_angle(self.filter.state[2]);
+        return ret;
+    }
+
+    /// Correct with an absolute position with independent errors along the axes.
+    pub fn update_position(
+        &mut self,
+        position: &Vector2D,
+        std_dev: f64,
+    ) -> Result<(), KalmanFilterError> {
+        let variance = std_dev * std_dev;
+        return self.filter.update(
+            &[position.x, position.y],
+            |s| ([s[0], s[1]], [[1_f64, 0_f64, 0_f64], [0_f64, 1_f64, 0_f64]]),
+            &[[variance, 0_f64], [0_f64, variance]],
+        );
+    }
+}
+
+fn multiply<const R: usize, const K: usize, const C: usize>(
+    a: &Matrix<R, K>,
+    b: &Matrix<K, C>,
+) -> Matrix<R, C> {
+    let mut ret = [[0_f64; C]; R];
+    for (i, row) in ret.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..K).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+
+    return ret;
+}
+
+fn add<const R: usize, const C: usize>(a: &Matrix<R, C>, b: &Matrix<R, C>) -> Matrix<R, C> {
+    let mut ret = *a;
+    for (i, row) in ret.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value += b[i][j];
+        }
+    }
+
+    return ret;
+}
+
+fn transpose<const R: usize, const C: usize>(a: &Matrix<R, C>) -> Matrix<C, R> {
+    let mut ret = [[0_f64; R]; C];
+    for (i, row) in ret.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a[j][i];
+        }
+    }
+
+    return ret;
+}
+
+/// Gauss-Jordan elimination with partial pivoting. Returns `None` if the matrix is singular.
+fn inverse<const N: usize>(a: &Matrix<N, N>) -> Option<Matrix<N, N>> {
+    let mut left = *a;
+    let mut ret = [[0_f64; N]; N];
+    for (i, row) in ret.iter_mut().enumerate() {
+        row[i] = 1_f64;
+    }
+
+    for column in 0..N {
+        let pivot = (column..N).max_by(|r1, r2| {
+            return left[*r1][column].abs().total_cmp(&left[*r2][column].abs());
+        })?;
+        if left[pivot][column] == 0_f64 {
+            return None;
+        }
+        left.swap(column, pivot);
+        ret.swap(column, pivot);
+
+        let scale = left[column][column];
+        for j in 0..N {
+            left[column][j] /= scale;
+            ret[column][j] /= scale;
+        }
+        for r in 0..N {
+            if r != column {
+                let factor = left[r][column];
+                for j in 0..N {
+                    left[r][j] -= factor * left[column][j];
+                    ret[r][j] -= factor * ret[column][j];
+                }
+            }
+        }
+    }
+
+    return Some(ret);
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{matrix3::Matrix3, pose2d::Pose2D, vector::Vector};
+    use std::f64::consts::PI;
+
+    use crate::motion::kinematic_model::DifferentialDriveControl;
+
+    use super::{DifferentialDriveEkf, ExtendedKalmanFilter, KalmanFilterError, OdometryNoise};
+
+    #[test]
+    fn fuse_odometry_and_heading() {
+        // Scalar filter averaging two measurements of equal noise.
+        let mut filter = ExtendedKalmanFilter::new([0_f64], [[1e6]]);
+        filter
+            .update(&[2_f64], |s| ([s[0]], [[1_f64]]), &[[1_f64]])
+            .unwrap();
+        filter
+            .update(&[4_f64], |s| ([s[0]], [[1_f64]]), &[[1_f64]])
+            .unwrap();
+        assert!((filter.state[0] - 3_f64).abs() < 1e-3);
+        assert!((filter.covariance[0][0] - 0.5).abs() < 1e-3);
+        assert_eq!(
+            ExtendedKalmanFilter::new([0_f64], [[0_f64]]).update(
+                &[1_f64],
+                |s| ([s[0]], [[1_f64]]),
+                &[[0_f64]]
+            ),
+            Err(KalmanFilterError::SingularInnovation)
+        );
+
+        // Quarter circle of radius 2 across the heading of pi, with a gyroscope reading 10% too
+        // high, corrected by a compass, and the position pinned at the end.
+        let mut ekf = DifferentialDriveEkf::new(
+            Pose2D::from_xytheta(0_f64, 0_f64, PI - 0.01),
+            Matrix3::identity() * 1e-4,
+            OdometryNoise::default(),
+        );
+        let mut truth = Pose2D::from_xytheta(0_f64, 0_f64, PI - 0.01);
+        for _ in 0..100 {
+            let (v, w) = (PI / 10_f64, PI / 20_f64);
+            let (sin, cos) = (truth.theta + w * 0.05).sin_cos();
+            truth = Pose2D::from_xytheta(
+                truth.position.x + v * 0.1 * cos,
+                truth.position.y + v * 0.1 * sin,
+                truth.theta + w * 0.1,
+            );
+
+            ekf.predict(
+                &DifferentialDriveControl {
+                    linear: v,
+                    angular: w * 1.1,
+                },
+                0.1,
+            );
+            ekf.update_heading(truth.theta, 0.02).unwrap();
+        }
+
+        let heading_error = (ekf.pose().theta - truth.theta).rem_euclid(2_f64 * PI);
+        assert!(heading_error.min(2_f64 * PI - heading_error) < 0.05);
+        assert!(ekf.covariance().rows[2][2] < 0.02 * 0.02);
+
+        let before = ekf.covariance().rows[0][0];
+        ekf.update_position(&truth.position, 0.01).unwrap();
+        assert!((ekf.pose().position - truth.position).magnitude() < 0.02);
+        assert!(ekf.covariance().rows[0][0] < before);
+    }
+}
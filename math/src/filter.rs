@@ -0,0 +1,174 @@
+use std::ops::{Add, Mul, Sub};
+
+/// Exponential moving average: each sample moves the estimate by `alpha` of the difference.
+/// Works on anything which can be blended linearly, e.g. `f64` or `Vector2D`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExponentialMovingAverage<T> {
+    alpha: f64,
+    value: Option<T>,
+}
+
+impl<T> ExponentialMovingAverage<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>,
+{
+    /// `alpha` in (0, 1]: 1 follows the samples, values near 0 smooth heavily.
+    pub fn new(alpha: f64) -> Self {
+        return Self {
+            alpha: alpha.clamp(0_f64, 1_f64),
+            value: None,
+        };
+    }
+
+    /// Returns `None` before the first sample.
+    pub fn value(&self) -> Option<T> {
+        return self.value;
+    }
+
+    /// Feed a sample and return the new estimate. The first sample is taken as is.
+    pub fn update(&mut self, sample: T) -> T {
+        let ret = match self.value {
+            Some(v) => v + (sample - v) * self.alpha,
+            None => sample,
+        };
+
+        self.value = Some(ret);
+        return ret;
+    }
+
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+/// First-order low-pass filter for samples at irregular intervals, characterized by its
+/// time constant rather than a fixed blending factor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LowPassFilter<T> {
+    time_constant: f64,
+    value: Option<T>,
+}
+
+impl<T> LowPassFilter<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>,
+{
+    /// Time constant in seconds, after which the response to a step reaches 63%.
+    pub fn new(time_constant: f64) -> Self {
+        return Self {
+            time_constant: time_constant,
+            value: None,
+        };
+    }
+
+    /// Cutoff frequency in hertz, where the filter attenuates by 3 dB.
+    pub fn from_cutoff_frequency(cutoff_frequency: f64) -> Self {
+        return Self::new(1_f64 / (2_f64 * std::f64::consts::PI * cutoff_frequency));
+    }
+
+    /// Returns `None` before the first sample.
+    pub fn value(&self) -> Option<T> {
+        return self.value;
+    }
+
+    /// Feed a sample taken dt seconds after the previous one and return the new estimate.
+    /// The first sample is taken as is.
+    pub fn update(&mut self, sample: T, dt: f64) -> T {
+        let ret = match self.value {
+            Some(v) => {
+                let alpha = if self.time_constant > 0_f64 {
+                    1_f64 - (-dt / self.time_constant).exp()
+                } else {
+                    1_f64
+                };
+                v + (sample - v) * alpha
+            }
+            None => sample,
+        };
+
+        self.value = Some(ret);
+        return ret;
+    }
+
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+/// Fuse a rate measurement, accurate short-term but drifting, with an absolute measurement,
+/// noisy but drift-free, e.g. gyroscope and accelerometer tilt or wheel odometry and compass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplementaryFilter {
+    time_constant: f64,
+    value: Option<f64>,
+}
+
+impl ComplementaryFilter {
+    /// Time constant in seconds: the rate dominates on shorter time scales, the absolute
+    /// measurement on longer ones.
+    pub fn new(time_constant: f64) -> Self {
+        return Self {
+            time_constant: time_constant,
+            value: None,
+        };
+    }
+
+    /// Returns `None` before the first update.
+    pub fn value(&self) -> Option<f64> {
+        return self.value;
+    }
+
+    /// Integrate rate over dt and blend with the absolute measurement.
+    /// The first update takes the measurement as is.
+    pub fn update(&mut self, rate: f64, measurement: f64, dt: f64) -> f64 {
+        let ret = match self.value {
+            Some(v) => {
+                let alpha = self.time_constant / (self.time_constant + dt);
+                alpha * (v + rate * dt) + (1_f64 - alpha) * measurement
+            }
+            None => measurement,
+        };
+
+        self.value = Some(ret);
+        return ret;
+    }
+
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::{ComplementaryFilter, ExponentialMovingAverage, LowPassFilter};
+
+    #[test]
+    fn filters_converge() {
+        let mut ema = ExponentialMovingAverage::new(0.5);
+        assert_eq!(
+            ema.update(Vector2D::from_xy(2_f64, 0_f64)),
+            Vector2D::from_xy(2_f64, 0_f64)
+        );
+        assert_eq!(
+            ema.update(Vector2D::zero()),
+            Vector2D::from_xy(1_f64, 0_f64)
+        );
+
+        // Response to a step reaches 1 - 1 / e after one time constant.
+        let mut low_pass = LowPassFilter::new(1_f64);
+        low_pass.update(0_f64, 0_f64);
+        for _ in 0..100 {
+            low_pass.update(1_f64, 0.01);
+        }
+        assert!((low_pass.value().unwrap() - (1_f64 - (-1_f64).exp())).abs() < 1e-9);
+
+        // A biased rate drifts, the measurement keeps the estimate near the truth.
+        let mut complementary = ComplementaryFilter::new(0.5);
+        for _ in 0..1000 {
+            complementary.update(0.1, 1_f64, 0.01);
+        }
+        assert!((complementary.value().unwrap() - 1_f64).abs() < 0.06);
+    }
+}
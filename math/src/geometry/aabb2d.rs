@@ -0,0 +1,156 @@
+use core::numerics::vector2d::Vector2D;
+
+/// Axis-aligned bounding box, including its boundary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AABB2D {
+    pub min: Vector2D,
+    pub max: Vector2D,
+}
+
+impl AABB2D {
+    /// Box spanned by two opposite corners, in any order.
+    pub fn new(corner1: Vector2D, corner2: Vector2D) -> Self {
+        return Self {
+            min: Vector2D::from_xy(corner1.x.min(corner2.x), corner1.y.min(corner2.y)),
+            max: Vector2D::from_xy(corner1.x.max(corner2.x), corner1.y.max(corner2.y)),
+        };
+    }
+
+    /// Smallest box containing all points. Returns `None` if there are no points.
+    pub fn from_points<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Vector2D>,
+    {
+        let mut iter = points.into_iter();
+        let first = iter.next()?;
+        let mut ret = Self::new(*first, *first);
+        for p in iter {
+            ret = ret.expanded_to_include(p);
+        }
+
+        return Some(ret);
+    }
+
+    pub fn width(&self) -> f64 {
+        return self.max.x - self.min.x;
+    }
+
+    pub fn height(&self) -> f64 {
+        return self.max.y - self.min.y;
+    }
+
+    pub fn area(&self) -> f64 {
+        return self.width() * self.height();
+    }
+
+    pub fn center(&self) -> Vector2D {
+        return (self.min + self.max) / 2_f64;
+    }
+
+    pub fn contains_point(&self, point: &Vector2D) -> bool {
+        return point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y;
+    }
+
+    pub fn contains(&self, other: &AABB2D) -> bool {
+        return self.contains_point(&other.min) && self.contains_point(&other.max);
+    }
+
+    /// Distance from point to the closest point of the box, zero inside it.
+    pub fn distance_to_point(&self, point: &Vector2D) -> f64 {
+        let dx = (self.min.x - point.x).max(point.x - self.max.x).max(0_f64);
+        let dy = (self.min.y - point.y).max(point.y - self.max.y).max(0_f64);
+        return dx.hypot(dy);
+    }
+
+    /// Check whether the boxes share at least one point.
+    pub fn intersects(&self, other: &AABB2D) -> bool {
+        return self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y;
+    }
+
+    /// Returns `None` if the boxes do not intersect.
+    pub fn intersection(&self, other: &AABB2D) -> Option<AABB2D> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        return Some(AABB2D {
+            min: Vector2D::from_xy(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Vector2D::from_xy(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        });
+    }
+
+    /// Smallest box containing both boxes.
+    pub fn union(&self, other: &AABB2D) -> AABB2D {
+        return AABB2D {
+            min: Vector2D::from_xy(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Vector2D::from_xy(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        };
+    }
+
+    pub fn expanded_to_include(&self, point: &Vector2D) -> AABB2D {
+        return self.union(&AABB2D::new(*point, *point));
+    }
+
+    /// Grow the box by margin on every side, or shrink it if negative.
+    /// Shrinking never goes past the center.
+    pub fn expanded(&self, margin: f64) -> AABB2D {
+        let center = self.center();
+        return AABB2D {
+            min: Vector2D::from_xy(
+                (self.min.x - margin).min(center.x),
+                (self.min.y - margin).min(center.y),
+            ),
+            max: Vector2D::from_xy(
+                (self.max.x + margin).max(center.x),
+                (self.max.y + margin).max(center.y),
+            ),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::AABB2D;
+
+    #[test]
+    fn set_operations() {
+        let a = AABB2D::new(
+            Vector2D::from_xy(2_f64, 2_f64),
+            Vector2D::from_xy(0_f64, 0_f64),
+        );
+        let b = AABB2D::new(
+            Vector2D::from_xy(1_f64, 1_f64),
+            Vector2D::from_xy(3_f64, 4_f64),
+        );
+        assert_eq!(a.min, Vector2D::from_xy(0_f64, 0_f64));
+        assert_eq!(
+            a.intersection(&b),
+            Some(AABB2D::new(
+                Vector2D::from_xy(1_f64, 1_f64),
+                Vector2D::from_xy(2_f64, 2_f64)
+            ))
+        );
+        assert_eq!(a.union(&b).area(), 12_f64);
+        assert!(a.union(&b).contains(&a) && !a.contains(&b));
+        assert!(a.contains_point(&Vector2D::from_xy(2_f64, 0_f64)));
+        assert_eq!(a.distance_to_point(&Vector2D::from_xy(5_f64, 6_f64)), 5_f64);
+        assert_eq!(a.distance_to_point(&Vector2D::from_xy(1_f64, 1_f64)), 0_f64);
+        assert_eq!(a.expanded(1_f64).width(), 4_f64);
+        assert_eq!(a.expanded(-5_f64).area(), 0_f64);
+
+        let points = [
+            Vector2D::from_xy(1_f64, -1_f64),
+            Vector2D::from_xy(-2_f64, 3_f64),
+        ];
+        assert_eq!(AABB2D::from_points(&points).unwrap().height(), 4_f64);
+        assert_eq!(AABB2D::from_points(&[]), None);
+    }
+}
@@ -0,0 +1,100 @@
+use core::numerics::vector2d::Vector2D;
+
+use super::curve2d::Curve2D;
+
+/// Uniform Catmull-Rom spline passing through all its points. t in [0, 1] covers the whole
+/// spline, with an equal share of the parameter per piece between consecutive points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRomSpline {
+    points: Vec<Vector2D>,
+}
+
+impl CatmullRomSpline {
+    /// Returns `None` for fewer than two points.
+    pub fn new(points: Vec<Vector2D>) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+
+        return Some(Self { points: points });
+    }
+
+    pub fn points(&self) -> &Vec<Vector2D> {
+        return &self.points;
+    }
+
+    /// Map t to the index of the piece and the parameter within it.
+    fn locate(&self, t: f64) -> (usize, f64) {
+        let pieces = self.points.len() - 1;
+        let scaled = t.clamp(0_f64, 1_f64) * pieces as f64;
+        let index = (scaled.floor() as usize).min(pieces - 1);
+        return (index, scaled - index as f64);
+    }
+
+    /// Polynomial coefficients of a piece, with the end points repeated as outer neighbors.
+    fn coefficients(&self, index: usize) -> [Vector2D; 4] {
+        let last = self.points.len() - 1;
+        let p0 = self.points[index.saturating_sub(1)];
+        let p1 = self.points[index];
+        let p2 = self.points[index + 1];
+        let p3 = self.points[(index + 2).min(last)];
+
+        return [
+            p1,
+            0.5 * (p2 - p0),
+            0.5 * (2_f64 * p0 - 5_f64 * p1 + 4_f64 * p2 - p3),
+            0.5 * (3_f64 * p1 - p0 - 3_f64 * p2 + p3),
+        ];
+    }
+}
+
+impl Curve2D for CatmullRomSpline {
+    fn point_at(&self, t: f64) -> Vector2D {
+        let (index, u) = self.locate(t);
+        let c = self.coefficients(index);
+        return c[0] + u * c[1] + u.powi(2) * c[2] + u.powi(3) * c[3];
+    }
+
+    fn derivative(&self, t: f64) -> Vector2D {
+        let (index, u) = self.locate(t);
+        let c = self.coefficients(index);
+        let pieces = (self.points.len() - 1) as f64;
+        return pieces * (c[1] + 2_f64 * u * c[2] + 3_f64 * u.powi(2) * c[3]);
+    }
+
+    fn second_derivative(&self, t: f64) -> Vector2D {
+        let (index, u) = self.locate(t);
+        let c = self.coefficients(index);
+        let pieces = (self.points.len() - 1) as f64;
+        return pieces.powi(2) * (2_f64 * c[2] + 6_f64 * u * c[3]);
+    }
+
+    fn piece_count(&self) -> usize {
+        return self.points.len() - 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use crate::geometry::curve2d::Curve2D;
+
+    use super::CatmullRomSpline;
+
+    #[test]
+    fn passes_through_points() {
+        let points = vec![
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(1_f64, 1_f64),
+            Vector2D::from_xy(2_f64, 0_f64),
+        ];
+        let spline = CatmullRomSpline::new(points.clone()).unwrap();
+        assert_eq!(spline.point_at(0_f64), points[0]);
+        assert!((spline.point_at(0.5) - points[1]).magnitude() < 1e-9);
+        assert_eq!(spline.point_at(1_f64), points[2]);
+        assert!(spline.curvature(0.5) < 0_f64);
+        assert!(spline.arc_length() > 2_f64 * 2_f64.sqrt());
+        assert!(CatmullRomSpline::new(vec![points[0]]).is_none());
+    }
+}
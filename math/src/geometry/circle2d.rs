@@ -0,0 +1,170 @@
+use core::numerics::{vector::Vector, vector2d::Vector2D};
+use std::f64::consts::PI;
+
+use super::line_segment2d::LineSegment2D;
+
+/// Circle in the plane, including its interior for containment checks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle2D {
+    pub center: Vector2D,
+    pub radius: f64,
+}
+
+impl Circle2D {
+    pub fn new(center: Vector2D, radius: f64) -> Self {
+        return Self {
+            center: center,
+            radius: radius,
+        };
+    }
+
+    pub fn area(&self) -> f64 {
+        return PI * self.radius.powi(2);
+    }
+
+    pub fn circumference(&self) -> f64 {
+        return 2_f64 * PI * self.radius;
+    }
+
+    pub fn contains(&self, point: &Vector2D) -> bool {
+        return (point - self.center).magnitude() <= self.radius;
+    }
+
+    /// Intersections with the infinite line through two distinct points, as parameters t
+    /// of `p1 + t (p2 - p1)` in ascending order.
+    fn line_parameters(&self, p1: &Vector2D, p2: &Vector2D) -> Vec<f64> {
+        let d = p2 - p1;
+        let f = p1 - self.center;
+        let a = d.dot(&d);
+        if a == 0_f64 {
+            return Vec::new();
+        }
+
+        let b = 2_f64 * f.dot(&d);
+        let c = f.dot(&f) - self.radius.powi(2);
+        let discriminant = b.powi(2) - 4_f64 * a * c;
+
+        if discriminant < 0_f64 {
+            return Vec::new();
+        } else if discriminant == 0_f64 {
+            return vec![-b / (2_f64 * a)];
+        }
+
+        let root = discriminant.sqrt();
+        return vec![(-b - root) / (2_f64 * a), (-b + root) / (2_f64 * a)];
+    }
+
+    /// Intersections with the infinite line through two distinct points, ordered from p1
+    /// towards p2.
+    pub fn intersect_line(&self, p1: &Vector2D, p2: &Vector2D) -> Vec<Vector2D> {
+        return self
+            .line_parameters(p1, p2)
+            .into_iter()
+            .map(|t| p1 + (p2 - p1) * t)
+            .collect();
+    }
+
+    /// Intersections with the boundary of the circle, ordered from the start of the segment.
+    /// E.g. the lookahead point of pure pursuit is the last intersection with the path.
+    pub fn intersect_segment(&self, segment: &LineSegment2D) -> Vec<Vector2D> {
+        return self
+            .line_parameters(&segment.start, &segment.end)
+            .into_iter()
+            .filter(|t| (0_f64..=1_f64).contains(t))
+            .map(|t| segment.point_at(t))
+            .collect();
+    }
+
+    /// Intersections of the boundaries. Returns no points for identical circles.
+    pub fn intersect_circle(&self, other: &Circle2D) -> Vec<Vector2D> {
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+
+        if distance == 0_f64
+            || distance > self.radius + other.radius
+            || distance < (self.radius - other.radius).abs()
+        {
+            return Vec::new();
+        }
+
+        // Distance from this center to the chord, and half the chord length.
+        let a =
+            (self.radius.powi(2) - other.radius.powi(2) + distance.powi(2)) / (2_f64 * distance);
+        let h = (self.radius.powi(2) - a.powi(2)).max(0_f64).sqrt();
+        let direction = offset / distance;
+        let mid = self.center + direction * a;
+
+        if h == 0_f64 {
+            return vec![mid];
+        }
+
+        return vec![
+            mid + direction.perpendicular() * h,
+            mid - direction.perpendicular() * h,
+        ];
+    }
+
+    /// Points of the circle where the tangents through point touch it.
+    /// Returns point itself if it lies on the circle and nothing if it lies inside.
+    pub fn tangent_points(&self, point: &Vector2D) -> Vec<Vector2D> {
+        let distance = (point - self.center).magnitude();
+        if distance < self.radius {
+            return Vec::new();
+        } else if distance == self.radius {
+            return vec![*point];
+        }
+
+        // The tangent points lie on the circle with the segment to point as diameter.
+        let thales = Circle2D::new((self.center + point) / 2_f64, distance / 2_f64);
+        return self.intersect_circle(&thales);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use crate::geometry::line_segment2d::LineSegment2D;
+
+    use super::Circle2D;
+
+    #[test]
+    fn intersections_and_tangents() {
+        let circle = Circle2D::new(Vector2D::zero(), 5_f64);
+        let p1 = Vector2D::from_xy(-10_f64, 3_f64);
+        let p2 = Vector2D::from_xy(10_f64, 3_f64);
+        assert_eq!(
+            circle.intersect_line(&p1, &p2),
+            vec![
+                Vector2D::from_xy(-4_f64, 3_f64),
+                Vector2D::from_xy(4_f64, 3_f64)
+            ]
+        );
+        assert_eq!(
+            circle.intersect_segment(&LineSegment2D::new(Vector2D::from_xy(0_f64, 3_f64), p2)),
+            vec![Vector2D::from_xy(4_f64, 3_f64)]
+        );
+
+        let other = Circle2D::new(Vector2D::from_xy(8_f64, 0_f64), 5_f64);
+        let points = circle.intersect_circle(&other);
+        assert_eq!(points.len(), 2);
+        assert!(points
+            .iter()
+            .all(|p| (p.x - 4_f64).abs() < 1e-9 && (p.y.abs() - 3_f64).abs() < 1e-9));
+        assert!(circle
+            .intersect_circle(&Circle2D::new(Vector2D::zero(), 1_f64))
+            .is_empty());
+
+        let tangents = circle.tangent_points(&Vector2D::from_xy(0_f64, 10_f64));
+        assert_eq!(tangents.len(), 2);
+        for t in tangents {
+            assert!(((t - circle.center).magnitude() - 5_f64).abs() < 1e-9);
+            assert!(
+                (t - circle.center)
+                    .dot(&(t - Vector2D::from_xy(0_f64, 10_f64)))
+                    .abs()
+                    < 1e-9
+            );
+        }
+    }
+}
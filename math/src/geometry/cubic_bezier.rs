@@ -0,0 +1,81 @@
+use core::numerics::vector2d::Vector2D;
+
+use super::curve2d::Curve2D;
+
+/// Cubic Bezier curve from `p0` to `p3`, shaped by the control points `p1` and `p2`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier {
+    pub p0: Vector2D,
+    pub p1: Vector2D,
+    pub p2: Vector2D,
+    pub p3: Vector2D,
+}
+
+impl CubicBezier {
+    pub fn new(p0: Vector2D, p1: Vector2D, p2: Vector2D, p3: Vector2D) -> Self {
+        return Self {
+            p0: p0,
+            p1: p1,
+            p2: p2,
+            p3: p3,
+        };
+    }
+}
+
+impl Curve2D for CubicBezier {
+    fn point_at(&self, t: f64) -> Vector2D {
+        let s = 1_f64 - t;
+        return s.powi(3) * self.p0
+            + 3_f64 * s.powi(2) * t * self.p1
+            + 3_f64 * s * t.powi(2) * self.p2
+            + t.powi(3) * self.p3;
+    }
+
+    fn derivative(&self, t: f64) -> Vector2D {
+        let s = 1_f64 - t;
+        return 3_f64 * s.powi(2) * (self.p1 - self.p0)
+            + 6_f64 * s * t * (self.p2 - self.p1)
+            + 3_f64 * t.powi(2) * (self.p3 - self.p2);
+    }
+
+    fn second_derivative(&self, t: f64) -> Vector2D {
+        return 6_f64 * (1_f64 - t) * (self.p2 - 2_f64 * self.p1 + self.p0)
+            + 6_f64 * t * (self.p3 - 2_f64 * self.p2 + self.p1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use crate::geometry::curve2d::Curve2D;
+
+    use super::CubicBezier;
+
+    #[test]
+    fn straight_and_curved() {
+        let line = CubicBezier::new(
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(1_f64, 0_f64),
+            Vector2D::from_xy(2_f64, 0_f64),
+            Vector2D::from_xy(3_f64, 0_f64),
+        );
+        assert_eq!(line.point_at(0.5), Vector2D::from_xy(1.5, 0_f64));
+        assert!((line.arc_length() - 3_f64).abs() < 1e-9);
+        assert_eq!(line.curvature(0.3), 0_f64);
+        let samples = line.sample_by_arc_length(1_f64);
+        assert_eq!(samples.len(), 4);
+        assert!((samples[1] - Vector2D::from_xy(1_f64, 0_f64)).magnitude() < 1e-6);
+
+        // Approximation of a quarter circle with radius 1, turning counterclockwise.
+        let k = 0.5523;
+        let arc = CubicBezier::new(
+            Vector2D::from_xy(1_f64, 0_f64),
+            Vector2D::from_xy(1_f64, k),
+            Vector2D::from_xy(k, 1_f64),
+            Vector2D::from_xy(0_f64, 1_f64),
+        );
+        assert!((arc.arc_length() - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+        assert!((arc.curvature(0.5) - 1_f64).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,89 @@
+use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+/// Number of chords per unit of the curve parameter used to approximate arc lengths.
+const ARC_LENGTH_RESOLUTION: usize = 256;
+
+/// Parametric curve in the plane, defined for t in [0, 1].
+pub trait Curve2D {
+    fn point_at(&self, t: f64) -> Vector2D;
+
+    /// First derivative with respect to t.
+    fn derivative(&self, t: f64) -> Vector2D;
+
+    /// Second derivative with respect to t.
+    fn second_derivative(&self, t: f64) -> Vector2D;
+
+    /// Signed curvature, positive when the curve turns counterclockwise.
+    /// Returns 0 where the curve has no tangent.
+    fn curvature(&self, t: f64) -> f64 {
+        let d1 = self.derivative(t);
+        let d2 = self.second_derivative(t);
+        let speed = d1.magnitude();
+        if speed == 0_f64 {
+            return 0_f64;
+        }
+
+        return d1.cross(&d2) / speed.powi(3);
+    }
+
+    /// Approximate length of the curve.
+    fn arc_length(&self) -> f64 {
+        return *self.cumulative_lengths().last().unwrap();
+    }
+
+    /// Sample points evenly spaced along the curve, including both ends.
+    /// The last interval may be shorter than spacing.
+    fn sample_by_arc_length(&self, spacing: f64) -> Vec<Vector2D> {
+        let lengths = self.cumulative_lengths();
+        let total_length = *lengths.last().unwrap();
+        let steps = lengths.len() - 1;
+        let mut ret: Vec<Vector2D> = vec![self.point_at(0_f64)];
+        if spacing <= 0_f64 {
+            return ret;
+        }
+
+        let mut i = 0;
+        let mut target = spacing;
+        while target < total_length {
+            while lengths[i + 1] < target {
+                i += 1;
+            }
+
+            // Interpolate the parameter within the chord.
+            let chord = lengths[i + 1] - lengths[i];
+            let fraction = if chord > 0_f64 {
+                (target - lengths[i]) / chord
+            } else {
+                0_f64
+            };
+            ret.push(self.point_at((i as f64 + fraction) / steps as f64));
+            target += spacing;
+        }
+
+        ret.push(self.point_at(1_f64));
+        return ret;
+    }
+
+    /// Number of pieces the curve is made of, which refines the arc length approximation.
+    fn piece_count(&self) -> usize {
+        return 1;
+    }
+
+    /// Lengths along the curve at evenly spaced parameters, starting with 0.
+    fn cumulative_lengths(&self) -> Vec<f64> {
+        let steps = ARC_LENGTH_RESOLUTION * self.piece_count().max(1);
+        let mut ret: Vec<f64> = Vec::with_capacity(steps + 1);
+        let mut prev_point = self.point_at(0_f64);
+        let mut length = 0_f64;
+        ret.push(length);
+
+        for i in 1..=steps {
+            let point = self.point_at(i as f64 / steps as f64);
+            length += (point - prev_point).magnitude();
+            ret.push(length);
+            prev_point = point;
+        }
+
+        return ret;
+    }
+}
@@ -0,0 +1,164 @@
+use core::numerics::{matrix2::Matrix2, vector2d::Vector2D, vector2i::Vector2I};
+use std::f64::consts::PI;
+
+use super::{aabb2d::AABB2D, polygon2d::Polygon2D};
+
+/// Ellipse in the plane, including its interior for containment checks.
+/// The semi-major axis points along orientation (radians, counterclockwise from the x axis).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipse2D {
+    pub center: Vector2D,
+    pub semi_major: f64,
+    pub semi_minor: f64,
+    pub orientation: f64,
+}
+
+impl Ellipse2D {
+    pub fn new(center: Vector2D, semi_major: f64, semi_minor: f64, orientation: f64) -> Self {
+        return Self {
+            center: center,
+            semi_major: semi_major,
+            semi_minor: semi_minor,
+            orientation: orientation,
+        };
+    }
+
+    /// Region containing a 2D Gaussian sample with the given probability, e.g. 0.95.
+    /// Returns `None` if confidence is outside of (0, 1) or covariance is not a symmetric
+    /// positive semi-definite matrix.
+    pub fn from_covariance(
+        center: Vector2D,
+        covariance: &Matrix2,
+        confidence: f64,
+    ) -> Option<Self> {
+        if confidence <= 0_f64 || confidence >= 1_f64 {
+            return None;
+        }
+
+        let r = &covariance.rows;
+        if r[0][1] != r[1][0] {
+            return None;
+        }
+
+        // Eigenvalues of the symmetric matrix are the variances along the principal axes.
+        let mean = (r[0][0] + r[1][1]) / 2_f64;
+        let spread = ((r[0][0] - r[1][1]) / 2_f64).hypot(r[0][1]);
+        let (major, minor) = (mean + spread, mean - spread);
+        if minor < 0_f64 {
+            return None;
+        }
+
+        // Squared Mahalanobis distances are chi-squared distributed with two degrees of freedom.
+        let scale = (-2_f64 * (1_f64 - confidence).ln()).sqrt();
+        let orientation = (2_f64 * r[0][1]).atan2(r[0][0] - r[1][1]) / 2_f64;
+
+        return Some(Self::new(
+            center,
+            scale * major.sqrt(),
+            scale * minor.sqrt(),
+            orientation,
+        ));
+    }
+
+    pub fn area(&self) -> f64 {
+        return PI * self.semi_major * self.semi_minor;
+    }
+
+    pub fn contains(&self, point: &Vector2D) -> bool {
+        let local = (point - self.center).rotate(-self.orientation);
+        let (a, b) = (self.semi_major, self.semi_minor);
+        if local.x.abs() > a || local.y.abs() > b {
+            return false;
+        }
+
+        return (local.x * b).powi(2) + (local.y * a).powi(2) <= (a * b).powi(2);
+    }
+
+    pub fn bounding_box(&self) -> AABB2D {
+        let (sin, cos) = self.orientation.sin_cos();
+        let (a, b) = (self.semi_major, self.semi_minor);
+        let half_extent = Vector2D::from_xy((a * cos).hypot(b * sin), (a * sin).hypot(b * cos));
+        return AABB2D::new(self.center - half_extent, self.center + half_extent);
+    }
+
+    /// Point on the boundary at parameter angle (radians) from the end of the semi-major axis.
+    pub fn point_at(&self, angle: f64) -> Vector2D {
+        let (sin, cos) = angle.sin_cos();
+        let local = Vector2D::from_xy(self.semi_major * cos, self.semi_minor * sin);
+        return self.center + local.rotate(self.orientation);
+    }
+
+    /// Approximate the boundary by a counterclockwise polygon, e.g. for drawing.
+    pub fn to_polygon(&self, vertex_count: usize) -> Polygon2D {
+        return Polygon2D::new(
+            (0..vertex_count)
+                .map(|i| self.point_at(2_f64 * PI * i as f64 / vertex_count as f64))
+                .collect(),
+        );
+    }
+
+    /// Indices (x, y) of the grid cells of size cell_size, with cell (0, 0) at the origin,
+    /// whose centers lie inside the ellipse. Sorted by x, then y.
+    pub fn rasterize(&self, cell_size: f64) -> Vec<Vector2I> {
+        let bounds = self.bounding_box();
+        let min_x = (bounds.min.x / cell_size).floor() as i64;
+        let max_x = (bounds.max.x / cell_size).floor() as i64;
+        let min_y = (bounds.min.y / cell_size).floor() as i64;
+        let max_y = (bounds.max.y / cell_size).floor() as i64;
+
+        let mut ret: Vec<Vector2I> = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                let center =
+                    Vector2D::from_xy((x as f64 + 0.5) * cell_size, (y as f64 + 0.5) * cell_size);
+                if self.contains(&center) {
+                    ret.push(Vector2I::from_xy(x, y));
+                }
+            }
+        }
+
+        return ret;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{matrix2::Matrix2, vector2d::Vector2D, vector2i::Vector2I};
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::Ellipse2D;
+
+    #[test]
+    fn covariance_ellipse() {
+        let covariance = Matrix2::from_rows([[2_f64, 1_f64], [1_f64, 2_f64]]);
+        let confidence = 1_f64 - (-0.5_f64).exp();
+        let ellipse =
+            Ellipse2D::from_covariance(Vector2D::zero(), &covariance, confidence).unwrap();
+        assert!((ellipse.semi_major - 3_f64.sqrt()).abs() < 1e-9);
+        assert!((ellipse.semi_minor - 1_f64).abs() < 1e-9);
+        assert!((ellipse.orientation - FRAC_PI_4).abs() < 1e-9);
+
+        assert!(ellipse.contains(&Vector2D::from_xy(1_f64, 1_f64)));
+        assert!(!ellipse.contains(&Vector2D::from_xy(1_f64, -1_f64)));
+        assert!(Ellipse2D::from_covariance(Vector2D::zero(), &covariance, 1_f64).is_none());
+
+        let bounds = ellipse.bounding_box();
+        assert!((bounds.max.x - 2_f64.sqrt()).abs() < 1e-9);
+
+        let cells =
+            Ellipse2D::new(Vector2D::from_xy(2_f64, 2_f64), 1_f64, 0.5, 0_f64).rasterize(0.5);
+        assert_eq!(
+            cells,
+            vec![
+                Vector2I::from_xy(2, 3),
+                Vector2I::from_xy(2, 4),
+                Vector2I::from_xy(3, 3),
+                Vector2I::from_xy(3, 4),
+                Vector2I::from_xy(4, 3),
+                Vector2I::from_xy(4, 4),
+                Vector2I::from_xy(5, 3),
+                Vector2I::from_xy(5, 4),
+            ]
+        );
+    }
+}
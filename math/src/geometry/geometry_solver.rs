@@ -1,4 +1,4 @@
-use crate::math::numerics::{vector::Vector, vector2d::Vector2D, vector3d::Vector3D};
+use core::numerics::{vector::Vector, vector3d::Vector3D};
 
 pub struct GeometrySolver {
     accuracy: f64,
@@ -6,9 +6,11 @@ pub struct GeometrySolver {
 
 impl GeometrySolver {
     pub fn new(accuracy: f64) -> Self {
-        return Self {
-            accuracy: accuracy,
-        };
+        return Self { accuracy: accuracy };
+    }
+
+    pub fn accuracy(&self) -> f64 {
+        return self.accuracy;
     }
 
     /// Compute distance from point to line.
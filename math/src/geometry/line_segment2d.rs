@@ -0,0 +1,167 @@
+use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+/// Straight line segment between two points in the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineSegment2D {
+    pub start: Vector2D,
+    pub end: Vector2D,
+}
+
+/// Result of `LineSegment2D::intersection`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SegmentIntersection {
+    None,
+    Point(Vector2D),
+    /// Collinear segments sharing more than one point.
+    Overlap(LineSegment2D),
+}
+
+impl LineSegment2D {
+    pub fn new(start: Vector2D, end: Vector2D) -> Self {
+        return Self {
+            start: start,
+            end: end,
+        };
+    }
+
+    pub fn length(&self) -> f64 {
+        return (self.end - self.start).magnitude();
+    }
+
+    /// Point at parameter t, with t = 0 at the start and t = 1 at the end.
+    pub fn point_at(&self, t: f64) -> Vector2D {
+        return self.start + (self.end - self.start) * t;
+    }
+
+    /// Parameter in [0, 1] of the point of the segment closest to point.
+    fn closest_parameter(&self, point: &Vector2D) -> f64 {
+        let d = self.end - self.start;
+        let length_squared = d.dot(&d);
+        if length_squared == 0_f64 {
+            return 0_f64;
+        }
+
+        return ((point - self.start).dot(&d) / length_squared).clamp(0_f64, 1_f64);
+    }
+
+    pub fn closest_point(&self, point: &Vector2D) -> Vector2D {
+        return self.point_at(self.closest_parameter(point));
+    }
+
+    pub fn distance_to_point(&self, point: &Vector2D) -> f64 {
+        return (self.closest_point(point) - point).magnitude();
+    }
+
+    pub fn intersection(&self, other: &LineSegment2D) -> SegmentIntersection {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let offset = other.start - self.start;
+        let denominator = d1.cross(&d2);
+
+        if denominator != 0_f64 {
+            let t = offset.cross(&d2) / denominator;
+            let u = offset.cross(&d1) / denominator;
+            if (0_f64..=1_f64).contains(&t) && (0_f64..=1_f64).contains(&u) {
+                return SegmentIntersection::Point(self.point_at(t));
+            }
+
+            return SegmentIntersection::None;
+        }
+
+        if offset.cross(&d1) != 0_f64 {
+            // Parallel but not collinear.
+            return SegmentIntersection::None;
+        }
+
+        let length_squared = d1.dot(&d1);
+        if length_squared == 0_f64 {
+            // This segment is a point.
+            if other.distance_to_point(&self.start) == 0_f64 {
+                return SegmentIntersection::Point(self.start);
+            }
+
+            return SegmentIntersection::None;
+        }
+
+        // Project the other segment onto this one and clip to [0, 1].
+        let t0 = offset.dot(&d1) / length_squared;
+        let t1 = (other.end - self.start).dot(&d1) / length_squared;
+        let lower = f64::max(f64::min(t0, t1), 0_f64);
+        let upper = f64::min(f64::max(t0, t1), 1_f64);
+
+        if lower > upper {
+            return SegmentIntersection::None;
+        } else if lower == upper {
+            return SegmentIntersection::Point(self.point_at(lower));
+        } else {
+            return SegmentIntersection::Overlap(LineSegment2D::new(
+                self.point_at(lower),
+                self.point_at(upper),
+            ));
+        }
+    }
+
+    pub fn intersects(&self, other: &LineSegment2D) -> bool {
+        return self.intersection(other) != SegmentIntersection::None;
+    }
+
+    /// Minimum distance between any two points of the segments.
+    pub fn distance_to_segment(&self, other: &LineSegment2D) -> f64 {
+        if self.intersects(other) {
+            return 0_f64;
+        }
+
+        return [
+            self.distance_to_point(&other.start),
+            self.distance_to_point(&other.end),
+            other.distance_to_point(&self.start),
+            other.distance_to_point(&self.end),
+        ]
+        .into_iter()
+        .fold(f64::INFINITY, f64::min);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::{LineSegment2D, SegmentIntersection};
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> LineSegment2D {
+        return LineSegment2D::new(Vector2D::from_xy(x1, y1), Vector2D::from_xy(x2, y2));
+    }
+
+    #[test]
+    fn intersections_and_distances() {
+        let s = segment(0_f64, 0_f64, 4_f64, 0_f64);
+        assert_eq!(
+            s.intersection(&segment(1_f64, -1_f64, 1_f64, 1_f64)),
+            SegmentIntersection::Point(Vector2D::from_xy(1_f64, 0_f64))
+        );
+        assert_eq!(
+            s.intersection(&segment(5_f64, 0_f64, 2_f64, 0_f64)),
+            SegmentIntersection::Overlap(segment(2_f64, 0_f64, 4_f64, 0_f64))
+        );
+        assert_eq!(
+            s.intersection(&segment(4_f64, 0_f64, 6_f64, 0_f64)),
+            SegmentIntersection::Point(Vector2D::from_xy(4_f64, 0_f64))
+        );
+        assert_eq!(
+            s.intersection(&segment(0_f64, 1_f64, 4_f64, 1_f64)),
+            SegmentIntersection::None
+        );
+
+        let p = Vector2D::from_xy(6_f64, 3_f64);
+        assert_eq!(s.closest_point(&p), Vector2D::from_xy(4_f64, 0_f64));
+        assert_eq!(s.distance_to_point(&p), 13_f64.sqrt());
+        assert_eq!(
+            s.distance_to_point(&Vector2D::from_xy(2_f64, -2_f64)),
+            2_f64
+        );
+        assert_eq!(
+            s.distance_to_segment(&segment(1_f64, 2_f64, 3_f64, 5_f64)),
+            2_f64
+        );
+    }
+}
@@ -1 +1,11 @@
+pub mod aabb2d;
+pub mod catmull_rom_spline;
+pub mod circle2d;
+pub mod cubic_bezier;
+pub mod curve2d;
+pub mod ellipse2d;
 pub mod geometry_solver;
+pub mod line_segment2d;
+pub mod polygon2d;
+pub mod polyline2d;
+pub mod segment_r_tree;
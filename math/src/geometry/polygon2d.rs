@@ -0,0 +1,211 @@
+use core::numerics::vector2d::Vector2D;
+
+use super::{aabb2d::AABB2D, line_segment2d::LineSegment2D};
+use crate::algorithm::convex_hull::convex_hull_algorithm::monotone_chain_convex_hull;
+
+/// Simple polygon given by its vertices in order, either clockwise or counterclockwise.
+/// The last vertex connects back to the first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon2D {
+    pub vertices: Vec<Vector2D>,
+}
+
+impl Polygon2D {
+    pub fn new(vertices: Vec<Vector2D>) -> Self {
+        return Self { vertices: vertices };
+    }
+
+    pub fn edges(&self) -> Vec<LineSegment2D> {
+        let n = self.vertices.len();
+        return (0..n)
+            .map(|i| LineSegment2D::new(self.vertices[i], self.vertices[(i + 1) % n]))
+            .collect();
+    }
+
+    /// Returns `None` if the polygon has no vertices.
+    pub fn bounding_box(&self) -> Option<AABB2D> {
+        return AABB2D::from_points(&self.vertices);
+    }
+
+    /// Area by the shoelace formula, positive for counterclockwise vertices.
+    pub fn signed_area(&self) -> f64 {
+        return self
+            .edges()
+            .iter()
+            .map(|e| e.start.cross(&e.end))
+            .sum::<f64>()
+            / 2_f64;
+    }
+
+    pub fn area(&self) -> f64 {
+        return self.signed_area().abs();
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        return self.edges().iter().map(|e| e.length()).sum();
+    }
+
+    /// Center of mass of the enclosed area.
+    /// Falls back to the mean of the vertices if the area is zero.
+    pub fn centroid(&self) -> Vector2D {
+        let area = self.signed_area();
+        if area == 0_f64 {
+            let sum = self
+                .vertices
+                .iter()
+                .fold(Vector2D::zero(), |sum, v| sum + v);
+            return sum / self.vertices.len().max(1) as f64;
+        }
+
+        let mut ret = Vector2D::zero();
+        for e in self.edges() {
+            ret += (e.start + e.end) * e.start.cross(&e.end);
+        }
+
+        return ret / (6_f64 * area);
+    }
+
+    /// Check whether point lies inside the polygon or on its boundary.
+    pub fn contains(&self, point: &Vector2D) -> bool {
+        let mut inside = false;
+
+        for e in self.edges() {
+            if e.distance_to_point(point) == 0_f64 {
+                return true;
+            }
+
+            // Count crossings of a ray towards +x.
+            if (e.start.y > point.y) != (e.end.y > point.y) {
+                let x = e.start.x
+                    + (point.y - e.start.y) / (e.end.y - e.start.y) * (e.end.x - e.start.x);
+                if x > point.x {
+                    inside = !inside;
+                }
+            }
+        }
+
+        return inside;
+    }
+
+    /// Check whether all turns along the boundary go the same way.
+    /// Collinear consecutive vertices are allowed.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+
+        let mut sign = 0_f64;
+        for i in 0..n {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            let c = self.vertices[(i + 2) % n];
+            let turn = (b - a).cross(&(c - b));
+
+            if turn == 0_f64 {
+                continue;
+            } else if sign == 0_f64 {
+                sign = turn.signum();
+            } else if turn.signum() != sign {
+                return false;
+            }
+        }
+
+        return sign != 0_f64;
+    }
+
+    /// Smallest convex polygon containing this one, counterclockwise, e.g. to simplify a
+    /// robot footprint for collision checks.
+    pub fn convex_hull(&self) -> Polygon2D {
+        return Polygon2D::new(monotone_chain_convex_hull(&self.vertices));
+    }
+
+    /// Move every edge outwards by distance, or inwards if negative, and join the moved edges
+    /// at their intersections (mitered corners). Shrinking by more than the polygon allows
+    /// produces a self-intersecting result.
+    pub fn offset(&self, distance: f64) -> Polygon2D {
+        let n = self.vertices.len();
+        if n < 3 {
+            return self.clone();
+        }
+
+        // Outward normals point right of counterclockwise edges.
+        let orientation = self.signed_area().signum();
+        let normals: Vec<Vector2D> = self
+            .edges()
+            .iter()
+            .map(|e| {
+                let d = (e.end - e.start).unit_vector();
+                return -d.perpendicular() * orientation;
+            })
+            .collect();
+
+        let mut ret: Vec<Vector2D> = Vec::with_capacity(n);
+        for i in 0..n {
+            let n1 = normals[(i + n - 1) % n];
+            let n2 = normals[i];
+
+            // Corner point p satisfies p . n1 = v . n1 + d and p . n2 = v . n2 + d,
+            // i.e. p = v + d (n1 + n2) / (1 + n1 . n2).
+            let denominator = 1_f64 + n1.dot(&n2);
+            let shift = if denominator.abs() < 1e-12 {
+                n2 * distance
+            } else {
+                (n1 + n2) * (distance / denominator)
+            };
+            ret.push(self.vertices[i] + shift);
+        }
+
+        return Polygon2D::new(ret);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::Polygon2D;
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon2D {
+        return Polygon2D::new(
+            points
+                .iter()
+                .map(|(x, y)| Vector2D::from_xy(*x, *y))
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn area_containment_and_offset() {
+        let square = polygon(&[
+            (0_f64, 0_f64),
+            (0_f64, 2_f64),
+            (2_f64, 2_f64),
+            (2_f64, 0_f64),
+        ]);
+        assert_eq!(square.signed_area(), -4_f64);
+        assert_eq!(square.centroid(), Vector2D::from_xy(1_f64, 1_f64));
+        assert!(square.is_convex());
+        assert!(square.contains(&Vector2D::from_xy(1_f64, 1_f64)));
+        assert!(square.contains(&Vector2D::from_xy(2_f64, 1_f64)));
+        assert!(!square.contains(&Vector2D::from_xy(3_f64, 1_f64)));
+
+        let grown = square.offset(1_f64);
+        assert_eq!(grown.vertices[0], Vector2D::from_xy(-1_f64, -1_f64));
+        assert_eq!(grown.area(), 16_f64);
+        assert_eq!(square.offset(-0.5).area(), 1_f64);
+
+        let l_shape = polygon(&[
+            (0_f64, 0_f64),
+            (3_f64, 0_f64),
+            (3_f64, 1_f64),
+            (1_f64, 1_f64),
+            (1_f64, 3_f64),
+            (0_f64, 3_f64),
+        ]);
+        assert_eq!(l_shape.area(), 5_f64);
+        assert!(!l_shape.is_convex());
+        assert!(!l_shape.contains(&Vector2D::from_xy(2_f64, 2_f64)));
+        assert!(l_shape.contains(&Vector2D::from_xy(0.5, 2_f64)));
+    }
+}
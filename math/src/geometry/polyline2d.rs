@@ -0,0 +1,188 @@
+use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+
+use super::line_segment2d::LineSegment2D;
+
+/// Open chain of points in the plane, parameterized by the arc length from its first point.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Polyline2D {
+    pub points: Vec<Vector2D>,
+}
+
+impl Polyline2D {
+    pub fn new(points: Vec<Vector2D>) -> Self {
+        return Self { points: points };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.points.is_empty();
+    }
+
+    pub fn segments(&self) -> Vec<LineSegment2D> {
+        return self
+            .points
+            .windows(2)
+            .map(|w| LineSegment2D::new(w[0], w[1]))
+            .collect();
+    }
+
+    pub fn length(&self) -> f64 {
+        return self.segments().iter().map(|s| s.length()).sum();
+    }
+
+    /// Find the segment containing the point at arc length, clamped to the polyline, and the
+    /// parameter of the point on that segment. Returns `None` if there is no segment.
+    fn locate(&self, arc_length: f64) -> Option<(LineSegment2D, f64)> {
+        let segments = self.segments();
+        let mut remaining = arc_length.max(0_f64);
+
+        for (i, segment) in segments.iter().enumerate() {
+            let length = segment.length();
+            if remaining <= length || i == segments.len() - 1 {
+                let t = if length > 0_f64 {
+                    (remaining / length).min(1_f64)
+                } else {
+                    0_f64
+                };
+                return Some((*segment, t));
+            }
+
+            remaining -= length;
+        }
+
+        return None;
+    }
+
+    /// Point at arc length from the first point, clamped to the ends.
+    /// Returns `None` for an empty polyline.
+    pub fn point_at_arc_length(&self, arc_length: f64) -> Option<Vector2D> {
+        if self.points.len() == 1 {
+            return Some(self.points[0]);
+        }
+
+        let (segment, t) = self.locate(arc_length)?;
+        return Some(segment.point_at(t));
+    }
+
+    /// Unit direction of travel at arc length, clamped to the ends.
+    /// Returns `None` if there is no segment or it has no direction.
+    pub fn tangent_at_arc_length(&self, arc_length: f64) -> Option<Vector2D> {
+        let (segment, _) = self.locate(arc_length)?;
+        let direction = segment.end - segment.start;
+        if direction.magnitude() == 0_f64 {
+            return None;
+        }
+
+        return Some(direction.unit_vector());
+    }
+
+    /// Pose at arc length, heading along the polyline, clamped to the ends.
+    /// Returns `None` if there is no segment or it has no direction.
+    pub fn pose_at_arc_length(&self, arc_length: f64) -> Option<Pose2D> {
+        let position = self.point_at_arc_length(arc_length)?;
+        let tangent = self.tangent_at_arc_length(arc_length)?;
+        return Some(Pose2D::new(position, tangent.angle()));
+    }
+
+    /// Returns the point of the polyline closest to point and its arc length.
+    /// Returns `None` for an empty polyline.
+    pub fn closest_point(&self, point: &Vector2D) -> Option<(Vector2D, f64)> {
+        if self.points.len() == 1 {
+            return Some((self.points[0], 0_f64));
+        }
+
+        let mut ret: Option<(Vector2D, f64)> = None;
+        let mut min_distance = f64::INFINITY;
+        let mut arc_length = 0_f64;
+
+        for segment in self.segments() {
+            let closest = segment.closest_point(point);
+            let distance = (closest - point).magnitude();
+            if distance < min_distance {
+                min_distance = distance;
+                ret = Some((closest, arc_length + (closest - segment.start).magnitude()));
+            }
+
+            arc_length += segment.length();
+        }
+
+        return ret;
+    }
+
+    /// Points evenly spaced along the polyline, including both ends.
+    /// The last interval may be shorter than spacing.
+    pub fn resample(&self, spacing: f64) -> Polyline2D {
+        let length = self.length();
+        if self.points.len() < 2 || spacing <= 0_f64 {
+            return self.clone();
+        }
+
+        let mut ret: Vec<Vector2D> = Vec::new();
+        let mut arc_length = 0_f64;
+        while arc_length < length {
+            ret.push(self.point_at_arc_length(arc_length).unwrap());
+            arc_length += spacing;
+        }
+
+        ret.push(*self.points.last().unwrap());
+        return Polyline2D::new(ret);
+    }
+
+    pub fn reversed(&self) -> Polyline2D {
+        return Polyline2D::new(self.points.iter().rev().copied().collect());
+    }
+}
+
+impl From<Vec<Vector2D>> for Polyline2D {
+    fn from(value: Vec<Vector2D>) -> Self {
+        return Polyline2D::new(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector2d::Vector2D};
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::Polyline2D;
+
+    #[test]
+    fn arc_length_queries() {
+        let polyline = Polyline2D::new(vec![
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(3_f64, 0_f64),
+            Vector2D::from_xy(3_f64, 4_f64),
+        ]);
+        assert_eq!(polyline.length(), 7_f64);
+        assert_eq!(
+            polyline.point_at_arc_length(5_f64),
+            Some(Vector2D::from_xy(3_f64, 2_f64))
+        );
+        assert_eq!(
+            polyline.point_at_arc_length(10_f64),
+            Some(Vector2D::from_xy(3_f64, 4_f64))
+        );
+        assert_eq!(
+            polyline.tangent_at_arc_length(1_f64),
+            Some(Vector2D::from_xy(1_f64, 0_f64))
+        );
+        assert_eq!(
+            polyline.pose_at_arc_length(4_f64),
+            Some(Pose2D::from_xytheta(3_f64, 1_f64, FRAC_PI_2))
+        );
+        assert_eq!(
+            polyline.closest_point(&Vector2D::from_xy(5_f64, 1_f64)),
+            Some((Vector2D::from_xy(3_f64, 1_f64), 4_f64))
+        );
+
+        let resampled = polyline.resample(2_f64);
+        assert_eq!(resampled.points.len(), 5);
+        assert_eq!(resampled.points[2], Vector2D::from_xy(3_f64, 1_f64));
+        assert_eq!(
+            polyline.reversed().points[0],
+            Vector2D::from_xy(3_f64, 4_f64)
+        );
+        assert_eq!(Polyline2D::default().point_at_arc_length(0_f64), None);
+    }
+}
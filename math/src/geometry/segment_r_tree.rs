@@ -0,0 +1,270 @@
+use core::numerics::vector2d::Vector2D;
+
+use super::{aabb2d::AABB2D, line_segment2d::LineSegment2D};
+
+/// Maximum number of children of a node.
+const NODE_CAPACITY: usize = 8;
+
+/// R-tree of line segments with values, e.g. the segments of topology edges with their
+/// edge IDs, for box and nearest segment queries. The tree is packed once with the
+/// sort-tile-recursive method and is rebuilt rather than updated when the segments change.
+/// Results that are otherwise equal come in the order the segments were given.
+#[derive(Clone, Debug)]
+pub struct SegmentRTree<V> {
+    entries: Vec<(LineSegment2D, V)>,
+    nodes: Vec<RTreeNode>,
+    root: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct RTreeNode {
+    bounds: AABB2D,
+    /// Indices into the nodes, or into the entries for leaves.
+    children: Vec<usize>,
+    is_leaf: bool,
+}
+
+impl<V> SegmentRTree<V> {
+    pub fn new(entries: Vec<(LineSegment2D, V)>) -> Self {
+        let mut ret = Self {
+            entries: entries,
+            nodes: Vec::new(),
+            root: None,
+        };
+
+        let mut level: Vec<(usize, AABB2D)> = ret
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, (segment, _))| (i, AABB2D::new(segment.start, segment.end)))
+            .collect();
+        let mut is_leaf = true;
+        while !level.is_empty() {
+            let mut parents: Vec<(usize, AABB2D)> = Vec::new();
+            for group in pack(level) {
+                let bounds = group
+                    .iter()
+                    .skip(1)
+                    .fold(group[0].1, |bounds, (_, b)| bounds.union(b));
+                parents.push((ret.nodes.len(), bounds));
+                ret.nodes.push(RTreeNode {
+                    bounds: bounds,
+                    children: group.iter().map(|(i, _)| *i).collect(),
+                    is_leaf: is_leaf,
+                });
+            }
+
+            is_leaf = false;
+            if parents.len() == 1 {
+                ret.root = Some(parents[0].0);
+                break;
+            }
+            level = parents;
+        }
+
+        return ret;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.entries.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.entries.is_empty();
+    }
+
+    /// Bounding box of all segments. Returns `None` if the tree is empty.
+    pub fn bounds(&self) -> Option<AABB2D> {
+        return self.root.map(|root| self.nodes[root].bounds);
+    }
+
+    /// Segments whose bounding boxes intersect region, with their values, in the order they
+    /// were given.
+    pub fn query(&self, region: &AABB2D) -> Vec<(&LineSegment2D, &V)> {
+        let mut found: Vec<usize> = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds.intersects(region) {
+                continue;
+            }
+
+            if node.is_leaf {
+                found.extend(node.children.iter().filter(|i| {
+                    let (segment, _) = &self.entries[**i];
+                    return AABB2D::new(segment.start, segment.end).intersects(region);
+                }));
+            } else {
+                stack.extend(node.children.iter());
+            }
+        }
+
+        found.sort();
+        return found.into_iter().map(|i| self.entry(i)).collect();
+    }
+
+    /// Segment closest to point, with its value. Returns `None` if the tree is empty.
+    pub fn nearest(&self, point: &Vector2D) -> Option<(&LineSegment2D, &V)> {
+        let mut best: Option<(f64, usize)> = None;
+        self.search_nearest(self.root, point, &mut best);
+        return best.map(|(_, i)| self.entry(i));
+    }
+
+    /// Segments within distance of point, with their values, from the closest.
+    pub fn within_distance(&self, point: &Vector2D, distance: f64) -> Vec<(&LineSegment2D, &V)> {
+        let region = AABB2D::new(*point, *point).expanded(distance);
+        let mut found: Vec<(f64, usize)> = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds.intersects(&region) {
+                continue;
+            }
+
+            if node.is_leaf {
+                for i in node.children.iter() {
+                    let d = self.entries[*i].0.distance_to_point(point);
+                    if d <= distance {
+                        found.push((d, *i));
+                    }
+                }
+            } else {
+                stack.extend(node.children.iter());
+            }
+        }
+
+        found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        return found.into_iter().map(|(_, i)| self.entry(i)).collect();
+    }
+
+    fn entry(&self, index: usize) -> (&LineSegment2D, &V) {
+        return (&self.entries[index].0, &self.entries[index].1);
+    }
+
+    /// Branch and bound, visiting the children from the closest box and skipping boxes
+    /// farther than the best segment so far as (distance, entry index).
+    fn search_nearest(
+        &self,
+        node: Option<usize>,
+        point: &Vector2D,
+        best: &mut Option<(f64, usize)>,
+    ) {
+        let node = match node {
+            Some(index) => &self.nodes[index],
+            None => return,
+        };
+
+        if node.is_leaf {
+            for i in node.children.iter() {
+                let d = self.entries[*i].0.distance_to_point(point);
+                if best.is_none_or(|(best_d, best_i)| d < best_d || (d == best_d && *i < best_i)) {
+                    *best = Some((d, *i));
+                }
+            }
+            return;
+        }
+
+        let mut children: Vec<(f64, usize)> = node
+            .children
+            .iter()
+            .map(|i| (self.nodes[*i].bounds.distance_to_point(point), *i))
+            .collect();
+        children.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for (d, i) in children {
+            if best.is_some_and(|(best_d, _)| d > best_d) {
+                break;
+            }
+            self.search_nearest(Some(i), point, best);
+        }
+    }
+}
+
+/// Group boxes into nodes with the sort-tile-recursive method: sort by center x, cut into
+/// vertical slices, sort each slice by center y and cut it into nodes.
+fn pack(mut items: Vec<(usize, AABB2D)>) -> Vec<Vec<(usize, AABB2D)>> {
+    let node_count = items.len().div_ceil(NODE_CAPACITY);
+    let slice_count = (node_count as f64).sqrt().ceil() as usize;
+    let slice_size = slice_count * NODE_CAPACITY;
+
+    items.sort_by(|a, b| {
+        a.1.center()
+            .x
+            .total_cmp(&b.1.center().x)
+            .then(a.0.cmp(&b.0))
+    });
+    let mut ret: Vec<Vec<(usize, AABB2D)>> = Vec::new();
+    for slice in items.chunks_mut(slice_size) {
+        slice.sort_by(|a, b| {
+            a.1.center()
+                .y
+                .total_cmp(&b.1.center().y)
+                .then(a.0.cmp(&b.0))
+        });
+        ret.extend(slice.chunks(NODE_CAPACITY).map(|group| group.to_vec()));
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::geometry::{aabb2d::AABB2D, line_segment2d::LineSegment2D};
+
+    use super::SegmentRTree;
+
+    #[test]
+    fn matches_brute_force() {
+        let mut state: u64 = 7;
+        let mut next = || -> f64 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            return ((state >> 33) % 1000) as f64 * 0.01;
+        };
+        let segments: Vec<LineSegment2D> = (0..250)
+            .map(|_| {
+                let start = Vector2D::from_xy(next(), next());
+                let end = start + Vector2D::from_xy(next() * 0.1 - 0.5, next() * 0.1 - 0.5);
+                return LineSegment2D::new(start, end);
+            })
+            .collect();
+        let tree = SegmentRTree::new(segments.iter().copied().zip(0..250).collect());
+        assert_eq!(tree.len(), 250);
+
+        for _ in 0..40 {
+            let point = Vector2D::from_xy(next(), next());
+            let mut expected: Vec<(f64, usize)> = segments
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (s.distance_to_point(&point), i))
+                .collect();
+            expected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+            assert_eq!(tree.nearest(&point).map(|(_, v)| *v), Some(expected[0].1));
+
+            let within: Vec<usize> = tree
+                .within_distance(&point, 1_f64)
+                .iter()
+                .map(|(_, v)| **v)
+                .collect();
+            let expected_within: Vec<usize> = expected
+                .iter()
+                .filter(|e| e.0 <= 1_f64)
+                .map(|e| e.1)
+                .collect();
+            assert_eq!(within, expected_within);
+
+            let region = AABB2D::new(point, point).expanded(1.5);
+            let queried: Vec<usize> = tree.query(&region).iter().map(|(_, v)| **v).collect();
+            let expected_queried: Vec<usize> = (0..250)
+                .filter(|i| AABB2D::new(segments[*i].start, segments[*i].end).intersects(&region))
+                .collect();
+            assert_eq!(queried, expected_queried);
+        }
+
+        let empty: SegmentRTree<usize> = SegmentRTree::new(Vec::new());
+        assert!(empty.nearest(&Vector2D::zero()).is_none() && empty.bounds().is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use core::numerics::vector2d::Vector2D;
+
+use crate::geometry::{aabb2d::AABB2D, polygon2d::Polygon2D};
+
+/// count evenly spaced values from start to end, both included.
+/// Returns only start for a count of one and nothing for zero.
+pub fn linspace(start: f64, end: f64, count: usize) -> Vec<f64> {
+    if count == 1 {
+        return vec![start];
+    }
+
+    let step = (end - start) / (count as f64 - 1_f64);
+    return (0..count)
+        .map(|i| {
+            if i == count - 1 {
+                return end;
+            }
+            return start + step * i as f64;
+        })
+        .collect();
+}
+
+/// Positions along one axis from min to max, spaced by spacing and centered in the range.
+fn lattice(min: f64, max: f64, spacing: f64) -> Vec<f64> {
+    let count = ((max - min) / spacing).floor() as usize + 1;
+    let offset = ((max - min) - (count - 1) as f64 * spacing) / 2_f64;
+    return (0..count)
+        .map(|i| min + offset + i as f64 * spacing)
+        .collect();
+}
+
+/// Points of a square lattice with the given spacing, centered in the rectangle.
+/// Ordered row by row from the bottom, left to right. Returns nothing for non-positive spacing.
+pub fn grid_points(rectangle: &AABB2D, spacing: f64) -> Vec<Vector2D> {
+    if spacing <= 0_f64 {
+        return Vec::new();
+    }
+
+    let xs = lattice(rectangle.min.x, rectangle.max.x, spacing);
+    let ys = lattice(rectangle.min.y, rectangle.max.y, spacing);
+
+    let mut ret: Vec<Vector2D> = Vec::with_capacity(xs.len() * ys.len());
+    for y in ys.iter() {
+        for x in xs.iter() {
+            ret.push(Vector2D::from_xy(*x, *y));
+        }
+    }
+
+    return ret;
+}
+
+/// Lattice points of the polygon's bounding box that lie inside the polygon.
+pub fn grid_points_in_polygon(polygon: &Polygon2D, spacing: f64) -> Vec<Vector2D> {
+    let bounding_box = match polygon.bounding_box() {
+        Some(b) => b,
+        None => return Vec::new(),
+    };
+
+    return grid_points(&bounding_box, spacing)
+        .into_iter()
+        .filter(|p| polygon.contains(p))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use crate::geometry::aabb2d::AABB2D;
+
+    use super::{grid_points, linspace};
+
+    #[test]
+    fn spacing() {
+        assert_eq!(
+            linspace(0_f64, 1_f64, 5),
+            vec![0_f64, 0.25, 0.5, 0.75, 1_f64]
+        );
+        assert_eq!(linspace(2_f64, 3_f64, 1), vec![2_f64]);
+        assert!(linspace(2_f64, 3_f64, 0).is_empty());
+
+        let rectangle = AABB2D::new(Vector2D::zero(), Vector2D::from_xy(2.5, 1_f64));
+        let points = grid_points(&rectangle, 1_f64);
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[0], Vector2D::from_xy(0.25, 0_f64));
+        assert_eq!(points[5], Vector2D::from_xy(2.25, 1_f64));
+    }
+}
@@ -0,0 +1,61 @@
+use core::numerics::{pose2d::Pose2D, pose3d::Pose3D, vector2d::Vector2D, vector3d::Vector3D};
+
+use crate::angle;
+
+/// Interpolate linearly, with t = 0 giving a and t = 1 giving b. t is not clamped.
+pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    return a + (b - a) * t;
+}
+
+/// Parameter t for which `lerp(a, b, t)` gives value. Returns 0 if a equals b.
+pub fn inverse_lerp(a: f64, b: f64, value: f64) -> f64 {
+    if a == b {
+        return 0_f64;
+    }
+
+    return (value - a) / (b - a);
+}
+
+pub fn lerp_vector2d(a: &Vector2D, b: &Vector2D, t: f64) -> Vector2D {
+    return a + (b - a) * t;
+}
+
+pub fn lerp_vector3d(a: &Vector3D, b: &Vector3D, t: f64) -> Vector3D {
+    return a + (b - a) * t;
+}
+
+/// Interpolate position linearly and heading along the shorter arc.
+pub fn interpolate_pose2d(a: &Pose2D, b: &Pose2D, t: f64) -> Pose2D {
+    return Pose2D::new(
+        lerp_vector2d(&a.position, &b.position, t),
+        angle::lerp(a.theta, b.theta, t),
+    );
+}
+
+/// Interpolate position linearly and orientation by spherical linear interpolation.
+pub fn interpolate_pose3d(a: &Pose3D, b: &Pose3D, t: f64) -> Pose3D {
+    return Pose3D::new(
+        lerp_vector3d(&a.position, &b.position, t),
+        a.orientation.slerp(&b.orientation, t),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector2d::Vector2D};
+    use std::f64::consts::PI;
+
+    use super::{interpolate_pose2d, inverse_lerp, lerp};
+
+    #[test]
+    fn interpolates() {
+        assert_eq!(lerp(2_f64, 4_f64, 0.25), 2.5);
+        assert_eq!(inverse_lerp(2_f64, 4_f64, 2.5), 0.25);
+
+        let a = Pose2D::from_xytheta(0_f64, 0_f64, 0.9 * PI);
+        let b = Pose2D::from_xytheta(2_f64, 4_f64, -0.9 * PI);
+        let mid = interpolate_pose2d(&a, &b, 0.5);
+        assert_eq!(mid.position, Vector2D::from_xy(1_f64, 2_f64));
+        assert!((mid.theta - PI).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,17 @@
+pub mod algorithm;
+pub mod angle;
+pub mod extended_kalman_filter;
+pub mod filter;
+pub mod geometry;
+pub mod grid_sampling;
+pub mod interp;
+pub mod motion;
+pub mod pose_graph;
+#[cfg(feature = "rand")]
+pub mod sampling;
+pub mod statistics;
+pub mod transform_tree;
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -0,0 +1,269 @@
+use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+use std::f64::consts::PI;
+
+use super::path_segment::{pose_along, sample_along, PathSegment, SegmentType};
+
+/// Tolerance for rounding error in the normalized path equations.
+const EPSILON: f64 = 1e-9;
+
+/// Sequence of turns and straight lines of a Dubins path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DubinsWord {
+    Lsl,
+    Rsr,
+    Lsr,
+    Rsl,
+    Rlr,
+    Lrl,
+}
+
+impl DubinsWord {
+    pub fn all() -> [DubinsWord; 6] {
+        return [
+            DubinsWord::Lsl,
+            DubinsWord::Rsr,
+            DubinsWord::Lsr,
+            DubinsWord::Rsl,
+            DubinsWord::Rlr,
+            DubinsWord::Lrl,
+        ];
+    }
+
+    fn segment_types(&self) -> [SegmentType; 3] {
+        let (l, s, r) = (SegmentType::Left, SegmentType::Straight, SegmentType::Right);
+        return match self {
+            DubinsWord::Lsl => [l, s, l],
+            DubinsWord::Rsr => [r, s, r],
+            DubinsWord::Lsr => [l, s, r],
+            DubinsWord::Rsl => [r, s, l],
+            DubinsWord::Rlr => [r, l, r],
+            DubinsWord::Lrl => [l, r, l],
+        };
+    }
+}
+
+/// Shortest forward-only path between two poses for a vehicle with a minimum turning radius,
+/// made of three arcs or straight lines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DubinsPath {
+    pub start: Pose2D,
+    pub radius: f64,
+    pub word: DubinsWord,
+    pub segments: [PathSegment; 3],
+}
+
+impl DubinsPath {
+    /// Shortest of the six candidate words. Returns `None` for a non-positive radius.
+    pub fn shortest(start: &Pose2D, goal: &Pose2D, radius: f64) -> Option<Self> {
+        return DubinsWord::all()
+            .iter()
+            .filter_map(|word| DubinsPath::with_word(start, goal, radius, *word))
+            .min_by(|a, b| a.length().total_cmp(&b.length()));
+    }
+
+    /// Path of the given word. Returns `None` if the word cannot connect the poses, e.g. a
+    /// three-arc word for poses far apart, or for a non-positive radius.
+    pub fn with_word(start: &Pose2D, goal: &Pose2D, radius: f64, word: DubinsWord) -> Option<Self> {
+        if radius <= 0_f64 {
+            return None;
+        }
+
+        // Normalize to a unit turning radius with the goal on the positive x axis.
+        let offset = goal.position - start.position;
+        let d = offset.magnitude() / radius;
+        let phi = offset.y.atan2(offset.x);
+        let alpha = mod_two_pi(start.theta - phi);
+        let beta = mod_two_pi(goal.theta - phi);
+
+        let [t, p, q] = word_lengths(word, alpha, beta, d)?;
+        let types = word.segment_types();
+        return Some(Self {
+            start: *start,
+            radius: radius,
+            word: word,
+            segments: [
+                PathSegment::new(types[0], t * radius),
+                PathSegment::new(types[1], p * radius),
+                PathSegment::new(types[2], q * radius),
+            ],
+        });
+    }
+
+    pub fn length(&self) -> f64 {
+        return self.segments.iter().map(|s| s.length).sum();
+    }
+
+    /// Pose after driving distance along the path, clamped to its ends.
+    pub fn pose_at(&self, distance: f64) -> Pose2D {
+        return pose_along(&self.start, self.radius, &self.segments, distance);
+    }
+
+    /// Poses spaced by step along the path, including both ends.
+    pub fn sample(&self, step: f64) -> Vec<Pose2D> {
+        return sample_along(&self.start, self.radius, &self.segments, step);
+    }
+}
+
+/// Dubins paths through consecutive waypoints, e.g. of a topology route. The heading at
+/// each waypoint points from the previous waypoint to the next one. Returns nothing for a
+/// non-positive radius or fewer than two waypoints.
+pub fn dubins_through_waypoints(waypoints: &[Vector2D], radius: f64) -> Vec<DubinsPath> {
+    let n = waypoints.len();
+    if n < 2 || radius <= 0_f64 {
+        return Vec::new();
+    }
+
+    let poses: Vec<Pose2D> = (0..n)
+        .map(|i| {
+            let from = waypoints[i.saturating_sub(1)];
+            let to = waypoints[(i + 1).min(n - 1)];
+            return Pose2D::new(waypoints[i], (to - from).angle());
+        })
+        .collect();
+
+    return poses
+        .windows(2)
+        .map(|pair| DubinsPath::shortest(&pair[0], &pair[1], radius).unwrap())
+        .collect();
+}
+
+/// Angle in [0, 2π), with angles within rounding error of a full turn mapped to zero.
+fn mod_two_pi(angle: f64) -> f64 {
+    let ret = angle.rem_euclid(2_f64 * PI);
+    if 2_f64 * PI - ret < EPSILON {
+        return 0_f64;
+    }
+    return ret;
+}
+
+/// Square root that tolerates rounding error around zero. Returns `None` for clearly
+/// negative values, i.e. when a word cannot connect the poses.
+fn non_negative_sqrt(value: f64) -> Option<f64> {
+    if value < -EPSILON {
+        return None;
+    }
+    return Some(value.max(0_f64).sqrt());
+}
+
+/// Normalized segment lengths (t, p, q) of a word for a unit turning radius, following
+/// Shkel and Lumelsky, "Classification of the Dubins set".
+fn word_lengths(word: DubinsWord, alpha: f64, beta: f64, d: f64) -> Option<[f64; 3]> {
+    let (sa, ca) = alpha.sin_cos();
+    let (sb, cb) = beta.sin_cos();
+    let c_ab = (alpha - beta).cos();
+
+    return match word {
+        DubinsWord::Lsl => {
+            let p_squared = 2_f64 + d * d - 2_f64 * c_ab + 2_f64 * d * (sa - sb);
+            let p = non_negative_sqrt(p_squared)?;
+            let angle = (cb - ca).atan2(d + sa - sb);
+            Some([mod_two_pi(angle - alpha), p, mod_two_pi(beta - angle)])
+        }
+        DubinsWord::Rsr => {
+            let p_squared = 2_f64 + d * d - 2_f64 * c_ab + 2_f64 * d * (sb - sa);
+            let p = non_negative_sqrt(p_squared)?;
+            let angle = (ca - cb).atan2(d - sa + sb);
+            Some([mod_two_pi(alpha - angle), p, mod_two_pi(angle - beta)])
+        }
+        DubinsWord::Lsr => {
+            let p_squared = -2_f64 + d * d + 2_f64 * c_ab + 2_f64 * d * (sa + sb);
+            let p = non_negative_sqrt(p_squared)?;
+            let angle = (-ca - cb).atan2(d + sa + sb) - (-2_f64).atan2(p);
+            Some([mod_two_pi(angle - alpha), p, mod_two_pi(angle - beta)])
+        }
+        DubinsWord::Rsl => {
+            let p_squared = -2_f64 + d * d + 2_f64 * c_ab - 2_f64 * d * (sa + sb);
+            let p = non_negative_sqrt(p_squared)?;
+            let angle = (ca + cb).atan2(d - sa - sb) - 2_f64.atan2(p);
+            Some([mod_two_pi(alpha - angle), p, mod_two_pi(beta - angle)])
+        }
+        DubinsWord::Rlr => {
+            let cos_p = (6_f64 - d * d + 2_f64 * c_ab + 2_f64 * d * (sa - sb)) / 8_f64;
+            if cos_p.abs() > 1_f64 + EPSILON {
+                return None;
+            }
+            let cos_p = cos_p.clamp(-1_f64, 1_f64);
+            let angle = (ca - cb).atan2(d - sa + sb);
+            let p = mod_two_pi(2_f64 * PI - cos_p.acos());
+            let t = mod_two_pi(alpha - angle + p / 2_f64);
+            Some([t, p, mod_two_pi(alpha - beta - t + p)])
+        }
+        DubinsWord::Lrl => {
+            let cos_p = (6_f64 - d * d + 2_f64 * c_ab + 2_f64 * d * (sb - sa)) / 8_f64;
+            if cos_p.abs() > 1_f64 + EPSILON {
+                return None;
+            }
+            let cos_p = cos_p.clamp(-1_f64, 1_f64);
+            let angle = (ca - cb).atan2(d + sa - sb);
+            let p = mod_two_pi(2_f64 * PI - cos_p.acos());
+            let t = mod_two_pi(-alpha - angle + p / 2_f64);
+            Some([t, p, mod_two_pi(beta - alpha - t + p)])
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use super::{dubins_through_waypoints, DubinsPath, DubinsWord};
+
+    fn assert_reaches(path: &DubinsPath, goal: &Pose2D) {
+        let end = path.pose_at(path.length());
+        assert!(
+            (end.position - goal.position).magnitude() < 1e-9,
+            "{:?}",
+            path.word
+        );
+        assert!(
+            (end.relative_pose(goal).theta).abs() < 1e-9,
+            "{:?}",
+            path.word
+        );
+    }
+
+    #[test]
+    fn paths_reach_goal() {
+        let start = Pose2D::from_xytheta(0_f64, 0_f64, 0_f64);
+
+        // Straight ahead needs no turning.
+        let straight =
+            DubinsPath::shortest(&start, &Pose2D::from_xytheta(5_f64, 0_f64, 0_f64), 1_f64)
+                .unwrap();
+        assert!((straight.length() - 5_f64).abs() < 1e-9);
+
+        // Quarter turn to the left.
+        let goal = Pose2D::from_xytheta(1_f64, 1_f64, FRAC_PI_2);
+        let turn = DubinsPath::shortest(&start, &goal, 1_f64).unwrap();
+        assert!((turn.length() - FRAC_PI_2).abs() < 1e-9);
+
+        // Every word that exists for a pose pair ends at the goal.
+        for i in 0..50 {
+            let goal = Pose2D::from_xytheta(
+                (i as f64 * 1.7).sin() * 4_f64,
+                (i as f64 * 0.9).cos() * 3_f64,
+                i as f64 * 0.37 * PI,
+            );
+            for word in DubinsWord::all() {
+                if let Some(path) = DubinsPath::with_word(&start, &goal, 1.5, word) {
+                    assert_reaches(&path, &goal);
+                }
+            }
+            assert!(DubinsPath::shortest(&start, &goal, 1.5).is_some());
+        }
+
+        let paths = dubins_through_waypoints(
+            &[
+                Vector2D::from_xy(0_f64, 0_f64),
+                Vector2D::from_xy(5_f64, 0_f64),
+                Vector2D::from_xy(5_f64, 5_f64),
+            ],
+            1_f64,
+        );
+        assert_eq!(paths.len(), 2);
+        let samples = paths[0].sample(0.1);
+        assert_eq!(samples[0], paths[0].start);
+        assert_reaches(&paths[0], &paths[1].start);
+    }
+}
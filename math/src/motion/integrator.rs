@@ -0,0 +1,90 @@
+/// Method for advancing a state along its time derivative.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Integrator {
+    /// Explicit Euler method, first order.
+    Euler,
+    /// Classic fourth-order Runge-Kutta method.
+    RungeKutta4,
+}
+
+impl Integrator {
+    /// Advance state from time t by dt, given its derivative as `derivative_fn(state, time)`.
+    pub fn step<const N: usize, F>(
+        &self,
+        derivative_fn: &F,
+        state: &[f64; N],
+        t: f64,
+        dt: f64,
+    ) -> [f64; N]
+    where
+        F: Fn(&[f64; N], f64) -> [f64; N],
+    {
+        return match self {
+            Integrator::Euler => euler_step(derivative_fn, state, t, dt),
+            Integrator::RungeKutta4 => rk4_step(derivative_fn, state, t, dt),
+        };
+    }
+}
+
+pub fn euler_step<const N: usize, F>(
+    derivative_fn: &F,
+    state: &[f64; N],
+    t: f64,
+    dt: f64,
+) -> [f64; N]
+where
+    F: Fn(&[f64; N], f64) -> [f64; N],
+{
+    return add_scaled(state, &(derivative_fn)(state, t), dt);
+}
+
+pub fn rk4_step<const N: usize, F>(derivative_fn: &F, state: &[f64; N], t: f64, dt: f64) -> [f64; N]
+where
+    F: Fn(&[f64; N], f64) -> [f64; N],
+{
+    let half_dt = dt / 2_f64;
+    let k1 = (derivative_fn)(state, t);
+    let k2 = (derivative_fn)(&add_scaled(state, &k1, half_dt), t + half_dt);
+    let k3 = (derivative_fn)(&add_scaled(state, &k2, half_dt), t + half_dt);
+    let k4 = (derivative_fn)(&add_scaled(state, &k3, dt), t + dt);
+
+    let mut ret = *state;
+    for i in 0..N {
+        ret[i] += dt / 6_f64 * (k1[i] + 2_f64 * k2[i] + 2_f64 * k3[i] + k4[i]);
+    }
+
+    return ret;
+}
+
+/// Compute state + derivative * scale.
+fn add_scaled<const N: usize>(state: &[f64; N], derivative: &[f64; N], scale: f64) -> [f64; N] {
+    let mut ret = *state;
+    for i in 0..N {
+        ret[i] += derivative[i] * scale;
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Integrator;
+
+    #[test]
+    fn rk4_is_more_accurate() {
+        // x' = x, so x(1) = e.
+        let derivative_fn = |x: &[f64; 1], _: f64| [x[0]];
+        let integrate = |integrator: Integrator| {
+            let mut state = [1_f64];
+            for i in 0..10 {
+                state = integrator.step(&derivative_fn, &state, i as f64 * 0.1, 0.1);
+            }
+            return state[0];
+        };
+
+        let euler_error = (integrate(Integrator::Euler) - std::f64::consts::E).abs();
+        let rk4_error = (integrate(Integrator::RungeKutta4) - std::f64::consts::E).abs();
+        assert!(euler_error > 0.1);
+        assert!(rk4_error < 1e-5);
+    }
+}
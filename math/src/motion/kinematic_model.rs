@@ -0,0 +1,156 @@
+use core::numerics::pose2d::Pose2D;
+
+use super::integrator::Integrator;
+
+/// Motion model of a planar robot, with state (x, y, theta).
+pub trait KinematicModel {
+    type Control;
+
+    /// Time derivative of (x, y, theta) under a constant control input.
+    fn derivative(&self, state: &[f64; 3], control: &Self::Control) -> [f64; 3];
+
+    /// Simulate holding the control for `steps` steps of dt, e.g. to roll out a candidate
+    /// trajectory of a local planner. Returns the poses after each step, excluding the start.
+    fn rollout(
+        &self,
+        start: &Pose2D,
+        control: &Self::Control,
+        dt: f64,
+        steps: usize,
+        integrator: Integrator,
+    ) -> Vec<Pose2D> {
+        let derivative_fn = |state: &[f64; 3], _: f64| self.derivative(state, control);
+        let mut state = [start.position.x, start.position.y, start.theta];
+        let mut ret: Vec<Pose2D> = Vec::with_capacity(steps);
+
+        for i in 0..steps {
+            state = integrator.step(&derivative_fn, &state, i as f64 * dt, dt);
+            ret.push(Pose2D::from_xytheta(state[0], state[1], state[2]));
+        }
+
+        return ret;
+    }
+}
+
+/// Velocity command of a differential drive robot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DifferentialDriveControl {
+    /// Forward speed in meters per second.
+    pub linear: f64,
+    /// Turn rate in radians per second, counterclockwise.
+    pub angular: f64,
+}
+
+/// Robot steering by the speed difference of two wheels on a common axle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DifferentialDrive {
+    /// Distance between the wheels in meters.
+    pub wheel_base: f64,
+}
+
+impl DifferentialDrive {
+    pub fn new(wheel_base: f64) -> Self {
+        return Self {
+            wheel_base: wheel_base,
+        };
+    }
+
+    /// Convert the speeds of the left and right wheel into a velocity command.
+    pub fn control_from_wheel_speeds(&self, left: f64, right: f64) -> DifferentialDriveControl {
+        return DifferentialDriveControl {
+            linear: (left + right) / 2_f64,
+            angular: (right - left) / self.wheel_base,
+        };
+    }
+}
+
+impl KinematicModel for DifferentialDrive {
+    type Control = DifferentialDriveControl;
+
+    fn derivative(&self, state: &[f64; 3], control: &Self::Control) -> [f64; 3] {
+        let (sin, cos) = state[2].sin_cos();
+        return [control.linear * cos, control.linear * sin, control.angular];
+    }
+}
+
+/// Command of a car-like robot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BicycleControl {
+    /// Speed of the rear axle in meters per second.
+    pub speed: f64,
+    /// Angle of the front wheel in radians, positive to the left.
+    pub steering_angle: f64,
+}
+
+/// Car-like robot reduced to one front and one rear wheel, with the rear axle as reference.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bicycle {
+    /// Distance between the axles in meters.
+    pub wheel_base: f64,
+}
+
+impl Bicycle {
+    pub fn new(wheel_base: f64) -> Self {
+        return Self {
+            wheel_base: wheel_base,
+        };
+    }
+}
+
+impl KinematicModel for Bicycle {
+    type Control = BicycleControl;
+
+    fn derivative(&self, state: &[f64; 3], control: &Self::Control) -> [f64; 3] {
+        let (sin, cos) = state[2].sin_cos();
+        return [
+            control.speed * cos,
+            control.speed * sin,
+            control.speed * control.steering_angle.tan() / self.wheel_base,
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+    use std::f64::consts::PI;
+
+    use crate::motion::integrator::Integrator;
+
+    use super::{Bicycle, BicycleControl, DifferentialDrive, KinematicModel};
+
+    #[test]
+    fn rolls_out_circles() {
+        // Half a circle with radius 1.
+        let drive = DifferentialDrive::new(0.5);
+        let control = drive.control_from_wheel_speeds(0.75, 1.25);
+        let poses = drive.rollout(
+            &Pose2D::identity(),
+            &control,
+            PI / 100_f64,
+            100,
+            Integrator::RungeKutta4,
+        );
+        assert_eq!(poses.len(), 100);
+        let end = poses.last().unwrap();
+        assert!((end.position - Vector2D::from_xy(0_f64, 2_f64)).magnitude() < 1e-6);
+        assert!((end.theta - PI).abs() < 1e-6);
+
+        // Turning radius of the bicycle is wheel_base / tan(steering_angle) = 2.
+        let bicycle = Bicycle::new(2_f64);
+        let control = BicycleControl {
+            speed: 1_f64,
+            steering_angle: PI / 4_f64,
+        };
+        let poses = bicycle.rollout(
+            &Pose2D::identity(),
+            &control,
+            PI / 100_f64,
+            100,
+            Integrator::RungeKutta4,
+        );
+        assert!(
+            (poses.last().unwrap().position - Vector2D::from_xy(2_f64, 2_f64)).magnitude() < 1e-6
+        );
+    }
+}
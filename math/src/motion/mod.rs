@@ -0,0 +1,6 @@
+pub mod dubins_path;
+pub mod integrator;
+pub mod kinematic_model;
+pub mod path_segment;
+pub mod reeds_shepp_path;
+pub mod trajectory;
@@ -0,0 +1,102 @@
+use core::numerics::{pose2d::Pose2D, vector2d::Vector2D};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SegmentType {
+    /// Arc turning counterclockwise.
+    Left,
+    Straight,
+    /// Arc turning clockwise.
+    Right,
+}
+
+/// Piece of a path for a car-like robot, either a straight line or an arc of the minimum
+/// turning radius. Negative lengths are driven in reverse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathSegment {
+    pub segment_type: SegmentType,
+    pub length: f64,
+}
+
+impl PathSegment {
+    pub fn new(segment_type: SegmentType, length: f64) -> Self {
+        return Self {
+            segment_type: segment_type,
+            length: length,
+        };
+    }
+
+    /// Pose after driving distance along the segment from start, in the direction of travel
+    /// of the segment.
+    pub fn pose_at(&self, start: &Pose2D, radius: f64, distance: f64) -> Pose2D {
+        let s = distance.abs() * self.length.signum();
+        let theta = start.theta;
+        let (sin, cos) = theta.sin_cos();
+
+        return match self.segment_type {
+            SegmentType::Straight => {
+                Pose2D::new(start.position + Vector2D::from_xy(cos, sin) * s, theta)
+            }
+            SegmentType::Left => {
+                let center = start.position + Vector2D::from_xy(-sin, cos) * radius;
+                let end_theta = theta + s / radius;
+                let (end_sin, end_cos) = end_theta.sin_cos();
+                Pose2D::new(
+                    center + Vector2D::from_xy(end_sin, -end_cos) * radius,
+                    end_theta,
+                )
+            }
+            SegmentType::Right => {
+                let center = start.position + Vector2D::from_xy(sin, -cos) * radius;
+                let end_theta = theta - s / radius;
+                let (end_sin, end_cos) = end_theta.sin_cos();
+                Pose2D::new(
+                    center + Vector2D::from_xy(-end_sin, end_cos) * radius,
+                    end_theta,
+                )
+            }
+        };
+    }
+
+    pub fn end_pose(&self, start: &Pose2D, radius: f64) -> Pose2D {
+        return self.pose_at(start, radius, self.length);
+    }
+}
+
+/// Pose after driving distance along consecutive segments, clamped to their ends.
+pub fn pose_along(start: &Pose2D, radius: f64, segments: &[PathSegment], distance: f64) -> Pose2D {
+    let mut pose = *start;
+    let mut remaining = distance.max(0_f64);
+
+    for segment in segments {
+        if remaining <= segment.length.abs() {
+            return segment.pose_at(&pose, radius, remaining);
+        }
+
+        pose = segment.end_pose(&pose, radius);
+        remaining -= segment.length.abs();
+    }
+
+    return pose;
+}
+
+/// Poses spaced by step along consecutive segments, including both ends.
+pub fn sample_along(
+    start: &Pose2D,
+    radius: f64,
+    segments: &[PathSegment],
+    step: f64,
+) -> Vec<Pose2D> {
+    let length: f64 = segments.iter().map(|s| s.length.abs()).sum();
+    let mut ret: Vec<Pose2D> = Vec::new();
+
+    if step > 0_f64 {
+        let mut distance = 0_f64;
+        while distance < length {
+            ret.push(pose_along(start, radius, segments, distance));
+            distance += step;
+        }
+    }
+
+    ret.push(pose_along(start, radius, segments, length));
+    return ret;
+}
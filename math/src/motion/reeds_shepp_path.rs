@@ -0,0 +1,386 @@
+use core::numerics::pose2d::Pose2D;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use super::path_segment::{pose_along, sample_along, PathSegment, SegmentType};
+use crate::angle::normalize;
+
+/// Tolerance for rounding error in the normalized path equations.
+const EPSILON: f64 = 1e-9;
+
+/// Solves a path family for a goal at (x, y, phi) relative to the start with a unit turning
+/// radius, returning its free parameters (t, u, v).
+type Formula = fn(f64, f64, f64) -> Option<[f64; 3]>;
+
+/// Signed normalized segment lengths of a path family from its free parameters.
+type Lengths = fn([f64; 3]) -> Vec<f64>;
+
+/// Shortest path between two poses for a vehicle with a minimum turning radius that can drive
+/// both forwards and in reverse. Segments with a negative length are driven in reverse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReedsSheppPath {
+    pub start: Pose2D,
+    pub radius: f64,
+    pub segments: Vec<PathSegment>,
+}
+
+impl ReedsSheppPath {
+    /// Shortest of all candidate paths. Returns `None` for a non-positive radius.
+    pub fn shortest(start: &Pose2D, goal: &Pose2D, radius: f64) -> Option<Self> {
+        return ReedsSheppPath::candidates(start, goal, radius)
+            .into_iter()
+            .min_by(|a, b| a.length().total_cmp(&b.length()));
+    }
+
+    /// Every path of the Reeds-Shepp families that connects the poses, including suboptimal
+    /// ones, e.g. to choose by a criterion other than length such as the number of reversals.
+    pub fn candidates(start: &Pose2D, goal: &Pose2D, radius: f64) -> Vec<Self> {
+        if radius <= 0_f64 {
+            return Vec::new();
+        }
+
+        // Normalize to a unit turning radius in the frame of the start pose.
+        let (sin, cos) = start.theta.sin_cos();
+        let offset = (goal.position - start.position) / radius;
+        let x = offset.x * cos + offset.y * sin;
+        let y = -offset.x * sin + offset.y * cos;
+        let phi = normalize(goal.theta - start.theta);
+
+        let (l, s, r) = (SegmentType::Left, SegmentType::Straight, SegmentType::Right);
+        let families: [(Formula, Vec<SegmentType>, Lengths); 8] = [
+            (left_straight_left, vec![l, s, l], |[t, u, v]| vec![t, u, v]),
+            (left_straight_right, vec![l, s, r], |[t, u, v]| {
+                vec![t, u, v]
+            }),
+            (left_right_left, vec![l, r, l], |[t, u, v]| vec![t, u, v]),
+            (left_right_cusp_left_right, vec![l, r, l, r], |[t, u, v]| {
+                vec![t, u, -u, v]
+            }),
+            (
+                left_cusp_right_left_cusp_right,
+                vec![l, r, l, r],
+                |[t, u, v]| vec![t, u, u, v],
+            ),
+            (left_right90_straight_left, vec![l, r, s, l], |[t, u, v]| {
+                vec![t, -FRAC_PI_2, u, v]
+            }),
+            (left_right90_straight_right, vec![l, r, s, r], |[t, u, v]| {
+                vec![t, -FRAC_PI_2, u, v]
+            }),
+            (
+                left_right90_straight_left90_right,
+                vec![l, r, s, l, r],
+                |[t, u, v]| vec![t, -FRAC_PI_2, u, -FRAC_PI_2, v],
+            ),
+        ];
+
+        let mut ret: Vec<Self> = Vec::new();
+        for (formula, types, lengths) in families.iter() {
+            for (types, lengths) in symmetric_solutions(*formula, x, y, phi, types, *lengths) {
+                let segments: Vec<PathSegment> = types
+                    .into_iter()
+                    .zip(lengths)
+                    .filter(|(_, length)| length.abs() > EPSILON)
+                    .map(|(segment_type, length)| PathSegment::new(segment_type, length * radius))
+                    .collect();
+                ret.push(Self {
+                    start: *start,
+                    radius: radius,
+                    segments: segments,
+                });
+            }
+        }
+
+        return ret;
+    }
+
+    /// Total distance driven, forwards and in reverse.
+    pub fn length(&self) -> f64 {
+        return self.segments.iter().map(|s| s.length.abs()).sum();
+    }
+
+    /// Number of changes between driving forwards and in reverse.
+    pub fn cusp_count(&self) -> usize {
+        return self
+            .segments
+            .windows(2)
+            .filter(|pair| (pair[0].length < 0_f64) != (pair[1].length < 0_f64))
+            .count();
+    }
+
+    /// Pose after driving distance along the path, clamped to its ends.
+    pub fn pose_at(&self, distance: f64) -> Pose2D {
+        return pose_along(&self.start, self.radius, &self.segments, distance);
+    }
+
+    /// Poses spaced by step of distance driven along the path, including both ends.
+    pub fn sample(&self, step: f64) -> Vec<Pose2D> {
+        return sample_along(&self.start, self.radius, &self.segments, step);
+    }
+}
+
+/// Solutions of a family for the goal as given and under the symmetries of the problem:
+/// driving in reverse (time flip), swapping left and right turns (reflection) and following
+/// the path from the goal to the start (backwards), in all combinations.
+fn symmetric_solutions(
+    formula: Formula,
+    x: f64,
+    y: f64,
+    phi: f64,
+    types: &[SegmentType],
+    lengths: Lengths,
+) -> Vec<(Vec<SegmentType>, Vec<f64>)> {
+    let (sin, cos) = phi.sin_cos();
+    let mut ret: Vec<(Vec<SegmentType>, Vec<f64>)> = Vec::new();
+
+    for backwards in [false, true] {
+        let (bx, by) = if backwards {
+            (x * cos + y * sin, x * sin - y * cos)
+        } else {
+            (x, y)
+        };
+
+        for (time_flip, reflect) in [(false, false), (true, false), (false, true), (true, true)] {
+            let fx = if time_flip { -bx } else { bx };
+            let fy = if reflect { -by } else { by };
+            let fphi = if time_flip != reflect { -phi } else { phi };
+            let solution = match formula(fx, fy, fphi) {
+                Some(solution) => solution,
+                None => continue,
+            };
+
+            let mut solution_types: Vec<SegmentType> = types
+                .iter()
+                .map(|segment_type| match (reflect, segment_type) {
+                    (true, SegmentType::Left) => SegmentType::Right,
+                    (true, SegmentType::Right) => SegmentType::Left,
+                    _ => *segment_type,
+                })
+                .collect();
+            let mut solution_lengths: Vec<f64> = lengths(solution)
+                .into_iter()
+                .map(|length| if time_flip { -length } else { length })
+                .collect();
+            if backwards {
+                solution_types.reverse();
+                solution_lengths.reverse();
+            }
+            ret.push((solution_types, solution_lengths));
+        }
+    }
+
+    return ret;
+}
+
+/// Polar coordinates (radius, angle) of a point.
+fn polar(x: f64, y: f64) -> (f64, f64) {
+    return (x.hypot(y), y.atan2(x));
+}
+
+// The formulas below follow Reeds and Shepp, "Optimal paths for a car that goes both forwards
+// and backwards" (1990), section 8, including the corrections to 8.3 and 8.11 used by OMPL.
+// Suffixes + and - in the comments mark driving forwards and in reverse, | a cusp.
+
+/// L+ S+ L+, formula 8.1.
+fn left_straight_left(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let (u, t) = polar(x - phi.sin(), y - 1_f64 + phi.cos());
+    if t < -EPSILON {
+        return None;
+    }
+    let v = normalize(phi - t);
+    if v < -EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ S+ R+, formula 8.2.
+fn left_straight_right(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let (u1, t1) = polar(x + phi.sin(), y - 1_f64 - phi.cos());
+    let u1_squared = u1 * u1;
+    if u1_squared < 4_f64 {
+        return None;
+    }
+    let u = (u1_squared - 4_f64).sqrt();
+    let t = normalize(t1 + 2_f64.atan2(u));
+    let v = normalize(t - phi);
+    if t < -EPSILON || v < -EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ R- L, formulas 8.3 and 8.4.
+fn left_right_left(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let (u1, theta) = polar(x - phi.sin(), y - 1_f64 + phi.cos());
+    if u1 > 4_f64 {
+        return None;
+    }
+    let u = -2_f64 * (0.25 * u1).asin();
+    let t = normalize(theta + 0.5 * u + PI);
+    let v = normalize(phi - t + u);
+    if t < -EPSILON || u > EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ R+ | L- R-, formula 8.7.
+fn left_right_cusp_left_right(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let xi = x + phi.sin();
+    let eta = y - 1_f64 - phi.cos();
+    let rho = 0.25 * (2_f64 + xi.hypot(eta));
+    if rho > 1_f64 {
+        return None;
+    }
+    let u = rho.acos();
+    let (t, v) = tau_omega(u, -u, xi, eta, phi);
+    if t < -EPSILON || v > EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ R- L- R+, formula 8.8.
+fn left_cusp_right_left_cusp_right(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let xi = x + phi.sin();
+    let eta = y - 1_f64 - phi.cos();
+    let rho = (20_f64 - xi * xi - eta * eta) / 16_f64;
+    if !(0_f64..=1_f64).contains(&rho) {
+        return None;
+    }
+    let u = -rho.acos();
+    if u < -FRAC_PI_2 {
+        return None;
+    }
+    let (t, v) = tau_omega(u, u, xi, eta, phi);
+    if t < -EPSILON || v < -EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ R-(π/2) S- L-, formula 8.9.
+fn left_right90_straight_left(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let (rho, theta) = polar(x - phi.sin(), y - 1_f64 + phi.cos());
+    if rho < 2_f64 {
+        return None;
+    }
+    let r = (rho * rho - 4_f64).sqrt();
+    let u = 2_f64 - r;
+    let t = normalize(theta + r.atan2(-2_f64));
+    let v = normalize(phi - FRAC_PI_2 - t);
+    if t < -EPSILON || u > EPSILON || v > EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ R-(π/2) S- R-, formula 8.10.
+fn left_right90_straight_right(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let xi = x + phi.sin();
+    let eta = y - 1_f64 - phi.cos();
+    let (rho, theta) = polar(-eta, xi);
+    if rho < 2_f64 {
+        return None;
+    }
+    let t = theta;
+    let u = 2_f64 - rho;
+    let v = normalize(t + FRAC_PI_2 - phi);
+    if t < -EPSILON || u > EPSILON || v > EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// L+ R-(π/2) S- L-(π/2) R+, formula 8.11.
+fn left_right90_straight_left90_right(x: f64, y: f64, phi: f64) -> Option<[f64; 3]> {
+    let xi = x + phi.sin();
+    let eta = y - 1_f64 - phi.cos();
+    let (rho, _) = polar(xi, eta);
+    if rho < 2_f64 {
+        return None;
+    }
+    let u = 4_f64 - (rho * rho - 4_f64).sqrt();
+    if u > EPSILON {
+        return None;
+    }
+    let t = normalize(((4_f64 - u) * xi - 2_f64 * eta).atan2(-2_f64 * xi + (u - 4_f64) * eta));
+    let v = normalize(t - phi);
+    if t < -EPSILON || v < -EPSILON {
+        return None;
+    }
+    return Some([t, u, v]);
+}
+
+/// First and last arc (tau, omega) of the four-arc families given the middle arcs u and v.
+fn tau_omega(u: f64, v: f64, xi: f64, eta: f64, phi: f64) -> (f64, f64) {
+    let delta = normalize(u - v);
+    let a = u.sin() - delta.sin();
+    let b = u.cos() - delta.cos() - 1_f64;
+    let t1 = (eta * a - xi * b).atan2(xi * a + eta * b);
+    let t2 = 2_f64 * (delta.cos() - v.cos() - u.cos()) + 3_f64;
+    let tau = if t2 < 0_f64 {
+        normalize(t1 + PI)
+    } else {
+        normalize(t1)
+    };
+    let omega = normalize(tau - u + v - phi);
+    return (tau, omega);
+}
+
+/// Reeds-Shepp paths through consecutive poses, e.g. parking manoeuvres. Returns nothing for a
+/// non-positive radius.
+pub fn reeds_shepp_through_poses(poses: &[Pose2D], radius: f64) -> Vec<ReedsSheppPath> {
+    return poses
+        .windows(2)
+        .filter_map(|pair| ReedsSheppPath::shortest(&pair[0], &pair[1], radius))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector};
+    use std::f64::consts::PI;
+
+    use super::ReedsSheppPath;
+    use crate::motion::dubins_path::DubinsPath;
+
+    #[test]
+    fn paths_reach_goal() {
+        let start = Pose2D::from_xytheta(1_f64, -2_f64, 0.3);
+
+        for i in 0..100 {
+            let goal = Pose2D::from_xytheta(
+                1_f64 + (i as f64 * 1.7).sin() * 4_f64,
+                -2_f64 + (i as f64 * 0.9).cos() * 3_f64,
+                i as f64 * 0.37 * PI,
+            );
+
+            let candidates = ReedsSheppPath::candidates(&start, &goal, 1.5);
+            assert!(!candidates.is_empty());
+            for path in candidates.iter() {
+                let end = path.pose_at(path.length());
+                assert!(
+                    (end.position - goal.position).magnitude() < 1e-6,
+                    "{:?}",
+                    path
+                );
+                assert!(end.relative_pose(&goal).theta.abs() < 1e-6, "{:?}", path);
+            }
+
+            // Reversing can only shorten the forward-only path.
+            let shortest = ReedsSheppPath::shortest(&start, &goal, 1.5).unwrap();
+            let dubins = DubinsPath::shortest(&start, &goal, 1.5).unwrap();
+            assert!(shortest.length() <= dubins.length() + 1e-9);
+        }
+
+        // Straight behind is reached by reversing.
+        let start = Pose2D::from_xytheta(0_f64, 0_f64, 0_f64);
+        let goal = Pose2D::from_xytheta(-3_f64, 0_f64, 0_f64);
+        let path = ReedsSheppPath::shortest(&start, &goal, 1_f64).unwrap();
+        assert_eq!(path.segments.len(), 1);
+        assert!((path.segments[0].length + 3_f64).abs() < 1e-9);
+        assert_eq!(path.cusp_count(), 0);
+        assert_eq!(path.sample(1_f64).len(), 4);
+    }
+}
@@ -0,0 +1,299 @@
+use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+use std::fmt;
+
+use crate::geometry::polyline2d::Polyline2D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrajectoryError {
+    /// The path has fewer than two distinct points.
+    DegeneratePath,
+    /// A limit is not positive, so the robot could not move along the path.
+    InvalidLimits,
+}
+
+impl fmt::Display for TrajectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TrajectoryError::DegeneratePath => write!(f, "path has fewer than two distinct points"),
+            TrajectoryError::InvalidLimits => write!(f, "trajectory limits must be positive"),
+        };
+    }
+}
+
+impl std::error::Error for TrajectoryError {}
+
+/// Kinematic limits of the robot along a trajectory. Unused limits can be set to infinity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrajectoryLimits {
+    /// Speed limit in meters per second.
+    pub max_velocity: f64,
+    /// Limit of speeding up in meters per second squared.
+    pub max_acceleration: f64,
+    /// Limit of slowing down in meters per second squared.
+    pub max_deceleration: f64,
+    /// Limit of the centripetal acceleration v² κ in curves, in meters per second squared.
+    pub max_lateral_acceleration: f64,
+    /// Turn rate limit v κ in radians per second.
+    pub max_angular_velocity: f64,
+}
+
+impl TrajectoryLimits {
+    /// Limits with equal acceleration and deceleration and no turn rate limit.
+    pub fn new(max_velocity: f64, max_acceleration: f64, max_lateral_acceleration: f64) -> Self {
+        return Self {
+            max_velocity: max_velocity,
+            max_acceleration: max_acceleration,
+            max_deceleration: max_acceleration,
+            max_lateral_acceleration: max_lateral_acceleration,
+            max_angular_velocity: f64::INFINITY,
+        };
+    }
+
+    /// Highest speed allowed on a curve of the given curvature.
+    pub fn velocity_limit(&self, curvature: f64) -> f64 {
+        let curvature = curvature.abs();
+        return self
+            .max_velocity
+            .min((self.max_lateral_acceleration / curvature).sqrt())
+            .min(self.max_angular_velocity / curvature);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrajectoryPoint {
+    pub pose: Pose2D,
+    /// Time since the start of the trajectory in seconds.
+    pub time: f64,
+    /// Distance along the path since the start of the trajectory.
+    pub arc_length: f64,
+    pub velocity: f64,
+    /// Acceleration until the next point, constant in between.
+    pub acceleration: f64,
+    /// Signed curvature, positive when turning counterclockwise.
+    pub curvature: f64,
+}
+
+/// Geometric path with a speed profile, giving the pose and velocity at each time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trajectory {
+    pub points: Vec<TrajectoryPoint>,
+}
+
+impl Trajectory {
+    /// Time-parameterize a path as fast as the limits allow, starting and ending at the
+    /// given speeds. The speeds are lowered where the limits cannot reach them, e.g. an end
+    /// velocity too high to brake for at the end of the path.
+    pub fn from_path(
+        path: &Polyline2D,
+        limits: &TrajectoryLimits,
+        start_velocity: f64,
+        end_velocity: f64,
+    ) -> Result<Self, TrajectoryError> {
+        let valid_limits = [
+            limits.max_velocity,
+            limits.max_acceleration,
+            limits.max_deceleration,
+            limits.max_lateral_acceleration,
+            limits.max_angular_velocity,
+        ];
+        if valid_limits
+            .iter()
+            .any(|limit| limit.is_nan() || *limit <= 0_f64)
+        {
+            return Err(TrajectoryError::InvalidLimits);
+        }
+
+        let mut points: Vec<Vector2D> = Vec::with_capacity(path.points.len());
+        for point in path.points.iter() {
+            if points.last() != Some(point) {
+                points.push(*point);
+            }
+        }
+        let n = points.len();
+        if n < 2 {
+            return Err(TrajectoryError::DegeneratePath);
+        }
+
+        let distances: Vec<f64> = points
+            .windows(2)
+            .map(|w| (w[1] - w[0]).magnitude())
+            .collect();
+        let curvatures: Vec<f64> = (0..n)
+            .map(|i| {
+                if i == 0 || i == n - 1 {
+                    return 0_f64;
+                }
+                return menger_curvature(&points[i - 1], &points[i], &points[i + 1]);
+            })
+            .collect();
+
+        // Speed limits of the points, then lowered so that the robot can speed up from the
+        // previous point and brake for the next one.
+        let mut velocities: Vec<f64> = curvatures
+            .iter()
+            .map(|c| limits.velocity_limit(*c))
+            .collect();
+        velocities[0] = velocities[0].min(start_velocity.max(0_f64));
+        velocities[n - 1] = velocities[n - 1].min(end_velocity.max(0_f64));
+        for i in 1..n {
+            let reachable = (velocities[i - 1].powi(2)
+                + 2_f64 * limits.max_acceleration * distances[i - 1])
+                .sqrt();
+            velocities[i] = velocities[i].min(reachable);
+        }
+        for i in (0..n - 1).rev() {
+            let brakeable =
+                (velocities[i + 1].powi(2) + 2_f64 * limits.max_deceleration * distances[i]).sqrt();
+            velocities[i] = velocities[i].min(brakeable);
+        }
+
+        let mut ret: Vec<TrajectoryPoint> = Vec::with_capacity(n);
+        let mut time = 0_f64;
+        let mut arc_length = 0_f64;
+        for i in 0..n {
+            let (from, to) = if i + 1 < n {
+                (points[i], points[i + 1])
+            } else {
+                (points[i - 1], points[i])
+            };
+            let acceleration = if i + 1 < n {
+                (velocities[i + 1].powi(2) - velocities[i].powi(2)) / (2_f64 * distances[i])
+            } else {
+                0_f64
+            };
+
+            ret.push(TrajectoryPoint {
+                pose: Pose2D::new(points[i], (to - from).angle()),
+                time: time,
+                arc_length: arc_length,
+                velocity: velocities[i],
+                acceleration: acceleration,
+                curvature: curvatures[i],
+            });
+
+            if i + 1 < n {
+                // Constant acceleration, so the mean speed is the mean of the end speeds.
+                let mean_velocity = (velocities[i] + velocities[i + 1]) / 2_f64;
+                time += distances[i] / mean_velocity;
+                arc_length += distances[i];
+            }
+        }
+
+        return Ok(Self { points: ret });
+    }
+
+    pub fn duration(&self) -> f64 {
+        return self.points.last().map_or(0_f64, |p| p.time);
+    }
+
+    pub fn length(&self) -> f64 {
+        return self.points.last().map_or(0_f64, |p| p.arc_length);
+    }
+
+    /// State at time, moving along the straight line between points at constant acceleration.
+    /// Times outside of the trajectory are clamped to its ends.
+    pub fn sample_at(&self, time: f64) -> Option<TrajectoryPoint> {
+        let first = self.points.first()?;
+        if time <= first.time {
+            return Some(*first);
+        }
+
+        let index = self.points.partition_point(|p| p.time <= time);
+        if index == self.points.len() {
+            return self.points.last().copied();
+        }
+
+        let from = &self.points[index - 1];
+        let to = &self.points[index];
+        let dt = time - from.time;
+        let distance = from.velocity * dt + 0.5 * from.acceleration * dt * dt;
+        let segment_length = to.arc_length - from.arc_length;
+        let ratio = (distance / segment_length).clamp(0_f64, 1_f64);
+
+        return Some(TrajectoryPoint {
+            pose: Pose2D::new(
+                from.pose.position + (to.pose.position - from.pose.position) * ratio,
+                from.pose.theta,
+            ),
+            time: time,
+            arc_length: from.arc_length + distance.min(segment_length),
+            velocity: from.velocity + from.acceleration * dt,
+            acceleration: from.acceleration,
+            curvature: from.curvature,
+        });
+    }
+}
+
+/// Signed curvature of the circle through three points, zero if they are collinear.
+fn menger_curvature(a: &Vector2D, b: &Vector2D, c: &Vector2D) -> f64 {
+    let ab = *b - *a;
+    let bc = *c - *b;
+    let ca = *a - *c;
+    let denominator = ab.magnitude() * bc.magnitude() * ca.magnitude();
+    if denominator == 0_f64 {
+        return 0_f64;
+    }
+    return 2_f64 * ab.cross(&bc) / denominator;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use super::{Trajectory, TrajectoryError, TrajectoryLimits};
+    use crate::geometry::polyline2d::Polyline2D;
+
+    #[test]
+    fn respects_limits() {
+        let limits = TrajectoryLimits::new(2_f64, 1_f64, 0.5);
+        let straight = Polyline2D::new(
+            (0..=10)
+                .map(|i| Vector2D::from_xy(i as f64, 0_f64))
+                .collect(),
+        );
+
+        // Speeds up at the acceleration limit to the speed limit, then brakes to a stop.
+        let trajectory = Trajectory::from_path(&straight, &limits, 0_f64, 0_f64).unwrap();
+        let velocities: Vec<f64> = trajectory.points.iter().map(|p| p.velocity).collect();
+        assert_eq!(velocities[0], 0_f64);
+        assert!((velocities[1] - 2_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(velocities[5], 2_f64);
+        assert_eq!(velocities[10], 0_f64);
+        // 2 s speeding up over 2 m, 3 s cruising over 6 m, 2 s braking over 2 m.
+        assert!((trajectory.duration() - 7_f64).abs() < 1e-12);
+        let middle = trajectory.sample_at(3.5).unwrap();
+        assert!((middle.arc_length - 5_f64).abs() < 1e-12);
+        assert!((middle.pose.position.x - 5_f64).abs() < 1e-12);
+
+        // Slows down on a curve of radius 2 to sqrt(0.5 * 2) = 1.
+        let curve = Polyline2D::new(
+            (0..=20)
+                .map(|i| Vector2D::from_angle(i as f64 * 0.1) * 2_f64)
+                .collect(),
+        );
+        let trajectory = Trajectory::from_path(&curve, &limits, 1_f64, 1_f64).unwrap();
+        for point in trajectory.points[1..20].iter() {
+            assert!((point.curvature - 0.5).abs() < 1e-2);
+            assert!(point.velocity <= 1_f64 + 1e-2);
+        }
+
+        assert_eq!(
+            Trajectory::from_path(
+                &Polyline2D::new(vec![Vector2D::zero(); 2]),
+                &limits,
+                0_f64,
+                0_f64
+            ),
+            Err(TrajectoryError::DegeneratePath)
+        );
+        assert_eq!(
+            Trajectory::from_path(
+                &straight,
+                &TrajectoryLimits::new(0_f64, 1_f64, 1_f64),
+                0_f64,
+                0_f64
+            ),
+            Err(TrajectoryError::InvalidLimits)
+        );
+    }
+}
@@ -0,0 +1,486 @@
+use core::{
+    graph::{graph::Graph, node::NodeId},
+    numerics::{
+        matrix3::Matrix3,
+        pose2d::{normalize_angle, Pose2D},
+    },
+};
+use std::{collections::HashMap, fmt};
+
+/// Measured pose of the second node of an edge in the frame of the first, e.g. from
+/// odometry or a loop closure found by scan matching.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseConstraint {
+    pub relative_pose: Pose2D,
+
+    /// Inverse covariance of the measurement in (x, y, theta).
+    pub information: Matrix3,
+}
+
+impl PoseConstraint {
+    pub fn new(relative_pose: Pose2D, information: Matrix3) -> Self {
+        return Self {
+            relative_pose: relative_pose,
+            information: information,
+        };
+    }
+}
+
+/// Graph of poses joined by relative pose constraints. Create it with
+/// `GraphOptions::multigraph()` to allow several loop closures between the same poses.
+pub type PoseGraph = Graph<Pose2D, PoseConstraint>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PoseGraphError {
+    /// The node to hold fixed is not in the graph.
+    NoSuchNode,
+}
+
+impl fmt::Display for PoseGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            PoseGraphError::NoSuchNode => write!(f, "fixed node is not in the pose graph"),
+        };
+    }
+}
+
+impl std::error::Error for PoseGraphError {}
+
+/// Parameters of `PoseGraphOptimizer`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseGraphOptimizerOptions {
+    pub max_iterations: usize,
+
+    /// Optimization stops once no pose moves by more than this in a step.
+    pub tolerance: f64,
+
+    /// Initial damping of Levenberg-Marquardt, where zero is Gauss-Newton.
+    pub initial_damping: f64,
+}
+
+impl Default for PoseGraphOptimizerOptions {
+    fn default() -> Self {
+        return Self {
+            max_iterations: 50,
+            tolerance: 1e-9,
+            initial_damping: 1e-4,
+        };
+    }
+}
+
+/// Outcome of `PoseGraphOptimizer::optimize`, with errors as sums of squared residuals
+/// weighted by the information matrices.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseGraphSummary {
+    pub initial_error: f64,
+    pub final_error: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Levenberg-Marquardt optimization of the poses of a pose graph, e.g. to distribute the
+/// drift revealed by loop closures before merging maps of several sessions. The linear
+/// systems are block sparse and solved iteratively, so large graphs stay tractable.
+pub struct PoseGraphOptimizer {
+    options: PoseGraphOptimizerOptions,
+}
+
+/// Sparse symmetric matrix of 3 x 3 blocks, row by row.
+type BlockMatrix = Vec<HashMap<usize, [[f64; 3]; 3]>>;
+
+impl PoseGraphOptimizer {
+    pub fn new(options: PoseGraphOptimizerOptions) -> Self {
+        return Self { options: options };
+    }
+
+    /// Optimize the poses in place, holding fixed_node where it is to anchor the graph.
+    pub fn optimize(
+        &self,
+        pose_graph: &mut PoseGraph,
+        fixed_node: NodeId,
+    ) -> Result<PoseGraphSummary, PoseGraphError> {
+        if pose_graph.get_node_by_id(&fixed_node).is_none() {
+            return Err(PoseGraphError::NoSuchNode);
+        }
+
+        // Variables are the free poses in ID order; the fixed pose has no index.
+        let mut node_ids: Vec<NodeId> = pose_graph.get_nodes().keys().copied().collect();
+        node_ids.sort();
+        let mut poses: Vec<Pose2D> = node_ids
+            .iter()
+            .map(|id| *pose_graph.get_node_by_id(id).unwrap().node_info())
+            .collect();
+        let fixed = node_ids.iter().position(|id| *id == fixed_node).unwrap();
+        let index_of: HashMap<NodeId, usize> = node_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, i))
+            .collect();
+
+        let mut edge_ids: Vec<_> = pose_graph.get_edges().keys().copied().collect();
+        edge_ids.sort();
+        let constraints: Vec<(usize, usize, PoseConstraint)> = edge_ids
+            .iter()
+            .map(|id| {
+                let edge = pose_graph.get_edge_by_id(id).unwrap();
+                return (
+                    index_of[&edge.node1()],
+                    index_of[&edge.node2()],
+                    *edge.edge_info(),
+                );
+            })
+            .collect();
+
+        let initial_error = total_error(&poses, &constraints);
+        let mut error = initial_error;
+        let mut damping = self.options.initial_damping;
+        let mut iterations = 0;
+        let mut converged = false;
+
+        while iterations < self.options.max_iterations {
+            iterations += 1;
+            let (hessian, gradient) = linearize(&poses, &constraints, fixed);
+
+            let mut step = damped_solve(&hessian, &gradient, damping);
+            let mut stalled = false;
+            loop {
+                let candidate: Vec<Pose2D> = poses
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        return Pose2D::from_xytheta(
+                            p.position.x + step[3 * i],
+                            p.position.y + step[3 * i + 1],
+                            normalize_angle(p.theta + step[3 * i + 2]),
+                        );
+                    })
+                    .collect();
+                let candidate_error = total_error(&candidate, &constraints);
+                if candidate_error <= error {
+                    poses = candidate;
+                    error = candidate_error;
+                    damping /= 10_f64;
+                    break;
+                }
+
+                damping = (damping * 10_f64).max(1e-9);
+                if damping > 1e12 {
+                    stalled = true;
+                    break;
+                }
+                step = damped_solve(&hessian, &gradient, damping);
+            }
+
+            // No step, however small, reduces the error, so the poses cannot improve further.
+            if stalled {
+                break;
+            }
+
+            if step.iter().all(|s| s.abs() <= self.options.tolerance) {
+                converged = true;
+                break;
+            }
+        }
+
+        for (id, pose) in node_ids.iter().zip(poses.iter()) {
+            *pose_graph.get_node_by_id_mut(id).unwrap().node_info_mut() = *pose;
+        }
+
+        return Ok(PoseGraphSummary {
+            initial_error: initial_error,
+            final_error: error,
+            iterations: iterations,
+            converged: converged,
+        });
+    }
+}
+
+/// Residual of a constraint between poses i and j, the measured relative pose taken off
+/// the current one, as (x, y, theta).
+fn residual(pose_i: &Pose2D, pose_j: &Pose2D, constraint: &PoseConstraint) -> [f64; 3] {
+    let relative = constraint
+        .relative_pose
+        .inverse()
+        .compose(&pose_i.relative_pose(pose_j));
+    return [
+        relative.position.x,
+        relative.position.y,
+        normalize_angle(relative.theta),
+    ];
+}
+
+fn weighted_square(residual: &[f64; 3], information: &Matrix3) -> f64 {
+    let mut ret = 0_f64;
+    for i in 0..3 {
+        for j in 0..3 {
+            ret += residual[i] * information.rows[i][j] * residual[j];
+        }
+    }
+
+    return ret;
+}
+
+fn total_error(poses: &[Pose2D], constraints: &[(usize, usize, PoseConstraint)]) -> f64 {
+    return constraints
+        .iter()
+        .map(|(i, j, c)| weighted_square(&residual(&poses[*i], &poses[*j], c), &c.information))
+        .sum();
+}
+
+/// Normal equations H dx = -b of the linearized error, leaving out the fixed pose.
+fn linearize(
+    poses: &[Pose2D],
+    constraints: &[(usize, usize, PoseConstraint)],
+    fixed: usize,
+) -> (BlockMatrix, Vec<f64>) {
+    let mut hessian: BlockMatrix = vec![HashMap::new(); poses.len()];
+    let mut gradient = vec![0_f64; 3 * poses.len()];
+
+    for (i, j, constraint) in constraints.iter() {
+        let (pose_i, pose_j) = (&poses[*i], &poses[*j]);
+        let e = residual(pose_i, pose_j, constraint);
+
+        // Jacobians of the residual with respect to poses i and j.
+        let (sin_i, cos_i) = pose_i.theta.sin_cos();
+        let (sin_z, cos_z) = constraint.relative_pose.theta.sin_cos();
+        let rz_t = [[cos_z, sin_z], [-sin_z, cos_z]];
+        let ri_t = [[cos_i, sin_i], [-sin_i, cos_i]];
+        let d_ri_t = [[-sin_i, cos_i], [-cos_i, -sin_i]];
+        let d = [
+            pose_j.position.x - pose_i.position.x,
+            pose_j.position.y - pose_i.position.y,
+        ];
+
+        let rotation = multiply2(&rz_t, &ri_t);
+        let d_rotation = multiply2(&rz_t, &d_ri_t);
+        let a = [
+            [
+                -rotation[0][0],
+                -rotation[0][1],
+                d_rotation[0][0] * d[0] + d_rotation[0][1] * d[1],
+            ],
+            [
+                -rotation[1][0],
+                -rotation[1][1],
+                d_rotation[1][0] * d[0] + d_rotation[1][1] * d[1],
+            ],
+            [0_f64, 0_f64, -1_f64],
+        ];
+        let b = [
+            [rotation[0][0], rotation[0][1], 0_f64],
+            [rotation[1][0], rotation[1][1], 0_f64],
+            [0_f64, 0_f64, 1_f64],
+        ];
+
+        let omega = &constraint.information.rows;
+        for (row, jacobian_row) in [(*i, &a), (*j, &b)] {
+            if row == fixed {
+                continue;
+            }
+
+            let jt_omega = multiply3(&transpose3(jacobian_row), omega);
+            for r in 0..3 {
+                gradient[3 * row + r] += (0..3).map(|k| jt_omega[r][k] * e[k]).sum::<f64>();
+            }
+            for (column, jacobian_column) in [(*i, &a), (*j, &b)] {
+                if column == fixed {
+                    continue;
+                }
+
+                let block = multiply3(&jt_omega, jacobian_column);
+                let entry = hessian[row].entry(column).or_insert([[0_f64; 3]; 3]);
+                for r in 0..3 {
+                    for c in 0..3 {
+                        entry[r][c] += block[r][c];
+                    }
+                }
+            }
+        }
+    }
+
+    return (hessian, gradient);
+}
+
+/// Solve (H + damping I) dx = -b by conjugate gradients preconditioned with the inverses
+/// of the diagonal blocks. Rows without blocks, e.g. of the fixed pose, stay zero.
+fn damped_solve(hessian: &BlockMatrix, gradient: &[f64], damping: f64) -> Vec<f64> {
+    let n = gradient.len();
+    let multiply = |x: &[f64]| -> Vec<f64> {
+        let mut ret = vec![0_f64; n];
+        for (row, blocks) in hessian.iter().enumerate() {
+            if blocks.is_empty() {
+                continue;
+            }
+            for (column, block) in blocks.iter() {
+                for r in 0..3 {
+                    for c in 0..3 {
+                        ret[3 * row + r] += block[r][c] * x[3 * column + c];
+                    }
+                }
+            }
+            for r in 0..3 {
+                ret[3 * row + r] += damping * x[3 * row + r];
+            }
+        }
+        return ret;
+    };
+
+    let preconditioner: Vec<Matrix3> = hessian
+        .iter()
+        .enumerate()
+        .map(|(row, blocks)| {
+            let mut block =
+                Matrix3::from_rows(blocks.get(&row).copied().unwrap_or([[0_f64; 3]; 3]));
+            for r in 0..3 {
+                block.rows[r][r] += damping;
+            }
+            return block.inverse().unwrap_or(Matrix3::zero());
+        })
+        .collect();
+    let precondition = |r: &[f64]| -> Vec<f64> {
+        let mut ret = vec![0_f64; n];
+        for (row, inverse) in preconditioner.iter().enumerate() {
+            for i in 0..3 {
+                ret[3 * row + i] = (0..3).map(|j| inverse.rows[i][j] * r[3 * row + j]).sum();
+            }
+        }
+        return ret;
+    };
+    let dot = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b.iter()).map(|(x, y)| x * y).sum() };
+
+    let mut x = vec![0_f64; n];
+    let mut r: Vec<f64> = gradient.iter().map(|g| -g).collect();
+    let mut z = precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    let threshold = 1e-24 * dot(&r, &r).max(f64::MIN_POSITIVE);
+
+    for _ in 0..n {
+        if dot(&r, &r) <= threshold {
+            break;
+        }
+
+        let hp = multiply(&p);
+        let php = dot(&p, &hp);
+        if php <= 0_f64 {
+            break;
+        }
+        let alpha = rz / php;
+        for i in 0..n {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * hp[i];
+        }
+
+        z = precondition(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for i in 0..n {
+            p[i] = z[i] + beta * p[i];
+        }
+    }
+
+    return x;
+}
+
+fn multiply2(a: &[[f64; 2]; 2], b: &[[f64; 2]; 2]) -> [[f64; 2]; 2] {
+    let mut ret = [[0_f64; 2]; 2];
+    for i in 0..2 {
+        for j in 0..2 {
+            ret[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j];
+        }
+    }
+
+    return ret;
+}
+
+fn multiply3(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    return (Matrix3::from_rows(*a) * Matrix3::from_rows(*b)).rows;
+}
+
+fn transpose3(a: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    return Matrix3::from_rows(*a).transpose().rows;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        graph::graph_options::GraphOptions,
+        numerics::{matrix3::Matrix3, pose2d::Pose2D, vector::Vector},
+    };
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::{
+        PoseConstraint, PoseGraph, PoseGraphError, PoseGraphOptimizer, PoseGraphOptimizerOptions,
+    };
+
+    #[test]
+    fn close_loop() {
+        // Square of side 2 driven counterclockwise, with odometry turning 0.05 rad too far
+        // at each corner and a loop closure back to the start.
+        let truth: Vec<Pose2D> = (0..4)
+            .map(|i| {
+                let corners = [
+                    (0_f64, 0_f64),
+                    (2_f64, 0_f64),
+                    (2_f64, 2_f64),
+                    (0_f64, 2_f64),
+                ];
+                return Pose2D::from_xytheta(corners[i].0, corners[i].1, i as f64 * FRAC_PI_2);
+            })
+            .collect();
+
+        let mut pose_graph = PoseGraph::with_options(GraphOptions::multigraph());
+        let mut guess = truth[0];
+        let mut node_ids = Vec::new();
+        for _ in 0..4 {
+            node_ids.push(pose_graph.add_node(guess));
+            guess = guess.compose(&Pose2D::from_xytheta(2_f64, 0_f64, FRAC_PI_2 + 0.05));
+        }
+        for i in 0..4 {
+            let (from, to) = (i, (i + 1) % 4);
+            let constraint =
+                PoseConstraint::new(truth[from].relative_pose(&truth[to]), Matrix3::identity());
+            pose_graph
+                .add_edge(node_ids[from], node_ids[to], constraint)
+                .unwrap();
+        }
+
+        let optimizer = PoseGraphOptimizer::new(PoseGraphOptimizerOptions::default());
+        let summary = optimizer.optimize(&mut pose_graph, node_ids[0]).unwrap();
+        assert!(summary.converged && summary.initial_error > 0.1);
+        assert!(summary.final_error < 1e-12);
+        for (id, expected) in node_ids.iter().zip(truth.iter()) {
+            let pose = pose_graph.get_node_by_id(id).unwrap().node_info();
+            assert!(
+                (pose.position - expected.position).magnitude() < 1e-6,
+                "{:?}",
+                pose
+            );
+            assert!((pose.theta - expected.theta).abs() < 1e-6, "{:?}", pose);
+        }
+
+        let mut empty = PoseGraph::with_options(GraphOptions::multigraph());
+        assert_eq!(
+            optimizer.optimize(&mut empty, node_ids[0]),
+            Err(PoseGraphError::NoSuchNode)
+        );
+    }
+
+    #[test]
+    fn stalls_without_converging() {
+        let mut pose_graph = PoseGraph::with_options(GraphOptions::multigraph());
+        let a = pose_graph.add_node(Pose2D::from_xytheta(0_f64, 0_f64, 0_f64));
+        let b = pose_graph.add_node(Pose2D::from_xytheta(f64::NAN, 0_f64, 0_f64));
+        let constraint = PoseConstraint::new(
+            Pose2D::from_xytheta(1_f64, 0_f64, 0_f64),
+            Matrix3::identity(),
+        );
+        pose_graph.add_edge(a, b, constraint).unwrap();
+
+        let optimizer = PoseGraphOptimizer::new(PoseGraphOptimizerOptions::default());
+        let summary = optimizer.optimize(&mut pose_graph, a).unwrap();
+        assert!(!summary.converged);
+        assert_eq!(summary.iterations, 1);
+    }
+}
@@ -0,0 +1,141 @@
+use core::numerics::{pose2d::Pose2D, vector2d::Vector2D};
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+use crate::geometry::{aabb2d::AABB2D, polygon2d::Polygon2D};
+
+/// Number of candidates `sample_in_polygon` draws from the bounding box before giving up.
+const MAX_REJECTION_ATTEMPTS: usize = 10000;
+
+/// Sample uniformly in the rectangle. Like all samplers here, this draws from the given
+/// generator only, so a seeded generator reproduces the samples.
+pub fn sample_in_rectangle<R: Rng>(rng: &mut R, rectangle: &AABB2D) -> Vector2D {
+    return Vector2D::from_xy(
+        rectangle.min.x + rng.gen::<f64>() * rectangle.width(),
+        rectangle.min.y + rng.gen::<f64>() * rectangle.height(),
+    );
+}
+
+/// Sample uniformly by area, not by radius.
+pub fn sample_in_circle<R: Rng>(rng: &mut R, center: &Vector2D, radius: f64) -> Vector2D {
+    let r = radius * rng.gen::<f64>().sqrt();
+    let angle = 2_f64 * PI * rng.gen::<f64>();
+    return center + Vector2D::from_xy(r, 0_f64).rotate(angle);
+}
+
+/// One uniform sample in each square cell of size spacing tiling the rectangle from its
+/// minimum corner, clipped at the far edges. Covers the rectangle more evenly than
+/// independent samples. Ordered row by row from the bottom, left to right.
+pub fn sample_jittered_grid<R: Rng>(
+    rng: &mut R,
+    rectangle: &AABB2D,
+    spacing: f64,
+) -> Vec<Vector2D> {
+    if spacing <= 0_f64 {
+        return Vec::new();
+    }
+
+    let columns = (rectangle.width() / spacing).ceil().max(1_f64) as usize;
+    let rows = (rectangle.height() / spacing).ceil().max(1_f64) as usize;
+
+    let mut ret: Vec<Vector2D> = Vec::with_capacity(columns * rows);
+    for r in 0..rows {
+        for c in 0..columns {
+            let min = rectangle.min + Vector2D::from_xy(c as f64, r as f64) * spacing;
+            let max = Vector2D::from_xy(
+                (min.x + spacing).min(rectangle.max.x),
+                (min.y + spacing).min(rectangle.max.y),
+            );
+            ret.push(sample_in_rectangle(rng, &AABB2D::new(min, max)));
+        }
+    }
+
+    return ret;
+}
+
+/// Sample by rejection from the bounding box.
+/// Returns `None` if no candidate fell inside, e.g. for a polygon without area.
+pub fn sample_in_polygon<R: Rng>(rng: &mut R, polygon: &Polygon2D) -> Option<Vector2D> {
+    let bounding_box = polygon.bounding_box()?;
+
+    for _ in 0..MAX_REJECTION_ATTEMPTS {
+        let candidate = sample_in_rectangle(rng, &bounding_box);
+        if polygon.contains(&candidate) {
+            return Some(candidate);
+        }
+    }
+
+    return None;
+}
+
+/// Sample from the normal distribution using the Box-Muller transform.
+pub fn sample_gaussian<R: Rng>(rng: &mut R, mean: f64, std_dev: f64) -> f64 {
+    // 1 - u lies in (0, 1], keeping the logarithm finite.
+    let u1 = 1_f64 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    let standard = (-2_f64 * u1.ln()).sqrt() * (2_f64 * PI * u2).cos();
+    return mean + std_dev * standard;
+}
+
+/// Perturb position and heading by independent Gaussian noise.
+pub fn perturb_pose<R: Rng>(
+    rng: &mut R,
+    pose: &Pose2D,
+    position_std_dev: f64,
+    theta_std_dev: f64,
+) -> Pose2D {
+    return Pose2D::new(
+        Vector2D::from_xy(
+            sample_gaussian(rng, pose.position.x, position_std_dev),
+            sample_gaussian(rng, pose.position.y, position_std_dev),
+        ),
+        sample_gaussian(rng, pose.theta, theta_std_dev),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::geometry::{aabb2d::AABB2D, polygon2d::Polygon2D};
+
+    use super::{sample_gaussian, sample_in_circle, sample_in_polygon, sample_jittered_grid};
+
+    #[test]
+    fn samples_stay_inside() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let center = Vector2D::from_xy(1_f64, -1_f64);
+        assert!((0..100)
+            .all(|_| (sample_in_circle(&mut rng, &center, 2_f64) - center).magnitude() <= 2_f64));
+
+        let triangle = Polygon2D::new(vec![
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(4_f64, 0_f64),
+            Vector2D::from_xy(0_f64, 4_f64),
+        ]);
+        for _ in 0..100 {
+            assert!(triangle.contains(&sample_in_polygon(&mut rng, &triangle).unwrap()));
+        }
+
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| sample_gaussian(&mut rng, 3_f64, 0.5))
+            .collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 3_f64).abs() < 0.05);
+
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        assert_eq!(
+            sample_in_polygon(&mut rng1, &triangle),
+            sample_in_polygon(&mut rng2, &triangle)
+        );
+
+        let rectangle = AABB2D::new(Vector2D::zero(), Vector2D::from_xy(2.5, 1_f64));
+        let jittered = sample_jittered_grid(&mut rng, &rectangle, 1_f64);
+        assert_eq!(jittered.len(), 3);
+        assert!(jittered[2].x >= 2_f64 && rectangle.contains_point(&jittered[2]));
+    }
+}
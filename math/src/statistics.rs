@@ -0,0 +1,146 @@
+use core::numerics::{matrix2::Matrix2, matrix3::Matrix3, vector2d::Vector2D, vector3d::Vector3D};
+use std::f64::consts::PI;
+
+/// Returns `None` if there are no points.
+pub fn mean(points: &[Vector2D]) -> Option<Vector2D> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let sum = points.iter().fold(Vector2D::zero(), |sum, p| sum + p);
+    return Some(sum / points.len() as f64);
+}
+
+/// Returns `None` if there are no points.
+pub fn mean_3d(points: &[Vector3D]) -> Option<Vector3D> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let sum = points.iter().fold(Vector3D::zero(), |sum, p| sum + p);
+    return Some(sum / points.len() as f64);
+}
+
+/// Maximum likelihood covariance, i.e. normalized by the number of points rather than one less.
+/// Returns `None` if there are no points.
+pub fn covariance(points: &[Vector2D]) -> Option<Matrix2> {
+    let mean = mean(points)?;
+    let mut ret = Matrix2::zero();
+
+    for p in points {
+        let d = [p.x - mean.x, p.y - mean.y];
+        for i in 0..2 {
+            for j in 0..2 {
+                ret.rows[i][j] += d[i] * d[j];
+            }
+        }
+    }
+
+    return Some(ret * (1_f64 / points.len() as f64));
+}
+
+/// Maximum likelihood covariance, i.e. normalized by the number of points rather than one less.
+/// Returns `None` if there are no points.
+pub fn covariance_3d(points: &[Vector3D]) -> Option<Matrix3> {
+    let mean = mean_3d(points)?;
+    let mut ret = Matrix3::zero();
+
+    for p in points {
+        let d = [p.x - mean.x, p.y - mean.y, p.z - mean.z];
+        for i in 0..3 {
+            for j in 0..3 {
+                ret.rows[i][j] += d[i] * d[j];
+            }
+        }
+    }
+
+    return Some(ret * (1_f64 / points.len() as f64));
+}
+
+/// Distance from mean in standard deviations along the direction of point.
+/// Returns `None` if the covariance is singular.
+pub fn mahalanobis_distance(
+    point: &Vector2D,
+    mean: &Vector2D,
+    covariance: &Matrix2,
+) -> Option<f64> {
+    let d = point - mean;
+    let squared = d.dot(&(covariance.inverse()? * d));
+    return Some(squared.max(0_f64).sqrt());
+}
+
+/// Normal distribution in the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gaussian2D {
+    pub mean: Vector2D,
+    pub covariance: Matrix2,
+}
+
+impl Gaussian2D {
+    pub fn new(mean: Vector2D, covariance: Matrix2) -> Self {
+        return Self {
+            mean: mean,
+            covariance: covariance,
+        };
+    }
+
+    /// Fit to points. Returns `None` if there are no points.
+    pub fn from_points(points: &[Vector2D]) -> Option<Self> {
+        return Some(Self::new(mean(points)?, covariance(points)?));
+    }
+
+    /// Returns `None` if the covariance is singular.
+    pub fn mahalanobis_distance(&self, point: &Vector2D) -> Option<f64> {
+        return mahalanobis_distance(point, &self.mean, &self.covariance);
+    }
+
+    /// Probability density at point. Returns `None` if the covariance is singular.
+    pub fn pdf(&self, point: &Vector2D) -> Option<f64> {
+        let distance = self.mahalanobis_distance(point)?;
+        let normalization = 2_f64 * PI * self.covariance.determinant().sqrt();
+        return Some((-0.5 * distance.powi(2)).exp() / normalization);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{matrix2::Matrix2, vector2d::Vector2D};
+
+    use super::{covariance, mean, Gaussian2D};
+
+    #[test]
+    fn fits_gaussian() {
+        let points: Vec<Vector2D> = [
+            (1_f64, 0_f64),
+            (-1_f64, 0_f64),
+            (0_f64, 2_f64),
+            (0_f64, -2_f64),
+        ]
+        .iter()
+        .map(|(x, y)| Vector2D::from_xy(*x, *y))
+        .collect();
+        assert_eq!(mean(&points), Some(Vector2D::zero()));
+        assert_eq!(
+            covariance(&points),
+            Some(Matrix2::from_rows([[0.5, 0_f64], [0_f64, 2_f64]]))
+        );
+        assert_eq!(mean(&[]), None);
+
+        let gaussian = Gaussian2D::from_points(&points).unwrap();
+        let distance = gaussian
+            .mahalanobis_distance(&Vector2D::from_xy(0_f64, 2_f64 * 2_f64.sqrt()))
+            .unwrap();
+        assert!((distance - 2_f64).abs() < 1e-12);
+        assert_eq!(
+            gaussian.mahalanobis_distance(&Vector2D::from_xy(1_f64, 0_f64)),
+            Some(2_f64.sqrt())
+        );
+        assert!(
+            (gaussian.pdf(&gaussian.mean).unwrap() - 1_f64 / (2_f64 * std::f64::consts::PI)).abs()
+                < 1e-12
+        );
+
+        let degenerate = Gaussian2D::from_points(&points[..2]).unwrap();
+        assert_eq!(degenerate.pdf(&Vector2D::zero()), None);
+    }
+}
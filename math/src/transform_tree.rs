@@ -0,0 +1,328 @@
+use core::numerics::{pose2d::Pose2D, pose3d::Pose3D};
+use std::{collections::HashMap, fmt};
+
+use crate::interp;
+
+/// Rigid transform which can be chained, inverted and interpolated over time.
+pub trait Transform: Copy {
+    fn identity() -> Self;
+
+    /// Apply other, given relative to this transform.
+    fn compose(&self, other: &Self) -> Self;
+
+    fn inverse(&self) -> Self;
+
+    /// Blend between transforms, with t = 0 giving this one and t = 1 the other.
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Transform for Pose2D {
+    fn identity() -> Self {
+        return Pose2D::identity();
+    }
+
+    fn compose(&self, other: &Self) -> Self {
+        return Pose2D::compose(self, other);
+    }
+
+    fn inverse(&self) -> Self {
+        return Pose2D::inverse(self);
+    }
+
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        return interp::interpolate_pose2d(self, other, t);
+    }
+}
+
+impl Transform for Pose3D {
+    fn identity() -> Self {
+        return Pose3D::identity();
+    }
+
+    fn compose(&self, other: &Self) -> Self {
+        return Pose3D::compose(self, other);
+    }
+
+    fn inverse(&self) -> Self {
+        return Pose3D::inverse(self);
+    }
+
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        return interp::interpolate_pose3d(self, other, t);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransformError {
+    UnknownFrame,
+    /// The frames belong to different trees.
+    NotConnected,
+    /// The requested time lies outside of the recorded transforms.
+    ExtrapolationRequired,
+    /// The child frame is already attached to a different parent.
+    ParentMismatch,
+    /// Attaching the child would make it its own ancestor.
+    CycleDetected,
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TransformError::UnknownFrame => write!(f, "unknown frame"),
+            TransformError::NotConnected => write!(f, "frames are not connected"),
+            TransformError::ExtrapolationRequired => write!(f, "no transform at requested time"),
+            TransformError::ParentMismatch => write!(f, "frame already has a different parent"),
+            TransformError::CycleDetected => write!(f, "frame would become its own ancestor"),
+        };
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+/// Transforms of a frame relative to its parent.
+#[derive(Clone, Debug)]
+struct FrameLink<T> {
+    parent: String,
+    /// (time, pose of the frame in its parent), in order of time. Empty for static links.
+    history: Vec<(f64, T)>,
+    static_transform: Option<T>,
+}
+
+/// Named coordinate frames arranged in trees, each frame knowing its pose in its parent over
+/// time, e.g. "map" -> "odom" -> "base_link" -> "laser".
+#[derive(Clone, Debug)]
+pub struct TransformTree<T> {
+    links: HashMap<String, FrameLink<T>>,
+}
+
+impl<T> TransformTree<T>
+where
+    T: Transform,
+{
+    pub fn new() -> Self {
+        return Self {
+            links: HashMap::new(),
+        };
+    }
+
+    /// Check whether the frame has been mentioned as parent or child.
+    pub fn has_frame(&self, frame: &str) -> bool {
+        return self.links.contains_key(frame)
+            || self.links.values().any(|link| link.parent == frame);
+    }
+
+    /// Record the pose of child in parent at time, replacing any pose recorded at the same time.
+    pub fn set_transform(
+        &mut self,
+        parent: &str,
+        child: &str,
+        time: f64,
+        pose: T,
+    ) -> Result<(), TransformError> {
+        let link = self.link_mut(parent, child)?;
+        link.static_transform = None;
+
+        match link
+            .history
+            .binary_search_by(|(t, _)| f64::total_cmp(t, &time))
+        {
+            Ok(i) => link.history[i].1 = pose,
+            Err(i) => link.history.insert(i, (time, pose)),
+        }
+
+        return Ok(());
+    }
+
+    /// Record the pose of child in parent, valid at all times, e.g. a mounted sensor.
+    /// Replaces all previously recorded poses.
+    pub fn set_static_transform(
+        &mut self,
+        parent: &str,
+        child: &str,
+        pose: T,
+    ) -> Result<(), TransformError> {
+        let link = self.link_mut(parent, child)?;
+        link.history.clear();
+        link.static_transform = Some(pose);
+        return Ok(());
+    }
+
+    /// Find the pose of frame `from` in frame `to` at time, which maps points given in `from`
+    /// into `to`. Poses between two recorded times are interpolated.
+    pub fn lookup(&self, from: &str, to: &str, time: f64) -> Result<T, TransformError> {
+        if !self.has_frame(from) || !self.has_frame(to) {
+            return Err(TransformError::UnknownFrame);
+        }
+
+        // Only the links up to the closest common ancestor need to be known at time.
+        let from_ancestors = self.ancestors(from);
+        let common = match self
+            .ancestors(to)
+            .into_iter()
+            .find(|f| from_ancestors.contains(f))
+        {
+            Some(f) => f,
+            None => return Err(TransformError::NotConnected),
+        };
+
+        let from_pose = self.pose_in_ancestor(from, common, time)?;
+        let to_pose = self.pose_in_ancestor(to, common, time)?;
+        return Ok(to_pose.inverse().compose(&from_pose));
+    }
+
+    /// Drop recorded poses older than time, keeping the latest one before it so that lookups
+    /// at time remain possible.
+    pub fn prune_before(&mut self, time: f64) {
+        for link in self.links.values_mut() {
+            let count = link.history.iter().take_while(|(t, _)| *t < time).count();
+            if count > 1 {
+                link.history.drain(..count - 1);
+            }
+        }
+    }
+
+    fn link_mut(&mut self, parent: &str, child: &str) -> Result<&mut FrameLink<T>, TransformError> {
+        if let Some(link) = self.links.get(child) {
+            if link.parent != parent {
+                return Err(TransformError::ParentMismatch);
+            }
+        } else {
+            // Walk up from the parent to make sure child is not among its ancestors.
+            let mut frame = parent;
+            while let Some(link) = self.links.get(frame) {
+                if frame == child {
+                    return Err(TransformError::CycleDetected);
+                }
+                frame = &link.parent;
+            }
+
+            if frame == child {
+                return Err(TransformError::CycleDetected);
+            }
+
+            self.links.insert(
+                child.to_string(),
+                FrameLink {
+                    parent: parent.to_string(),
+                    history: Vec::new(),
+                    static_transform: None,
+                },
+            );
+        }
+
+        return Ok(self.links.get_mut(child).unwrap());
+    }
+
+    /// List the frame followed by its parent, grandparent and so on up to the root.
+    fn ancestors<'a>(&'a self, frame: &'a str) -> Vec<&'a str> {
+        let mut ret: Vec<&str> = vec![frame];
+        let mut frame = frame;
+
+        while let Some(link) = self.links.get(frame) {
+            frame = &link.parent;
+            ret.push(frame);
+        }
+
+        return ret;
+    }
+
+    /// Chain the poses of the links from frame up to one of its ancestors.
+    fn pose_in_ancestor(
+        &self,
+        frame: &str,
+        ancestor: &str,
+        time: f64,
+    ) -> Result<T, TransformError> {
+        let mut ret = T::identity();
+        let mut frame = frame;
+
+        while frame != ancestor {
+            let link = self.links.get(frame).unwrap();
+            ret = link.pose_at(time)?.compose(&ret);
+            frame = &link.parent;
+        }
+
+        return Ok(ret);
+    }
+}
+
+impl<T> FrameLink<T>
+where
+    T: Transform,
+{
+    fn pose_at(&self, time: f64) -> Result<T, TransformError> {
+        if let Some(pose) = self.static_transform {
+            return Ok(pose);
+        }
+
+        let i = self.history.partition_point(|(t, _)| *t < time);
+        if i < self.history.len() && self.history[i].0 == time {
+            return Ok(self.history[i].1);
+        }
+
+        if i == 0 || i == self.history.len() {
+            return Err(TransformError::ExtrapolationRequired);
+        }
+
+        let (t0, pose0) = self.history[i - 1];
+        let (t1, pose1) = self.history[i];
+        return Ok(pose0.interpolate(&pose1, (time - t0) / (t1 - t0)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector};
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::{TransformError, TransformTree};
+
+    #[test]
+    fn looks_up_interpolated_chains() {
+        let mut tree: TransformTree<Pose2D> = TransformTree::new();
+        tree.set_transform(
+            "map",
+            "base",
+            0_f64,
+            Pose2D::from_xytheta(0_f64, 0_f64, 0_f64),
+        )
+        .unwrap();
+        tree.set_transform(
+            "map",
+            "base",
+            2_f64,
+            Pose2D::from_xytheta(2_f64, 0_f64, FRAC_PI_2),
+        )
+        .unwrap();
+        tree.set_static_transform("base", "laser", Pose2D::from_xytheta(1_f64, 0_f64, 0_f64))
+            .unwrap();
+
+        let laser_in_map = tree.lookup("laser", "map", 1_f64).unwrap();
+        let expected = Pose2D::from_xytheta(1_f64, 0_f64, FRAC_PI_2 / 2_f64)
+            .compose(&Pose2D::from_xytheta(1_f64, 0_f64, 0_f64));
+        assert!((laser_in_map.position - expected.position).magnitude() < 1e-9);
+        assert!((laser_in_map.theta - expected.theta).abs() < 1e-9);
+
+        let map_in_laser = tree.lookup("map", "laser", 1_f64).unwrap();
+        let origin = map_in_laser.compose(&laser_in_map);
+        assert!(origin.position.magnitude() < 1e-9 && origin.theta.abs() < 1e-9);
+        assert_eq!(tree.lookup("base", "base", 5_f64), Ok(Pose2D::identity()));
+
+        assert_eq!(
+            tree.lookup("laser", "map", 3_f64),
+            Err(TransformError::ExtrapolationRequired)
+        );
+        assert_eq!(
+            tree.lookup("laser", "odom", 1_f64),
+            Err(TransformError::UnknownFrame)
+        );
+        assert_eq!(
+            tree.set_static_transform("laser", "map", Pose2D::identity()),
+            Err(TransformError::CycleDetected)
+        );
+        assert_eq!(
+            tree.set_static_transform("odom", "base", Pose2D::identity()),
+            Err(TransformError::ParentMismatch)
+        );
+    }
+}
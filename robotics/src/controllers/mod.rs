@@ -0,0 +1 @@
+pub mod pure_pursuit;
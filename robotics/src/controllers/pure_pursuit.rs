@@ -0,0 +1,191 @@
+use core::numerics::{pose2d::Pose2D, vector::Vector};
+
+use math::{geometry::polyline2d::Polyline2D, motion::kinematic_model::DifferentialDriveControl};
+
+/// Parameters of `PurePursuit`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PurePursuitOptions {
+    /// Lookahead distance per unit of current speed, i.e. the lookahead time in seconds.
+    pub lookahead_gain: f64,
+
+    /// Shortest lookahead distance, used when standing still.
+    pub min_lookahead: f64,
+
+    /// Longest lookahead distance.
+    pub max_lookahead: f64,
+
+    /// Cruising speed in meters per second.
+    pub target_velocity: f64,
+
+    /// Turn rate limit in radians per second. The speed is lowered in tight turns to keep it.
+    pub max_angular_velocity: f64,
+
+    /// Deceleration used to slow down for the end of the path, in meters per second squared.
+    pub max_deceleration: f64,
+
+    /// Distance to the end of the path at which the robot stops.
+    pub goal_tolerance: f64,
+}
+
+impl Default for PurePursuitOptions {
+    fn default() -> Self {
+        return Self {
+            lookahead_gain: 1_f64,
+            min_lookahead: 0.3,
+            max_lookahead: 2_f64,
+            target_velocity: 0.5,
+            max_angular_velocity: 1_f64,
+            max_deceleration: 0.5,
+            goal_tolerance: 0.05,
+        };
+    }
+}
+
+/// Path tracking controller steering a differential drive robot along the arc through a
+/// point on the path a lookahead distance ahead of it. The lookahead grows with the speed,
+/// which smooths tracking at high speed and keeps it tight when slow.
+pub struct PurePursuit {
+    options: PurePursuitOptions,
+    path: Polyline2D,
+    /// Arc length of the path already passed, so the robot never turns back to earlier parts.
+    progress: f64,
+    finished: bool,
+}
+
+impl PurePursuit {
+    pub fn new(options: PurePursuitOptions, path: Polyline2D) -> Self {
+        return Self {
+            options: options,
+            path: path,
+            progress: 0_f64,
+            finished: false,
+        };
+    }
+
+    pub fn path(&self) -> &Polyline2D {
+        return &self.path;
+    }
+
+    /// Follow a new path from its start, e.g. after replanning.
+    pub fn set_path(&mut self, path: Polyline2D) {
+        self.path = path;
+        self.progress = 0_f64;
+        self.finished = false;
+    }
+
+    /// Whether the robot has come within the goal tolerance of the end of the path.
+    pub fn is_finished(&self) -> bool {
+        return self.finished;
+    }
+
+    /// Lookahead distance at the given speed.
+    pub fn lookahead(&self, velocity: f64) -> f64 {
+        return (self.options.lookahead_gain * velocity.abs())
+            .clamp(self.options.min_lookahead, self.options.max_lookahead);
+    }
+
+    /// Velocity command for the robot at pose, currently moving at velocity. Commands stop
+    /// the robot once it has reached the end of the path, or if the path is empty.
+    pub fn control(&mut self, pose: &Pose2D, velocity: f64) -> DifferentialDriveControl {
+        let stop = DifferentialDriveControl {
+            linear: 0_f64,
+            angular: 0_f64,
+        };
+
+        let (_, arc_length) = match self.path.closest_point(&pose.position) {
+            Some(closest) => closest,
+            None => return stop,
+        };
+        self.progress = self.progress.max(arc_length);
+
+        let length = self.path.length();
+        let end = *self.path.points.last().unwrap();
+        let distance_to_end = (end - pose.position).magnitude();
+        if self.finished
+            || (distance_to_end <= self.options.goal_tolerance
+                && length - self.progress <= self.lookahead(velocity))
+        {
+            self.finished = true;
+            return stop;
+        }
+
+        let target_arc_length = (self.progress + self.lookahead(velocity)).min(length);
+        let target = self.path.point_at_arc_length(target_arc_length).unwrap();
+
+        // Curvature of the arc through the robot position, tangent to its heading, and the
+        // target point, from the target in the robot frame.
+        let local = pose.inverse().transform_point(&target);
+        let squared_distance = local.x * local.x + local.y * local.y;
+        let curvature = if squared_distance > 0_f64 {
+            2_f64 * local.y / squared_distance
+        } else {
+            0_f64
+        };
+
+        let remaining = (length - self.progress).max(distance_to_end);
+        let linear = self
+            .options
+            .target_velocity
+            .min(self.options.max_angular_velocity / curvature.abs())
+            .min((2_f64 * self.options.max_deceleration * remaining).sqrt());
+
+        return DifferentialDriveControl {
+            linear: linear,
+            angular: (linear * curvature).clamp(
+                -self.options.max_angular_velocity,
+                self.options.max_angular_velocity,
+            ),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+
+    use math::{
+        geometry::polyline2d::Polyline2D,
+        motion::{
+            integrator::Integrator,
+            kinematic_model::{DifferentialDrive, KinematicModel},
+        },
+    };
+
+    use super::{PurePursuit, PurePursuitOptions};
+
+    #[test]
+    fn follows_path_to_goal() {
+        let path = Polyline2D::new(vec![
+            Vector2D::from_xy(0_f64, 0_f64),
+            Vector2D::from_xy(4_f64, 0_f64),
+            Vector2D::from_xy(4_f64, 4_f64),
+        ]);
+        let mut controller = PurePursuit::new(PurePursuitOptions::default(), path);
+        let robot = DifferentialDrive::new(0.4);
+
+        // Start beside the path, so the first command turns towards it.
+        let mut pose = Pose2D::from_xytheta(0_f64, -0.5, 0_f64);
+        let mut velocity = 0_f64;
+        let first = controller.control(&pose, velocity);
+        assert!(first.linear > 0_f64 && first.angular > 0_f64);
+
+        for _ in 0..1000 {
+            let command = controller.control(&pose, velocity);
+            if controller.is_finished() {
+                break;
+            }
+            pose = *robot
+                .rollout(&pose, &command, 0.05, 1, Integrator::RungeKutta4)
+                .last()
+                .unwrap();
+            velocity = command.linear;
+
+            let (closest, _) = controller.path().closest_point(&pose.position).unwrap();
+            assert!((closest - pose.position).magnitude() < 0.6);
+        }
+
+        assert!(controller.is_finished());
+        assert!((pose.position - Vector2D::from_xy(4_f64, 4_f64)).magnitude() <= 0.05);
+        assert_eq!(controller.control(&pose, velocity).linear, 0_f64);
+    }
+}
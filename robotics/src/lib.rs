@@ -1,3 +1,7 @@
+pub mod controllers;
+pub mod localization;
+pub mod planners;
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -0,0 +1,322 @@
+use core::numerics::{
+    pose2d::{normalize_angle, Pose2D},
+    vector::Vector,
+    vector2d::Vector2D,
+};
+
+use map::grid::grid_map::GridMap;
+
+/// Parameters of `CorrelativeScanMatcher`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CorrelativeScanMatcherOptions {
+    /// Largest offset of the position from the initial guess searched along each axis.
+    pub linear_window: f64,
+
+    /// Largest offset of the heading from the initial guess searched, in radians.
+    pub angular_window: f64,
+
+    /// Heading step of the search. If `None`, the step moves the farthest scan point by
+    /// about one cell.
+    pub angular_step: Option<f64>,
+
+    /// Spread of the likelihood of a scan point around the closest obstacle.
+    pub sigma: f64,
+
+    /// Number of coarser grids bounding the score, each halving the resolution.
+    pub levels: usize,
+
+    /// Matches scoring at most this, in [0, 1], are rejected.
+    pub min_score: f64,
+}
+
+impl Default for CorrelativeScanMatcherOptions {
+    fn default() -> Self {
+        return Self {
+            linear_window: 1_f64,
+            angular_window: 0.5,
+            angular_step: None,
+            sigma: 0.1,
+            levels: 4,
+            min_score: 0.5,
+        };
+    }
+}
+
+/// Outcome of `CorrelativeScanMatcher::match_scan`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScanMatch {
+    pub pose: Pose2D,
+
+    /// Mean likelihood of the scan points at the pose, 1 if all lie on obstacles.
+    pub score: f64,
+}
+
+/// Candidate heading and cell offset, with the upper bound of the score of the offsets
+/// [x, x + 2^level) x [y, y + 2^level).
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    angle_index: usize,
+    x: i64,
+    y: i64,
+    score: f64,
+}
+
+/// Scan matcher searching every pose in a window around an initial guess for the one that
+/// puts the scan points closest to the obstacles of a grid map, so unlike ICP it does not
+/// need a close guess, e.g. for relocalization. Branch and bound over precomputed grids of
+/// maxima keeps the exhaustive search fast; a match can be refined with ICP afterwards.
+pub struct CorrelativeScanMatcher {
+    options: CorrelativeScanMatcherOptions,
+    cell_size: f64,
+    width: i64,
+    height: i64,
+
+    /// Grids of maxima of the likelihood for each level, where level k holds at (x, y) the
+    /// maximum over the cells [x, x + 2^k) x [y, y + 2^k). Cells are counted from the
+    /// bottom-left, and padded below and to the left so that windows may start outside.
+    grids: Vec<Vec<f64>>,
+    padding: i64,
+}
+
+impl CorrelativeScanMatcher {
+    pub fn new(grid_map: &GridMap, options: CorrelativeScanMatcherOptions) -> Self {
+        let distances = grid_map.obstacle_distances();
+        let (width, height) = (
+            grid_map.horizontal_cells() as i64,
+            grid_map.vertical_cells() as i64,
+        );
+        let padding = (1_i64 << options.levels) - 1;
+        let stride = width + padding;
+
+        let mut ret = Self {
+            options: options,
+            cell_size: grid_map.cell_size(),
+            width: width,
+            height: height,
+            grids: Vec::with_capacity(options.levels + 1),
+            padding: padding,
+        };
+
+        let mut grid = vec![0_f64; ((width + padding) * (height + padding)) as usize];
+        for y in 0..height {
+            for x in 0..width {
+                let d = distances[[(height - 1 - y) as usize, x as usize]] / options.sigma;
+                grid[((y + padding) * stride + x + padding) as usize] = (-0.5 * d * d).exp();
+            }
+        }
+        ret.grids.push(grid);
+
+        for level in 1..=options.levels {
+            let half = 1_i64 << (level - 1);
+            let previous = &ret.grids[level - 1];
+            let mut grid = vec![0_f64; previous.len()];
+            for y in -padding..height {
+                for x in -padding..width {
+                    let mut value = 0_f64;
+                    for (dx, dy) in [(0, 0), (half, 0), (0, half), (half, half)] {
+                        value = value.max(ret.value(previous, x + dx, y + dy));
+                    }
+                    grid[((y + padding) * stride + x + padding) as usize] = value;
+                }
+            }
+            ret.grids.push(grid);
+        }
+
+        return ret;
+    }
+
+    /// Best pose of the scan, given as points in the frame of the robot, within the search
+    /// window around initial_pose. Returns `None` if the scan is empty or no pose scores
+    /// above the minimum score.
+    pub fn match_scan(&self, scan: &[Vector2D], initial_pose: &Pose2D) -> Option<ScanMatch> {
+        if scan.is_empty() {
+            return None;
+        }
+
+        let max_range = scan.iter().map(|p| p.magnitude()).fold(0_f64, f64::max);
+
+        let angular_step = match self.options.angular_step {
+            Some(step) => step,
+            None if max_range > self.cell_size => {
+                (1_f64 - self.cell_size * self.cell_size / (2_f64 * max_range * max_range)).acos()
+            }
+            None => self.options.angular_window.max(f64::EPSILON),
+        };
+        let angle_steps = (self.options.angular_window / angular_step).ceil() as i64;
+        let angles: Vec<f64> = (-angle_steps..=angle_steps)
+            .map(|i| initial_pose.theta + i as f64 * angular_step)
+            .collect();
+
+        // Cells of the scan points at each heading, before offsetting the position.
+        let scan_cells: Vec<Vec<(i64, i64)>> = angles
+            .iter()
+            .map(|theta| {
+                let pose = Pose2D::new(initial_pose.position, *theta);
+                return scan
+                    .iter()
+                    .map(|p| {
+                        let q = pose.transform_point(p) / self.cell_size;
+                        return (q.x.floor() as i64, q.y.floor() as i64);
+                    })
+                    .collect();
+            })
+            .collect();
+
+        let window = (self.options.linear_window / self.cell_size).ceil() as i64;
+        let top = self.options.levels;
+        let size = 1_i64 << top;
+        let mut candidates: Vec<Candidate> = Vec::new();
+        for angle_index in 0..angles.len() {
+            let mut x = -window;
+            while x <= window {
+                let mut y = -window;
+                while y <= window {
+                    candidates.push(self.score(&scan_cells, angle_index, x, y, top));
+                    y += size;
+                }
+                x += size;
+            }
+        }
+
+        let mut best: Option<Candidate> = None;
+        self.branch_and_bound(&scan_cells, candidates, top, window, &mut best);
+
+        return best.map(|c| ScanMatch {
+            pose: Pose2D::new(
+                initial_pose.position + Vector2D::from_xy(c.x as f64, c.y as f64) * self.cell_size,
+                normalize_angle(angles[c.angle_index]),
+            ),
+            score: c.score,
+        });
+    }
+
+    /// Depth first search from the most promising candidates, skipping those whose bound
+    /// does not beat the best match so far.
+    fn branch_and_bound(
+        &self,
+        scan_cells: &[Vec<(i64, i64)>],
+        mut candidates: Vec<Candidate>,
+        level: usize,
+        window: i64,
+        best: &mut Option<Candidate>,
+    ) {
+        candidates.sort_by(|a, b| {
+            return b
+                .score
+                .total_cmp(&a.score)
+                .then((a.angle_index, a.x, a.y).cmp(&(b.angle_index, b.x, b.y)));
+        });
+
+        for candidate in candidates {
+            let threshold = best.map_or(self.options.min_score, |b| b.score);
+            if candidate.score <= threshold {
+                break;
+            }
+
+            if level == 0 {
+                *best = Some(candidate);
+                continue;
+            }
+
+            let half = 1_i64 << (level - 1);
+            let mut children: Vec<Candidate> = Vec::with_capacity(4);
+            for (dx, dy) in [(0, 0), (half, 0), (0, half), (half, half)] {
+                let (x, y) = (candidate.x + dx, candidate.y + dy);
+                if x <= window && y <= window {
+                    children.push(self.score(scan_cells, candidate.angle_index, x, y, level - 1));
+                }
+            }
+            self.branch_and_bound(scan_cells, children, level - 1, window, best);
+        }
+    }
+
+    fn score(
+        &self,
+        scan_cells: &[Vec<(i64, i64)>],
+        angle_index: usize,
+        x: i64,
+        y: i64,
+        level: usize,
+    ) -> Candidate {
+        let cells = &scan_cells[angle_index];
+        let sum: f64 = cells
+            .iter()
+            .map(|(cx, cy)| self.value(&self.grids[level], cx + x, cy + y))
+            .sum();
+
+        return Candidate {
+            angle_index: angle_index,
+            x: x,
+            y: y,
+            score: sum / cells.len() as f64,
+        };
+    }
+
+    fn value(&self, grid: &[f64], x: i64, y: i64) -> f64 {
+        if x < -self.padding || y < -self.padding || x >= self.width || y >= self.height {
+            return 0_f64;
+        }
+
+        let stride = self.width + self.padding;
+        return grid[((y + self.padding) * stride + x + self.padding) as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+
+    use super::{CorrelativeScanMatcher, CorrelativeScanMatcherOptions};
+
+    #[test]
+    fn relocalize() {
+        // Room of 8 m x 6 m in 0.1 m cells, with an L-shaped obstacle breaking the symmetry.
+        let mut grid_map = GridMap::with_cell_state(80, 60, 0.1, GridMapCellState::Vacant);
+        for r in 0..60 {
+            for c in 0..80 {
+                let wall = r == 0 || c == 0 || r == 59 || c == 79;
+                let obstacle =
+                    (r == 20 && (50..65).contains(&c)) || (c == 50 && (20..35).contains(&r));
+                if wall || obstacle {
+                    *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() =
+                        GridMapCellState::Occupied;
+                }
+            }
+        }
+
+        // Scan of the occupied cell centers within 4 m of the robot, in its frame.
+        let truth = Pose2D::from_xytheta(3.05, 2.95, 0.3);
+        let mut scan: Vec<Vector2D> = Vec::new();
+        for r in 0..60 {
+            for c in 0..80 {
+                if *grid_map.get_by_cell(r, c).unwrap().state() == GridMapCellState::Occupied {
+                    let (x, y) = grid_map.cell_to_coordinate(r, c);
+                    let p = truth.inverse().transform_point(&Vector2D::from_xy(x, y));
+                    if p.magnitude() < 4_f64 {
+                        scan.push(p);
+                    }
+                }
+            }
+        }
+
+        let matcher =
+            CorrelativeScanMatcher::new(&grid_map, CorrelativeScanMatcherOptions::default());
+        let guess = Pose2D::from_xytheta(3.6, 2.4, 0.05);
+        let result = matcher.match_scan(&scan, &guess).unwrap();
+        assert!(
+            (result.pose.position - truth.position).magnitude() < 0.11,
+            "{:?}",
+            result
+        );
+        assert!(
+            (result.pose.theta - truth.theta).abs() < 0.03,
+            "{:?}",
+            result
+        );
+        assert!(result.score > 0.7, "{:?}", result);
+
+        assert!(matcher.match_scan(&[], &guess).is_none());
+    }
+}
@@ -0,0 +1,250 @@
+use core::{
+    graph::node::NodeId,
+    numerics::{matrix3::Matrix3, pose2d::Pose2D, vector::Vector, vector2d::Vector2D},
+};
+
+use map::grid::grid_map::GridMap;
+use math::pose_graph::{PoseConstraint, PoseGraph};
+
+use super::correlative_scan_matcher::{CorrelativeScanMatcher, CorrelativeScanMatcherOptions};
+
+/// Parameters of `LoopClosureDetector`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoopClosureOptions {
+    /// Submaps whose node is farther than this from the current node are not searched.
+    pub search_radius: f64,
+
+    /// Number of most recently added submaps skipped, as the current node is usually
+    /// already constrained to them by odometry.
+    pub skip_recent: usize,
+
+    /// Search window and minimum score of the scan matching.
+    pub matcher: CorrelativeScanMatcherOptions,
+
+    /// Information matrix of the emitted constraints in (x, y, theta).
+    pub information: Matrix3,
+}
+
+impl Default for LoopClosureOptions {
+    fn default() -> Self {
+        let mut information = Matrix3::identity() * 100_f64;
+        information.rows[2][2] = 400_f64;
+
+        return Self {
+            search_radius: 5_f64,
+            skip_recent: 1,
+            matcher: CorrelativeScanMatcherOptions {
+                linear_window: 1.5,
+                min_score: 0.6,
+                ..CorrelativeScanMatcherOptions::default()
+            },
+            information: information,
+        };
+    }
+}
+
+/// Constraint between the node of a stored submap and the current node, to be added to the
+/// pose graph as an edge from `from` to `to`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoopClosure {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub constraint: PoseConstraint,
+    pub score: f64,
+}
+
+struct Submap {
+    node_id: NodeId,
+
+    /// Pose of the node in the frame of the grid map, fixed when the submap was built.
+    node_in_grid: Pose2D,
+    matcher: CorrelativeScanMatcher,
+}
+
+/// Detects loop closures by matching scans of the current node against stored submaps,
+/// e.g. grid maps built by `Mapper` around earlier nodes of the pose graph. Submaps are
+/// found from the current poses of the pose graph, so the search follows the corrections
+/// of previous optimizations.
+pub struct LoopClosureDetector {
+    options: LoopClosureOptions,
+    submaps: Vec<Submap>,
+}
+
+impl LoopClosureDetector {
+    pub fn new(options: LoopClosureOptions) -> Self {
+        return Self {
+            options: options,
+            submaps: Vec::new(),
+        };
+    }
+
+    /// Store a submap attached to the node at node_in_grid, its pose in the frame of the
+    /// grid map. Unknown cells of the grid map should be vacant, e.g. binarized with
+    /// `UnknownCellPolicy::Vacant`, as scan points on occupied cells count as matches.
+    pub fn add_submap(&mut self, node_id: NodeId, node_in_grid: Pose2D, grid_map: &GridMap) {
+        self.submaps.push(Submap {
+            node_id: node_id,
+            node_in_grid: node_in_grid,
+            matcher: CorrelativeScanMatcher::new(grid_map, self.options.matcher),
+        });
+    }
+
+    pub fn submap_count(&self) -> usize {
+        return self.submaps.len();
+    }
+
+    /// Match the scan taken at node_id, given as points in the frame of the node, against
+    /// the submaps near the node, from the best match. Returns nothing if the node is not
+    /// in the pose graph.
+    pub fn detect(
+        &self,
+        pose_graph: &PoseGraph,
+        node_id: NodeId,
+        scan: &[Vector2D],
+    ) -> Vec<LoopClosure> {
+        let current = match pose_graph.get_node_by_id(&node_id) {
+            Some(node) => *node.node_info(),
+            None => return Vec::new(),
+        };
+
+        let searched = self.submaps.len().saturating_sub(self.options.skip_recent);
+        let mut ret: Vec<LoopClosure> = Vec::new();
+        for submap in self.submaps[..searched].iter() {
+            let submap_pose = match pose_graph.get_node_by_id(&submap.node_id) {
+                Some(node) => *node.node_info(),
+                None => continue,
+            };
+            if submap.node_id == node_id
+                || (submap_pose.position - current.position).magnitude()
+                    > self.options.search_radius
+            {
+                continue;
+            }
+
+            // Current pose in the frame of the grid map, as estimated by the pose graph.
+            let guess = submap
+                .node_in_grid
+                .compose(&submap_pose.relative_pose(&current));
+            if let Some(result) = submap.matcher.match_scan(scan, &guess) {
+                ret.push(LoopClosure {
+                    from: submap.node_id,
+                    to: node_id,
+                    constraint: PoseConstraint::new(
+                        submap.node_in_grid.relative_pose(&result.pose),
+                        self.options.information,
+                    ),
+                    score: result.score,
+                });
+            }
+        }
+
+        ret.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.from.cmp(&b.from)));
+        return ret;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        graph::graph_options::GraphOptions,
+        numerics::{matrix3::Matrix3, pose2d::Pose2D, vector::Vector, vector2d::Vector2D},
+    };
+    use std::f64::consts::PI;
+
+    use map::grid::{
+        mapper::{LaserScan, Mapper, MapperOptions},
+        probabilistic_grid_map::{OccupancyThresholdPolicy, UnknownCellPolicy},
+    };
+    use math::pose_graph::{
+        PoseConstraint, PoseGraph, PoseGraphOptimizer, PoseGraphOptimizerOptions,
+    };
+
+    use super::{LoopClosureDetector, LoopClosureOptions};
+
+    /// Scan of 360 beams inside the room [1, 9] x [1, 5] with a pillar [6, 6.5] x [1, 2.5].
+    fn scan(pose: &Pose2D) -> LaserScan {
+        let ranges: Vec<f64> = (0..360)
+            .map(|i| {
+                let direction = Vector2D::from_angle(pose.theta + i as f64 * PI / 180_f64);
+                let mut range = 0_f64;
+                while range < 8_f64 {
+                    let p = pose.position + direction * range;
+                    let in_room = p.x > 1_f64 && p.x < 9_f64 && p.y > 1_f64 && p.y < 5_f64;
+                    let in_pillar = p.x > 6_f64 && p.x < 6.5 && p.y < 2.5;
+                    if !in_room || in_pillar {
+                        return range;
+                    }
+                    range += 0.01;
+                }
+                return f64::INFINITY;
+            })
+            .collect();
+        return LaserScan::new(0_f64, PI / 180_f64, ranges, 8_f64);
+    }
+
+    #[test]
+    fn close_loop() {
+        // Submap around the first node, with the grid frame as the world frame.
+        let start = Pose2D::from_xytheta(3_f64, 3_f64, 0_f64);
+        let mut mapper = Mapper::new(100, 60, 0.1, MapperOptions::default());
+        for _ in 0..3 {
+            mapper.integrate(&start, &scan(&start));
+        }
+        let policy = OccupancyThresholdPolicy {
+            unknown_cell_policy: UnknownCellPolicy::Vacant,
+            ..OccupancyThresholdPolicy::default()
+        };
+        let submap = mapper.map().to_grid_map(&policy);
+
+        // The robot returns near the start, but odometry has drifted.
+        let truth = Pose2D::from_xytheta(4.5, 3.5, 0.3);
+        let odometry = Pose2D::from_xytheta(1.9, 0.1, 0.15);
+        let mut pose_graph = PoseGraph::with_options(GraphOptions::multigraph());
+        let first = pose_graph.add_node(start);
+        let second = pose_graph.add_node(start.compose(&odometry));
+        pose_graph
+            .add_edge(
+                first,
+                second,
+                PoseConstraint::new(odometry, Matrix3::identity()),
+            )
+            .unwrap();
+
+        let mut detector = LoopClosureDetector::new(LoopClosureOptions {
+            skip_recent: 0,
+            ..LoopClosureOptions::default()
+        });
+        detector.add_submap(first, start, &submap);
+        let closures = detector.detect(&pose_graph, second, &scan(&truth).points());
+        assert_eq!(closures.len(), 1);
+        assert_eq!((closures[0].from, closures[0].to), (first, second));
+
+        pose_graph
+            .add_edge(first, second, closures[0].constraint)
+            .unwrap();
+        PoseGraphOptimizer::new(PoseGraphOptimizerOptions::default())
+            .optimize(&mut pose_graph, first)
+            .unwrap();
+        let corrected = pose_graph.get_node_by_id(&second).unwrap().node_info();
+        assert!(
+            (corrected.position - truth.position).magnitude() < 0.1,
+            "{:?}",
+            corrected
+        );
+        assert!(
+            (corrected.theta - truth.theta).abs() < 0.05,
+            "{:?}",
+            corrected
+        );
+
+        // The only submap is the most recent one, which is skipped by default.
+        let detector_default = {
+            let mut d = LoopClosureDetector::new(LoopClosureOptions::default());
+            d.add_submap(first, start, &submap);
+            d
+        };
+        assert!(detector_default
+            .detect(&pose_graph, second, &scan(&truth).points())
+            .is_empty());
+    }
+}
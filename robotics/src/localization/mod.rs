@@ -0,0 +1,4 @@
+pub mod correlative_scan_matcher;
+pub mod loop_closure_detector;
+#[cfg(feature = "rand")]
+pub mod monte_carlo_localization;
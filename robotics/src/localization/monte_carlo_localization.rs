@@ -0,0 +1,444 @@
+use core::numerics::{
+    matrix3::Matrix3,
+    pose2d::{normalize_angle, Pose2D},
+    vector::Vector,
+    vector2d::Vector2D,
+};
+use std::f64::consts::PI;
+
+use map::grid::{grid_map::GridMap, mapper::LaserScan};
+use math::sampling::{perturb_pose, sample_gaussian};
+use rand::Rng;
+
+/// Parameters of `MonteCarloLocalization`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MonteCarloLocalizationOptions {
+    pub particle_count: usize,
+
+    /// Noise of the odometry motion model, as the standard deviation of the rotations per
+    /// radian turned and per meter driven.
+    pub rotation_noise_per_rotation: f64,
+    pub rotation_noise_per_meter: f64,
+
+    /// Noise of the odometry motion model, as the standard deviation of the translation
+    /// per meter driven and per radian turned.
+    pub translation_noise_per_meter: f64,
+    pub translation_noise_per_rotation: f64,
+
+    /// Spread of the likelihood of a beam endpoint around the closest obstacle.
+    pub sigma_hit: f64,
+
+    /// Weight of measurements explained by the map; the rest are spread uniformly over
+    /// the range of the scanner, e.g. people walking by.
+    pub z_hit: f64,
+
+    /// Number of beams of a scan used, evenly spread over the scan.
+    pub max_beams: usize,
+
+    /// Particles are resampled once the effective sample size falls below this fraction
+    /// of the particle count.
+    pub resample_threshold: f64,
+}
+
+impl Default for MonteCarloLocalizationOptions {
+    fn default() -> Self {
+        return Self {
+            particle_count: 500,
+            rotation_noise_per_rotation: 0.1,
+            rotation_noise_per_meter: 0.05,
+            translation_noise_per_meter: 0.1,
+            translation_noise_per_rotation: 0.05,
+            sigma_hit: 0.2,
+            z_hit: 0.9,
+            max_beams: 60,
+            resample_threshold: 0.5,
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Particle {
+    pub pose: Pose2D,
+
+    /// Normalized so that the weights of all particles sum to one.
+    pub weight: f64,
+}
+
+/// Weighted mean pose of the particles and its covariance in (x, y, theta).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseEstimate {
+    pub pose: Pose2D,
+    pub covariance: Matrix3,
+}
+
+/// Monte Carlo localization, tracking the pose of a robot on a grid map with a particle
+/// filter: odometry moves the particles, laser scans weight them with a likelihood field
+/// of the obstacles, and particles are resampled when few carry most of the weight.
+/// Random draws come from the given generator only, so a seeded generator reproduces them.
+pub struct MonteCarloLocalization {
+    options: MonteCarloLocalizationOptions,
+    particles: Vec<Particle>,
+
+    /// Distance from each cell to the closest obstacle, zero for occupied cells, row by
+    /// row from the bottom of the map.
+    distances: Vec<f64>,
+    width: usize,
+    height: usize,
+    cell_size: f64,
+}
+
+impl MonteCarloLocalization {
+    /// Filter without particles; initialize it before predicting or updating.
+    pub fn new(grid_map: &GridMap, options: MonteCarloLocalizationOptions) -> Self {
+        let (width, height) = (grid_map.horizontal_cells(), grid_map.vertical_cells());
+        let distances = grid_map.obstacle_distances();
+        return Self {
+            options: options,
+            particles: Vec::new(),
+            distances: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (height - 1 - y, x)))
+                .map(|(row, column)| distances[[row, column]])
+                .collect(),
+            width: width,
+            height: height,
+            cell_size: grid_map.cell_size(),
+        };
+    }
+
+    /// Spread the particles around a known pose, e.g. the pose the robot was started at.
+    pub fn initialize<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        pose: &Pose2D,
+        position_std_dev: f64,
+        theta_std_dev: f64,
+    ) {
+        let weight = 1_f64 / self.options.particle_count as f64;
+        self.particles = (0..self.options.particle_count)
+            .map(|_| Particle {
+                pose: perturb_pose(rng, pose, position_std_dev, theta_std_dev),
+                weight: weight,
+            })
+            .collect();
+    }
+
+    /// Spread the particles uniformly over the vacant cells, for a robot that does not know
+    /// where it is. Leaves no particles if no cell is vacant.
+    pub fn initialize_global<R: Rng>(&mut self, rng: &mut R) {
+        let vacant: Vec<usize> = (0..self.distances.len())
+            .filter(|i| self.distances[*i] > 0_f64)
+            .collect();
+        self.particles.clear();
+        if vacant.is_empty() {
+            return;
+        }
+
+        let weight = 1_f64 / self.options.particle_count as f64;
+        for _ in 0..self.options.particle_count {
+            let cell = vacant[rng.gen_range(0..vacant.len())];
+            let position = Vector2D::from_xy(
+                ((cell % self.width) as f64 + rng.gen::<f64>()) * self.cell_size,
+                ((cell / self.width) as f64 + rng.gen::<f64>()) * self.cell_size,
+            );
+            self.particles.push(Particle {
+                pose: Pose2D::new(position, rng.gen_range(-PI..PI)),
+                weight: weight,
+            });
+        }
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        return &self.particles;
+    }
+
+    /// Move the particles by the motion between two odometry poses, decomposed into a
+    /// rotation, a translation and another rotation, each perturbed by noise.
+    pub fn predict<R: Rng>(&mut self, rng: &mut R, previous_odometry: &Pose2D, odometry: &Pose2D) {
+        let delta = odometry.position - previous_odometry.position;
+        let translation = delta.magnitude();
+        let rotation1 = if translation < 1e-9 {
+            0_f64
+        } else {
+            normalize_angle(delta.angle() - previous_odometry.theta)
+        };
+        let rotation2 = normalize_angle(odometry.theta - previous_odometry.theta - rotation1);
+
+        let options = &self.options;
+        let rotation_std_dev = |rotation: f64| {
+            return options.rotation_noise_per_rotation * rotation.abs()
+                + options.rotation_noise_per_meter * translation;
+        };
+        let translation_std_dev = options.translation_noise_per_meter * translation
+            + options.translation_noise_per_rotation * (rotation1.abs() + rotation2.abs());
+
+        for particle in self.particles.iter_mut() {
+            let r1 = sample_gaussian(rng, rotation1, rotation_std_dev(rotation1));
+            let t = sample_gaussian(rng, translation, translation_std_dev);
+            let r2 = sample_gaussian(rng, rotation2, rotation_std_dev(rotation2));
+
+            let pose = &mut particle.pose;
+            pose.position += Vector2D::from_angle(pose.theta + r1) * t;
+            pose.theta = normalize_angle(pose.theta + r1 + r2);
+        }
+    }
+
+    /// Weight the particles by how well the scan, taken at the robot pose, matches the map,
+    /// and resample them if the weight concentrated on few particles.
+    pub fn update<R: Rng>(&mut self, rng: &mut R, scan: &LaserScan) {
+        let hits: Vec<Vector2D> = scan.points();
+        if hits.is_empty() || self.particles.is_empty() {
+            return;
+        }
+
+        let stride = hits.len().div_ceil(self.options.max_beams.max(1));
+        let beams: Vec<Vector2D> = hits.into_iter().step_by(stride).collect();
+        let random = (1_f64 - self.options.z_hit) / scan.max_range;
+
+        let log_likelihoods: Vec<f64> = self
+            .particles
+            .iter()
+            .map(|particle| {
+                return beams
+                    .iter()
+                    .map(|beam| {
+                        let hit = match self.distance_at(&particle.pose.transform_point(beam)) {
+                            Some(d) => {
+                                let d = d / self.options.sigma_hit;
+                                self.options.z_hit * (-0.5 * d * d).exp()
+                            }
+                            None => 0_f64,
+                        };
+                        // Without random measurements, a beam off the map or far from any
+                        // obstacle would rule out every particle at once.
+                        return (hit + random).max(f64::MIN_POSITIVE).ln();
+                    })
+                    .sum();
+            })
+            .collect();
+
+        // Relative to the most likely particle, so that the weights do not underflow.
+        let max = log_likelihoods
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        for (particle, log_likelihood) in self.particles.iter_mut().zip(log_likelihoods) {
+            particle.weight *= (log_likelihood - max).exp();
+        }
+        self.normalize_weights();
+
+        let threshold = self.options.resample_threshold * self.particles.len() as f64;
+        if self.effective_sample_size() < threshold {
+            self.resample(rng);
+        }
+    }
+
+    /// Number of equally weighted particles that would carry the same information.
+    pub fn effective_sample_size(&self) -> f64 {
+        let sum_of_squares: f64 = self.particles.iter().map(|p| p.weight * p.weight).sum();
+        return if sum_of_squares > 0_f64 {
+            1_f64 / sum_of_squares
+        } else {
+            0_f64
+        };
+    }
+
+    /// Weighted mean and covariance of the particles, with the circular mean of the
+    /// headings. Returns `None` without particles.
+    pub fn estimate(&self) -> Option<PoseEstimate> {
+        if self.particles.is_empty() {
+            return None;
+        }
+
+        let (mut position, mut sin, mut cos) = (Vector2D::zero(), 0_f64, 0_f64);
+        for particle in self.particles.iter() {
+            position += particle.pose.position * particle.weight;
+            sin += particle.pose.theta.sin() * particle.weight;
+            cos += particle.pose.theta.cos() * particle.weight;
+        }
+        let mean = Pose2D::new(position, sin.atan2(cos));
+
+        let mut covariance = Matrix3::zero();
+        for particle in self.particles.iter() {
+            let d = [
+                particle.pose.position.x - mean.position.x,
+                particle.pose.position.y - mean.position.y,
+                normalize_angle(particle.pose.theta - mean.theta),
+            ];
+            for i in 0..3 {
+                for j in 0..3 {
+                    covariance.rows[i][j] += particle.weight * d[i] * d[j];
+                }
+            }
+        }
+
+        return Some(PoseEstimate {
+            pose: mean,
+            covariance: covariance,
+        });
+    }
+
+    /// Distance from point to the closest obstacle, or `None` outside of the map.
+    fn distance_at(&self, point: &Vector2D) -> Option<f64> {
+        let (x, y) = (
+            (point.x / self.cell_size).floor(),
+            (point.y / self.cell_size).floor(),
+        );
+        if x < 0_f64 || y < 0_f64 || x >= self.width as f64 || y >= self.height as f64 {
+            return None;
+        }
+
+        return Some(self.distances[y as usize * self.width + x as usize]);
+    }
+
+    fn normalize_weights(&mut self) {
+        let sum: f64 = self.particles.iter().map(|p| p.weight).sum();
+        let count = self.particles.len() as f64;
+        for particle in self.particles.iter_mut() {
+            particle.weight = if sum > 0_f64 && sum.is_finite() {
+                particle.weight / sum
+            } else {
+                1_f64 / count
+            };
+        }
+    }
+
+    /// Low variance resampling: one random offset, then evenly spaced picks along the
+    /// cumulative weights.
+    fn resample<R: Rng>(&mut self, rng: &mut R) {
+        let count = self.particles.len();
+        let step = 1_f64 / count as f64;
+        let mut target = rng.gen::<f64>() * step;
+        let mut cumulative = self.particles[0].weight;
+        let mut i = 0;
+
+        let mut resampled: Vec<Particle> = Vec::with_capacity(count);
+        for _ in 0..count {
+            while target > cumulative && i + 1 < count {
+                i += 1;
+                cumulative += self.particles[i].weight;
+            }
+            resampled.push(Particle {
+                pose: self.particles[i].pose,
+                weight: step,
+            });
+            target += step;
+        }
+
+        self.particles = resampled;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{pose2d::Pose2D, vector::Vector, vector2d::Vector2D};
+    use std::f64::consts::PI;
+
+    use map::grid::{
+        grid_map::{GridMap, GridMapCellState},
+        mapper::LaserScan,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{MonteCarloLocalization, MonteCarloLocalizationOptions};
+
+    /// Scan of 180 beams from pose, marching along each beam until an occupied cell.
+    fn scan(grid_map: &GridMap, pose: &Pose2D) -> LaserScan {
+        let ranges: Vec<f64> = (0..180)
+            .map(|i| {
+                let direction = Vector2D::from_angle(pose.theta + i as f64 * PI / 90_f64);
+                let mut range = 0_f64;
+                while range < 8_f64 {
+                    let p = pose.position + direction * range;
+                    if grid_map
+                        .get_by_coordinate(p.x, p.y)
+                        .is_none_or(|c| *c.state() == GridMapCellState::Occupied)
+                    {
+                        return range;
+                    }
+                    range += 0.01;
+                }
+                return f64::INFINITY;
+            })
+            .collect();
+        return LaserScan::new(0_f64, PI / 90_f64, ranges, 8_f64);
+    }
+
+    #[test]
+    fn track_robot() {
+        // Room of 8 m x 6 m in 0.1 m cells, with an L-shaped obstacle.
+        let mut grid_map = GridMap::with_cell_state(80, 60, 0.1, GridMapCellState::Vacant);
+        for r in 0..60 {
+            for c in 0..80 {
+                let wall = r == 0 || c == 0 || r == 59 || c == 79;
+                let obstacle =
+                    (r == 20 && (50..65).contains(&c)) || (c == 50 && (20..35).contains(&r));
+                if wall || obstacle {
+                    *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() =
+                        GridMapCellState::Occupied;
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut mcl =
+            MonteCarloLocalization::new(&grid_map, MonteCarloLocalizationOptions::default());
+        let mut truth = Pose2D::from_xytheta(2_f64, 2_f64, 0.2);
+        mcl.initialize(&mut rng, &Pose2D::from_xytheta(2.3, 1.8, 0_f64), 0.4, 0.3);
+
+        // Drive forward while turning slowly, with exact odometry.
+        for _ in 0..15 {
+            let next = truth.compose(&Pose2D::from_xytheta(0.2, 0_f64, 0.05));
+            mcl.predict(&mut rng, &truth, &next);
+            truth = next;
+            mcl.update(&mut rng, &scan(&grid_map, &truth));
+        }
+
+        let estimate = mcl.estimate().unwrap();
+        assert!(
+            (estimate.pose.position - truth.position).magnitude() < 0.15,
+            "{:?}",
+            estimate
+        );
+        assert!(
+            (estimate.pose.theta - truth.theta).abs() < 0.1,
+            "{:?}",
+            estimate
+        );
+        assert!(estimate.covariance.rows[0][0] < 0.05 && estimate.covariance.rows[0][0] > 0_f64);
+        assert!((mcl.particles().iter().map(|p| p.weight).sum::<f64>() - 1_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn scan_off_the_map_keeps_weights() {
+        let mut grid_map = GridMap::with_cell_state(40, 40, 0.1, GridMapCellState::Vacant);
+        for r in 0..40 {
+            for c in 0..40 {
+                if r == 0 || c == 0 || r == 39 || c == 39 {
+                    *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() =
+                        GridMapCellState::Occupied;
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut mcl = MonteCarloLocalization::new(
+            &grid_map,
+            MonteCarloLocalizationOptions {
+                z_hit: 1_f64,
+                resample_threshold: 0_f64,
+                ..MonteCarloLocalizationOptions::default()
+            },
+        );
+        let pose = Pose2D::from_xytheta(2_f64, 2_f64, 0_f64);
+        mcl.initialize(&mut rng, &pose, 0.02, 0.01);
+        mcl.update(&mut rng, &scan(&grid_map, &pose));
+        let weights: Vec<f64> = mcl.particles().iter().map(|p| p.weight).collect();
+
+        // Without random measurements, every particle explains the scan equally badly.
+        let off_the_map = LaserScan::new(0_f64, PI / 90_f64, vec![50_f64; 180], 100_f64);
+        mcl.update(&mut rng, &off_the_map);
+        for (particle, weight) in mcl.particles().iter().zip(weights) {
+            assert!((particle.weight - weight).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::{cmp::Ordering, collections::BinaryHeap, f64::consts::SQRT_2, fmt};
+
+use map::grid::grid_map::{GridMap, GridMapCellState};
+
+/// Diagonal moves of `Connectivity::Eight` are subject to the corner cutting rule.
+pub use map::grid::connectivity::Connectivity;
+
+/// Which diagonal moves are allowed past blocked cells.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CornerCutting {
+    Allowed,
+    /// At least one of the two cells beside the diagonal must be traversable.
+    IfOneFree,
+    /// Both cells beside the diagonal must be traversable, so paths keep clear of corners.
+    Never,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AStarError {
+    /// The start cell is outside of the map or not traversable.
+    InvalidStart,
+    /// The goal cell is outside of the map or not traversable.
+    InvalidGoal,
+    NoPath,
+}
+
+impl fmt::Display for AStarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            AStarError::InvalidStart => write!(f, "start cell is not traversable"),
+            AStarError::InvalidGoal => write!(f, "goal cell is not traversable"),
+            AStarError::NoPath => write!(f, "no path between start and goal"),
+        };
+    }
+}
+
+impl std::error::Error for AStarError {}
+
+/// A* search over the cells of a grid, with cells given as (row, column).
+pub struct AStarGrid {
+    connectivity: Connectivity,
+    corner_cutting: CornerCutting,
+}
+
+/// Open list entry, ordered so that the binary heap pops the lowest estimated total cost.
+/// Ties go to the entry with the lower remaining estimate, i.e. the one further along.
+pub(crate) struct OpenEntry {
+    pub(crate) estimate: f64,
+    pub(crate) remaining: f64,
+    pub(crate) index: usize,
+}
+
+impl PartialEq for OpenEntry {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for OpenEntry {}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for OpenEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then(other.remaining.total_cmp(&self.remaining))
+            .then(other.index.cmp(&self.index));
+    }
+}
+
+impl AStarGrid {
+    pub fn new(connectivity: Connectivity, corner_cutting: CornerCutting) -> Self {
+        return Self {
+            connectivity: connectivity,
+            corner_cutting: corner_cutting,
+        };
+    }
+
+    /// Plan a shortest path between vacant cells of the grid map.
+    /// Returns the cells from start to goal, both included.
+    pub fn plan(
+        &self,
+        grid_map: &GridMap,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<Vec<(usize, usize)>, AStarError> {
+        return self.plan_with_cost_fn(
+            grid_map.vertical_cells(),
+            grid_map.horizontal_cells(),
+            |r, c| match grid_map.get_by_cell(r, c).unwrap().state() {
+                GridMapCellState::Vacant => Some(0_f64),
+                GridMapCellState::Occupied => None,
+            },
+            start,
+            goal,
+        );
+    }
+
+    /// Plan a path of least cost over a rows x columns grid, e.g. a costmap.
+    /// cost_fn gives the non-negative cost of entering a cell, or `None` if it is not
+    /// traversable. A move costs its length in cells times one plus the cost of the entered
+    /// cell, so with zero costs the path is the shortest one.
+    /// Returns the cells from start to goal, both included.
+    pub fn plan_with_cost_fn<F>(
+        &self,
+        rows: usize,
+        columns: usize,
+        cost_fn: F,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<Vec<(usize, usize)>, AStarError>
+    where
+        F: Fn(usize, usize) -> Option<f64>,
+    {
+        if !is_traversable(rows, columns, &cost_fn, start) {
+            return Err(AStarError::InvalidStart);
+        }
+        if !is_traversable(rows, columns, &cost_fn, goal) {
+            return Err(AStarError::InvalidGoal);
+        }
+
+        let index = |r: usize, c: usize| -> usize { r * columns + c };
+        let mut costs: Vec<f64> = vec![f64::INFINITY; rows * columns];
+        let mut parents: Vec<Option<usize>> = vec![None; rows * columns];
+        let mut closed: Vec<bool> = vec![false; rows * columns];
+        let mut open: BinaryHeap<OpenEntry> = BinaryHeap::new();
+
+        let start_index = index(start.0, start.1);
+        let goal_index = index(goal.0, goal.1);
+        let remaining = self.heuristic(start, goal);
+        costs[start_index] = 0_f64;
+        open.push(OpenEntry {
+            estimate: remaining,
+            remaining: remaining,
+            index: start_index,
+        });
+
+        while let Some(entry) = open.pop() {
+            if closed[entry.index] {
+                continue;
+            }
+            closed[entry.index] = true;
+
+            if entry.index == goal_index {
+                let mut ret: Vec<(usize, usize)> = vec![goal];
+                let mut current = goal_index;
+                while let Some(parent) = parents[current] {
+                    ret.push((parent / columns, parent % columns));
+                    current = parent;
+                }
+                ret.reverse();
+                return Ok(ret);
+            }
+
+            let cell = (entry.index / columns, entry.index % columns);
+            for ((nr, nc), move_cost) in self.moves(rows, columns, &cost_fn, cell) {
+                let neighbor_index = index(nr, nc);
+                let cost = costs[entry.index] + move_cost;
+                if closed[neighbor_index] || cost >= costs[neighbor_index] {
+                    continue;
+                }
+
+                costs[neighbor_index] = cost;
+                parents[neighbor_index] = Some(entry.index);
+                let remaining = self.heuristic((nr, nc), goal);
+                open.push(OpenEntry {
+                    estimate: cost + remaining,
+                    remaining: remaining,
+                    index: neighbor_index,
+                });
+            }
+        }
+
+        return Err(AStarError::NoPath);
+    }
+
+    /// Cells reachable from cell in one move, with the cost of the move.
+    /// A move costs its length in cells times one plus the cost of the entered cell.
+    pub(crate) fn moves<F>(
+        &self,
+        rows: usize,
+        columns: usize,
+        cost_fn: &F,
+        cell: (usize, usize),
+    ) -> Vec<((usize, usize), f64)>
+    where
+        F: Fn(usize, usize) -> Option<f64>,
+    {
+        let (r, c) = cell;
+        let mut ret: Vec<((usize, usize), f64)> = Vec::new();
+        for [dr, dc] in self.connectivity.offsets().iter() {
+            let neighbor = match (r.checked_add_signed(*dr), c.checked_add_signed(*dc)) {
+                (Some(nr), Some(nc)) if nr < rows && nc < columns => (nr, nc),
+                _ => continue,
+            };
+            let cell_cost = match cost_fn(neighbor.0, neighbor.1) {
+                Some(cost) => cost,
+                None => continue,
+            };
+
+            let diagonal = *dr != 0 && *dc != 0;
+            if diagonal {
+                let free_sides = [(neighbor.0, c), (r, neighbor.1)]
+                    .iter()
+                    .filter(|side| is_traversable(rows, columns, cost_fn, **side))
+                    .count();
+                let allowed = match self.corner_cutting {
+                    CornerCutting::Allowed => true,
+                    CornerCutting::IfOneFree => free_sides >= 1,
+                    CornerCutting::Never => free_sides == 2,
+                };
+                if !allowed {
+                    continue;
+                }
+            }
+
+            let length = if diagonal { SQRT_2 } else { 1_f64 };
+            ret.push((neighbor, length * (1_f64 + cell_cost)));
+        }
+
+        return ret;
+    }
+
+    /// Length of the shortest path without obstacles, which never overestimates the cost.
+    pub(crate) fn heuristic(&self, from: (usize, usize), to: (usize, usize)) -> f64 {
+        let dr = from.0.abs_diff(to.0) as f64;
+        let dc = from.1.abs_diff(to.1) as f64;
+        return match self.connectivity {
+            Connectivity::Four => dr + dc,
+            Connectivity::Eight => dr.max(dc) + (SQRT_2 - 1_f64) * dr.min(dc),
+        };
+    }
+}
+
+pub(crate) fn is_traversable<F>(
+    rows: usize,
+    columns: usize,
+    cost_fn: &F,
+    cell: (usize, usize),
+) -> bool
+where
+    F: Fn(usize, usize) -> Option<f64>,
+{
+    return cell.0 < rows && cell.1 < columns && cost_fn(cell.0, cell.1).is_some();
+}
+
+#[cfg(test)]
+mod tests {
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+
+    use super::{AStarError, AStarGrid, Connectivity, CornerCutting};
+
+    #[test]
+    fn plan_around_wall() {
+        // Wall in column 2 with a gap in the bottom row.
+        let mut grid_map = GridMap::with_cell_state(5, 4, 1_f64, GridMapCellState::Vacant);
+        for r in 0..3 {
+            *grid_map.get_by_cell_mut(r, 2).unwrap().state_mut() = GridMapCellState::Occupied;
+        }
+
+        let planner = AStarGrid::new(Connectivity::Four, CornerCutting::Never);
+        let path = planner.plan(&grid_map, (0, 0), (0, 4)).unwrap();
+        assert_eq!(path.len(), 11);
+        assert!(path.contains(&(3, 2)));
+
+        let planner = AStarGrid::new(Connectivity::Eight, CornerCutting::Allowed);
+        let path = planner.plan(&grid_map, (0, 0), (0, 4)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[3], (3, 2));
+
+        let planner = AStarGrid::new(Connectivity::Eight, CornerCutting::Never);
+        let path = planner.plan(&grid_map, (0, 0), (0, 4)).unwrap();
+        assert!(path.contains(&(3, 1)) && path.contains(&(3, 3)));
+
+        *grid_map.get_by_cell_mut(3, 2).unwrap().state_mut() = GridMapCellState::Occupied;
+        assert_eq!(
+            planner.plan(&grid_map, (0, 0), (0, 4)),
+            Err(AStarError::NoPath)
+        );
+        assert_eq!(
+            planner.plan(&grid_map, (0, 2), (0, 4)),
+            Err(AStarError::InvalidStart)
+        );
+
+        // Expensive cells are avoided when a cheaper detour exists.
+        let path = planner
+            .plan_with_cost_fn(
+                3,
+                3,
+                |r, c| Some(if (r, c) == (1, 1) { 10_f64 } else { 0_f64 }),
+                (0, 1),
+                (2, 1),
+            )
+            .unwrap();
+        assert!(!path.contains(&(1, 1)));
+    }
+}
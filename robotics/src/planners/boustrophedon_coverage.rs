@@ -0,0 +1,315 @@
+use core::numerics::vector2d::Vector2D;
+use std::fmt;
+
+use map::grid::grid_map::{GridMap, GridMapCellState};
+use math::geometry::polyline2d::Polyline2D;
+
+use super::a_star_grid::{AStarGrid, Connectivity, CornerCutting};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoverageError {
+    /// The start is outside of the map or not vacant.
+    InvalidStart,
+    /// The tool width is not positive.
+    InvalidToolWidth,
+}
+
+impl fmt::Display for CoverageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            CoverageError::InvalidStart => write!(f, "start is not vacant"),
+            CoverageError::InvalidToolWidth => write!(f, "tool width must be positive"),
+        };
+    }
+}
+
+impl std::error::Error for CoverageError {}
+
+/// Region of vacant space swept column by column, made of one run of vacant rows in each of
+/// its consecutive columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverageCell {
+    pub first_column: usize,
+    /// (top row, bottom row) of the vacant run in each column, both included.
+    pub intervals: Vec<(usize, usize)>,
+    /// Indices of the cells sharing a border with this one, sorted.
+    pub neighbors: Vec<usize>,
+}
+
+impl CoverageCell {
+    pub fn last_column(&self) -> usize {
+        return self.first_column + self.intervals.len() - 1;
+    }
+}
+
+/// Coverage planner for e.g. cleaning or inspection robots. It splits the vacant space of a
+/// grid map into cells by the boustrophedon decomposition, sweeping the map from left to
+/// right, and covers each cell with back-and-forth lanes one tool width apart.
+pub struct BoustrophedonCoverage {
+    tool_width: f64,
+}
+
+impl BoustrophedonCoverage {
+    pub fn new(tool_width: f64) -> Self {
+        return Self {
+            tool_width: tool_width,
+        };
+    }
+
+    /// Split the vacant space into cells. A cell ends where the number of vacant runs in a
+    /// column changes, i.e. where the sweep line meets an obstacle or leaves one behind.
+    pub fn decompose(&self, grid_map: &GridMap) -> Vec<CoverageCell> {
+        let mut cells: Vec<CoverageCell> = Vec::new();
+        // Vacant runs of the previous column with the index of the cell they belong to.
+        let mut previous: Vec<((usize, usize), usize)> = Vec::new();
+
+        for column in 0..grid_map.horizontal_cells() {
+            let intervals = vacant_runs(grid_map, column);
+            let overlaps = |a: &(usize, usize), b: &(usize, usize)| a.0 <= b.1 && b.0 <= a.1;
+
+            let mut current: Vec<((usize, usize), usize)> = Vec::with_capacity(intervals.len());
+            for interval in intervals.iter() {
+                let touching: Vec<usize> = previous
+                    .iter()
+                    .filter(|(run, _)| overlaps(run, interval))
+                    .map(|(_, cell)| *cell)
+                    .collect();
+
+                // Continue a cell only if its run and this one touch nothing else.
+                let continued = match touching[..] {
+                    [cell] => {
+                        let (run, _) = previous.iter().find(|(_, c)| *c == cell).unwrap();
+                        let splits = intervals.iter().filter(|i| overlaps(run, i)).count();
+                        if splits == 1 {
+                            Some(cell)
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                };
+
+                let index = match continued {
+                    Some(cell) => {
+                        cells[cell].intervals.push(*interval);
+                        cell
+                    }
+                    None => {
+                        let index = cells.len();
+                        cells.push(CoverageCell {
+                            first_column: column,
+                            intervals: vec![*interval],
+                            neighbors: touching.clone(),
+                        });
+                        for cell in touching {
+                            cells[cell].neighbors.push(index);
+                        }
+                        index
+                    }
+                };
+                current.push((*interval, index));
+            }
+
+            previous = current;
+        }
+
+        for cell in cells.iter_mut() {
+            cell.neighbors.sort();
+            cell.neighbors.dedup();
+        }
+        return cells;
+    }
+
+    /// Plan a path from start through every cell reachable from it, as planar coordinates.
+    /// Cells are visited depth first from the one containing start, and connected by straight
+    /// lines or, where those would cross obstacles, by shortest grid paths.
+    pub fn plan(&self, grid_map: &GridMap, start: &Vector2D) -> Result<Polyline2D, CoverageError> {
+        if self.tool_width.is_nan() || self.tool_width <= 0_f64 {
+            return Err(CoverageError::InvalidToolWidth);
+        }
+        let start_cell = match grid_map.coordinate_to_cell(start.x, start.y) {
+            Some((r, c)) if is_vacant(grid_map, r, c) => (r, c),
+            _ => return Err(CoverageError::InvalidStart),
+        };
+
+        let cells = self.decompose(grid_map);
+        let first = cells
+            .iter()
+            .position(|cell| {
+                let column = start_cell.1;
+                if column < cell.first_column || column > cell.last_column() {
+                    return false;
+                }
+                let (top, bottom) = cell.intervals[column - cell.first_column];
+                return top <= start_cell.0 && start_cell.0 <= bottom;
+            })
+            .unwrap();
+
+        // Depth first order of the cells, visiting lower indices, i.e. cells further left,
+        // first.
+        let mut order: Vec<usize> = Vec::new();
+        let mut visited: Vec<bool> = vec![false; cells.len()];
+        let mut stack: Vec<usize> = vec![first];
+        while let Some(index) = stack.pop() {
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            order.push(index);
+            stack.extend(
+                cells[index]
+                    .neighbors
+                    .iter()
+                    .rev()
+                    .filter(|n| !visited[**n]),
+            );
+        }
+
+        let planner = AStarGrid::new(Connectivity::Eight, CornerCutting::Never);
+        let mut ret: Vec<Vector2D> = vec![*start];
+        let mut position = start_cell;
+        for index in order {
+            for lane in self.lanes(grid_map, &cells[index]) {
+                for waypoint in lane {
+                    let from = to_coordinate(grid_map, position);
+                    let to = to_coordinate(grid_map, waypoint);
+                    let route: Vec<(usize, usize)> = if grid_map.is_segment_vacant(&from, &to) {
+                        vec![waypoint]
+                    } else {
+                        // Reachable by construction, as the cells are connected to the start.
+                        let path = planner.plan(grid_map, position, waypoint).unwrap();
+                        path.into_iter().skip(1).collect()
+                    };
+                    for cell in route {
+                        let coordinate = to_coordinate(grid_map, cell);
+                        if ret.last() != Some(&coordinate) {
+                            ret.push(coordinate);
+                        }
+                    }
+                    position = waypoint;
+                }
+            }
+        }
+
+        return Ok(Polyline2D::new(ret));
+    }
+
+    /// Lanes covering a cell as (start, end) grid cells, alternating between driving up and
+    /// down. Lanes are one tool width apart and placed so that they also cover the border
+    /// columns.
+    fn lanes(&self, grid_map: &GridMap, cell: &CoverageCell) -> Vec<[(usize, usize); 2]> {
+        let step = ((self.tool_width / grid_map.cell_size()).floor() as usize).max(1);
+        let half = (step - 1) / 2;
+        let (first, last) = (cell.first_column, cell.last_column());
+
+        let mut columns: Vec<usize> = (first + half..=last).step_by(step).collect();
+        if columns.last().is_none_or(|c| c + (step - half - 1) < last) {
+            columns.push(last);
+        }
+
+        return columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let (top, bottom) = cell.intervals[column - first];
+                if i % 2 == 0 {
+                    return [(bottom, *column), (top, *column)];
+                }
+                return [(top, *column), (bottom, *column)];
+            })
+            .collect();
+    }
+}
+
+fn is_vacant(grid_map: &GridMap, row: usize, column: usize) -> bool {
+    return matches!(
+        grid_map.get_by_cell(row, column).unwrap().state(),
+        GridMapCellState::Vacant
+    );
+}
+
+fn to_coordinate(grid_map: &GridMap, cell: (usize, usize)) -> Vector2D {
+    let (x, y) = grid_map.cell_to_coordinate(cell.0, cell.1);
+    return Vector2D::from_xy(x, y);
+}
+
+/// Runs of vacant cells in a column as (top row, bottom row), both included, from the top.
+fn vacant_runs(grid_map: &GridMap, column: usize) -> Vec<(usize, usize)> {
+    let mut ret: Vec<(usize, usize)> = Vec::new();
+    let mut run_start: Option<usize> = None;
+
+    for row in 0..grid_map.vertical_cells() {
+        match (is_vacant(grid_map, row, column), run_start) {
+            (true, None) => run_start = Some(row),
+            (false, Some(top)) => {
+                ret.push((top, row - 1));
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(top) = run_start {
+        ret.push((top, grid_map.vertical_cells() - 1));
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+
+    use super::{BoustrophedonCoverage, CoverageError};
+
+    #[test]
+    fn covers_around_obstacle() {
+        // Block in the middle of a 10 x 10 map, splitting the sweep into four cells.
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        for r in 3..6 {
+            for c in 4..7 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Occupied;
+            }
+        }
+
+        let planner = BoustrophedonCoverage::new(1_f64);
+        let cells = planner.decompose(&grid_map);
+        assert_eq!(cells.len(), 4);
+        assert_eq!(cells[0].neighbors, vec![1, 2]);
+        assert_eq!(cells[3].neighbors, vec![1, 2]);
+
+        let path = planner
+            .plan(&grid_map, &Vector2D::from_xy(0.5, 0.5))
+            .unwrap();
+        let mut covered = vec![vec![false; 10]; 10];
+        for segment in path.segments() {
+            assert!(grid_map.is_segment_vacant(&segment.start, &segment.end));
+            let steps = (segment.length() * 10_f64).ceil() as usize;
+            for i in 0..=steps {
+                let t = i as f64 / steps.max(1) as f64;
+                let point = segment.start + (segment.end - segment.start) * t;
+                let (r, c) = grid_map.coordinate_to_cell(point.x, point.y).unwrap();
+                covered[r][c] = true;
+            }
+        }
+        for r in 0..10 {
+            for c in 0..10 {
+                let vacant = !(3..6).contains(&r) || !(4..7).contains(&c);
+                assert_eq!(covered[r][c], vacant, "({}, {})", r, c);
+            }
+        }
+
+        // Wider tools need fewer lanes.
+        let wide = BoustrophedonCoverage::new(3_f64)
+            .plan(&grid_map, &Vector2D::from_xy(0.5, 0.5))
+            .unwrap();
+        assert!(wide.length() < path.length());
+        assert!((wide.points[0] - Vector2D::from_xy(0.5, 0.5)).magnitude() < 1e-12);
+
+        assert_eq!(
+            planner.plan(&grid_map, &Vector2D::from_xy(5.5, 5.5)),
+            Err(CoverageError::InvalidStart)
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use super::a_star_grid::{AStarError, AStarGrid, Connectivity, CornerCutting};
+
+/// Queue entry, ordered so that the binary heap pops the lowest key.
+/// Keys are compared lexicographically and exactly.
+struct QueueEntry {
+    key: (f64, f64),
+    index: usize,
+}
+
+impl PartialEq for QueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for QueueEntry {}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other
+            .key
+            .0
+            .total_cmp(&self.key.0)
+            .then(other.key.1.total_cmp(&self.key.1))
+            .then(other.index.cmp(&self.index));
+    }
+}
+
+/// Relative tolerance below which key components count as equal. Costs summed along
+/// different paths differ in the last bits, which must not end the search early.
+const KEY_TOLERANCE: f64 = 1e-9;
+
+/// Compare keys lexicographically, treating components within `KEY_TOLERANCE` as equal.
+fn compare_keys(a: &(f64, f64), b: &(f64, f64)) -> Ordering {
+    return compare_key_components(a.0, b.0).then(compare_key_components(a.1, b.1));
+}
+
+fn compare_key_components(a: f64, b: f64) -> Ordering {
+    if a == b || (a - b).abs() <= KEY_TOLERANCE * a.abs().max(b.abs()).max(1_f64) {
+        return Ordering::Equal;
+    }
+
+    return a.total_cmp(&b);
+}
+
+/// Incremental planner over a grid, searching backwards from the goal so that moving the
+/// start and changing cell costs only repairs the affected part of the previous search.
+/// Cells are given as (row, column) and cost the same as for `AStarGrid`.
+pub struct DStarLite {
+    rules: AStarGrid,
+    rows: usize,
+    columns: usize,
+    costs: Vec<Option<f64>>,
+    start: (usize, usize),
+    goal: (usize, usize),
+
+    /// Cost to the goal from the last search.
+    g: Vec<f64>,
+    /// One-step lookahead of g.
+    rhs: Vec<f64>,
+    queue: BinaryHeap<QueueEntry>,
+
+    /// Accumulated heuristic offset, keeping queued keys valid as the start moves.
+    key_modifier: f64,
+}
+
+impl DStarLite {
+    /// cost_fn gives the non-negative cost of entering a cell, or `None` if it is not
+    /// traversable, and is evaluated once per cell.
+    pub fn new<F>(
+        connectivity: Connectivity,
+        corner_cutting: CornerCutting,
+        rows: usize,
+        columns: usize,
+        cost_fn: F,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Self
+    where
+        F: Fn(usize, usize) -> Option<f64>,
+    {
+        let mut costs: Vec<Option<f64>> = Vec::with_capacity(rows * columns);
+        for r in 0..rows {
+            for c in 0..columns {
+                costs.push(cost_fn(r, c));
+            }
+        }
+
+        let mut ret = Self {
+            rules: AStarGrid::new(connectivity, corner_cutting),
+            rows: rows,
+            columns: columns,
+            costs: costs,
+            start: start,
+            goal: goal,
+            g: vec![f64::INFINITY; rows * columns],
+            rhs: vec![f64::INFINITY; rows * columns],
+            queue: BinaryHeap::new(),
+            key_modifier: 0_f64,
+        };
+
+        if goal.0 < rows && goal.1 < columns {
+            let goal_index = ret.index(goal);
+            ret.rhs[goal_index] = 0_f64;
+            ret.queue.push(QueueEntry {
+                key: ret.key(goal_index),
+                index: goal_index,
+            });
+        }
+
+        return ret;
+    }
+
+    /// Plan from the current start to the goal, reusing the previous search.
+    /// Returns the cells from start to goal, both included.
+    pub fn plan(&mut self) -> Result<Vec<(usize, usize)>, AStarError> {
+        if !self.is_traversable(self.start) {
+            return Err(AStarError::InvalidStart);
+        }
+        if !self.is_traversable(self.goal) {
+            return Err(AStarError::InvalidGoal);
+        }
+
+        self.compute_shortest_path();
+
+        let mut current = self.start;
+        let mut ret: Vec<(usize, usize)> = vec![current];
+        while current != self.goal {
+            if self.g[self.index(current)].is_infinite() || ret.len() > self.rows * self.columns {
+                return Err(AStarError::NoPath);
+            }
+
+            let next = self
+                .successors(current)
+                .into_iter()
+                .map(|(cell, cost)| (cell, cost + self.g[self.index(cell)]))
+                .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            current = match next {
+                Some((cell, cost)) if cost.is_finite() => cell,
+                _ => return Err(AStarError::NoPath),
+            };
+            ret.push(current);
+        }
+
+        return Ok(ret);
+    }
+
+    /// Move the start, e.g. after the robot followed part of the path.
+    pub fn set_start(&mut self, start: (usize, usize)) {
+        self.key_modifier += self.rules.heuristic(self.start, start);
+        self.start = start;
+    }
+
+    /// Re-read the costs of the changed cells, e.g. the dirty region of a map update.
+    pub fn update_cells<F>(&mut self, cells: &[(usize, usize)], cost_fn: F)
+    where
+        F: Fn(usize, usize) -> Option<f64>,
+    {
+        let mut affected: Vec<(usize, usize)> = Vec::new();
+        for cell in cells {
+            if cell.0 >= self.rows || cell.1 >= self.columns {
+                continue;
+            }
+
+            let index = self.index(*cell);
+            self.costs[index] = cost_fn(cell.0, cell.1);
+
+            // Diagonal moves past a cell depend on it as well, so update the whole neighborhood.
+            for r in cell.0.saturating_sub(1)..=(cell.0 + 1).min(self.rows - 1) {
+                for c in cell.1.saturating_sub(1)..=(cell.1 + 1).min(self.columns - 1) {
+                    affected.push((r, c));
+                }
+            }
+        }
+
+        affected.sort();
+        affected.dedup();
+        for cell in affected {
+            self.update_vertex(cell);
+        }
+    }
+
+    fn compute_shortest_path(&mut self) {
+        let start_index = self.index(self.start);
+
+        while let Some(top) = self.queue.peek() {
+            // Keys tied with the start's within rounding are expanded as well, as their cells
+            // may still lower the cost of the start.
+            if compare_keys(&top.key, &self.key(start_index)) == Ordering::Greater
+                && self.g[start_index] == self.rhs[start_index]
+            {
+                break;
+            }
+
+            let entry = self.queue.pop().unwrap();
+            let u = entry.index;
+            if self.g[u] == self.rhs[u] {
+                continue;
+            }
+
+            // Stale entries: a raised key is requeued, while for a lowered key the up to date
+            // entry has been queued already.
+            let key = self.key(u);
+            match compare_keys(&entry.key, &key) {
+                Ordering::Less => {
+                    self.queue.push(QueueEntry { key: key, index: u });
+                    continue;
+                }
+                Ordering::Greater => continue,
+                Ordering::Equal => {}
+            }
+
+            let cell = (u / self.columns, u % self.columns);
+            if self.g[u] > self.rhs[u] {
+                self.g[u] = self.rhs[u];
+            } else {
+                self.g[u] = f64::INFINITY;
+                self.update_vertex(cell);
+            }
+
+            for (predecessor, _) in self.successors(cell) {
+                self.update_vertex(predecessor);
+            }
+        }
+    }
+
+    fn update_vertex(&mut self, cell: (usize, usize)) {
+        let index = self.index(cell);
+        if cell != self.goal {
+            self.rhs[index] = if self.is_traversable(cell) {
+                self.successors(cell)
+                    .into_iter()
+                    .map(|(successor, cost)| cost + self.g[self.index(successor)])
+                    .fold(f64::INFINITY, f64::min)
+            } else {
+                f64::INFINITY
+            };
+        }
+
+        if self.g[index] != self.rhs[index] {
+            self.queue.push(QueueEntry {
+                key: self.key(index),
+                index: index,
+            });
+        }
+    }
+
+    fn key(&self, index: usize) -> (f64, f64) {
+        let cell = (index / self.columns, index % self.columns);
+        let k = self.g[index].min(self.rhs[index]);
+        return (
+            k + self.rules.heuristic(self.start, cell) + self.key_modifier,
+            k,
+        );
+    }
+
+    /// Moves between neighbors are allowed in both directions, so these are the predecessors
+    /// as well.
+    fn successors(&self, cell: (usize, usize)) -> Vec<((usize, usize), f64)> {
+        let costs = &self.costs;
+        let columns = self.columns;
+        return self.rules.moves(
+            self.rows,
+            self.columns,
+            &|r: usize, c: usize| costs[r * columns + c],
+            cell,
+        );
+    }
+
+    fn is_traversable(&self, cell: (usize, usize)) -> bool {
+        return cell.0 < self.rows
+            && cell.1 < self.columns
+            && self.costs[self.index(cell)].is_some();
+    }
+
+    fn index(&self, cell: (usize, usize)) -> usize {
+        return cell.0 * self.columns + cell.1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::SQRT_2;
+
+    use crate::planners::a_star_grid::{AStarError, AStarGrid, Connectivity, CornerCutting};
+
+    use super::DStarLite;
+
+    /// Cost of a path of cells which all cost nothing to enter.
+    fn path_length(path: &[(usize, usize)]) -> f64 {
+        return path
+            .windows(2)
+            .map(|w| {
+                if w[0].0 != w[1].0 && w[0].1 != w[1].1 {
+                    SQRT_2
+                } else {
+                    1_f64
+                }
+            })
+            .sum();
+    }
+
+    #[test]
+    fn replan_after_changes() {
+        let mut blocked: Vec<(usize, usize)> = vec![(1, 1), (2, 1), (3, 1)];
+        let cost_fn = |blocked: &Vec<(usize, usize)>, r: usize, c: usize| {
+            return if blocked.contains(&(r, c)) {
+                None
+            } else {
+                Some(0_f64)
+            };
+        };
+
+        let mut planner = DStarLite::new(
+            Connectivity::Eight,
+            CornerCutting::Never,
+            6,
+            6,
+            |r, c| cost_fn(&blocked, r, c),
+            (2, 0),
+            (2, 5),
+        );
+        let a_star = AStarGrid::new(Connectivity::Eight, CornerCutting::Never);
+        let expected = a_star
+            .plan_with_cost_fn(6, 6, |r, c| cost_fn(&blocked, r, c), (2, 0), (2, 5))
+            .unwrap();
+        assert_eq!(planner.plan().unwrap().len(), expected.len());
+
+        // Close the gaps around the wall, leaving only the bottom row open.
+        let changed = vec![(0, 1), (4, 1)];
+        blocked.extend(changed.iter());
+        planner.update_cells(&changed, |r, c| cost_fn(&blocked, r, c));
+        let path = planner.plan().unwrap();
+        assert!(path.contains(&(5, 1)));
+
+        planner.set_start(path[2]);
+        let expected = a_star
+            .plan_with_cost_fn(6, 6, |r, c| cost_fn(&blocked, r, c), path[2], (2, 5))
+            .unwrap();
+        assert_eq!(planner.plan().unwrap().len(), expected.len());
+
+        blocked.push((5, 1));
+        planner.update_cells(&[(5, 1)], |r, c| cost_fn(&blocked, r, c));
+        planner.set_start((2, 0));
+        assert_eq!(planner.plan(), Err(AStarError::NoPath));
+    }
+
+    #[test]
+    fn replan_with_keys_differing_by_rounding() {
+        let cost_fn = |blocked: &[(usize, usize)], r: usize, c: usize| {
+            return if blocked.contains(&(r, c)) {
+                None
+            } else {
+                Some(0_f64)
+            };
+        };
+
+        let blocked = [(0, 0), (3, 2), (3, 3)];
+        let mut planner = DStarLite::new(
+            Connectivity::Eight,
+            CornerCutting::Never,
+            5,
+            4,
+            |r, c| cost_fn(&blocked, r, c),
+            (0, 3),
+            (4, 0),
+        );
+        planner.plan().unwrap();
+
+        let blocked = [(0, 0), (3, 1), (3, 3)];
+        planner.update_cells(&[(3, 1), (3, 2)], |r, c| cost_fn(&blocked, r, c));
+        let expected = AStarGrid::new(Connectivity::Eight, CornerCutting::Never)
+            .plan_with_cost_fn(5, 4, |r, c| cost_fn(&blocked, r, c), (0, 3), (4, 0))
+            .unwrap();
+        assert_eq!(
+            path_length(&planner.plan().unwrap()),
+            path_length(&expected)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn matches_a_star_after_random_updates() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let a_star = AStarGrid::new(Connectivity::Eight, CornerCutting::Never);
+
+        for _ in 0..3000 {
+            let (rows, columns) = (rng.gen_range(3..9), rng.gen_range(3..9));
+            let start = (rng.gen_range(0..rows), rng.gen_range(0..columns));
+            let goal = (rng.gen_range(0..rows), rng.gen_range(0..columns));
+            let mut blocked: Vec<bool> = (0..rows * columns).map(|_| rng.gen_bool(0.25)).collect();
+            blocked[start.0 * columns + start.1] = false;
+            blocked[goal.0 * columns + goal.1] = false;
+
+            let before = blocked.clone();
+            let mut planner = DStarLite::new(
+                Connectivity::Eight,
+                CornerCutting::Never,
+                rows,
+                columns,
+                |r, c| (!before[r * columns + c]).then_some(0_f64),
+                start,
+                goal,
+            );
+            let _ = planner.plan();
+
+            let mut changed: Vec<(usize, usize)> = Vec::new();
+            for _ in 0..rng.gen_range(1..5) {
+                let cell = (rng.gen_range(0..rows), rng.gen_range(0..columns));
+                if cell != start && cell != goal {
+                    blocked[cell.0 * columns + cell.1] = !blocked[cell.0 * columns + cell.1];
+                    changed.push(cell);
+                }
+            }
+            let cost_fn = |r: usize, c: usize| (!blocked[r * columns + c]).then_some(0_f64);
+            planner.update_cells(&changed, cost_fn);
+
+            let expected = a_star.plan_with_cost_fn(rows, columns, cost_fn, start, goal);
+            match (planner.plan(), expected) {
+                (Ok(path), Ok(expected)) => {
+                    assert!((path_length(&path) - path_length(&expected)).abs() < 1e-9)
+                }
+                (actual, expected) => assert_eq!(actual.err(), expected.err()),
+            };
+        }
+    }
+}
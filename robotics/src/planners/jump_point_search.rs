@@ -0,0 +1,300 @@
+use std::{collections::BinaryHeap, f64::consts::SQRT_2};
+
+use map::grid::grid_map::{GridMap, GridMapCellState};
+
+use super::a_star_grid::{AStarError, OpenEntry};
+
+/// Jump Point Search over the cells of a uniform-cost grid, with cells given as (row, column).
+/// Moves are 8-connected without cutting corners, and paths are as short as those of
+/// `AStarGrid` with `Connectivity::Eight` and `CornerCutting::Never`, but far fewer cells
+/// are expanded on large open maps.
+pub struct JumpPointSearch;
+
+impl JumpPointSearch {
+    /// Plan a shortest path between vacant cells of the grid map.
+    /// Returns the cells from start to goal, both included.
+    pub fn plan(
+        grid_map: &GridMap,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<Vec<(usize, usize)>, AStarError> {
+        return JumpPointSearch::plan_with_fn(
+            grid_map.vertical_cells(),
+            grid_map.horizontal_cells(),
+            |r, c| *grid_map.get_by_cell(r, c).unwrap().state() == GridMapCellState::Vacant,
+            start,
+            goal,
+        );
+    }
+
+    /// Plan a shortest path over a rows x columns grid whose traversable cells are given by
+    /// traversable_fn. Returns the cells from start to goal, both included.
+    pub fn plan_with_fn<F>(
+        rows: usize,
+        columns: usize,
+        traversable_fn: F,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<Vec<(usize, usize)>, AStarError>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let grid = Grid {
+            rows: rows as isize,
+            columns: columns as isize,
+            traversable_fn: traversable_fn,
+        };
+        let start = (start.0 as isize, start.1 as isize);
+        let goal = (goal.0 as isize, goal.1 as isize);
+        if !grid.free(start) {
+            return Err(AStarError::InvalidStart);
+        }
+        if !grid.free(goal) {
+            return Err(AStarError::InvalidGoal);
+        }
+
+        let index = |cell: (isize, isize)| -> usize { (cell.0 * grid.columns + cell.1) as usize };
+        let cell_of = |index: usize| -> (isize, isize) {
+            return (index as isize / grid.columns, index as isize % grid.columns);
+        };
+        let mut costs: Vec<f64> = vec![f64::INFINITY; rows * columns];
+        let mut parents: Vec<Option<usize>> = vec![None; rows * columns];
+        let mut closed: Vec<bool> = vec![false; rows * columns];
+        let mut open: BinaryHeap<OpenEntry> = BinaryHeap::new();
+
+        costs[index(start)] = 0_f64;
+        open.push(OpenEntry {
+            estimate: octile_distance(start, goal),
+            remaining: octile_distance(start, goal),
+            index: index(start),
+        });
+
+        while let Some(entry) = open.pop() {
+            if closed[entry.index] {
+                continue;
+            }
+            closed[entry.index] = true;
+
+            let cell = cell_of(entry.index);
+            if cell == goal {
+                let mut jump_points: Vec<(isize, isize)> = vec![goal];
+                let mut current = entry.index;
+                while let Some(parent) = parents[current] {
+                    jump_points.push(cell_of(parent));
+                    current = parent;
+                }
+                jump_points.reverse();
+                return Ok(expand(&jump_points));
+            }
+
+            let parent = parents[entry.index].map(cell_of);
+            for direction in grid.directions(cell, parent) {
+                let jump_point = match grid.jump(cell, direction, goal) {
+                    Some(jump_point) => jump_point,
+                    None => continue,
+                };
+
+                let jump_index = index(jump_point);
+                let cost = costs[entry.index] + octile_distance(cell, jump_point);
+                if closed[jump_index] || cost >= costs[jump_index] {
+                    continue;
+                }
+
+                costs[jump_index] = cost;
+                parents[jump_index] = Some(entry.index);
+                let remaining = octile_distance(jump_point, goal);
+                open.push(OpenEntry {
+                    estimate: cost + remaining,
+                    remaining: remaining,
+                    index: jump_index,
+                });
+            }
+        }
+
+        return Err(AStarError::NoPath);
+    }
+}
+
+struct Grid<F> {
+    rows: isize,
+    columns: isize,
+    traversable_fn: F,
+}
+
+impl<F> Grid<F>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    fn free(&self, cell: (isize, isize)) -> bool {
+        return cell.0 >= 0
+            && cell.1 >= 0
+            && cell.0 < self.rows
+            && cell.1 < self.columns
+            && (self.traversable_fn)(cell.0 as usize, cell.1 as usize);
+    }
+
+    /// Whether the move from cell by direction stays on free cells without cutting a corner.
+    fn can_move(&self, cell: (isize, isize), direction: (isize, isize)) -> bool {
+        let (r, c) = cell;
+        let (dr, dc) = direction;
+        if !self.free((r + dr, c + dc)) {
+            return false;
+        }
+
+        return dr == 0 || dc == 0 || (self.free((r + dr, c)) && self.free((r, c + dc)));
+    }
+
+    /// Directions worth searching from cell when it was reached from parent.
+    /// Every direction is searched from the start.
+    fn directions(
+        &self,
+        cell: (isize, isize),
+        parent: Option<(isize, isize)>,
+    ) -> Vec<(isize, isize)> {
+        let (r, c) = cell;
+        let (dr, dc) = match parent {
+            Some((pr, pc)) => ((r - pr).signum(), (c - pc).signum()),
+            None => {
+                let mut ret: Vec<(isize, isize)> = Vec::new();
+                for dr in -1..=1 {
+                    for dc in -1..=1 {
+                        if (dr, dc) != (0, 0) && self.can_move(cell, (dr, dc)) {
+                            ret.push((dr, dc));
+                        }
+                    }
+                }
+                return ret;
+            }
+        };
+
+        let candidates: Vec<(isize, isize)> = if dr != 0 && dc != 0 {
+            vec![(dr, 0), (0, dc), (dr, dc)]
+        } else if dr != 0 {
+            // Sideways moves are needed where an obstacle behind blocked the diagonal.
+            vec![(dr, 0), (dr, 1), (dr, -1), (0, 1), (0, -1)]
+        } else {
+            vec![(0, dc), (1, dc), (-1, dc), (1, 0), (-1, 0)]
+        };
+
+        return candidates
+            .into_iter()
+            .filter(|direction| self.can_move(cell, *direction))
+            .collect();
+    }
+
+    /// Follow direction from cell up to the next cell where the search has to branch,
+    /// i.e. the goal or a cell with a forced neighbor.
+    fn jump(
+        &self,
+        cell: (isize, isize),
+        direction: (isize, isize),
+        goal: (isize, isize),
+    ) -> Option<(isize, isize)> {
+        let (dr, dc) = direction;
+        let mut current = cell;
+
+        loop {
+            if !self.can_move(current, direction) {
+                return None;
+            }
+            current = (current.0 + dr, current.1 + dc);
+            if current == goal {
+                return Some(current);
+            }
+
+            let (r, c) = current;
+            if dr != 0 && dc != 0 {
+                if self.jump(current, (dr, 0), goal).is_some()
+                    || self.jump(current, (0, dc), goal).is_some()
+                {
+                    return Some(current);
+                }
+            } else if dr != 0 {
+                // A neighbor beside is forced if the cell beside the previous one is blocked.
+                if (self.free((r, c + 1)) && !self.free((r - dr, c + 1)))
+                    || (self.free((r, c - 1)) && !self.free((r - dr, c - 1)))
+                {
+                    return Some(current);
+                }
+            } else if (self.free((r + 1, c)) && !self.free((r + 1, c - dc)))
+                || (self.free((r - 1, c)) && !self.free((r - 1, c - dc)))
+            {
+                return Some(current);
+            }
+        }
+    }
+}
+
+/// Length of the shortest 8-connected path without obstacles.
+fn octile_distance(from: (isize, isize), to: (isize, isize)) -> f64 {
+    let dr = from.0.abs_diff(to.0) as f64;
+    let dc = from.1.abs_diff(to.1) as f64;
+    return dr.max(dc) + (SQRT_2 - 1_f64) * dr.min(dc);
+}
+
+/// Fill in the cells between consecutive jump points, which lie on straight or diagonal lines.
+fn expand(jump_points: &[(isize, isize)]) -> Vec<(usize, usize)> {
+    let mut ret: Vec<(usize, usize)> = vec![(jump_points[0].0 as usize, jump_points[0].1 as usize)];
+    for pair in jump_points.windows(2) {
+        let (mut current, to) = (pair[0], pair[1]);
+        let direction = ((to.0 - current.0).signum(), (to.1 - current.1).signum());
+        while current != to {
+            current = (current.0 + direction.0, current.1 + direction.1);
+            ret.push((current.0 as usize, current.1 as usize));
+        }
+    }
+
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::SQRT_2;
+
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+
+    use crate::planners::a_star_grid::{AStarError, AStarGrid, Connectivity, CornerCutting};
+
+    use super::JumpPointSearch;
+
+    fn path_length(path: &[(usize, usize)]) -> f64 {
+        return path
+            .windows(2)
+            .map(|pair| {
+                let dr = pair[0].0.abs_diff(pair[1].0);
+                let dc = pair[0].1.abs_diff(pair[1].1);
+                assert!(dr <= 1 && dc <= 1);
+                return if dr + dc == 2 { SQRT_2 } else { 1_f64 };
+            })
+            .sum();
+    }
+
+    #[test]
+    fn matches_a_star() {
+        let mut grid_map = GridMap::with_cell_state(12, 10, 1_f64, GridMapCellState::Vacant);
+        let walls = [(1, 2..9), (4, 0..6), (4, 7..12), (7, 3..11)];
+        for (r, columns) in walls.iter() {
+            for c in columns.clone() {
+                *grid_map.get_by_cell_mut(*r, c).unwrap().state_mut() = GridMapCellState::Occupied;
+            }
+        }
+
+        let a_star = AStarGrid::new(Connectivity::Eight, CornerCutting::Never);
+        for (start, goal) in [((0, 0), (9, 11)), ((9, 0), (0, 11)), ((5, 5), (2, 3))] {
+            let expected = a_star.plan(&grid_map, start, goal).unwrap();
+            let path = JumpPointSearch::plan(&grid_map, start, goal).unwrap();
+            assert_eq!(path[0], start);
+            assert_eq!(*path.last().unwrap(), goal);
+            assert!(path
+                .iter()
+                .all(|(r, c)| *grid_map.get_by_cell(*r, *c).unwrap().state()
+                    == GridMapCellState::Vacant));
+            assert!((path_length(&path) - path_length(&expected)).abs() < 1e-9);
+        }
+
+        assert_eq!(
+            JumpPointSearch::plan(&grid_map, (0, 0), (4, 0)),
+            Err(AStarError::InvalidGoal)
+        );
+    }
+}
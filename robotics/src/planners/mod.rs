@@ -0,0 +1,10 @@
+pub mod a_star_grid;
+pub mod boustrophedon_coverage;
+pub mod d_star_lite;
+pub mod jump_point_search;
+pub mod path_smoothing;
+#[cfg(feature = "rand")]
+pub mod prm;
+#[cfg(feature = "rand")]
+pub mod rrt;
+pub mod spanning_tree_coverage;
@@ -0,0 +1,219 @@
+use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+use map::grid::grid_map::GridMap;
+use math::geometry::polyline2d::Polyline2D;
+
+/// Parameters of `PathSmoother`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathSmoothingOptions {
+    /// Pull of each point towards its position on the unsmoothed path.
+    pub data_weight: f64,
+
+    /// Pull of each point towards the midpoint of its neighbors.
+    pub smooth_weight: f64,
+
+    /// Upper bound on the gradient steps.
+    pub max_iterations: usize,
+
+    /// Smoothing stops once no point moves further than this in a step.
+    pub tolerance: f64,
+
+    /// Spacing of the points the shortcut path is resampled to before smoothing, so that its
+    /// corners can be rounded. `None` uses two cells of the grid map.
+    pub resample_spacing: Option<f64>,
+}
+
+impl Default for PathSmoothingOptions {
+    fn default() -> Self {
+        return Self {
+            data_weight: 0.1,
+            smooth_weight: 0.3,
+            max_iterations: 500,
+            tolerance: 1e-6,
+            resample_spacing: None,
+        };
+    }
+}
+
+/// Post-processor turning jagged paths, e.g. of grid planners or through a topology map, into
+/// drivable ones. Every change is checked against the vacant cells of the grid map, so a
+/// collision-free path stays collision-free.
+pub struct PathSmoother {
+    options: PathSmoothingOptions,
+}
+
+impl PathSmoother {
+    pub fn new(options: PathSmoothingOptions) -> Self {
+        return Self { options: options };
+    }
+
+    /// Shortcut the path, then resample and smooth it.
+    pub fn optimize(&self, grid_map: &GridMap, path: &Polyline2D) -> Polyline2D {
+        let spacing = self
+            .options
+            .resample_spacing
+            .unwrap_or(2_f64 * grid_map.cell_size());
+        let shortcut = self.shortcut(grid_map, path);
+        return self.smooth(grid_map, &resample_segments(&shortcut, spacing));
+    }
+
+    /// Skip waypoints wherever a straight line to a later one is vacant, going greedily to
+    /// the furthest such waypoint. The ends are kept.
+    pub fn shortcut(&self, grid_map: &GridMap, path: &Polyline2D) -> Polyline2D {
+        let points = &path.points;
+        if points.len() < 3 {
+            return path.clone();
+        }
+
+        let mut ret: Vec<Vector2D> = vec![points[0]];
+        let mut i = 0;
+        while i < points.len() - 1 {
+            let next = (i + 2..points.len())
+                .rev()
+                .find(|j| grid_map.is_segment_vacant(&points[i], &points[*j]))
+                .unwrap_or(i + 1);
+            ret.push(points[next]);
+            i = next;
+        }
+
+        return Polyline2D::new(ret);
+    }
+
+    /// Gradient descent on the distance of each point to its unsmoothed position and to the
+    /// midpoint of its neighbors. The ends are kept, and a point only moves if the segments to
+    /// its neighbors stay vacant.
+    pub fn smooth(&self, grid_map: &GridMap, path: &Polyline2D) -> Polyline2D {
+        let original = &path.points;
+        let mut points = original.clone();
+        if points.len() < 3 {
+            return path.clone();
+        }
+
+        for _ in 0..self.options.max_iterations {
+            let mut max_change = 0_f64;
+            for i in 1..points.len() - 1 {
+                let step = (original[i] - points[i]) * self.options.data_weight
+                    + (points[i - 1] + points[i + 1] - points[i] * 2_f64)
+                        * self.options.smooth_weight;
+                let candidate = points[i] + step;
+                if grid_map.is_segment_vacant(&points[i - 1], &candidate)
+                    && grid_map.is_segment_vacant(&candidate, &points[i + 1])
+                {
+                    max_change = max_change.max(step.magnitude());
+                    points[i] = candidate;
+                }
+            }
+
+            if max_change < self.options.tolerance {
+                break;
+            }
+        }
+
+        return Polyline2D::new(points);
+    }
+}
+
+/// Resample every segment on its own, so that the vertices are kept and the resampled path
+/// covers no other cells than the original one.
+fn resample_segments(path: &Polyline2D, spacing: f64) -> Polyline2D {
+    let mut points: Vec<Vector2D> = path.points.iter().take(1).copied().collect();
+    for segment in path.points.windows(2) {
+        let resampled = Polyline2D::new(segment.to_vec()).resample(spacing);
+        points.extend(resampled.points.iter().skip(1));
+    }
+
+    return Polyline2D::new(points);
+}
+
+/// Path through the centers of grid cells given as (row, column), e.g. from `AStarGrid`.
+pub fn cells_to_polyline(grid_map: &GridMap, cells: &[(usize, usize)]) -> Polyline2D {
+    return Polyline2D::new(
+        cells
+            .iter()
+            .map(|(r, c)| {
+                let (x, y) = grid_map.cell_to_coordinate(*r, *c);
+                return Vector2D::from_xy(x, y);
+            })
+            .collect(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+    use math::geometry::polyline2d::Polyline2D;
+
+    use super::{cells_to_polyline, PathSmoother, PathSmoothingOptions};
+    use crate::planners::a_star_grid::{AStarGrid, Connectivity, CornerCutting};
+
+    #[test]
+    fn shortcuts_and_smooths_grid_path() {
+        // Wall in column 5 with a gap at the bottom.
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        for r in 0..7 {
+            *grid_map.get_by_cell_mut(r, 5).unwrap().state_mut() = GridMapCellState::Occupied;
+        }
+        let cells = AStarGrid::new(Connectivity::Four, CornerCutting::Never)
+            .plan(&grid_map, (0, 0), (0, 9))
+            .unwrap();
+        let path = cells_to_polyline(&grid_map, &cells);
+
+        let smoother = PathSmoother::new(PathSmoothingOptions::default());
+        let shortcut = smoother.shortcut(&grid_map, &path);
+        assert!(shortcut.points.len() < 6);
+        assert!(shortcut.length() < path.length());
+        assert_eq!(shortcut.points.first(), path.points.first());
+        assert_eq!(shortcut.points.last(), path.points.last());
+
+        let optimized = smoother.optimize(&grid_map, &path);
+        assert_eq!(optimized.points.first(), path.points.first());
+        assert_eq!(optimized.points.last(), path.points.last());
+        for segment in optimized.segments() {
+            assert!(grid_map.is_segment_vacant(&segment.start, &segment.end));
+        }
+
+        // Smoothing rounds corners in open space, so consecutive segments turn less.
+        let open = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        let corner = Polyline2D::new(vec![
+            Vector2D::from_xy(1_f64, 1_f64),
+            Vector2D::from_xy(8_f64, 1_f64),
+            Vector2D::from_xy(8_f64, 8_f64),
+        ])
+        .resample(1_f64);
+        let smoothed = smoother.smooth(&open, &corner);
+        let max_turn = |path: &Polyline2D| -> f64 {
+            return path
+                .points
+                .windows(3)
+                .map(|w| (w[1] - w[0]).angle_to(&(w[2] - w[1])).abs())
+                .fold(0_f64, f64::max);
+        };
+        assert!(max_turn(&smoothed) < max_turn(&corner) / 2_f64);
+    }
+
+    #[test]
+    fn optimize_keeps_tight_corners_vacant() {
+        // Block in the top left corner, with the path turning right around its corner.
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        for r in 0..6 {
+            for c in 0..5 {
+                *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Occupied;
+            }
+        }
+        let path = Polyline2D::new(vec![
+            Vector2D::from_xy(0.5, 3.5),
+            Vector2D::from_xy(5.5, 3.5),
+            Vector2D::from_xy(5.5, 9.5),
+        ]);
+
+        let optimized =
+            PathSmoother::new(PathSmoothingOptions::default()).optimize(&grid_map, &path);
+        assert_eq!(optimized.points.first(), path.points.first());
+        assert_eq!(optimized.points.last(), path.points.last());
+        for segment in optimized.segments() {
+            assert!(grid_map.is_segment_vacant(&segment.start, &segment.end));
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use core::{
+    collections::kd_tree::KdTree,
+    graph::{graph::Graph, node::NodeId},
+    numerics::{vector::Vector, vector2d::Vector2D},
+};
+
+use map::{
+    grid::grid_map::GridMap,
+    topology::{
+        topology_edge::TopologyEdge,
+        topology_node::{TopologyNode, TopologyNodeType},
+    },
+};
+use math::{geometry::aabb2d::AABB2D, sampling::sample_in_rectangle};
+use rand::Rng;
+
+type TopologyMap = Graph<TopologyNode, TopologyEdge>;
+
+/// Number of candidates drawn per requested sample before giving up, e.g. on a full map.
+const MAX_ATTEMPTS_PER_SAMPLE: usize = 100;
+
+/// Parameters of `Prm`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrmOptions {
+    /// Number of roadmap nodes sampled in the vacant space.
+    pub sample_count: usize,
+
+    /// Number of nearest nodes each node tries to connect to.
+    pub neighbor_count: usize,
+
+    /// Longest edge of the roadmap.
+    pub max_connection_distance: f64,
+}
+
+impl Default for PrmOptions {
+    fn default() -> Self {
+        return Self {
+            sample_count: 500,
+            neighbor_count: 10,
+            max_connection_distance: f64::INFINITY,
+        };
+    }
+}
+
+/// Probabilistic roadmap over the vacant space of a grid map, in its planar coordinates.
+/// The roadmap is a topology map of waypoints joined by straight edges through vacant cells,
+/// so it can be planned on and combined like an extracted topology.
+pub struct Prm {
+    options: PrmOptions,
+}
+
+impl Prm {
+    pub fn new(options: PrmOptions) -> Self {
+        return Self { options: options };
+    }
+
+    /// Sample the roadmap, drawing from rng only, so a seeded generator reproduces it.
+    pub fn build_roadmap<R: Rng>(&self, rng: &mut R, grid_map: &GridMap) -> TopologyMap {
+        let bounds = AABB2D::new(
+            Vector2D::zero(),
+            Vector2D::from_xy(
+                grid_map.horizontal_cells() as f64 * grid_map.cell_size(),
+                grid_map.vertical_cells() as f64 * grid_map.cell_size(),
+            ),
+        );
+
+        let mut roadmap = TopologyMap::new(false, false);
+        let mut nodes: Vec<(NodeId, Vector2D)> = Vec::new();
+        for _ in 0..(self.options.sample_count * MAX_ATTEMPTS_PER_SAMPLE) {
+            if nodes.len() == self.options.sample_count {
+                break;
+            }
+
+            let sample = sample_in_rectangle(rng, &bounds);
+            if grid_map.is_segment_vacant(&sample, &sample) {
+                let node_id =
+                    roadmap.add_node(TopologyNode::new(TopologyNodeType::Waypoint, sample));
+                nodes.push((node_id, sample));
+            }
+        }
+
+        let index = KdTree::from_points(nodes.iter().map(|(id, p)| (*p, *id)).collect());
+        for (node_id, position) in nodes.iter() {
+            self.connect_to_nearest(&mut roadmap, grid_map, &index, *node_id, position);
+        }
+
+        return roadmap;
+    }
+
+    /// Add a position, e.g. a start or goal, to the roadmap and connect it like a sampled
+    /// node. Returns `None` if the position is not vacant or no roadmap node is reachable
+    /// from it in a straight line.
+    pub fn connect(
+        &self,
+        roadmap: &mut TopologyMap,
+        grid_map: &GridMap,
+        position: Vector2D,
+    ) -> Option<NodeId> {
+        if !grid_map.is_segment_vacant(&position, &position) {
+            return None;
+        }
+
+        let mut nodes: Vec<(Vector2D, NodeId)> = roadmap
+            .get_nodes()
+            .iter()
+            .map(|(id, node)| (node.node_info().position, *id))
+            .collect();
+        nodes.sort_by_key(|(_, id)| *id);
+        let index = KdTree::from_points(nodes);
+
+        let node_id = roadmap.add_node(TopologyNode::new(TopologyNodeType::Waypoint, position));
+        self.connect_to_nearest(roadmap, grid_map, &index, node_id, &position);
+        if roadmap.degree(&node_id) == Some(0) {
+            roadmap.remove_node(&node_id).unwrap();
+            return None;
+        }
+
+        return Some(node_id);
+    }
+
+    fn connect_to_nearest(
+        &self,
+        roadmap: &mut TopologyMap,
+        grid_map: &GridMap,
+        index: &KdTree<NodeId>,
+        node_id: NodeId,
+        position: &Vector2D,
+    ) {
+        // Nodes were indexed in ID order, so equally distant neighbors come by ID.
+        let candidates: Vec<(NodeId, Vector2D)> = index
+            .k_nearest(position, self.options.neighbor_count + 1)
+            .into_iter()
+            .filter(|(p, id)| {
+                return **id != node_id
+                    && (*p - position).magnitude() <= self.options.max_connection_distance;
+            })
+            .map(|(p, id)| (*id, *p))
+            .take(self.options.neighbor_count)
+            .collect();
+
+        for (other_id, other) in candidates {
+            if roadmap.edges_between(&node_id, &other_id).is_empty()
+                && grid_map.is_segment_vacant(position, &other)
+            {
+                roadmap
+                    .add_edge(
+                        node_id,
+                        other_id,
+                        TopologyEdge::from_waypoints(vec![*position, other]),
+                    )
+                    .unwrap();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use map::{
+        grid::grid_map::{GridMap, GridMapCellState},
+        topology::topology_planning::TopologyPlanning,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Prm, PrmOptions};
+
+    #[test]
+    fn plan_on_roadmap() {
+        // Vertical wall at x in [4, 5) with a gap at the top, in a 10 x 10 map of 1 m cells.
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        for r in 2..10 {
+            *grid_map.get_by_cell_mut(r, 4).unwrap().state_mut() = GridMapCellState::Occupied;
+        }
+
+        let prm = Prm::new(PrmOptions {
+            sample_count: 100,
+            neighbor_count: 8,
+            max_connection_distance: 4_f64,
+        });
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut roadmap = prm.build_roadmap(&mut rng, &grid_map);
+        assert_eq!(roadmap.get_node_count(), 100);
+        assert!(roadmap.get_edges().values().all(|e| {
+            let waypoints = e.edge_info().get_waypoints();
+            return grid_map.is_segment_vacant(&waypoints[0], &waypoints[1]);
+        }));
+
+        let start = prm
+            .connect(&mut roadmap, &grid_map, Vector2D::from_xy(1_f64, 1_f64))
+            .unwrap();
+        let goal = prm
+            .connect(&mut roadmap, &grid_map, Vector2D::from_xy(8_f64, 1_f64))
+            .unwrap();
+        let route = roadmap.plan_route(start, goal).unwrap();
+        let waypoints = roadmap.route_waypoints(&route);
+        assert_eq!(waypoints[0], Vector2D::from_xy(1_f64, 1_f64));
+        assert!(waypoints.iter().any(|p| p.y >= 8_f64));
+
+        assert!(prm
+            .connect(&mut roadmap, &grid_map, Vector2D::from_xy(4.5, 5_f64))
+            .is_none());
+    }
+}
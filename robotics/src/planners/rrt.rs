@@ -0,0 +1,324 @@
+use core::numerics::{vector::Vector, vector2d::Vector2D};
+use std::fmt;
+
+use map::grid::grid_map::GridMap;
+use math::{geometry::aabb2d::AABB2D, sampling::sample_in_rectangle};
+use rand::Rng;
+
+/// Parameters of `Rrt`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RrtOptions {
+    /// Longest edge added to the tree, towards each sample.
+    pub step_size: f64,
+
+    /// Probability of sampling the goal instead of a random point.
+    pub goal_bias: f64,
+
+    /// Distance from the goal at which a node may connect to it.
+    pub goal_tolerance: f64,
+
+    pub max_iterations: usize,
+
+    /// Radius within which RRT* picks the cheapest parent for each new node and rewires its
+    /// neighbors through it. `None` plans with plain RRT, returning the first path found.
+    pub rewire_radius: Option<f64>,
+}
+
+impl RrtOptions {
+    /// Plain RRT, returning the first path found.
+    pub fn rrt(step_size: f64) -> Self {
+        return Self {
+            step_size: step_size,
+            goal_bias: 0.05,
+            goal_tolerance: step_size,
+            max_iterations: 10000,
+            rewire_radius: None,
+        };
+    }
+
+    /// RRT*, improving the path until the iterations are used up.
+    pub fn rrt_star(step_size: f64) -> Self {
+        return Self {
+            rewire_radius: Some(2_f64 * step_size),
+            ..Self::rrt(step_size)
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RrtError {
+    /// The start position is outside of the map or not vacant.
+    InvalidStart,
+    /// The goal position is outside of the map or not vacant.
+    InvalidGoal,
+    /// No path was found within the iterations.
+    NoPath,
+}
+
+impl fmt::Display for RrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            RrtError::InvalidStart => write!(f, "start position is not vacant"),
+            RrtError::InvalidGoal => write!(f, "goal position is not vacant"),
+            RrtError::NoPath => write!(f, "no path found within the iterations"),
+        };
+    }
+}
+
+impl std::error::Error for RrtError {}
+
+/// Rapidly-exploring random tree planner in the planar coordinates of a grid map.
+/// Edges are straight segments that only pass through vacant cells.
+pub struct Rrt {
+    options: RrtOptions,
+}
+
+/// Tree of collision-free positions rooted at the start.
+struct Tree {
+    positions: Vec<Vector2D>,
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    costs: Vec<f64>,
+}
+
+impl Tree {
+    fn nearest(&self, point: &Vector2D) -> usize {
+        let mut ret = 0;
+        let mut min_distance = f64::INFINITY;
+        for (i, p) in self.positions.iter().enumerate() {
+            let distance = (p - point).magnitude();
+            if distance < min_distance {
+                min_distance = distance;
+                ret = i;
+            }
+        }
+
+        return ret;
+    }
+
+    fn within(&self, point: &Vector2D, radius: f64) -> Vec<usize> {
+        return (0..self.positions.len())
+            .filter(|i| (self.positions[*i] - point).magnitude() <= radius)
+            .collect();
+    }
+
+    fn add(&mut self, position: Vector2D, parent: usize) -> usize {
+        let cost = self.costs[parent] + (position - self.positions[parent]).magnitude();
+        self.positions.push(position);
+        self.parents.push(Some(parent));
+        self.children.push(Vec::new());
+        self.costs.push(cost);
+        self.children[parent].push(self.positions.len() - 1);
+        return self.positions.len() - 1;
+    }
+
+    /// Attach node to a new parent and update the costs of its descendants.
+    fn reparent(&mut self, node: usize, parent: usize) {
+        let delta = self.costs[parent]
+            + (self.positions[node] - self.positions[parent]).magnitude()
+            - self.costs[node];
+        if let Some(old_parent) = self.parents[node] {
+            self.children[old_parent].retain(|child| *child != node);
+        }
+        self.parents[node] = Some(parent);
+        self.children[parent].push(node);
+
+        let mut stack: Vec<usize> = vec![node];
+        while let Some(current) = stack.pop() {
+            self.costs[current] += delta;
+            stack.extend(self.children[current].iter());
+        }
+    }
+
+    fn path_to(&self, node: usize) -> Vec<Vector2D> {
+        let mut ret: Vec<Vector2D> = vec![self.positions[node]];
+        let mut current = node;
+        while let Some(parent) = self.parents[current] {
+            ret.push(self.positions[parent]);
+            current = parent;
+        }
+
+        ret.reverse();
+        return ret;
+    }
+}
+
+impl Rrt {
+    pub fn new(options: RrtOptions) -> Self {
+        return Self { options: options };
+    }
+
+    /// Plan from start to goal, drawing samples from rng only, so a seeded generator
+    /// reproduces the path. Returns the positions from start to goal, both included.
+    pub fn plan<R: Rng>(
+        &self,
+        rng: &mut R,
+        grid_map: &GridMap,
+        start: Vector2D,
+        goal: Vector2D,
+    ) -> Result<Vec<Vector2D>, RrtError> {
+        if !grid_map.is_segment_vacant(&start, &start) {
+            return Err(RrtError::InvalidStart);
+        }
+        if !grid_map.is_segment_vacant(&goal, &goal) {
+            return Err(RrtError::InvalidGoal);
+        }
+
+        let bounds = AABB2D::new(
+            Vector2D::zero(),
+            Vector2D::from_xy(
+                grid_map.horizontal_cells() as f64 * grid_map.cell_size(),
+                grid_map.vertical_cells() as f64 * grid_map.cell_size(),
+            ),
+        );
+        let mut tree = Tree {
+            positions: vec![start],
+            parents: vec![None],
+            children: vec![Vec::new()],
+            costs: vec![0_f64],
+        };
+
+        let mut connected = false;
+
+        for _ in 0..self.options.max_iterations {
+            let sample = if rng.gen::<f64>() < self.options.goal_bias {
+                goal
+            } else {
+                sample_in_rectangle(rng, &bounds)
+            };
+
+            let nearest = tree.nearest(&sample);
+            let offset = sample - tree.positions[nearest];
+            let position = if offset.magnitude() > self.options.step_size {
+                tree.positions[nearest] + offset.unit_vector() * self.options.step_size
+            } else {
+                sample
+            };
+            if !grid_map.is_segment_vacant(&tree.positions[nearest], &position) {
+                continue;
+            }
+
+            match self.options.rewire_radius {
+                None => {
+                    tree.add(position, nearest);
+                }
+                Some(radius) => {
+                    let near = tree.within(&position, radius);
+                    let mut parent = nearest;
+                    let mut cost =
+                        tree.costs[nearest] + (position - tree.positions[nearest]).magnitude();
+                    for candidate in near.iter() {
+                        let candidate_cost = tree.costs[*candidate]
+                            + (position - tree.positions[*candidate]).magnitude();
+                        if candidate_cost < cost
+                            && grid_map.is_segment_vacant(&tree.positions[*candidate], &position)
+                        {
+                            parent = *candidate;
+                            cost = candidate_cost;
+                        }
+                    }
+
+                    let node = tree.add(position, parent);
+                    for candidate in near {
+                        let rewired_cost =
+                            cost + (position - tree.positions[candidate]).magnitude();
+                        if rewired_cost < tree.costs[candidate]
+                            && grid_map.is_segment_vacant(&position, &tree.positions[candidate])
+                        {
+                            tree.reparent(candidate, node);
+                        }
+                    }
+                }
+            };
+
+            if (goal - position).magnitude() <= self.options.goal_tolerance
+                && grid_map.is_segment_vacant(&position, &goal)
+            {
+                connected = true;
+                if self.options.rewire_radius.is_none() {
+                    break;
+                }
+            }
+        }
+
+        if !connected {
+            return Err(RrtError::NoPath);
+        }
+
+        // Rewiring may have lowered the cost of any node, so pick the cheapest connection last.
+        let node = tree
+            .within(&goal, self.options.goal_tolerance)
+            .into_iter()
+            .filter(|i| grid_map.is_segment_vacant(&tree.positions[*i], &goal))
+            .min_by(|a, b| {
+                let cost_a = tree.costs[*a] + (goal - tree.positions[*a]).magnitude();
+                let cost_b = tree.costs[*b] + (goal - tree.positions[*b]).magnitude();
+                return cost_a.total_cmp(&cost_b);
+            })
+            .unwrap();
+
+        let mut ret = tree.path_to(node);
+        if *ret.last().unwrap() != goal {
+            ret.push(goal);
+        }
+        return Ok(ret);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::{vector::Vector, vector2d::Vector2D};
+
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Rrt, RrtError, RrtOptions};
+
+    fn path_length(path: &[Vector2D]) -> f64 {
+        return path.windows(2).map(|p| (p[1] - p[0]).magnitude()).sum();
+    }
+
+    #[test]
+    fn plan_around_wall() {
+        // Vertical wall at x in [4, 5) with a gap at the top, in a 10 x 10 map of 1 m cells.
+        let mut grid_map = GridMap::with_cell_state(10, 10, 1_f64, GridMapCellState::Vacant);
+        for r in 2..10 {
+            *grid_map.get_by_cell_mut(r, 4).unwrap().state_mut() = GridMapCellState::Occupied;
+        }
+
+        let start = Vector2D::from_xy(1_f64, 1_f64);
+        let goal = Vector2D::from_xy(8_f64, 1_f64);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let rrt_path = Rrt::new(RrtOptions::rrt(1_f64))
+            .plan(&mut rng, &grid_map, start, goal)
+            .unwrap();
+        let options = RrtOptions {
+            max_iterations: 2000,
+            ..RrtOptions::rrt_star(1_f64)
+        };
+        let rrt_star_path = Rrt::new(options)
+            .plan(&mut rng, &grid_map, start, goal)
+            .unwrap();
+
+        for path in [&rrt_path, &rrt_star_path] {
+            assert_eq!(path[0], start);
+            assert_eq!(*path.last().unwrap(), goal);
+            assert!(path
+                .windows(2)
+                .all(|p| grid_map.is_segment_vacant(&p[0], &p[1])));
+        }
+
+        // Shortest path goes around the wall corner at (5, 8), about 17.4 long.
+        assert!(path_length(&rrt_star_path) < 20_f64);
+        assert_eq!(
+            Rrt::new(RrtOptions::rrt(1_f64)).plan(
+                &mut rng,
+                &grid_map,
+                Vector2D::from_xy(4.5, 5_f64),
+                goal
+            ),
+            Err(RrtError::InvalidStart)
+        );
+    }
+}
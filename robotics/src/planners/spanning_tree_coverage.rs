@@ -0,0 +1,170 @@
+use core::numerics::vector2d::Vector2D;
+
+use map::grid::grid_map::{GridMap, GridMapCellState};
+use math::geometry::polyline2d::Polyline2D;
+
+use super::boustrophedon_coverage::CoverageError;
+
+/// Offsets (row, column) of the neighbors of a coarse cell, in the order up, right, down, left.
+static OFFSETS: [[isize; 2]; 4] = [[-1, 0], [0, 1], [1, 0], [0, -1]];
+
+const UP: usize = 0;
+const RIGHT: usize = 1;
+const DOWN: usize = 2;
+const LEFT: usize = 3;
+
+/// Spanning tree coverage (STC) by Gabriely and Rimon, for grid maps whose cells are the
+/// size of the robot. Blocks of 2 x 2 cells that are entirely vacant form a coarse grid; the
+/// path circumnavigates a spanning tree of the coarse grid, visiting every cell of the
+/// blocks exactly once and returning to the start. Partially occupied blocks are left out.
+pub struct SpanningTreeCoverage;
+
+impl SpanningTreeCoverage {
+    pub fn new() -> Self {
+        return Self;
+    }
+
+    /// Plan a closed coverage path from start, as planar coordinates of cell centers.
+    pub fn plan(&self, grid_map: &GridMap, start: &Vector2D) -> Result<Polyline2D, CoverageError> {
+        let rows = grid_map.vertical_cells() / 2;
+        let columns = grid_map.horizontal_cells() / 2;
+        let is_free = |r: usize, c: usize| -> bool {
+            return (0..4).all(|i| {
+                matches!(
+                    grid_map
+                        .get_by_cell(2 * r + i / 2, 2 * c + i % 2)
+                        .unwrap()
+                        .state(),
+                    GridMapCellState::Vacant
+                )
+            });
+        };
+
+        let start_cell = match grid_map.coordinate_to_cell(start.x, start.y) {
+            Some((r, c)) if r / 2 < rows && c / 2 < columns && is_free(r / 2, c / 2) => (r, c),
+            _ => return Err(CoverageError::InvalidStart),
+        };
+
+        // Depth first spanning tree of the free coarse cells reachable from the start, stored
+        // as the tree edges leaving each coarse cell in the directions of OFFSETS.
+        let mut links: Vec<[bool; 4]> = vec![[false; 4]; rows * columns];
+        let mut visited: Vec<bool> = vec![false; rows * columns];
+        let mut stack: Vec<((usize, usize), Option<usize>)> =
+            vec![((start_cell.0 / 2, start_cell.1 / 2), None)];
+        while let Some(((r, c), from_direction)) = stack.pop() {
+            if visited[r * columns + c] {
+                continue;
+            }
+            visited[r * columns + c] = true;
+            if let Some(direction) = from_direction {
+                // direction leads from the parent to this cell.
+                let [dr, dc] = OFFSETS[direction];
+                let parent = (r.wrapping_add_signed(-dr), c.wrapping_add_signed(-dc));
+                links[parent.0 * columns + parent.1][direction] = true;
+                links[r * columns + c][(direction + 2) % 4] = true;
+            }
+
+            for direction in (0..4).rev() {
+                let [dr, dc] = OFFSETS[direction];
+                match (r.checked_add_signed(dr), c.checked_add_signed(dc)) {
+                    (Some(nr), Some(nc))
+                        if nr < rows
+                            && nc < columns
+                            && !visited[nr * columns + nc]
+                            && is_free(nr, nc) =>
+                    {
+                        stack.push(((nr, nc), Some(direction)));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Walk around the tree. Each cell of a block joins the cell beside it in the same
+        // block unless a tree edge passes between them, and the cell beside it in the next
+        // block if a tree edge leads there, which gives every cell two neighbors on one cycle.
+        let next_cells = |(r, c): (usize, usize)| -> [(usize, usize); 2] {
+            let link = &links[(r / 2) * columns + c / 2];
+            let (vertical, horizontal) = (
+                if r % 2 == 0 { UP } else { DOWN },
+                if c % 2 == 0 { LEFT } else { RIGHT },
+            );
+            let across = if link[vertical] {
+                let [dr, _] = OFFSETS[vertical];
+                (r.wrapping_add_signed(dr), c)
+            } else {
+                (r, c ^ 1)
+            };
+            let along = if link[horizontal] {
+                let [_, dc] = OFFSETS[horizontal];
+                (r, c.wrapping_add_signed(dc))
+            } else {
+                (r ^ 1, c)
+            };
+            return [across, along];
+        };
+
+        let mut cells: Vec<(usize, usize)> = vec![start_cell];
+        let mut previous = start_cell;
+        let mut current = next_cells(start_cell)[0];
+        while current != start_cell {
+            cells.push(current);
+            let [a, b] = next_cells(current);
+            let next = if a == previous { b } else { a };
+            previous = current;
+            current = next;
+        }
+        cells.push(start_cell);
+
+        return Ok(Polyline2D::new(
+            cells
+                .into_iter()
+                .map(|(r, c)| {
+                    let (x, y) = grid_map.cell_to_coordinate(r, c);
+                    return Vector2D::from_xy(x, y);
+                })
+                .collect(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::numerics::vector2d::Vector2D;
+
+    use map::grid::grid_map::{GridMap, GridMapCellState};
+
+    use super::SpanningTreeCoverage;
+    use crate::planners::boustrophedon_coverage::CoverageError;
+
+    #[test]
+    fn covers_every_free_block_once() {
+        let mut grid_map = GridMap::with_cell_state(8, 8, 0.5, GridMapCellState::Vacant);
+        // Occupies parts of two blocks, which are left out entirely.
+        for (r, c) in [(2, 3), (3, 4)] {
+            *grid_map.get_by_cell_mut(r, c).unwrap().state_mut() = GridMapCellState::Occupied;
+        }
+
+        let start = Vector2D::from_xy(0.25, 0.25);
+        let path = SpanningTreeCoverage::new().plan(&grid_map, &start).unwrap();
+        assert_eq!(path.points.len(), 64 - 8 + 1);
+        assert_eq!(path.points[0], start);
+        assert_eq!(path.points.last(), Some(&start));
+
+        let mut visited = vec![vec![false; 8]; 8];
+        for point in path.points[1..].iter() {
+            let (r, c) = grid_map.coordinate_to_cell(point.x, point.y).unwrap();
+            assert!(!visited[r][c]);
+            visited[r][c] = true;
+            assert!(!(2..4).contains(&r) || !(2..6).contains(&c));
+        }
+        for segment in path.segments() {
+            assert!((segment.length() - 0.5).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            SpanningTreeCoverage::new().plan(&grid_map, &Vector2D::from_xy(1.25, 2.75)),
+            Err(CoverageError::InvalidStart)
+        );
+    }
+}